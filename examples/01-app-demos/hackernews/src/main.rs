@@ -138,7 +138,8 @@ fn Preview(story_id: ReadSignal<i64>) -> Element {
     rsx! {
         div { padding: "0.5rem",
             div { font_size: "1.5rem", a { href: story.item.url, "{story.item.title}" } }
-            if let Some(text) = &story.item.text { div { dangerous_inner_html: "{text}" } }
+            // Hacker News sends the text of stories and comments as HTML
+            if let Some(text) = &story.item.text { div { dangerous_inner_html: UnsafeHtml(text.clone()) } }
             for comment in story.item.kids.iter().copied() {
                 ChildrenOrLoading {
                     key: "{comment}",
@@ -171,7 +172,7 @@ fn Comment(comment: ReadSignal<i64>) -> Element {
     rsx! {
         div { padding: "0.5rem",
             div { color: "gray", "by {by}" }
-            div { dangerous_inner_html: "{text}" }
+            div { dangerous_inner_html: UnsafeHtml(text) }
             for comment in kids.iter().copied() {
                 ChildrenOrLoading {
                     key: "{comment}",
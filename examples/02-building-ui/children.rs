@@ -3,6 +3,10 @@
 //! Any component can take a `children: Element` prop. Anything nested inside the component
 //! in `rsx!` is passed in as `children`. This is how you build reusable layout and container
 //! components like cards, modals, or page shells.
+//!
+//! Components can also accept several independent slots by taking more than one `Element` prop.
+//! Named slots are passed with `rsx!` like any other prop while the unnamed children still go to
+//! `children`.
 
 use dioxus::prelude::*;
 
@@ -25,6 +29,17 @@ fn app() -> Element {
         Section { title: "Reusable layout",
             p { "The section component takes both a title prop and children." }
         }
+
+        Panel {
+            header: rsx! { h3 { "Named slots" } },
+            footer: rsx! { button { "Close" } },
+            p { "The header and footer are passed as props, the rest goes to children." }
+        }
+
+        Panel {
+            header: rsx! { h3 { "Optional slots" } },
+            p { "The footer slot has a default, so it can be left out." }
+        }
     }
 }
 
@@ -50,3 +65,17 @@ fn Section(title: String, children: Element) -> Element {
         }
     }
 }
+
+#[component]
+fn Panel(header: Element, #[props(default)] footer: Element, children: Element) -> Element {
+    rsx! {
+        div {
+            border: "1px solid #ccc",
+            border_radius: "8px",
+            margin: "8px 0",
+            header { padding: "12px", border_bottom: "1px solid #ccc", {header} }
+            div { padding: "12px", {children} }
+            footer { padding: "12px", {footer} }
+        }
+    }
+}
@@ -61,6 +61,11 @@ fn app() -> Element {
             oninput: move |e| async move { _ = socket.send(ClientEvent::TextInput(e.value())).await; },
         }
         button { onclick: move |_| messages.clear(), "Clear messages" }
+        if socket.is_closed() {
+            button { onclick: move |_| socket.reconnect(), "Reconnect" }
+        } else {
+            button { onclick: move |_| async move { _ = socket.close().await; }, "Disconnect" }
+        }
         for message in messages.read().iter().rev() {
             pre { "{message:?}" }
         }
@@ -0,0 +1,55 @@
+//! Handling IME composition
+//!
+//! Input methods for languages like Chinese, Japanese, and Korean compose text over several key presses. While the
+//! user is composing, the browser fires `input` and `keydown` events for text that isn't final yet. This example
+//! tracks composition with `oncompositionstart` and `oncompositionend` and ignores input events until the composed
+//! text is committed.
+
+use dioxus::prelude::*;
+
+fn main() {
+    dioxus::launch(app);
+}
+
+fn app() -> Element {
+    let mut composing = use_signal(|| false);
+    let mut preview = use_signal(String::new);
+    let mut committed = use_signal(Vec::<String>::new);
+    let mut text = use_signal(String::new);
+
+    rsx! {
+        h1 { "IME composition" }
+        input {
+            placeholder: "Type with an input method, then press enter",
+            value: "{text}",
+            oncompositionstart: move |_| composing.set(true),
+            oncompositionupdate: move |event| preview.set(event.data()),
+            oncompositionend: move |event| {
+                composing.set(false);
+                preview.set(String::new());
+                text.set(format!("{text}{}", event.data()));
+            },
+            oninput: move |event| {
+                // The value is still being composed, so wait for compositionend
+                if composing() {
+                    return;
+                }
+                text.set(event.value());
+            },
+            onkeydown: move |event| {
+                // Enter is used to confirm the composition, so it shouldn't submit the text
+                if event.key() == Key::Enter && !event.is_composing() && !composing() {
+                    committed.push(text.take());
+                }
+            },
+        }
+        if composing() {
+            p { "Composing: {preview}" }
+        }
+        ul {
+            for entry in committed.iter() {
+                li { "{entry}" }
+            }
+        }
+    }
+}
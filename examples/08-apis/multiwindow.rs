@@ -3,6 +3,9 @@
 //! This example shows how to implement a simple multiwindow application using dioxus.
 //! This works by spawning a new window when the user clicks a button. We have to build a new virtualdom which has its
 //! own context, root elements, etc.
+//!
+//! Signals can be shared between windows by passing them into the new virtualdom as root context. Writing to the
+//! signal in one window will rerender the components that read it in every window.
 
 use dioxus::prelude::*;
 
@@ -11,22 +14,28 @@ fn main() {
 }
 
 fn app() -> Element {
+    let mut count = use_signal(|| 0);
+
     let onclick = move |_| {
-        dioxus::desktop::window().new_window(VirtualDom::new(popup), Default::default());
+        let dom = VirtualDom::new(popup).with_root_context(count);
+        dioxus::desktop::window().new_window(dom, Default::default());
     };
 
     rsx! {
         button { onclick, "New Window" }
+        p { "Count: {count}" }
+        button { onclick: move |_| count += 1, "Increment" }
     }
 }
 
 fn popup() -> Element {
-    let mut count = use_signal(|| 0);
+    let mut count = use_context::<Signal<i32>>();
     rsx! {
         div {
             h1 { "Popup Window" }
             p { "Count: {count}" }
             button { onclick: move |_| count += 1, "Increment" }
+            button { onclick: move |_| dioxus::desktop::window().close(), "Close" }
         }
     }
 }
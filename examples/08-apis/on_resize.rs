@@ -1,8 +1,7 @@
-//! Run a callback
+//! Respond to the size of an element changing
 //!
-//! Whenever an Element is finally mounted to the Dom, its data is available to be read.
-//! These fields can typically only be read asynchronously, since various renderers need to release the main thread to
-//! perform layout and painting.
+//! The `onresize` event fires whenever the observed element changes size, not just when the window does. This lets
+//! components adapt to their container instead of the viewport. Resize the window to see the layout switch.
 
 use dioxus::prelude::*;
 use dioxus_elements::geometry::euclid::Size2D;
@@ -22,6 +21,11 @@ fn app() -> Element {
             background_color: "red",
             onresize: move |evt| dimensions.set(evt.data().get_content_box_size().unwrap()),
             "This element is {dimensions():?}"
+            if dimensions().width < 400.0 {
+                p { "Compact layout" }
+            } else {
+                p { "Wide layout" }
+            }
         }
     )
 }
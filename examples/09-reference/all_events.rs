@@ -4,7 +4,7 @@
 //! on adding interactivity to your own application.
 
 use dioxus::prelude::*;
-use std::{collections::VecDeque, fmt::Debug, rc::Rc};
+use std::{collections::VecDeque, fmt::Debug, rc::Rc, time::Duration};
 
 const STYLE: Asset = asset!("/examples/assets/events.css");
 
@@ -25,6 +25,12 @@ fn app() -> Element {
         events.write().push_back(event);
     };
 
+    // Mouse moves fire constantly, so only log the latest one every 100ms instead of flooding the log
+    let log_mousemove =
+        use_throttled_callback(Duration::from_millis(100), move |event: MouseEvent| {
+            log_event(event.data())
+        });
+
     let random_text = "This is some random repeating text. ".repeat(1000);
 
     rsx! {
@@ -32,7 +38,11 @@ fn app() -> Element {
         div { id: "container",
             // focusing is necessary to catch keyboard events
             div { id: "receiver", tabindex: 0,
-                onmousemove: move |event| log_event(event.data()),
+                // Focus the receiver as soon as it is mounted so keyboard events are logged right away
+                onmounted: move |event| async move {
+                    _ = event.set_focus(true).await;
+                },
+                onmousemove: log_mousemove,
                 onclick: move |event| log_event(event.data()),
                 ondoubleclick: move |event| log_event(event.data()),
                 onmousedown: move |event| log_event(event.data()),
@@ -84,12 +94,14 @@ fn app() -> Element {
                     }
                     label {
                         r#for: "paste-input",
-                        "Paste into this field to read the clipboard text (and any files)"
+                        "Copy, cut or paste in this field to read the clipboard text (and any files)"
                     }
                     input {
                         id: "paste-input",
                         style: "font: inherit; padding: 8px 10px;",
                         onpaste: move |event| log_event(event.data()),
+                        oncopy: move |event| log_event(event.data()),
+                        oncut: move |event| log_event(event.data()),
                     }
                 }
                 div {
@@ -97,6 +109,9 @@ fn app() -> Element {
                     onscroll: move |event: Event<ScrollData>| {
                         log_event(event.data());
                     },
+                    onscrollend: move |event: Event<ScrollData>| {
+                        log_event(event.data());
+                    },
                     div { style: "margin: 20px; padding: 15px; border: 1px solid #ccc; border-radius: 5px;",
                         p { "{random_text}" }
                     }
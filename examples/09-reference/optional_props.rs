@@ -37,6 +37,22 @@ fn app() -> Element {
             c: "asd".to_string(),
             d: Some("asd".to_string()),
         }
+
+        // `#[props(into)]` lets us pass a `&str` where a `String` is expected
+        Badge { label: "new" }
+        Badge { label: "sale", color: "green" }
+    }
+}
+
+#[component]
+fn Badge(
+    // Required props are checked at compile time. Leaving out `label` is an error, not a runtime panic.
+    #[props(into)] label: String,
+    // `default = expr` is only evaluated when the prop is omitted
+    #[props(into, default = "red".to_string())] color: String,
+) -> Element {
+    rsx! {
+        span { background_color: "{color}", padding: "2px 6px", "{label}" }
     }
 }
 
@@ -569,6 +569,7 @@ impl<'a> Writer<'a> {
         match attr {
             AttributeName::BuiltIn(name) => write!(self.out, "{}", name),
             AttributeName::Custom(name) => write!(self.out, "{}", name.to_token_stream()),
+            AttributeName::Bind { name, .. } => write!(self.out, "bind:{}", name),
             AttributeName::Spread(_) => unreachable!(),
         }
     }
@@ -883,6 +884,7 @@ impl<'a> Writer<'a> {
                     name.len()
                 }
                 AttributeName::Custom(name) => name.value().len() + 2,
+                AttributeName::Bind { name, .. } => name.to_string().len() + 5,
                 AttributeName::Spread(_) => unreachable!(),
             };
 
@@ -6,7 +6,10 @@ use std::path::Path;
 use crate::opt::css::{process_css_module, process_scss};
 
 use super::{
-    css::process_css, folder::process_folder, image::process_image, js::process_js,
+    css::process_css,
+    folder::process_folder,
+    image::{process_image, process_responsive_images},
+    js::process_js,
     json::process_json,
 };
 
@@ -91,6 +94,10 @@ pub(crate) fn process_file_to(
     std::fs::rename(temp_path, output_path)
         .with_context(|| format!("Failed to rename output file to: {}", output_path.display()))?;
 
+    if let ResolvedAssetType::Image(options) = &resolved_options {
+        process_responsive_images(options, source, output_path)?;
+    }
+
     Ok(())
 }
 
@@ -1,18 +1,15 @@
 use anyhow::Context;
-use image::{DynamicImage, EncodableLayout};
-use manganis_core::{ImageAssetOptions, ImageFormat, ImageSize};
+use image::{DynamicImage, EncodableLayout, GenericImageView, ImageResult};
+use manganis_core::{ImageAssetOptions, ImageFormat, ImageSize, responsive_image_path};
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn process_image(
     image_options: &ImageAssetOptions,
     source: &Path,
     output_path: &Path,
 ) -> anyhow::Result<()> {
-    let mut image = image::ImageReader::new(std::io::Cursor::new(&*std::fs::read(source)?))
-        .with_guessed_format()
-        .context("Failed to guess image format")?
-        .decode();
+    let mut image = decode_image(source)?;
 
     if let Ok(image) = &mut image {
         if let ImageSize::Manual { width, height } = image_options.size() {
@@ -20,7 +17,60 @@ pub(crate) fn process_image(
         }
     }
 
-    match (image, image_options.format()) {
+    save_image(image, image_options.format(), source, output_path)
+}
+
+/// Write a resized copy of the image next to the output path for each of the responsive widths in the options
+pub(crate) fn process_responsive_images(
+    image_options: &ImageAssetOptions,
+    source: &Path,
+    output_path: &Path,
+) -> anyhow::Result<()> {
+    let mut widths = image_options.responsive_widths().peekable();
+    if widths.peek().is_none() {
+        return Ok(());
+    }
+
+    let image = decode_image(source)?.context("Failed to decode image")?;
+    let (width, height) = match image_options.size() {
+        ImageSize::Manual { width, height } => (width, height),
+        ImageSize::Automatic => image.dimensions(),
+    };
+
+    for variant_width in widths {
+        // Keep the aspect ratio of the full size image
+        let variant_height = (height as u64 * variant_width as u64 / width.max(1) as u64).max(1);
+        let variant = image.resize_exact(
+            variant_width,
+            variant_height as u32,
+            image::imageops::FilterType::Lanczos3,
+        );
+        let variant_path = PathBuf::from(responsive_image_path(
+            &output_path.to_string_lossy(),
+            variant_width,
+        ));
+        save_image(Ok(variant), image_options.format(), source, &variant_path)?;
+    }
+
+    Ok(())
+}
+
+fn decode_image(source: &Path) -> anyhow::Result<ImageResult<DynamicImage>> {
+    Ok(
+        image::ImageReader::new(std::io::Cursor::new(&*std::fs::read(source)?))
+            .with_guessed_format()
+            .context("Failed to guess image format")?
+            .decode(),
+    )
+}
+
+fn save_image(
+    image: ImageResult<DynamicImage>,
+    format: ImageFormat,
+    source: &Path,
+    output_path: &Path,
+) -> anyhow::Result<()> {
+    match (image, format) {
         (image, ImageFormat::Png) => {
             compress_png(image.context("Failed to decode image")?, output_path);
         }
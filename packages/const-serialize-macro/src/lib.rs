@@ -18,6 +18,24 @@ fn add_bounds(where_clause: &mut Option<WhereClause>, generics: &Generics, krate
     }
 }
 
+/// Check if a field is marked with `#[const_serialize(default)]`
+fn field_has_default(field: &syn::Field) -> syn::Result<bool> {
+    let mut default = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("const_serialize") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    default = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown const_serialize field attribute"))
+                }
+            })?;
+        }
+    }
+    Ok(default)
+}
+
 /// Derive the const serialize trait for a struct
 #[proc_macro_derive(SerializeConst, attributes(const_serialize))]
 pub fn derive_parse(raw_input: TokenStream) -> TokenStream {
@@ -65,6 +83,17 @@ pub fn derive_parse(raw_input: TokenStream) -> TokenStream {
                         })
                 });
                 let field_types = data.fields.iter().map(|field| &field.ty);
+                let mut field_defaults = Vec::new();
+                for field in &data.fields {
+                    match field_has_default(field) {
+                        Ok(true) => {
+                            let ty = &field.ty;
+                            field_defaults.push(quote! { .zeroed_if_missing::<#ty>() })
+                        }
+                        Ok(false) => field_defaults.push(quote! {}),
+                        Err(err) => return err.into_compile_error().into(),
+                    }
+                }
                 quote! {
                     unsafe impl #impl_generics #krate::SerializeConst for #ty #ty_generics #where_clause {
                         const MEMORY_LAYOUT: #krate::Layout = #krate::Layout::Struct(#krate::StructLayout::new(
@@ -74,7 +103,7 @@ pub fn derive_parse(raw_input: TokenStream) -> TokenStream {
                                     stringify!(#field_names),
                                     std::mem::offset_of!(#ty, #field_names),
                                     <#field_types as #krate::SerializeConst>::MEMORY_LAYOUT,
-                                ),
+                                )#field_defaults,
                             )*],
                         ));
                     }
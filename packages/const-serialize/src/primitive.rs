@@ -70,6 +70,23 @@ impl_serialize_const!(bool);
 impl_serialize_const!(f32);
 impl_serialize_const!(f64);
 
+/// A type where every byte being zero is a valid value. Struct fields of these types can be marked with
+/// `#[const_serialize(default)]` to decode as zero when they are missing from the serialized data.
+///
+/// # Safety
+/// The all zero bit pattern must be a valid value of the type
+pub unsafe trait Zeroable: SerializeConst {}
+
+macro_rules! impl_zeroable {
+    ($($type:ty),*) => {
+        $(unsafe impl Zeroable for $type {})*
+    };
+}
+
+impl_zeroable!(u8, u16, u32, u64, i8, i16, i32, i64, bool, f32, f64);
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+
 /// Serialize a primitive type that is stored at the pointer passed in
 pub(crate) const unsafe fn serialize_const_primitive(
     ptr: *const (),
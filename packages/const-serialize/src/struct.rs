@@ -6,6 +6,8 @@ pub struct StructFieldLayout {
     name: &'static str,
     offset: usize,
     layout: Layout,
+    /// Fill the field with zeros if it is missing from the serialized data
+    zeroed_if_missing: bool,
 }
 
 impl StructFieldLayout {
@@ -15,8 +17,16 @@ impl StructFieldLayout {
             name,
             offset,
             layout,
+            zeroed_if_missing: false,
         }
     }
+
+    /// Decode the field as zero if it is missing from the serialized data. This lets a struct gain new fields while
+    /// still decoding data that was serialized before the field existed.
+    pub const fn zeroed_if_missing<T: Zeroable>(mut self) -> Self {
+        self.zeroed_if_missing = true;
+        self
+    }
 }
 
 /// Layout for a struct. The struct layout is just a list of fields with offsets
@@ -48,6 +58,7 @@ pub(crate) const unsafe fn serialize_const_struct(
             name,
             offset,
             layout,
+            ..
         } = &layout.data[i];
         to = write_map_key(to, name);
         let field = ptr.wrapping_byte_add(*offset as _);
@@ -73,15 +84,29 @@ pub(crate) const fn deserialize_const_struct<'a>(
             name,
             offset,
             layout,
+            zeroed_if_missing,
         } = &layout.data[i];
-        let Ok(Some(from)) = map.find(name) else {
-            return None;
-        };
         let Some((_, field_bytes)) = out.split_at_mut_checked(*offset) else {
             return None;
         };
-        if deserialize_const_ptr(from, layout, field_bytes).is_none() {
-            return None;
+        match map.find(name) {
+            Ok(Some(from)) => {
+                if deserialize_const_ptr(from, layout, field_bytes).is_none() {
+                    return None;
+                }
+            }
+            Ok(None) if *zeroed_if_missing => {
+                let size = layout.size();
+                if field_bytes.len() < size {
+                    return None;
+                }
+                let mut byte = 0;
+                while byte < size {
+                    field_bytes[byte] = MaybeUninit::new(0);
+                    byte += 1;
+                }
+            }
+            _ => return None,
         }
         i += 1;
     }
@@ -196,3 +196,64 @@ fn test_adding_struct_field_non_breaking() {
         data2
     );
 }
+
+#[test]
+fn test_default_fields_can_be_missing() {
+    #[derive(Debug, PartialEq, SerializeConst)]
+    struct Initial {
+        a: u32,
+    }
+
+    #[derive(Debug, PartialEq, SerializeConst)]
+    struct New {
+        a: u32,
+        #[const_serialize(default)]
+        b: [u16; 2],
+        #[const_serialize(default)]
+        c: bool,
+    }
+
+    #[derive(Debug, PartialEq, SerializeConst)]
+    struct Required {
+        a: u32,
+        b: [u16; 2],
+    }
+
+    let mut buf = ConstVec::new();
+    buf = serialize_const(&Initial { a: 0x11111111 }, buf);
+    let buf = buf.as_ref();
+
+    // Data serialized before the fields were added decodes with the fields zeroed
+    let (_, data) = deserialize_const!(New, buf).unwrap();
+    assert_eq!(
+        data,
+        New {
+            a: 0x11111111,
+            b: [0, 0],
+            c: false,
+        }
+    );
+
+    // Fields without a default are still required
+    assert!(deserialize_const!(Required, buf).is_none());
+
+    // Fields that are present are decoded as usual
+    let mut buf = ConstVec::new();
+    buf = serialize_const(
+        &New {
+            a: 1,
+            b: [2, 3],
+            c: true,
+        },
+        buf,
+    );
+    let (_, data) = deserialize_const!(New, buf.as_ref()).unwrap();
+    assert_eq!(
+        data,
+        New {
+            a: 1,
+            b: [2, 3],
+            c: true,
+        }
+    );
+}
@@ -24,6 +24,12 @@ impl Parse for ComponentBody {
 impl ComponentBody {
     pub fn with_options(mut self, options: ComponentMacroOptions) -> Self {
         self.options = options;
+        if self.options.reactive
+            && !self.is_explicit_props_ident()
+            && !self.has_struct_parameter_pattern()
+        {
+            make_props_reactive(&mut self.item_fn);
+        }
         self
     }
 }
@@ -263,14 +269,28 @@ impl ComponentBody {
             }
         };
 
+        // Either defer to the custom comparison function or compare every field that isn't marked with `#[props(skip_eq)]`
+        let partial_eq_body = match &self.options.memo {
+            Some(memo) => quote! { #memo(self, other) },
+            None => {
+                let compared_field_idents = inputs
+                    .iter()
+                    .filter(|f| !has_skip_eq_attr(f))
+                    .map(make_prop_struct_field_idents);
+                quote! {
+                    #(
+                        self.#compared_field_idents == other.#compared_field_idents &&
+                    )*
+                    true
+                }
+            }
+        };
+
         let item_impl_partial_eq = parse_quote! {
             impl #generics ::core::cmp::PartialEq for #struct_ident #generic_arguments #where_clause {
                 #[inline]
                 fn eq(&self, other: &Self) -> bool {
-                    #(
-                        self.#struct_field_idents == other.#struct_field_idents &&
-                    )*
-                    true
+                    #partial_eq_body
                 }
             }
         };
@@ -534,6 +554,60 @@ fn make_prop_struct_field(f: &FnArg, vis: &Visibility) -> TokenStream {
     }
 }
 
+/// Check if a function arg has the `#[props(skip_eq)]` attribute
+fn has_skip_eq_attr(f: &FnArg) -> bool {
+    let FnArg::Typed(pt) = f else { unreachable!() };
+
+    pt.attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("props"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|expr| matches!(expr, Expr::Path(path) if path.path.is_ident("skip_eq")))
+}
+
+/// Check if a function arg has the `#[props(extends = ...)]` attribute
+fn has_extends_attr(f: &PatType) -> bool {
+    f.attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("props"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|expr| match expr {
+            Expr::Assign(assign) => {
+                matches!(assign.left.as_ref(), Expr::Path(path) if path.path.is_ident("extends"))
+            }
+            _ => false,
+        })
+}
+
+/// Wrap the type of every prop in a `ReadSignal` for `#[component(reactive)]`. Props that are already reactive or
+/// owned by the child, like signals, stores, and callbacks, children, and props that extend elements keep their type.
+fn make_props_reactive(item_fn: &mut ItemFn) {
+    for input in item_fn.sig.inputs.iter_mut() {
+        let FnArg::Typed(pt) = input else {
+            continue;
+        };
+        let is_children = matches!(pt.pat.as_ref(), Pat::Ident(ident) if ident.ident == "children");
+        let is_element = crate::props::last_segment_matches(&pt.ty, &parse_quote!(Element));
+        if is_children
+            || is_element
+            || has_extends_attr(pt)
+            || crate::props::reactive_prop_type(&pt.ty)
+        {
+            continue;
+        }
+        let ty = &pt.ty;
+        pt.ty = parse_quote!(dioxus_signals::ReadSignal<#ty>);
+    }
+}
+
 /// Get ident from a function arg
 fn make_prop_struct_field_idents(f: &FnArg) -> &Ident {
     // There's no receivers (&self) allowed in the component body
@@ -635,17 +709,34 @@ fn allow_camel_case_for_fn_ident(item_fn: &ItemFn) -> ItemFn {
 #[derive(Default)]
 pub struct ComponentMacroOptions {
     pub lazy: bool,
+    /// Wrap every prop in a `ReadSignal` so the component only reruns when a prop it reads changes
+    pub reactive: bool,
+    /// A function with the signature `fn(&Props, &Props) -> bool` used to compare the props instead of comparing
+    /// every field
+    pub memo: Option<Path>,
 }
 
 impl Parse for ComponentMacroOptions {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut lazy_load = false;
+        let mut reactive = false;
+        let mut memo = None;
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>()?;
             let ident_name = ident.to_string();
             if ident_name == "lazy" {
                 lazy_load = true;
+            } else if ident_name == "reactive" {
+                reactive = true;
+            } else if ident_name == "memo" {
+                input.parse::<Token![=]>()?;
+                // Accept both `memo = props_equal` and `memo = "props_equal"`
+                memo = Some(if input.peek(LitStr) {
+                    input.parse::<LitStr>()?.parse::<Path>()?
+                } else {
+                    input.parse::<Path>()?
+                });
             } else if ident_name == "no_case_check" {
                 // we used to have this?
             } else {
@@ -660,6 +751,10 @@ impl Parse for ComponentMacroOptions {
             }
         }
 
-        Ok(Self { lazy: lazy_load })
+        Ok(Self {
+            lazy: lazy_load,
+            reactive,
+            memo,
+        })
     }
 }
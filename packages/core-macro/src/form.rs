@@ -0,0 +1,138 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr};
+
+/// Implement `FormFields` with the names that serde deserializes each field from
+pub(crate) fn derive_form(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "Form can only be derived for structs with named fields",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "Form can only be derived for structs with named fields",
+        ));
+    };
+
+    let container = SerdeAttributes::parse(&input.attrs)?;
+    let mut names = Vec::new();
+    for field in &fields.named {
+        let attributes = SerdeAttributes::parse(&field.attrs)?;
+        if attributes.skip {
+            continue;
+        }
+        if attributes.flatten {
+            return Err(syn::Error::new_spanned(
+                field,
+                "Form doesn't support flattened fields. Add the fields of the inner struct to this struct instead",
+            ));
+        }
+        let name = match attributes.rename {
+            Some(name) => name,
+            None => {
+                let ident = field.ident.as_ref().expect("named fields have an ident");
+                let ident = ident.to_string();
+                let ident = ident.strip_prefix("r#").unwrap_or(&ident);
+                match &container.rename_all {
+                    Some(case) => rename_field(ident, case)?,
+                    None => ident.to_string(),
+                }
+            }
+        };
+        names.push(name);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics dioxus_elements::FormFields for #name #ty_generics #where_clause {
+            const FIELDS: &'static [&'static str] = &[#(#names),*];
+        }
+    })
+}
+
+/// The serde attributes that change which fields are deserialized and what they are called
+#[derive(Default)]
+struct SerdeAttributes {
+    rename: Option<String>,
+    rename_all: Option<LitStr>,
+    skip: bool,
+    flatten: bool,
+}
+
+impl SerdeAttributes {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    // Only `rename = "name"` changes the name fields are deserialized from. Ignore the other forms
+                    if let Ok(value) = meta.value() {
+                        attributes.rename = Some(value.parse::<LitStr>()?.value());
+                    }
+                } else if meta.path.is_ident("rename_all") {
+                    if let Ok(value) = meta.value() {
+                        attributes.rename_all = Some(value.parse::<LitStr>()?);
+                    }
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+                    attributes.skip = true;
+                } else if meta.path.is_ident("flatten") {
+                    attributes.flatten = true;
+                } else if meta.input.peek(syn::Token![=]) {
+                    // Skip the value of any other attribute
+                    meta.value()?.parse::<syn::Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|nested| {
+                        if nested.input.peek(syn::Token![=]) {
+                            nested.value()?.parse::<syn::Expr>()?;
+                        }
+                        Ok(())
+                    })?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attributes)
+    }
+}
+
+/// Rename a snake case field the same way `#[serde(rename_all = "...")]` does
+fn rename_field(field: &str, case: &LitStr) -> syn::Result<String> {
+    let pascal = || {
+        field
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect::<String>()
+    };
+
+    Ok(match case.value().as_str() {
+        "lowercase" | "snake_case" => field.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.replace('_', "-").to_ascii_uppercase(),
+        other => {
+            return Err(syn::Error::new_spanned(
+                case,
+                format!("unknown rename rule `rename_all = {other:?}`"),
+            ));
+        }
+    })
+}
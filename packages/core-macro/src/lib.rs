@@ -8,6 +8,7 @@ use quote::ToTokens;
 use syn::parse_macro_input;
 
 mod component;
+mod form;
 mod props;
 mod utils;
 
@@ -23,6 +24,16 @@ pub fn derive_props(input: TokenStream) -> TokenStream {
     }
 }
 
+#[doc = include_str!("../docs/form.md")]
+#[proc_macro_derive(Form)]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    match form::derive_form(&input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
 #[doc = include_str!("../docs/rsx.md")]
 #[proc_macro]
 pub fn rsx(tokens: TokenStream) -> TokenStream {
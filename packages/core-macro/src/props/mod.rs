@@ -292,6 +292,7 @@ mod field_info {
         pub default: Option<syn::Expr>,
         pub docs: Vec<syn::Attribute>,
         pub skip: bool,
+        pub skip_eq: bool,
         pub auto_into: bool,
         pub auto_to_string: bool,
         pub from_displayable: bool,
@@ -444,6 +445,7 @@ mod field_info {
                             }
                             handle_fields!(
                                 "skip", skip, "skipped";
+                                "skip_eq", skip_eq, "skipped in comparisons";
                                 "into", auto_into, "calling into() on the argument";
                                 "displayable", from_displayable, "calling to_string() on the argument";
                                 "strip_option", strip_option, "putting the argument in Some(...)";
@@ -694,6 +696,28 @@ mod struct_info {
                 })
                 .collect();
 
+            // Fields marked with `#[props(skip_eq)]` can change without rerunning the component. We move them into self
+            // before comparing, so they are always up to date and the PartialEq implementation sees them as equal, even
+            // if it was derived and compares every field
+            let skip_eq_fields: Vec<_> = self
+                .included_fields()
+                .filter(|f| {
+                    f.builder_attr.skip_eq
+                        && !looks_like_signal_type(f.ty)
+                        && !looks_like_callback_type(f.ty)
+                })
+                .map(|f| {
+                    let name = f.name;
+                    quote!(#name)
+                })
+                .collect();
+
+            let move_skip_eq_fields = quote! {
+                #(
+                    self.#skip_eq_fields = ::core::clone::Clone::clone(&new.#skip_eq_fields);
+                )*
+            };
+
             let move_event_handlers: TokenStream = event_handlers_fields.iter().map(|field| {
                 // If this is an optional event handler, we need to check if it's None before we try to update it
                 let optional = strip_option(field.ty).is_some();
@@ -720,6 +744,7 @@ mod struct_info {
             // If there are signals, we automatically try to memoize the signals
             if !signal_fields.is_empty() {
                 Ok(quote! {
+                    #move_skip_eq_fields
                     // First check if the fields are equal. This will compare the signal fields by pointer
                     let exactly_equal = self == new;
                     if exactly_equal {
@@ -753,6 +778,7 @@ mod struct_info {
                 })
             } else {
                 Ok(quote! {
+                    #move_skip_eq_fields
                     let equal = self == new;
                     // Move any signal and event fields into their old container.
                     #move_event_handlers
@@ -1759,12 +1785,19 @@ fn remove_option_wrapper(type_: Type) -> Type {
 }
 
 /// Check if a type should be owned by the child component after conversion
-fn child_owned_type(ty: &Type) -> bool {
+pub(crate) fn child_owned_type(ty: &Type) -> bool {
     looks_like_signal_type(ty) || looks_like_write_type(ty) || looks_like_callback_type(ty)
 }
 
+/// Check if a prop is already reactive or owned by the child, so `#[component(reactive)]` keeps its type. This
+/// includes optional signals, callbacks, and stores.
+pub(crate) fn reactive_prop_type(ty: &Type) -> bool {
+    let type_without_option = remove_option_wrapper(ty.clone());
+    child_owned_type(&type_without_option) || looks_like_store_type(&type_without_option)
+}
+
 /// Check if the path without generics matches the type we are looking for
-fn last_segment_matches(ty: &Type, expected: &Ident) -> bool {
+pub(crate) fn last_segment_matches(ty: &Type, expected: &Ident) -> bool {
     extract_base_type_without_generics(ty).is_some_and(|path_without_generics| {
         path_without_generics
             .segments
@@ -1835,6 +1868,20 @@ fn test_looks_like_type() {
     assert!(looks_like_callback_type(&parse_quote!(Callback<i32, u32>)));
 }
 
+#[test]
+fn test_reactive_prop_type() {
+    assert!(reactive_prop_type(&parse_quote!(ReadSignal<i32>)));
+    assert!(reactive_prop_type(&parse_quote!(Option<ReadSignal<i32>>)));
+    assert!(reactive_prop_type(&parse_quote!(Option<WriteSignal<i32>>)));
+    assert!(reactive_prop_type(&parse_quote!(EventHandler<MouseEvent>)));
+    assert!(reactive_prop_type(&parse_quote!(Store<Vec<i32>>)));
+    assert!(reactive_prop_type(&parse_quote!(ReadStore<Vec<i32>>)));
+    assert!(reactive_prop_type(&parse_quote!(WriteStore<Vec<i32>>)));
+
+    assert!(!reactive_prop_type(&parse_quote!(i32)));
+    assert!(!reactive_prop_type(&parse_quote!(Option<String>)));
+}
+
 #[test]
 fn test_remove_option_wrapper() {
     let type_without_option = remove_option_wrapper(parse_quote!(Option<i32>));
@@ -0,0 +1,107 @@
+use dioxus::{
+    core::{current_scope_id, generation},
+    prelude::*,
+};
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static RENDERS: Cell<usize> = const { Cell::new(0) };
+    static CHILD: Cell<Option<ScopeId>> = const { Cell::new(None) };
+    static LABEL: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+fn props_equal(old: &OnlyIdProps, new: &OnlyIdProps) -> bool {
+    old.id == new.id
+}
+
+#[component(memo = props_equal)]
+fn OnlyId(id: usize, label: String) -> Element {
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+    rsx! { "{id}: {label}" }
+}
+
+#[test]
+fn custom_props_equal() {
+    fn app() -> Element {
+        rsx! {
+            OnlyId { id: generation() / 2, label: generation().to_string() }
+        }
+    }
+
+    RENDERS.with(|renders| renders.set(0));
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(RENDERS.with(Cell::get), 1);
+
+    // The label changes, but the id doesn't so the child doesn't rerun
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(RENDERS.with(Cell::get), 1);
+
+    // The id changes, so the child reruns
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(RENDERS.with(Cell::get), 2);
+}
+
+#[component]
+fn SkipsLabel(id: usize, #[props(skip_eq)] label: String) -> Element {
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+    CHILD.with(|child| child.set(Some(current_scope_id())));
+    LABEL.with(|stored| *stored.borrow_mut() = label.clone());
+    rsx! { "{id}: {label}" }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct DerivedSkipsLabelProps {
+    id: usize,
+    #[props(skip_eq)]
+    label: String,
+}
+
+// The derived PartialEq compares every field, so skip_eq has to be handled when the props are memoized
+#[allow(non_snake_case)]
+fn DerivedSkipsLabel(props: DerivedSkipsLabelProps) -> Element {
+    let DerivedSkipsLabelProps { id, label } = props;
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+    CHILD.with(|child| child.set(Some(current_scope_id())));
+    LABEL.with(|stored| *stored.borrow_mut() = label.clone());
+    rsx! { "{id}: {label}" }
+}
+
+fn assert_skip_eq_fields_are_not_compared(app: fn() -> Element) {
+    RENDERS.with(|renders| renders.set(0));
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(RENDERS.with(Cell::get), 1);
+
+    // Only the skipped field changes, so the child doesn't rerun
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(RENDERS.with(Cell::get), 1);
+
+    // The next time the child runs, it sees the latest value of the skipped field
+    let child = CHILD.with(Cell::get).unwrap();
+    dom.mark_dirty(child);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(RENDERS.with(Cell::get), 2);
+    assert_eq!(LABEL.with(|label| label.borrow().clone()), "1");
+}
+
+#[test]
+fn skip_eq_fields_are_not_compared() {
+    assert_skip_eq_fields_are_not_compared(|| {
+        rsx! {
+            SkipsLabel { id: 0, label: generation().to_string() }
+        }
+    });
+}
+
+#[test]
+fn skip_eq_fields_are_not_compared_with_derived_props() {
+    assert_skip_eq_fields_are_not_compared(|| {
+        rsx! {
+            DerivedSkipsLabel { id: 0, label: generation().to_string() }
+        }
+    });
+}
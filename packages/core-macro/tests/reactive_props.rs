@@ -0,0 +1,81 @@
+use dioxus::{core::generation, prelude::*};
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static RENDERS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[component(reactive)]
+fn BreedOnly(breed: String, theme: String) -> Element {
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+    let _ = theme;
+    rsx! { "{breed}" }
+}
+
+#[test]
+fn reactive_props_only_rerun_readers() {
+    fn app() -> Element {
+        rsx! {
+            BreedOnly { breed: (generation() / 2).to_string(), theme: generation().to_string() }
+        }
+    }
+
+    RENDERS.with(|renders| renders.set(0));
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(RENDERS.with(Cell::get), 1);
+
+    // Only the theme changes, and the child never reads it, so the child doesn't rerun
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(RENDERS.with(Cell::get), 1);
+
+    // The breed changes, so the child reruns
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(RENDERS.with(Cell::get), 2);
+}
+
+thread_local! {
+    static GREETINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+#[component(reactive)]
+fn Greeting(
+    name: Option<String>,
+    #[props(default = 1)] count: usize,
+    #[props(default)] punctuation: String,
+) -> Element {
+    let name = name().unwrap_or_else(|| "stranger".to_string());
+    let greeting = format!("Hello {name}{punctuation} x{count}");
+    GREETINGS.with_borrow_mut(|greetings| greetings.push(greeting.clone()));
+    rsx! { "{greeting}" }
+}
+
+#[test]
+fn reactive_props_keep_optional_and_default_props() {
+    fn app() -> Element {
+        rsx! {
+            // Option props are optional, and defaulted props use their default
+            Greeting {}
+            Greeting { name: "Dioxus", count: 2, punctuation: "!" }
+            Greeting { count: generation() + 5 }
+        }
+    }
+
+    GREETINGS.with_borrow_mut(Vec::clear);
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!(
+        GREETINGS.with_borrow_mut(std::mem::take),
+        ["Hello stranger x1", "Hello Dioxus! x2", "Hello stranger x5"]
+    );
+
+    // Only the last greeting reads a prop that changed
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(
+        GREETINGS.with_borrow_mut(std::mem::take),
+        ["Hello stranger x6"]
+    );
+}
@@ -11,6 +11,7 @@
 pub fn event_bubbles(evt: &str) -> bool {
     match evt {
         "cancel" => false,
+        "close" => false,
         "copy" => true,
         "cut" => true,
         "paste" => true,
@@ -96,7 +97,11 @@ pub fn event_bubbles(evt: &str) -> bool {
         "animationstart" => true,
         "animationend" => true,
         "animationiteration" => true,
+        "animationcancel" => true,
+        "transitionrun" => true,
+        "transitionstart" => true,
         "transitionend" => true,
+        "transitioncancel" => true,
         "toggle" => false,
         "beforetoggle" => false,
         "mounted" => false,
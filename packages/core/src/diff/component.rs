@@ -130,6 +130,9 @@ impl VNode {
             return self.replace_vcomponent(mount, idx, new, parent, dom, to);
         }
 
+        #[cfg(feature = "instrument")]
+        let name = dom.runtime.get_state(scope_id).name;
+
         // copy out the box for both
         let old_scope = &mut dom.scopes[scope_id.0];
         let old_props: &mut dyn AnyProps = old_scope.props.deref_mut();
@@ -138,7 +141,17 @@ impl VNode {
         // If the props are static, then we try to memoize by setting the new with the old
         // The target ScopeState still has the reference to the old props, so there's no need to update anything
         // This also implicitly drops the new props since they're not used
-        if old_props.memoize(new_props.props()) {
+        let memoized = old_props.memoize(new_props.props());
+
+        #[cfg(feature = "instrument")]
+        tracing::debug!(
+            component = name,
+            scope = scope_id.0,
+            memo_hit = memoized,
+            "memoize"
+        );
+
+        if memoized {
             tracing::trace!("Memoized props for component {:#?}", scope_id,);
             return;
         }
@@ -0,0 +1,137 @@
+//! Warnings for form elements that are controlled by a value that nothing updates.
+//!
+//! Setting `value` or `checked` from state makes the element "controlled": every time the component reruns, the
+//! element is reset to the value in state. If no event handler writes what the user typed back to that state, the
+//! user's changes are thrown away on the next render, which looks like a bug in the input. Elements can opt out by
+//! setting `initial_value` or `initial_checked` instead, which only set the starting value.
+
+use crate::{Attribute, AttributeValue, Template, TemplateAttribute, TemplateNode, nodes::VNode};
+use rustc_hash::FxHashSet;
+use std::cell::RefCell;
+
+thread_local! {
+    /// Templates we already checked, so each template is only checked and reported once
+    static CHECKED: RefCell<FxHashSet<Template>> = RefCell::new(FxHashSet::default());
+}
+
+/// The controlled attributes of an element and the events that can update them
+fn controlled_attributes(
+    tag: &str,
+    input_type: &str,
+) -> &'static [(&'static str, &'static [&'static str])] {
+    match (tag, input_type) {
+        // The user can't change the value of buttons or hidden inputs
+        ("input", "hidden" | "submit" | "button" | "reset" | "image") => &[],
+        // The value of checkboxes and radio buttons is what they submit with the form, the user only changes `checked`
+        ("input", "checkbox" | "radio") => &[("checked", &["onchange", "oninput", "onclick"])],
+        ("input" | "textarea" | "select", _) => &[("value", &["oninput", "onchange"])],
+        _ => &[],
+    }
+}
+
+impl VNode {
+    /// Warn about form elements in this node that set a controlled attribute without a handler that updates it
+    pub(super) fn warn_about_uncontrolled_inputs(&self) {
+        let template = self.template;
+        if !CHECKED.with(|checked| checked.borrow_mut().insert(template)) {
+            return;
+        }
+
+        let mut stack: Vec<&TemplateNode> = template.roots().iter().collect();
+        while let Some(node) = stack.pop() {
+            let TemplateNode::Element {
+                tag,
+                attrs,
+                children,
+                ..
+            } = node
+            else {
+                continue;
+            };
+            stack.extend(children.iter());
+            self.warn_about_element(tag, attrs);
+        }
+    }
+
+    /// Warn if one element of the template is controlled without a handler
+    fn warn_about_element(&self, tag: &str, attrs: &[TemplateAttribute]) {
+        if !matches!(tag, "input" | "textarea" | "select") {
+            return;
+        }
+
+        let dynamic: Vec<&Attribute> = attrs
+            .iter()
+            .filter_map(|attr| match attr {
+                TemplateAttribute::Dynamic { id } => Some(self.dynamic_attrs[*id].iter()),
+                TemplateAttribute::Static { .. } => None,
+            })
+            .flatten()
+            .collect();
+
+        // Inputs without a type are text inputs
+        let input_type = attrs
+            .iter()
+            .find_map(|attr| match attr {
+                TemplateAttribute::Static { name, value, .. } if *name == "type" => Some(*value),
+                _ => None,
+            })
+            .or_else(|| {
+                dynamic.iter().find_map(|attr| match &attr.value {
+                    AttributeValue::Text(value) if attr.name == "type" => Some(value.as_str()),
+                    _ => None,
+                })
+            })
+            .unwrap_or("text")
+            .to_ascii_lowercase();
+        let controlled = controlled_attributes(tag, &input_type);
+        if controlled.is_empty() {
+            return;
+        }
+        let has_attribute = |name: &str| {
+            let is_static = |attr: &TemplateAttribute| match attr {
+                TemplateAttribute::Static {
+                    name: static_name, ..
+                } => *static_name == name,
+                TemplateAttribute::Dynamic { .. } => false,
+            };
+            dynamic.iter().any(|attr| attr.name == name) || attrs.iter().any(is_static)
+        };
+
+        // Elements the user can't change don't need a handler
+        if has_attribute("readonly") || has_attribute("disabled") {
+            return;
+        }
+
+        for (attribute, handlers) in controlled {
+            // Static values are only written when the element is created, so they can't fight with the user
+            let is_controlled = dynamic.iter().any(|attr| {
+                attr.name == *attribute
+                    && attr.namespace.is_none()
+                    && !matches!(
+                        attr.value,
+                        AttributeValue::Listener(_) | AttributeValue::None
+                    )
+            });
+            let has_handler = dynamic.iter().any(|attr| {
+                handlers.contains(&attr.name) && matches!(attr.value, AttributeValue::Listener(_))
+            });
+            if is_controlled && !has_handler {
+                let handler = handlers[0];
+                tracing::warn!(
+                    r#"A `<{tag}>` element sets `{attribute}` from a dynamic value, but doesn't have an `{handler}` handler to update it. Any changes the user makes are reset to `{attribute}` the next time the component reruns.
+
+Help:
+Update the state `{attribute}` is read from in an `{handler}` handler:
+```rust
+{tag} {{
+    {attribute}: state(),
+    {handler}: move |event| state.set(event.{attribute}()),
+}}
+```
+If the element doesn't need to be controlled, set `initial_{attribute}` instead to only set the starting value, or add `readonly` if the user shouldn't change it."#
+                );
+                return;
+            }
+        }
+    }
+}
@@ -14,18 +14,9 @@ impl VirtualDom {
         new: &[VNode],
         parent: Option<ElementRef>,
     ) {
-        let new_is_keyed = new[0].key.is_some();
-        let old_is_keyed = old[0].key.is_some();
-        debug_assert!(
-            new.iter().all(|n| n.key.is_some() == new_is_keyed),
-            "all siblings must be keyed or all siblings must be non-keyed"
-        );
-        debug_assert!(
-            old.iter().all(|o| o.key.is_some() == old_is_keyed),
-            "all siblings must be keyed or all siblings must be non-keyed"
-        );
-
-        if new_is_keyed && old_is_keyed {
+        // Lists are only diffed by key if every sibling in both the old and new list has a unique key. Otherwise,
+        // we fall back to diffing by position which is always correct, but may recreate nodes that moved.
+        if can_diff_by_key(old) && can_diff_by_key(new) {
             self.diff_keyed_children(to, old, new, parent);
         } else {
             self.diff_non_keyed_children(to, old, new, parent);
@@ -92,28 +83,6 @@ impl VirtualDom {
         new: &[VNode],
         parent: Option<ElementRef>,
     ) {
-        if cfg!(debug_assertions) {
-            let mut keys = rustc_hash::FxHashSet::default();
-            let mut assert_unique_keys = |children: &[VNode]| {
-                keys.clear();
-                for child in children {
-                    let key = child.key.clone();
-                    debug_assert!(
-                        key.is_some(),
-                        "if any sibling is keyed, all siblings must be keyed"
-                    );
-                    keys.insert(key);
-                }
-                debug_assert_eq!(
-                    children.len(),
-                    keys.len(),
-                    "keyed siblings must each have a unique key"
-                );
-            };
-            assert_unique_keys(old);
-            assert_unique_keys(new);
-        }
-
         // First up, we diff all the nodes with the same key at the beginning of the
         // children.
         //
@@ -516,3 +485,40 @@ impl VNode {
             .sum()
     }
 }
+
+/// Check if a list of siblings can be diffed by key.
+///
+/// - If none of the siblings are keyed, the list is diffed by position.
+/// - If only some of the siblings are keyed, we can't match every node by identity so the list is diffed by position.
+/// - If two siblings share the same key, we can't tell which old node each new node corresponds to so the list is
+///   diffed by position.
+///
+/// Both mistakes are reported with a warning in debug builds.
+fn can_diff_by_key(children: &[VNode]) -> bool {
+    let keyed = children.iter().filter(|child| child.key.is_some()).count();
+    if keyed == 0 {
+        return false;
+    }
+
+    if keyed != children.len() {
+        #[cfg(debug_assertions)]
+        tracing::warn!(
+            "Only some siblings in this list are keyed. If any sibling is keyed, all siblings must be keyed for the list to be diffed by key. Falling back to diffing by position."
+        );
+        return false;
+    }
+
+    let mut keys = FxHashSet::default();
+    for child in children {
+        let key = child.key.as_deref().unwrap();
+        if !keys.insert(key) {
+            #[cfg(debug_assertions)]
+            tracing::warn!(
+                "Found duplicate key {key:?} in a keyed list. Keyed siblings must each have a unique key for the list to be diffed by key. Falling back to diffing by position."
+            );
+            return false;
+        }
+    }
+
+    true
+}
@@ -18,6 +18,8 @@ use crate::{
 };
 
 mod component;
+#[cfg(debug_assertions)]
+mod controlled;
 mod iterator;
 mod node;
 
@@ -555,6 +555,8 @@ impl VNode {
             let mount = MountId(entry.key());
             self.mount.set(mount);
             tracing::trace!(?self, ?mount, "creating template");
+            #[cfg(debug_assertions)]
+            self.warn_about_uncontrolled_inputs();
             entry.insert(VNodeMount {
                 node: self.clone(),
                 parent,
@@ -2,7 +2,7 @@ use crate::{
     Element, IntoDynNode, Properties, ReactiveContext, Subscribers, Template, TemplateAttribute,
     TemplateNode, VNode,
     innerlude::{CapturedError, provide_context},
-    try_consume_context, use_hook,
+    throw_error, try_consume_context, use_hook,
 };
 use std::{
     any::Any,
@@ -25,6 +25,54 @@ macro_rules! bail {
     };
 }
 
+/// Throw the error of a [`Result`] or [`Option`] into the nearest [`ErrorBoundary()`] instead of returning it.
+///
+/// Unlike `?`, throwing does not stop the current component from rendering. This is useful in places
+/// where you cannot return an error like event handlers or spawned futures.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Component() -> Element {
+///     let mut count = use_signal(|| 0);
+///     rsx! {
+///         input {
+///             oninput: move |event| {
+///                 if let Some(value) = event.value().parse::<i32>().throw() {
+///                     count.set(value);
+///                 }
+///             }
+///         }
+///         "{count}"
+///     }
+/// }
+/// ```
+pub trait Throw<T>: Sized {
+    /// Return the value if it is present, otherwise throw the error into the nearest [`ErrorBoundary()`]
+    /// and return `None`.
+    fn throw(self) -> Option<T>;
+}
+
+impl<T, E: Into<CapturedError> + 'static> Throw<T> for Result<T, E> {
+    fn throw(self) -> Option<T> {
+        match self {
+            Ok(value) => Some(value),
+            Err(err) => {
+                throw_error(err);
+                None
+            }
+        }
+    }
+}
+
+impl<T> Throw<T> for Option<T> {
+    fn throw(self) -> Option<T> {
+        if self.is_none() {
+            throw_error(CapturedError::msg("Attempted to unwrap a `None` value"));
+        }
+        self
+    }
+}
+
 /// A panic in a component that was caught by an error boundary.
 ///
 /// <div class="warning">
@@ -30,6 +30,8 @@ pub struct Event<T: 'static + ?Sized> {
 pub(crate) struct EventMetadata {
     pub(crate) propagates: bool,
     pub(crate) prevent_default: bool,
+    /// Set once every listener has been called and the renderer has read the event state
+    pub(crate) dispatched: bool,
 }
 
 impl<T: ?Sized + 'static> Event<T> {
@@ -40,6 +42,7 @@ impl<T: ?Sized + 'static> Event<T> {
             metadata: Rc::new(RefCell::new(EventMetadata {
                 propagates,
                 prevent_default: false,
+                dispatched: false,
             })),
         }
     }
@@ -104,6 +107,11 @@ impl<T: ?Sized> Event<T> {
         self.metadata.borrow().propagates
     }
 
+    /// Check if a handler called [`Event::stop_propagation`] on this event
+    pub fn propagation_stopped(&self) -> bool {
+        !self.propagates()
+    }
+
     /// Prevent this event from continuing to bubble up the tree to parent elements.
     ///
     /// # Example
@@ -118,7 +126,12 @@ impl<T: ?Sized> Event<T> {
     ///     }
     /// };
     /// ```
+    ///
+    /// Note: This must be called synchronously when handling the event. Calling it after an `.await` in an async
+    /// handler has no effect because the event has already bubbled.
+    #[track_caller]
     pub fn stop_propagation(&self) {
+        self.warn_if_dispatched("stop_propagation");
         self.metadata.borrow_mut().propagates = false;
     }
 
@@ -161,7 +174,8 @@ impl<T: ?Sized> Event<T> {
     /// }
     /// ```
     ///
-    /// Note: This must be called synchronously when handling the event. Calling it after the event has been handled will have no effect.
+    /// Note: This must be called synchronously when handling the event. The renderer reads the state of the event as soon
+    /// as every handler returns, so calling this after an `.await` in an async handler has no effect.
     ///
     /// <div class="warning">
     ///
@@ -170,6 +184,7 @@ impl<T: ?Sized> Event<T> {
     /// </div>
     #[track_caller]
     pub fn prevent_default(&self) {
+        self.warn_if_dispatched("prevent_default");
         self.metadata.borrow_mut().prevent_default = true;
     }
 
@@ -177,6 +192,21 @@ impl<T: ?Sized> Event<T> {
     pub fn default_action_enabled(&self) -> bool {
         !self.metadata.borrow().prevent_default
     }
+
+    /// Check if a handler called [`Event::prevent_default`] on this event
+    pub fn default_prevented(&self) -> bool {
+        !self.default_action_enabled()
+    }
+
+    #[track_caller]
+    fn warn_if_dispatched(&self, method: &str) {
+        if self.metadata.borrow().dispatched {
+            tracing::warn!(
+                "`Event::{method}` was called at {} after the event finished dispatching. It must be called synchronously in the event handler, before any `.await`.",
+                Location::caller()
+            );
+        }
+    }
 }
 
 impl<T: ?Sized> Clone for Event<T> {
@@ -348,6 +348,10 @@ pub fn schedule_update_any() -> Arc<dyn Fn(ScopeId) + Send + Sync> {
 /// This can be used to clean up side effects from the component
 /// (created with [`use_effect`](https://docs.rs/dioxus-hooks/latest/dioxus_hooks/fn.use_effect.html)).
 ///
+/// The closure runs exactly once, when the scope is dropped. That includes dropping the whole [`VirtualDom`](crate::VirtualDom)
+/// when the app shuts down. Unlike the cleanup of an effect, it has no dependencies and never reruns while the component
+/// is mounted, which makes it a good fit for releasing resources like subscriptions or timers.
+///
 /// Note:
 /// Effects do not run on the server, but use_drop **DOES**. It runs any time the component is dropped including during SSR rendering on the server. If your clean up logic targets web, the logic has to be gated by a feature, see the below example for details.
 ///
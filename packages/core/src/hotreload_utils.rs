@@ -6,7 +6,8 @@ use std::{
 #[cfg(feature = "serialize")]
 use crate::nodes::deserialize_string_leaky;
 use crate::{
-    Attribute, AttributeValue, DynamicNode, Template, TemplateAttribute, TemplateNode, VNode, VText,
+    Attribute, AttributeValue, DynamicNode, Template, TemplateAttribute, TemplateNode, VNode,
+    VText, is_boolean_attribute,
 };
 
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
@@ -312,6 +313,11 @@ impl DynamicValuePool {
                     HotReloadAttributeValue::Literal(HotReloadLiteral::Int(i)) => {
                         AttributeValue::Int(*i)
                     }
+                    HotReloadAttributeValue::Literal(HotReloadLiteral::Bool(false))
+                        if namespace.is_none() && is_boolean_attribute(name) =>
+                    {
+                        AttributeValue::None
+                    }
                     HotReloadAttributeValue::Literal(HotReloadLiteral::Bool(b)) => {
                         AttributeValue::Bool(*b)
                     }
@@ -168,6 +168,53 @@ impl VNode {
         }
     }
 
+    /// Create a VNode for a single element with a tag name that is only known at runtime
+    ///
+    /// The attributes and children are inserted into the element as dynamic attributes and nodes.
+    /// Each unique tag and namespace creates one template, so changing the tag replaces the element
+    /// instead of diffing it.
+    pub fn dynamic_element(
+        tag: &str,
+        namespace: Option<&str>,
+        attributes: Vec<Attribute>,
+        children: Element,
+    ) -> Self {
+        use rustc_hash::FxHashMap;
+        use std::cell::RefCell;
+
+        // Templates require static data. We leak one template per unique tag and namespace which
+        // is bounded by the number of distinct tags the app renders.
+        thread_local! {
+            static TEMPLATES: RefCell<FxHashMap<(String, Option<String>), Template>> =
+                RefCell::new(FxHashMap::default());
+        }
+
+        let template = TEMPLATES.with_borrow_mut(|templates| {
+            *templates
+                .entry((tag.to_string(), namespace.map(ToString::to_string)))
+                .or_insert_with(|| {
+                    let tag: &'static str = Box::leak(tag.into());
+                    let namespace: Option<&'static str> =
+                        namespace.map(|namespace| &*Box::leak(namespace.into()));
+                    let roots: &'static [TemplateNode] =
+                        Box::leak(Box::new([TemplateNode::Element {
+                            tag,
+                            namespace,
+                            attrs: &[TemplateAttribute::Dynamic { id: 0 }],
+                            children: &[TemplateNode::Dynamic { id: 0 }],
+                        }]));
+                    Template::new(roots, &[&[0, 0]], &[&[0]])
+                })
+        });
+
+        Self::new(
+            None,
+            template,
+            Box::new([children.into_dyn_node()]),
+            Box::new([attributes.into_boxed_slice()]),
+        )
+    }
+
     /// Load a dynamic root at the given index
     ///
     /// Returns [`None`] if the root is actually a static node (Element/Text)
@@ -815,17 +862,27 @@ impl Attribute {
     ///
     /// "Volatile" refers to whether or not Dioxus should always override the value. This helps prevent the UI in
     /// some renderers stay in sync with the VirtualDom's understanding of the world
+    ///
+    /// `None` values are rendered as a missing attribute. `false` is also rendered as a missing attribute for
+    /// [boolean attributes](is_boolean_attribute) like `disabled`, where any value (even `"false"`) would enable them.
     pub fn new<T>(
         name: &'static str,
         value: impl IntoAttributeValue<T>,
         namespace: Option<&'static str>,
         volatile: bool,
     ) -> Attribute {
+        let mut value = value.into_value();
+        if namespace.is_none()
+            && matches!(value, AttributeValue::Bool(false))
+            && is_boolean_attribute(name)
+        {
+            value = AttributeValue::None;
+        }
         Attribute {
             name,
             namespace,
             volatile,
-            value: value.into_value(),
+            value,
         }
     }
 
@@ -840,6 +897,43 @@ impl Attribute {
     }
 }
 
+/// Check if an attribute is an html [boolean attribute](https://developer.mozilla.org/en-US/docs/Glossary/Boolean/HTML)
+/// that is enabled by being present, regardless of its value.
+///
+/// `checked` and `selected` are not included. Renderers set them as properties so they stay in sync after the user
+/// interacts with the element.
+pub fn is_boolean_attribute(name: &str) -> bool {
+    matches!(
+        name,
+        "allowfullscreen"
+            | "allowpaymentrequest"
+            | "async"
+            | "autofocus"
+            | "autoplay"
+            | "controls"
+            | "default"
+            | "defer"
+            | "disabled"
+            | "formnovalidate"
+            | "hidden"
+            | "inert"
+            | "ismap"
+            | "itemscope"
+            | "loop"
+            | "multiple"
+            | "muted"
+            | "nomodule"
+            | "novalidate"
+            | "open"
+            | "playsinline"
+            | "readonly"
+            | "required"
+            | "reversed"
+            | "truespeed"
+            | "webkitdirectory"
+    )
+}
+
 /// Any of the built-in values that the Dioxus VirtualDom supports as dynamic attributes on elements
 ///
 /// These are built-in to be faster during the diffing process. To use a custom value, use the [`AttributeValue::Any`]
@@ -1116,6 +1210,88 @@ impl IntoAttributeValue for String {
     }
 }
 
+/// A string of HTML that will be inserted into the page without escaping it.
+///
+/// # Security
+///
+/// **Never wrap untrusted input in `UnsafeHtml`.** The browser parses the string as markup, so any `<script>` tags,
+/// `onerror` handlers, or `javascript:` urls in it will run with full access to your page. This is a
+/// [cross-site scripting (XSS)](https://owasp.org/www-community/attacks/xss/) attack. Only use it for HTML you
+/// wrote yourself or HTML that was sanitized on the way in, like the output of a markdown renderer with raw HTML
+/// disabled.
+///
+/// Pass the string to the `dangerous_inner_html` attribute to replace the contents of an element with it. The string
+/// is opaque to the diffing: when it changes, the whole inner HTML of the element is replaced. Only string literals
+/// written directly in `rsx!` can be passed to `dangerous_inner_html` without wrapping them in `UnsafeHtml`.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn Article(rendered_markdown: String) -> Element {
+///     rsx! {
+///         article { dangerous_inner_html: UnsafeHtml(rendered_markdown) }
+///     }
+/// }
+/// ```
+///
+/// Strings built at runtime are rejected unless they are wrapped:
+///
+/// ```rust, compile_fail
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn Comment(text: String) -> Element {
+///     rsx! {
+///         div { dangerous_inner_html: "{text}" }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnsafeHtml(pub String);
+
+impl UnsafeHtml {
+    /// Get the raw HTML
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for UnsafeHtml {
+    fn from(html: String) -> Self {
+        Self(html)
+    }
+}
+
+impl From<&str> for UnsafeHtml {
+    fn from(html: &str) -> Self {
+        Self(html.to_string())
+    }
+}
+
+impl std::fmt::Display for UnsafeHtml {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl IntoAttributeValue for UnsafeHtml {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::Text(self.0)
+    }
+}
+
+/// A value that can be passed to the `dangerous_inner_html` attribute. This is only implemented for [`UnsafeHtml`] so
+/// strings built at runtime can't be inserted into the page as HTML by accident.
+#[diagnostic::on_unimplemented(
+    message = "`dangerous_inner_html` expects `UnsafeHtml`, found `{Self}`",
+    label = "raw HTML",
+    note = "Wrap the value in `UnsafeHtml` after making sure it doesn't contain untrusted input"
+)]
+pub trait InnerHtmlValue: IntoAttributeValue {}
+
+impl InnerHtmlValue for UnsafeHtml {}
+
+impl InnerHtmlValue for Option<UnsafeHtml> {}
+
 impl IntoAttributeValue for f32 {
     fn into_value(self) -> AttributeValue {
         AttributeValue::Float(self as _)
@@ -1249,3 +1425,88 @@ pub trait HasAttributes {
         volatile: bool,
     ) -> Self;
 }
+
+/// A collection of attributes that can be spread onto an element with `..attributes` in rsx.
+///
+/// This is implemented for lists of [`Attribute`]s and lists of `(name, value)` pairs:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// let attributes = vec![("data-kind", "primary"), ("aria-label", "Save")];
+///
+/// rsx! {
+///     button {
+///         // Attributes written explicitly win over attributes with the same name in the spread
+///         "aria-label": "Save changes",
+///         ..attributes,
+///         "Save"
+///     }
+/// };
+/// ```
+pub trait IntoAttributes {
+    /// Convert this value into a list of attributes
+    fn into_attributes(self) -> Vec<Attribute>;
+}
+
+impl IntoAttributes for Vec<Attribute> {
+    fn into_attributes(self) -> Vec<Attribute> {
+        self
+    }
+}
+
+impl IntoAttributes for Box<[Attribute]> {
+    fn into_attributes(self) -> Vec<Attribute> {
+        self.into_vec()
+    }
+}
+
+impl IntoAttributes for &[Attribute] {
+    fn into_attributes(self) -> Vec<Attribute> {
+        self.to_vec()
+    }
+}
+
+impl<const N: usize> IntoAttributes for [Attribute; N] {
+    fn into_attributes(self) -> Vec<Attribute> {
+        self.into()
+    }
+}
+
+impl IntoAttributes for Attribute {
+    fn into_attributes(self) -> Vec<Attribute> {
+        vec![self]
+    }
+}
+
+impl<V: IntoAttributeValue> IntoAttributes for Vec<(&'static str, V)> {
+    fn into_attributes(self) -> Vec<Attribute> {
+        self.into_iter()
+            .map(|(name, value)| Attribute::new(name, value, None, false))
+            .collect()
+    }
+}
+
+impl<V: IntoAttributeValue, const N: usize> IntoAttributes for [(&'static str, V); N] {
+    fn into_attributes(self) -> Vec<Attribute> {
+        Vec::from(self).into_attributes()
+    }
+}
+
+impl<T: IntoAttributes> IntoAttributes for Option<T> {
+    fn into_attributes(self) -> Vec<Attribute> {
+        self.map(IntoAttributes::into_attributes)
+            .unwrap_or_default()
+    }
+}
+
+/// Collect the attributes spread onto an element in rsx. Attributes with a name in `explicit` are skipped because
+/// the attributes written on the element take priority. The remaining attributes keep the order they were spread in.
+#[doc(hidden)]
+pub fn spread_attributes(
+    attributes: impl IntoAttributes,
+    explicit: &[&'static str],
+) -> Vec<Attribute> {
+    let mut attributes = attributes.into_attributes();
+    attributes.retain(|attribute| !explicit.contains(&attribute.name));
+    attributes
+}
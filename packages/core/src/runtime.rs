@@ -372,11 +372,14 @@ fn MyComponent() -> Element {{
 
         if let Some(Some(parent_path)) = elements.get(element.0).copied() {
             if event.propagates() {
-                self.handle_bubbling_event(parent_path, name, event);
+                self.handle_bubbling_event(parent_path, name, event.clone());
             } else {
-                self.handle_non_bubbling_event(parent_path, name, event);
+                self.handle_non_bubbling_event(parent_path, name, event.clone());
             }
         }
+
+        // Any changes to the event after this point are too late for the renderer to see
+        event.metadata.borrow_mut().dispatched = true;
     }
 
     /*
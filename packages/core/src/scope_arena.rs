@@ -62,8 +62,19 @@ impl VirtualDom {
 
                 let props: &dyn AnyProps = &*scope.props;
 
+                #[cfg(not(feature = "instrument"))]
                 let span = tracing::trace_span!("render", scope = %scope.state().name);
-                span.in_scope(|| {
+                #[cfg(feature = "instrument")]
+                let span = tracing::debug_span!(
+                    "render",
+                    component = %scope.state().name,
+                    scope = scope_id.0,
+                    duration_us = tracing::field::Empty,
+                );
+                #[cfg(feature = "instrument")]
+                let start = web_time::Instant::now();
+
+                let output = span.in_scope(|| {
                     scope.reactive_context.reset_and_run_in(|| {
                         let render_return = props.render();
                         // After the component is run, we need to do a deep clone of the VNode. This
@@ -87,7 +98,12 @@ impl VirtualDom {
                         self.handle_element_return(&mut render_return, &scope.state());
                         render_return
                     })
-                })
+                });
+
+                #[cfg(feature = "instrument")]
+                span.record("duration_us", start.elapsed().as_micros() as u64);
+
+                output
             };
 
             let scope_state = scope.state();
@@ -224,6 +224,12 @@ impl ::core::cmp::PartialEq for SuspenseBoundaryProps {
 
 /// Suspense Boundaries let you render a fallback UI while a child component is suspended.
 ///
+/// A component suspends by returning [`RenderError::Suspended`], usually with `resource.suspend()?`.
+/// The suspension is caught by the innermost boundary above the component, so nested boundaries
+/// resolve independently: the outer boundary only shows its fallback for components that are not
+/// inside an inner boundary. During SSR, each boundary is streamed to the client once all of the
+/// futures it caught have resolved.
+///
 /// # Example
 ///
 /// ```rust
@@ -279,6 +279,15 @@ impl Runtime {
             return Poll::Pending;
         }
 
+        #[cfg(feature = "instrument")]
+        let _span = tracing::debug_span!(
+            "poll",
+            component = self.try_get_state(task.scope).map(|scope| scope.name),
+            scope = task.scope.0,
+            task = ?id.id,
+        )
+        .entered();
+
         let mut cx = std::task::Context::from_waker(&task.waker);
 
         // poll the future with the scope on the stack
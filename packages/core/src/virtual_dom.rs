@@ -527,6 +527,17 @@ impl VirtualDom {
 
             // At this point, we have finished running all tasks that are pending and we haven't found any scopes to rerun. This means it is safe to run our lowest priority work: effects
             while let Some(effect) = self.pop_effect() {
+                #[cfg(feature = "instrument")]
+                let _span = tracing::debug_span!(
+                    "effect",
+                    component = self
+                        .runtime
+                        .try_get_state(effect.order.id)
+                        .map(|scope| scope.name),
+                    scope = effect.order.id.0,
+                )
+                .entered();
+
                 effect.run();
                 // Check if any new scopes are queued for rerun
                 self.queue_events();
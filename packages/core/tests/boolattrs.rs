@@ -11,7 +11,7 @@ fn bool_test() {
             LoadTemplate { index: 0, id: ElementId(1) },
             SetAttribute {
                 name: "hidden",
-                value: dioxus_core::AttributeValue::Bool(false),
+                value: dioxus_core::AttributeValue::None,
                 id: ElementId(1,),
                 ns: None
             },
@@ -19,3 +19,82 @@ fn bool_test() {
         ]
     );
 }
+
+#[test]
+fn false_boolean_attributes_are_removed() {
+    let mut app = VirtualDom::new(|| {
+        let disabled = generation() % 2 == 1;
+        rsx!(input { disabled, checked: false, title: None::<String> })
+    });
+
+    assert_eq!(
+        app.rebuild_to_vec().edits,
+        [
+            LoadTemplate { index: 0, id: ElementId(1) },
+            SetAttribute {
+                name: "disabled",
+                value: dioxus_core::AttributeValue::None,
+                id: ElementId(1),
+                ns: None
+            },
+            // Checked is set as a property, so it keeps the bool value
+            SetAttribute {
+                name: "checked",
+                value: dioxus_core::AttributeValue::Bool(false),
+                id: ElementId(1),
+                ns: None
+            },
+            SetAttribute {
+                name: "title",
+                value: dioxus_core::AttributeValue::None,
+                id: ElementId(1),
+                ns: None
+            },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+
+    app.mark_dirty(ScopeId::APP);
+    assert_eq!(
+        app.render_immediate_to_vec().edits,
+        [SetAttribute {
+            name: "disabled",
+            value: dioxus_core::AttributeValue::Bool(true),
+            id: ElementId(1),
+            ns: None
+        }]
+    );
+
+    app.mark_dirty(ScopeId::APP);
+    assert_eq!(
+        app.render_immediate_to_vec().edits,
+        [SetAttribute {
+            name: "disabled",
+            value: dioxus_core::AttributeValue::None,
+            id: ElementId(1),
+            ns: None
+        }]
+    );
+}
+
+#[test]
+fn false_multiple_is_removed() {
+    let mut app = VirtualDom::new(|| {
+        let multiple = false;
+        rsx!(select { multiple })
+    });
+
+    assert_eq!(
+        app.rebuild_to_vec().edits,
+        [
+            LoadTemplate { index: 0, id: ElementId(1) },
+            SetAttribute {
+                name: "multiple",
+                value: dioxus_core::AttributeValue::None,
+                id: ElementId(1),
+                ns: None
+            },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+}
@@ -0,0 +1,137 @@
+//! Debug builds warn about form elements that are controlled by state that no event handler updates
+#![cfg(debug_assertions)]
+
+use dioxus::prelude::*;
+use std::sync::{Arc, Mutex};
+
+/// A log writer that collects everything written to it
+#[derive(Clone, Default)]
+struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Build `app` and return the warnings it logged
+fn warnings(app: fn() -> Element) -> String {
+    let logs = Logs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::WARN)
+        .with_ansi(false)
+        .with_writer({
+            let logs = logs.clone();
+            move || logs.clone()
+        })
+        .finish();
+    tracing::subscriber::with_default(subscriber, || {
+        VirtualDom::new(app).rebuild_in_place();
+    });
+    let logs = logs.0.lock().unwrap();
+    String::from_utf8(logs.clone()).unwrap()
+}
+
+#[test]
+fn controlled_value_without_handler() {
+    let logs = warnings(|| {
+        let text = "hello";
+        rsx! {
+            input { value: "{text}" }
+        }
+    });
+    assert!(logs.contains("sets `value`"), "{logs}");
+}
+
+#[test]
+fn controlled_textarea_without_handler() {
+    let logs = warnings(|| {
+        let text = "hello";
+        rsx! {
+            textarea { value: "{text}" }
+        }
+    });
+    assert!(logs.contains("sets `value`"), "{logs}");
+}
+
+#[test]
+fn controlled_value_with_handler() {
+    let logs = warnings(|| {
+        let mut text = use_signal(|| "hello".to_string());
+        rsx! {
+            input { value: "{text}", oninput: move |event| text.set(event.value()) }
+        }
+    });
+    assert_eq!(logs, "");
+}
+
+#[test]
+fn controlled_checkbox_without_handler() {
+    let logs = warnings(|| {
+        let checked = true;
+        rsx! {
+            input { r#type: "checkbox", checked }
+        }
+    });
+    assert!(logs.contains("sets `checked`"), "{logs}");
+}
+
+#[test]
+fn dynamic_input_type() {
+    let logs = warnings(|| {
+        let kind = "radio";
+        let checked = true;
+        rsx! {
+            input { r#type: kind, checked }
+        }
+    });
+    assert!(logs.contains("sets `checked`"), "{logs}");
+}
+
+#[test]
+fn inputs_the_user_cant_edit_are_skipped() {
+    let logs = warnings(|| {
+        let label = "Send";
+        rsx! {
+            input { r#type: "hidden", value: "{label}" }
+            input { r#type: "submit", value: "{label}" }
+            input { r#type: "button", value: "{label}" }
+            input { r#type: "reset", value: "{label}" }
+            input { r#type: "image", value: "{label}" }
+            input { value: "{label}", readonly: true }
+            input { value: "{label}", disabled: true }
+        }
+    });
+    assert_eq!(logs, "");
+}
+
+#[test]
+fn checkbox_and_radio_values_are_skipped() {
+    let logs = warnings(|| {
+        let option = "red";
+        rsx! {
+            input { r#type: "checkbox", value: "{option}" }
+            input { r#type: "radio", value: "{option}" }
+        }
+    });
+    assert_eq!(logs, "");
+}
+
+#[test]
+fn initial_values_are_not_controlled() {
+    let logs = warnings(|| {
+        let text = "hello";
+        let checked = true;
+        rsx! {
+            input { initial_value: "{text}" }
+            textarea { initial_value: "{text}" }
+            input { r#type: "checkbox", initial_checked: checked }
+        }
+    });
+    assert_eq!(logs, "");
+}
@@ -207,3 +207,26 @@ fn diff_empty() {
         ]
     )
 }
+
+#[test]
+fn dynamic_element_swap() {
+    fn app() -> Element {
+        let g = generation();
+        let tag = if g < 2 { "h1" } else { "h2" };
+        Ok(VNode::dynamic_element(tag, None, vec![], rsx!("hello {g}")))
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    vdom.rebuild(&mut NoOpMutations);
+
+    // Keeping the same tag diffs the element in place
+    vdom.mark_dirty(ScopeId::APP);
+    let edits = vdom.render_immediate_to_vec().edits;
+    assert!(!edits.iter().any(|edit| matches!(edit, ReplaceWith { .. })));
+    assert!(edits.iter().any(|edit| matches!(edit, SetText { .. })));
+
+    // Changing the tag replaces the element
+    vdom.mark_dirty(ScopeId::APP);
+    let edits = vdom.render_immediate_to_vec().edits;
+    assert!(edits.iter().any(|edit| matches!(edit, ReplaceWith { .. })));
+}
@@ -586,3 +586,65 @@ fn keyed_list_with_dynamic_placeholder_and_text() {
         ]
     );
 }
+
+/// Duplicate keys can't be matched by identity, so the list is diffed by position instead of panicking
+#[test]
+fn keyed_diffing_duplicate_keys() {
+    let mut dom = VirtualDom::new(|| {
+        let order: &[_] = match generation() % 2 {
+            0 => &[0, 0, 1],
+            1 => &[1, 0, 0, 2],
+            _ => unreachable!(),
+        };
+
+        rsx!({
+            order.iter().map(|i| {
+                rsx! {
+                    div { key: "{i}" }
+                }
+            })
+        })
+    });
+
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    dom.mark_dirty(ScopeId::APP);
+    assert_eq!(
+        dom.render_immediate_to_vec().edits,
+        [
+            LoadTemplate { index: 0, id: ElementId(4) },
+            InsertAfter { id: ElementId(3), m: 1 },
+        ]
+    );
+}
+
+/// If only some siblings are keyed, the list is diffed by position instead of panicking
+#[test]
+fn keyed_diffing_mixed_keys() {
+    let mut dom = VirtualDom::new(|| {
+        let order: &[_] = match generation() % 2 {
+            0 => &[0, 1, 2],
+            1 => &[2, 1, 0],
+            _ => unreachable!(),
+        };
+
+        rsx!({
+            order.iter().map(|i| {
+                if i % 2 == 0 {
+                    rsx! {
+                        div { key: "{i}" }
+                    }
+                } else {
+                    rsx! {
+                        div {}
+                    }
+                }
+            })
+        })
+    });
+
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    dom.mark_dirty(ScopeId::APP);
+    assert_eq!(dom.render_immediate_to_vec().edits, []);
+}
@@ -90,3 +90,48 @@ fn clear_error_boundary() {
         assert!(consume_context::<ErrorContext>().error().is_none())
     })
 }
+
+#[test]
+fn throw_custom_error_to_boundary() {
+    #[derive(Debug)]
+    struct NotFound;
+
+    impl std::fmt::Display for NotFound {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("not found")
+        }
+    }
+
+    impl std::error::Error for NotFound {}
+
+    #[component]
+    fn App() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |errors: ErrorContext| {
+                    let error = errors.error().unwrap();
+                    if error.downcast_ref::<NotFound>().is_some() {
+                        rsx! { "Caught not found" }
+                    } else {
+                        rsx! { "Caught something else" }
+                    }
+                },
+                ThrowsNotFound {}
+            }
+        }
+    }
+
+    #[component]
+    fn ThrowsNotFound() -> Element {
+        let value: Option<i32> = Err(NotFound).throw();
+        assert!(value.is_none());
+        rsx! { "Rendered" }
+    }
+
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    let out = dioxus_ssr::render(&dom);
+    assert_eq!(out, "Caught not found");
+}
@@ -44,8 +44,13 @@ fn events_propagate() {
         Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())) as Rc<dyn Any>,
         true,
     );
-    dom.runtime().handle_event("click", event, ElementId(2));
+    dom.runtime()
+        .handle_event("click", event.clone(), ElementId(2));
     assert_eq!(*CLICKS.lock().unwrap(), 3);
+
+    // The event state can be read after it is dispatched
+    assert!(event.propagation_stopped());
+    assert!(!event.default_prevented());
 }
 
 fn app() -> Element {
@@ -65,3 +65,19 @@ fn drop_runs() {
     assert_eq!(*drop_count.lock().unwrap(), 1);
     *render_child.lock().unwrap() = false;
 }
+
+#[test]
+fn drop_runs_when_the_virtual_dom_is_dropped() {
+    let drop_count = Arc::new(Mutex::new(0));
+    let render_child = Arc::new(Mutex::new(true));
+    let mut dom = VirtualDom::new_with_props(
+        app,
+        AppProps { drop_count: drop_count.clone(), render_child: render_child.clone() },
+    );
+
+    dom.rebuild_in_place();
+    assert_eq!(*drop_count.lock().unwrap(), 0);
+
+    drop(dom);
+    assert_eq!(*drop_count.lock().unwrap(), 1);
+}
@@ -48,6 +48,8 @@ pub(crate) struct App {
 pub(crate) struct SharedContext {
     pub(crate) event_handlers: WindowEventHandlers,
     pub(crate) pending_webviews: RefCell<Vec<PendingWebview>>,
+    // Windows opened with `DesktopService::open_window` that are waiting to be added to the app
+    pub(crate) created_webviews: RefCell<Vec<WebviewInstance>>,
     pub(crate) shortcut_manager: ShortcutRegistry,
     pub(crate) proxy: EventLoopProxy<UserWindowEvent>,
     pub(crate) target: EventLoopWindowTarget<UserWindowEvent>,
@@ -77,6 +79,7 @@ impl App {
             shared: Rc::new(SharedContext {
                 event_handlers: WindowEventHandlers::default(),
                 pending_webviews: Default::default(),
+                created_webviews: Default::default(),
                 shortcut_manager: ShortcutRegistry::new(),
                 proxy: event_loop.create_proxy(),
                 target: event_loop.clone(),
@@ -190,9 +193,34 @@ impl App {
             self.webviews.insert(id, window);
             _ = self.shared.proxy.send_event(UserWindowEvent::Poll(id));
         }
+
+        let created: Vec<_> = self
+            .shared
+            .created_webviews
+            .borrow_mut()
+            .drain(..)
+            .collect();
+        for window in created {
+            let id = window.desktop_context.window.id();
+            self.webviews.insert(id, window);
+            _ = self.shared.proxy.send_event(UserWindowEvent::Poll(id));
+        }
     }
 
     pub fn handle_close_requested(&mut self, id: WindowId) {
+        let Some(window) = self.webviews.get(&id) else {
+            return;
+        };
+
+        // The user tried to close the window, give the app a chance to keep it open
+        if !window.desktop_context.should_close() {
+            return;
+        }
+
+        self.close_window(id);
+    }
+
+    pub fn close_window(&mut self, id: WindowId) {
         let Some(window) = self.webviews.get(&id) else {
             // If the window is not found, we can just return
             return;
@@ -0,0 +1,197 @@
+use dioxus_core::use_hook;
+
+/// Get a handle to the system clipboard.
+///
+/// Text and images are supported on Windows, macOS, Linux, and the BSDs. Using the clipboard on iOS and Android
+/// returns [`ClipboardError::Unavailable`].
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::use_clipboard;
+/// fn app() -> Element {
+///     let clipboard = use_clipboard();
+///     let mut pasted = use_signal(String::new);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| async move {
+///                 if let Err(err) = clipboard.write_text("Hello from dioxus").await {
+///                     pasted.set(err.to_string());
+///                 }
+///             },
+///             "Copy"
+///         }
+///         button {
+///             onclick: move |_| async move {
+///                 match clipboard.read_text().await {
+///                     Ok(text) => pasted.set(text),
+///                     Err(err) => pasted.set(err.to_string()),
+///                 }
+///             },
+///             "Paste"
+///         }
+///         "{pasted}"
+///     }
+/// }
+/// ```
+pub fn use_clipboard() -> Clipboard {
+    use_hook(|| Clipboard { _private: () })
+}
+
+/// A handle to the system clipboard returned by [`use_clipboard`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Clipboard {
+    _private: (),
+}
+
+impl Clipboard {
+    /// Read the text on the clipboard. Returns an empty string if the clipboard doesn't contain text.
+    pub async fn read_text(&self) -> Result<String, ClipboardError> {
+        native::read_text()
+    }
+
+    /// Replace the contents of the clipboard with text
+    pub async fn write_text(&self, text: &str) -> Result<(), ClipboardError> {
+        native::write_text(text)
+    }
+
+    /// Read the image on the clipboard encoded as a png. Returns `None` if the clipboard doesn't contain an image.
+    pub async fn read_image(&self) -> Result<Option<Vec<u8>>, ClipboardError> {
+        native::read_image()
+    }
+
+    /// Replace the contents of the clipboard with a png image
+    pub async fn write_image(&self, png: &[u8]) -> Result<(), ClipboardError> {
+        native::write_image(png)
+    }
+}
+
+/// An error that occurred while using the clipboard
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum ClipboardError {
+    /// The clipboard is not available on this platform
+    #[error("The clipboard is not available")]
+    Unavailable,
+    /// The system denied access to the clipboard. Desktop platforms don't ask for permission to use the clipboard,
+    /// so this is never returned on desktop. It is kept so the error matches the web clipboard.
+    #[error("Permission to use the clipboard was denied")]
+    PermissionDenied,
+    /// Any other error from the clipboard
+    #[error("Failed to use the clipboard: {0}")]
+    Other(String),
+}
+
+#[cfg(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod native {
+    use super::ClipboardError;
+    use image::{ImageFormat, RgbaImage};
+    use std::cell::RefCell;
+    use std::io::Cursor;
+
+    thread_local! {
+        // On linux, the contents of the clipboard are owned by the process and are lost when the clipboard is
+        // dropped, so we keep one alive for the lifetime of the app
+        static CLIPBOARD: RefCell<Option<arboard::Clipboard>> = const { RefCell::new(None) };
+    }
+
+    fn with_clipboard<T>(
+        f: impl FnOnce(&mut arboard::Clipboard) -> Result<T, arboard::Error>,
+    ) -> Result<T, arboard::Error> {
+        CLIPBOARD.with_borrow_mut(|clipboard| {
+            if clipboard.is_none() {
+                *clipboard = Some(arboard::Clipboard::new()?);
+            }
+            f(clipboard.as_mut().unwrap())
+        })
+    }
+
+    pub(super) fn read_text() -> Result<String, ClipboardError> {
+        match with_clipboard(|clipboard| clipboard.get_text()) {
+            Err(arboard::Error::ContentNotAvailable) => Ok(String::new()),
+            result => result.map_err(ClipboardError::from),
+        }
+    }
+
+    pub(super) fn write_text(text: &str) -> Result<(), ClipboardError> {
+        with_clipboard(|clipboard| clipboard.set_text(text)).map_err(ClipboardError::from)
+    }
+
+    pub(super) fn read_image() -> Result<Option<Vec<u8>>, ClipboardError> {
+        let image = match with_clipboard(|clipboard| clipboard.get_image()) {
+            Err(arboard::Error::ContentNotAvailable) => return Ok(None),
+            result => result?,
+        };
+        // The clipboard holds raw rgba pixels, so encode them as a png to match the web clipboard
+        let image = RgbaImage::from_raw(
+            image.width as u32,
+            image.height as u32,
+            image.bytes.into_owned(),
+        )
+        .ok_or_else(|| {
+            ClipboardError::Other("The clipboard image has the wrong size".to_string())
+        })?;
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .map_err(|err| ClipboardError::Other(err.to_string()))?;
+        Ok(Some(png))
+    }
+
+    pub(super) fn write_image(png: &[u8]) -> Result<(), ClipboardError> {
+        let image = image::load_from_memory_with_format(png, ImageFormat::Png)
+            .map_err(|err| ClipboardError::Other(err.to_string()))?
+            .into_rgba8();
+        let image = arboard::ImageData {
+            width: image.width() as usize,
+            height: image.height() as usize,
+            bytes: image.into_raw().into(),
+        };
+        with_clipboard(|clipboard| clipboard.set_image(image)).map_err(ClipboardError::from)
+    }
+
+    impl From<arboard::Error> for ClipboardError {
+        fn from(err: arboard::Error) -> Self {
+            match err {
+                arboard::Error::ClipboardNotSupported => Self::Unavailable,
+                err => Self::Other(err.to_string()),
+            }
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+mod native {
+    use super::ClipboardError;
+
+    pub(super) fn read_text() -> Result<String, ClipboardError> {
+        Err(ClipboardError::Unavailable)
+    }
+
+    pub(super) fn write_text(_text: &str) -> Result<(), ClipboardError> {
+        Err(ClipboardError::Unavailable)
+    }
+
+    pub(super) fn read_image() -> Result<Option<Vec<u8>>, ClipboardError> {
+        Err(ClipboardError::Unavailable)
+    }
+
+    pub(super) fn write_image(_png: &[u8]) -> Result<(), ClipboardError> {
+        Err(ClipboardError::Unavailable)
+    }
+}
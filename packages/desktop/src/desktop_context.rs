@@ -6,11 +6,12 @@ use crate::{
     ipc::UserWindowEvent,
     query::QueryEngine,
     shortcut::{HotKey, HotKeyState, ShortcutHandle, ShortcutRegistryError},
-    webview::PendingWebview,
+    webview::{PendingWebview, WebviewInstance},
 };
-use dioxus_core::{Callback, VirtualDom};
+use dioxus_core::{Callback, Element, VirtualDom};
+use slab::Slab;
 use std::{
-    cell::Cell,
+    cell::{Cell, RefCell},
     future::{Future, IntoFuture},
     pin::Pin,
     rc::{Rc, Weak},
@@ -30,6 +31,9 @@ use objc2_ui_kit::UIView;
 #[cfg(target_os = "ios")]
 use tao::platform::ios::WindowExtIOS;
 
+/// A handler registered with [`crate::use_close_requested_handler`]
+pub(crate) type CloseRequestedHandler = Rc<RefCell<dyn FnMut() -> bool>>;
+
 /// Get an imperative handle to the current window without using a hook
 ///
 /// ## Panics
@@ -39,6 +43,36 @@ pub fn window() -> DesktopContext {
     dioxus_core::consume_context()
 }
 
+/// Open a new window that renders `app` and return its id.
+///
+/// The new window has its own [`VirtualDom`]. To share signals with it, build the [`VirtualDom`] yourself with
+/// [`VirtualDom::with_root_context`] and pass it to [`DesktopService::open_window`].
+///
+/// ## Panics
+///
+/// This function will panic if it is called outside of the context of a Dioxus App.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 dioxus::desktop::new_window(Default::default(), panel);
+///             },
+///             "Open panel"
+///         }
+///     }
+/// }
+///
+/// fn panel() -> Element {
+///     rsx! { "Detached panel" }
+/// }
+/// ```
+pub fn new_window(config: Config, app: fn() -> Element) -> WindowId {
+    window().open_window(VirtualDom::new(app), config)
+}
+
 /// A handle to the [`DesktopService`] that can be passed around.
 pub type DesktopContext = Rc<DesktopService>;
 
@@ -73,6 +107,7 @@ pub struct DesktopService {
     pub(crate) asset_handlers: AssetHandlerRegistry,
     pub(crate) file_hover: NativeFileHover,
     pub(crate) close_behaviour: Rc<Cell<WindowCloseBehaviour>>,
+    pub(crate) close_requested_handlers: Rc<RefCell<Slab<CloseRequestedHandler>>>,
 
     #[cfg(target_os = "ios")]
     pub(crate) views: Rc<std::cell::RefCell<Vec<Retained<UIView>>>>,
@@ -103,6 +138,7 @@ impl DesktopService {
             asset_handlers,
             file_hover,
             close_behaviour: Rc::new(Cell::new(close_behaviour)),
+            close_requested_handlers: Default::default(),
             query: Default::default(),
             #[cfg(target_os = "ios")]
             views: Default::default(),
@@ -116,6 +152,11 @@ impl DesktopService {
     ///
     /// Be careful to not create a cycle of windows, or you might leak memory.
     ///
+    /// Every window runs its own [`VirtualDom`], but all windows live on the same thread. You can share
+    /// state between windows by providing a [`Signal`](https://docs.rs/dioxus-signals) as root context
+    /// with [`VirtualDom::with_root_context`]. Components in any window that read the signal will rerun
+    /// when it changes.
+    ///
     /// # Example
     ///
     /// ```rust, no_run
@@ -155,6 +196,20 @@ impl DesktopService {
         context
     }
 
+    /// Create a new window that renders `dom` and return its id.
+    ///
+    /// Unlike [`Self::new_window`], the window is created right away instead of on the next turn of the event loop,
+    /// so the id can be used immediately, for example to close the window later with [`Self::close_window`].
+    pub fn open_window(&self, dom: VirtualDom, cfg: Config) -> WindowId {
+        let window = WebviewInstance::new(cfg, dom, self.shared.clone());
+        let id = window.desktop_context.window.id();
+
+        self.shared.created_webviews.borrow_mut().push(window);
+        _ = self.shared.proxy.send_event(UserWindowEvent::NewWindow);
+
+        id
+    }
+
     /// trigger the drag-window event
     ///
     /// Moves the window with the left mouse button until the button is released.
@@ -182,7 +237,26 @@ impl DesktopService {
         self.close_behaviour.set(behaviour);
     }
 
+    /// Check if every close requested handler registered with [`crate::use_close_requested_handler`] allows
+    /// this window to close.
+    pub(crate) fn should_close(&self) -> bool {
+        // Handlers can add or remove other handlers when they run, so they are collected before running them
+        let handlers: Vec<_> = self
+            .close_requested_handlers
+            .borrow()
+            .iter()
+            .map(|(_, handler)| handler.clone())
+            .collect();
+        // Run every handler even if an earlier one already prevented the close
+        handlers.iter().fold(true, |should_close, handler| {
+            (handler.borrow_mut())() && should_close
+        })
+    }
+
     /// Close this window
+    ///
+    /// Unlike closing the window from the title bar, this does not run handlers registered with
+    /// [`crate::use_close_requested_handler`].
     pub fn close(&self) {
         let _ = self
             .shared
@@ -207,6 +281,37 @@ impl DesktopService {
         }
     }
 
+    /// Show a native context menu over this window at `position`, or at the cursor if `position` is `None`.
+    ///
+    /// This blocks until the menu is closed and returns `true` if an item was clicked. Clicks on the items are
+    /// delivered to [`crate::use_muda_event_handler`] like clicks in the menu bar. Call this from an `oncontextmenu`
+    /// handler after calling `prevent_default` to replace the webview's menu:
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_desktop::{muda::{Menu, MenuItem}, window};
+    /// fn App() -> Element {
+    ///     rsx! {
+    ///         div {
+    ///             oncontextmenu: move |event| {
+    ///                 event.prevent_default();
+    ///                 let menu = Menu::new();
+    ///                 _ = menu.append(&MenuItem::with_id("copy", "Copy", true, None));
+    ///                 window().show_context_menu(&menu, None);
+    ///             },
+    ///             "Right click me"
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn show_context_menu(
+        &self,
+        menu: &crate::menubar::DioxusMenu,
+        position: Option<tao::dpi::LogicalPosition<f64>>,
+    ) -> bool {
+        crate::menubar::show_context_menu(menu, &self.window, position)
+    }
+
     /// launch print modal
     pub fn print(&self) {
         if let Err(e) = self.webview.print() {
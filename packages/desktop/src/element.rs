@@ -54,6 +54,56 @@ macro_rules! scripted_getter {
     };
 }
 
+macro_rules! scripted_action {
+    ($meth_name:ident, $script:literal) => {
+        fn $meth_name(
+            &self,
+        ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<()>>>> {
+            let script = format!($script, id = self.id.0);
+            let webview = self
+                .webview
+                .upgrade()
+                .expect("Webview should be alive if the element is being queried");
+            let fut = self.query.new_query::<bool>(&script, webview).resolve();
+            Box::pin(async move {
+                match fut.await {
+                    Ok(true) => Ok(()),
+                    Ok(false) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                        Box::new(DesktopQueryError::FailedToQuery),
+                    )),
+                    Err(err) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                        Box::new(err),
+                    )),
+                }
+            })
+        }
+    };
+    ($meth_name:ident, $arg:ident: $arg_ty:ty, $script:literal) => {
+        fn $meth_name(
+            &self,
+            $arg: $arg_ty,
+        ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<()>>>> {
+            let script = format!($script, id = self.id.0, $arg = $arg);
+            let webview = self
+                .webview
+                .upgrade()
+                .expect("Webview should be alive if the element is being queried");
+            let fut = self.query.new_query::<bool>(&script, webview).resolve();
+            Box::pin(async move {
+                match fut.await {
+                    Ok(true) => Ok(()),
+                    Ok(false) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                        Box::new(DesktopQueryError::FailedToQuery),
+                    )),
+                    Err(err) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                        Box::new(err),
+                    )),
+                }
+            })
+        }
+    };
+}
+
 impl RenderedElementBacking for DesktopElement {
     fn as_any(&self) -> &dyn std::any::Any {
         self
@@ -134,6 +184,35 @@ impl RenderedElementBacking for DesktopElement {
         })
     }
 
+    scripted_action!(
+        show_modal,
+        "const node = window.interpreter.nodes[{id}]; if (!(node instanceof HTMLDialogElement)) {{ return false; }} if (!node.open) {{ node.showModal(); }} return true;"
+    );
+
+    scripted_action!(
+        close,
+        "const node = window.interpreter.nodes[{id}]; if (!(node instanceof HTMLDialogElement)) {{ return false; }} node.close(); return true;"
+    );
+
+    scripted_action!(
+        set_pointer_capture,
+        pointer_id: i32,
+        "try {{ window.interpreter.nodes[{id}].setPointerCapture({pointer_id}); return true; }} catch {{ return false; }}"
+    );
+
+    scripted_action!(
+        release_pointer_capture,
+        pointer_id: i32,
+        "try {{ window.interpreter.nodes[{id}].releasePointerCapture({pointer_id}); return true; }} catch {{ return false; }}"
+    );
+
+    scripted_action!(trap_focus, "return window.interpreter.trapFocus({id});");
+
+    scripted_action!(
+        release_focus_trap,
+        "return window.interpreter.releaseFocusTrap({id});"
+    );
+
     fn set_focus(
         &self,
         focus: bool,
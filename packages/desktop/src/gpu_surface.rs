@@ -0,0 +1,222 @@
+use crate::{ipc::UserWindowEvent, use_window, use_wry_event_handler};
+use dioxus_core::{Attribute, spawn, use_hook};
+use dioxus_html::{MountedData, MountedEvent, SurfaceSize, geometry::PixelsRect};
+use dioxus_signals::{ReadSignal, ReadableExt, Signal, WritableExt};
+use std::{rc::Rc, sync::Arc};
+use tao::{
+    event::{Event, WindowEvent},
+    window::{Window, WindowId},
+};
+
+/// Get a handle to the native window that you can render to with wgpu behind the Dioxus UI.
+///
+/// Desktop only offers a full-window surface behind a transparent webview. The webview can't host a GPU surface, so
+/// unlike `use_gpu_canvas` on the web there is no surface for a single element: the surface covers the whole window
+/// and the webview is drawn on top of it. Launch the app with [`Config::with_as_child_window`](crate::Config::with_as_child_window) and a
+/// transparent window so the surface shows through the page, then create the surface from
+/// [`GpuSurface::window`]. The window implements `HasWindowHandle` and `HasDisplayHandle` from `raw-window-handle`
+/// 0.6, so you can pass it directly to `wgpu::Instance::create_surface`.
+///
+/// [`GpuSurface::size`] tracks the size of the window the component is rendered in, in physical pixels, and updates
+/// when that window is resized or moved to a display with a different scale factor. To render into part of the window, spread
+/// [`GpuSurface::attributes`] onto the element the content should appear behind and read its bounds from
+/// [`GpuSurface::viewport`].
+///
+/// ## Lifetime of the surface
+///
+/// The window lives as long as the app, so a surface created from it stays valid after the component is dropped.
+/// Store the surface in the component that renders to it so it is dropped with the component, or create it once in
+/// [`Config::with_on_window`](crate::Config::with_on_window) and provide it as a root context if it should outlive
+/// the component.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// # use dioxus::prelude::*;
+/// # use dioxus::desktop::use_gpu_surface;
+/// fn App() -> Element {
+///     let surface = use_gpu_surface();
+///
+///     let renderer = use_resource({
+///         let surface = surface.clone();
+///         move || {
+///             let window = surface.window();
+///             async move {
+///                 let instance = wgpu::Instance::default();
+///                 let target = instance.create_surface(window).ok()?;
+///                 Some(Renderer::new(instance, target).await)
+///             }
+///         }
+///     });
+///
+///     // Configure the surface when the window is resized
+///     use_effect(move || {
+///         let size = surface.size()();
+///         if let Some(Some(renderer)) = &*renderer.read() {
+///             renderer.resize(size.width, size.height);
+///         }
+///     });
+///
+///     rsx! {
+///         div { class: "overlay", "Dioxus UI on top of the 3D scene" }
+///     }
+/// }
+/// ```
+pub fn use_gpu_surface() -> GpuSurface {
+    let window = use_window();
+    let surface = use_hook(|| GpuSurface {
+        window: window.window.clone(),
+        size: Signal::new(surface_size(&window.window)),
+        element: Signal::new(None),
+        viewport: Signal::new(None),
+    });
+
+    use_wry_event_handler({
+        let surface = surface.clone();
+        move |event, _| {
+            // Other windows of the app share the event loop, so ignore their events
+            if resized_window(event) == Some(surface.window.id()) {
+                let mut size = surface.size;
+                let new = surface_size(&surface.window);
+                if new != *size.peek() {
+                    size.set(new);
+                }
+                surface.measure();
+            }
+        }
+    });
+
+    surface
+}
+
+/// A handle to the native window for a GPU surface, created with [`use_gpu_surface`]
+#[derive(Clone)]
+pub struct GpuSurface {
+    window: Arc<Window>,
+    size: Signal<SurfaceSize>,
+    element: Signal<Option<Rc<MountedData>>>,
+    viewport: Signal<Option<PixelsRect>>,
+}
+
+impl GpuSurface {
+    /// Get the window to create the surface from
+    pub fn window(&self) -> Arc<Window> {
+        self.window.clone()
+    }
+
+    /// Track the size of the window in physical pixels
+    pub fn size(&self) -> ReadSignal<SurfaceSize> {
+        self.size.into()
+    }
+
+    /// The attributes to spread onto the element the surface should render behind
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let handle = self.clone();
+        vec![dioxus_html::events::onmounted(
+            move |event: MountedEvent| handle.attach(event.data()),
+        )]
+    }
+
+    /// Track the bounds of `element` in [`GpuSurface::viewport`]. Call this from your own `onmounted` handler if you
+    /// can't spread [`GpuSurface::attributes`] onto the element.
+    pub fn attach(&self, element: Rc<MountedData>) {
+        let mut handle = self.clone();
+        handle.element.set(Some(element));
+        self.measure();
+    }
+
+    /// Get the bounds of the attached element in physical pixels relative to the top left of the window, or `None`
+    /// if no element has been attached yet.
+    ///
+    /// The bounds are measured when the element is mounted and when the window is resized. Set the viewport or
+    /// scissor rect of your render pass to them to only draw behind the element.
+    pub fn viewport(&self) -> ReadSignal<Option<PixelsRect>> {
+        self.viewport.into()
+    }
+
+    /// Measure the bounds of the attached element
+    fn measure(&self) {
+        let Some(element) = (*self.element.peek()).clone() else {
+            return;
+        };
+        let scale_factor = self.window.scale_factor();
+        let mut viewport = self.viewport;
+        spawn(async move {
+            if let Ok(rect) = element.get_client_rect().await {
+                let new = Some(rect.scale(scale_factor, scale_factor));
+                if new != *viewport.peek() {
+                    viewport.set(new);
+                }
+            }
+        });
+    }
+}
+
+/// The window that was resized or moved to a display with a different scale factor, if the event is one of those
+fn resized_window(event: &Event<UserWindowEvent>) -> Option<WindowId> {
+    match event {
+        Event::WindowEvent {
+            window_id,
+            event: WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. },
+            ..
+        } => Some(*window_id),
+        _ => None,
+    }
+}
+
+fn surface_size(window: &Window) -> SurfaceSize {
+    let size = window.inner_size();
+    SurfaceSize {
+        width: size.width,
+        height: size.height,
+        scale_factor: window.scale_factor(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tao::dpi::PhysicalSize;
+
+    fn window_event(event: WindowEvent<'static>) -> Event<'static, UserWindowEvent> {
+        Event::WindowEvent {
+            // SAFETY: the id is only compared, never used to look up a window
+            window_id: unsafe { WindowId::dummy() },
+            event,
+        }
+    }
+
+    #[test]
+    fn resizes_are_reported_with_their_window() {
+        let event = window_event(WindowEvent::Resized(PhysicalSize::new(800, 600)));
+        assert_eq!(resized_window(&event), Some(unsafe { WindowId::dummy() }));
+    }
+
+    #[test]
+    fn scale_factor_changes_are_reported_with_their_window() {
+        let mut size = PhysicalSize::new(1600, 1200);
+        let event = Event::WindowEvent {
+            window_id: unsafe { WindowId::dummy() },
+            event: WindowEvent::ScaleFactorChanged {
+                scale_factor: 2.0,
+                new_inner_size: &mut size,
+            },
+        };
+        assert_eq!(resized_window(&event), Some(unsafe { WindowId::dummy() }));
+    }
+
+    #[test]
+    fn other_events_are_ignored() {
+        assert_eq!(
+            resized_window(&window_event(WindowEvent::Focused(true))),
+            None
+        );
+        assert_eq!(resized_window(&Event::MainEventsCleared), None);
+        assert_eq!(
+            resized_window(&Event::UserEvent(UserWindowEvent::Poll(unsafe {
+                WindowId::dummy()
+            }))),
+            None
+        );
+    }
+}
@@ -1,10 +1,11 @@
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
 use crate::{
     DesktopContext, HotKeyState, ShortcutHandle, ShortcutRegistryError, WryEventHandler, assets::*,
     ipc::UserWindowEvent, shortcut::IntoAccelerator, window,
 };
 use dioxus_core::{Runtime, consume_context, use_hook, use_hook_with_cleanup};
+pub use dioxus_html::ShouldPrevent;
 
 use dioxus_hooks::use_callback;
 use tao::{event::Event, event_loop::EventLoopWindowTarget};
@@ -35,6 +36,90 @@ pub fn use_wry_event_handler(
     )
 }
 
+/// Register a handler that runs when the user tries to close the current window, for example with the
+/// close button in the title bar. Return `false` from the handler to keep the window open.
+///
+/// The handler is not called when the window is closed with [`DesktopService::close`](crate::DesktopService::close).
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::use_close_requested_handler;
+/// fn app() -> Element {
+///     let mut unsaved = use_signal(|| true);
+///     let mut show_prompt = use_signal(|| false);
+///     use_close_requested_handler(move || {
+///         show_prompt.set(unsaved());
+///         !unsaved()
+///     });
+///
+///     rsx! {
+///         if show_prompt() {
+///             "You have unsaved changes"
+///             button {
+///                 onclick: move |_| dioxus::desktop::window().close(),
+///                 "Close anyway"
+///             }
+///         }
+///         button { onclick: move |_| unsaved.set(false), "Save" }
+///     }
+/// }
+/// ```
+pub fn use_close_requested_handler(mut handler: impl FnMut() -> bool + 'static) {
+    use dioxus_core::current_scope_id;
+
+    let runtime = Runtime::current();
+    let scope_id = current_scope_id();
+
+    use_hook_with_cleanup(
+        move || {
+            let window = window();
+            let key = window
+                .close_requested_handlers
+                .borrow_mut()
+                .insert(Rc::new(RefCell::new(move || {
+                    runtime.in_scope(scope_id, &mut handler)
+                })));
+            (window, key)
+        },
+        |(window, key)| {
+            window.close_requested_handlers.borrow_mut().try_remove(key);
+        },
+    );
+}
+
+/// Register a handler that runs when the user tries to close the current window. Return [`ShouldPrevent::Yes`] from
+/// the handler to keep the window open, for example to show your own "unsaved changes" dialog.
+///
+/// This takes the same handler as `use_before_unload` in `dioxus-web`, so the same code can block leaving on both
+/// platforms. Unlike the web, the desktop renderer doesn't show a confirmation dialog of its own.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::{ShouldPrevent, use_before_unload};
+/// fn app() -> Element {
+///     let mut dirty = use_signal(|| false);
+///     let mut show_prompt = use_signal(|| false);
+///     use_before_unload(move || {
+///         show_prompt.set(dirty());
+///         ShouldPrevent::from(dirty())
+///     });
+///
+///     rsx! {
+///         textarea { oninput: move |_| dirty.set(true) }
+///         if show_prompt() {
+///             "You have unsaved changes"
+///             button {
+///                 onclick: move |_| dioxus::desktop::window().close(),
+///                 "Close anyway"
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_before_unload(mut handler: impl FnMut() -> ShouldPrevent + 'static) {
+    use_close_requested_handler(move || handler() == ShouldPrevent::No)
+}
+
 /// Register an event handler that runs when a muda event is processed.
 #[cfg_attr(
     docsrs,
@@ -110,7 +195,35 @@ pub fn use_asset_handler(
     );
 }
 
-/// Get a closure that executes any JavaScript in the WebView context.
+/// Register a global shortcut that fires even when the window is not focused.
+///
+/// Accelerators can be written as strings like `"CmdOrCtrl+Shift+P"`. The handler runs inside the
+/// component, so it can write to signals. The shortcut is unregistered when the component is dropped.
+///
+/// Returns an error if the accelerator is invalid or if the shortcut is already taken by another
+/// application or the operating system.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::{use_global_shortcut, HotKeyState};
+/// fn app() -> Element {
+///     let mut open = use_signal(|| false);
+///     let shortcut = use_global_shortcut("CmdOrCtrl+Shift+P", move |state| {
+///         if state == HotKeyState::Pressed {
+///             open.toggle();
+///         }
+///     });
+///
+///     rsx! {
+///         if let Err(err) = shortcut {
+///             "Failed to register the shortcut: {err:?}"
+///         }
+///         if open() {
+///             "Command palette"
+///         }
+///     }
+/// }
+/// ```
 pub fn use_global_shortcut(
     accelerator: impl IntoAccelerator,
     handler: impl FnMut(HotKeyState) + 'static,
@@ -120,7 +233,11 @@ pub fn use_global_shortcut(
 
     use_hook_with_cleanup(
         #[allow(clippy::redundant_closure)]
-        move || window().create_shortcut(accelerator.accelerator(), move |state| cb(state)),
+        move || {
+            accelerator
+                .try_accelerator()
+                .and_then(|hotkey| window().create_shortcut(hotkey, move |state| cb(state)))
+        },
         |handle| {
             if let Ok(handle) = handle {
                 handle.remove();
@@ -8,6 +8,7 @@
 mod android_sync_lock;
 mod app;
 mod assets;
+mod clipboard;
 mod config;
 mod default_icon;
 mod desktop_context;
@@ -17,15 +18,22 @@ mod element;
 mod event_handlers;
 mod events;
 mod file_upload;
+mod gpu_surface;
 mod hooks;
 mod ipc;
+mod media_query;
 mod menubar;
 mod mobile;
+mod page_visibility;
 mod protocol;
 mod query;
 mod shortcut;
 mod waker;
 mod webview;
+mod window_metrics;
+
+#[cfg(feature = "sse")]
+mod server_sent_events;
 
 // mobile shortcut is only supported on mobile platforms
 #[cfg(any(target_os = "ios", target_os = "android"))]
@@ -50,12 +58,21 @@ pub mod trayicon;
 
 // Public exports
 pub use assets::AssetRequest;
+pub use clipboard::{Clipboard, ClipboardError, use_clipboard};
 pub use config::{Config, WindowCloseBehaviour};
 pub use default_icon::{default_icon, icon_from_memory, icon_from_path};
 pub use desktop_context::{
-    DesktopContext, DesktopService, PendingDesktopContext, WeakDesktopContext, window,
+    DesktopContext, DesktopService, PendingDesktopContext, WeakDesktopContext, new_window, window,
 };
 pub use event_handlers::WryEventHandler;
+pub use gpu_surface::{GpuSurface, use_gpu_surface};
 pub use hooks::*;
+pub use media_query::use_media_query;
+pub use page_visibility::use_page_visibility;
+#[cfg(feature = "sse")]
+pub use server_sent_events::{
+    ServerSentEvents, SseEvent, SseState, use_named_server_sent_events, use_server_sent_events,
+};
 pub use shortcut::{HotKeyState, ShortcutHandle, ShortcutRegistryError};
+pub use window_metrics::{use_scroll_position, use_window_size};
 pub use wry::RequestAsyncResponder;
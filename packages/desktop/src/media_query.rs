@@ -0,0 +1,144 @@
+use crate::{DesktopContext, use_window, use_wry_event_handler};
+use dioxus_core::use_hook;
+use dioxus_signals::{ReadSignal, Signal, WritableExt};
+use tao::{
+    event::{Event, WindowEvent},
+    window::Theme,
+};
+
+/// Track whether a css media query matches the current window.
+///
+/// Desktop apps evaluate the query against the size and theme of the native window instead of the webview, so
+/// only a subset of media features is supported:
+/// - `min-width`, `max-width`, `min-height`, and `max-height` in `px` (logical pixels)
+/// - `orientation: portrait` and `orientation: landscape`
+/// - `prefers-color-scheme: dark` and `prefers-color-scheme: light`
+///
+/// Features can be combined with `and`. Queries with unsupported features never match. The signal updates when the
+/// window is resized or the system theme changes, and the listener is removed when the component is dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::use_media_query;
+/// fn app() -> Element {
+///     let dark = use_media_query("(prefers-color-scheme: dark)");
+///     let narrow = use_media_query("(max-width: 600px)");
+///
+///     rsx! {
+///         div {
+///             class: if dark() { "dark" } else { "light" },
+///             if narrow() { "Narrow layout" } else { "Wide layout" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_media_query(query: &str) -> ReadSignal<bool> {
+    let window = use_window();
+    let (parsed, mut matches) = use_hook(|| {
+        let parsed = MediaQuery::parse(query);
+        if parsed.is_none() {
+            tracing::warn!("Unsupported media query {query:?} on desktop. It will never match.");
+        }
+        let matches = Signal::new(parsed.as_ref().is_some_and(|q| q.matches(&window)));
+        (parsed, matches)
+    });
+
+    use_wry_event_handler(move |event, _| {
+        if let Event::WindowEvent {
+            event:
+                WindowEvent::Resized(_)
+                | WindowEvent::ScaleFactorChanged { .. }
+                | WindowEvent::ThemeChanged(_),
+            ..
+        } = event
+        {
+            let new = parsed.as_ref().is_some_and(|q| q.matches(&window));
+            if new != *matches.peek() {
+                matches.set(new);
+            }
+        }
+    });
+
+    matches.into()
+}
+
+/// A media query that can be evaluated against the metrics of a native window
+#[derive(Clone, Debug, PartialEq)]
+struct MediaQuery {
+    conditions: Vec<MediaCondition>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MediaCondition {
+    MinWidth(f64),
+    MaxWidth(f64),
+    MinHeight(f64),
+    MaxHeight(f64),
+    Portrait,
+    Landscape,
+    Dark,
+    Light,
+}
+
+impl MediaQuery {
+    /// Parse a query like `(min-width: 600px) and (orientation: landscape)`. Returns `None` if any part is unsupported.
+    fn parse(query: &str) -> Option<Self> {
+        let conditions = query
+            .split(" and ")
+            .map(|condition| {
+                let condition = condition.trim().strip_prefix('(')?.strip_suffix(')')?;
+                let (feature, value) = condition.split_once(':')?;
+                let value = value.trim();
+                let pixels = || value.strip_suffix("px")?.trim().parse::<f64>().ok();
+                Some(match feature.trim() {
+                    "min-width" => MediaCondition::MinWidth(pixels()?),
+                    "max-width" => MediaCondition::MaxWidth(pixels()?),
+                    "min-height" => MediaCondition::MinHeight(pixels()?),
+                    "max-height" => MediaCondition::MaxHeight(pixels()?),
+                    "orientation" if value == "portrait" => MediaCondition::Portrait,
+                    "orientation" if value == "landscape" => MediaCondition::Landscape,
+                    "prefers-color-scheme" if value == "dark" => MediaCondition::Dark,
+                    "prefers-color-scheme" if value == "light" => MediaCondition::Light,
+                    _ => return None,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { conditions })
+    }
+
+    fn matches(&self, window: &DesktopContext) -> bool {
+        let size = window
+            .window
+            .inner_size()
+            .to_logical::<f64>(window.window.scale_factor());
+        self.matches_metrics(size.width, size.height, window.window.theme())
+    }
+
+    fn matches_metrics(&self, width: f64, height: f64, theme: Theme) -> bool {
+        self.conditions.iter().all(|condition| match *condition {
+            MediaCondition::MinWidth(min) => width >= min,
+            MediaCondition::MaxWidth(max) => width <= max,
+            MediaCondition::MinHeight(min) => height >= min,
+            MediaCondition::MaxHeight(max) => height <= max,
+            MediaCondition::Portrait => height >= width,
+            MediaCondition::Landscape => width > height,
+            MediaCondition::Dark => theme == Theme::Dark,
+            MediaCondition::Light => theme == Theme::Light,
+        })
+    }
+}
+
+#[test]
+fn evaluates_media_queries() {
+    let query = MediaQuery::parse("(min-width: 600px) and (orientation: landscape)").unwrap();
+    assert!(query.matches_metrics(800.0, 600.0, Theme::Light));
+    assert!(!query.matches_metrics(500.0, 400.0, Theme::Light));
+    assert!(!query.matches_metrics(600.0, 800.0, Theme::Light));
+
+    let query = MediaQuery::parse("(prefers-color-scheme: dark)").unwrap();
+    assert!(query.matches_metrics(800.0, 600.0, Theme::Dark));
+    assert!(!query.matches_metrics(800.0, 600.0, Theme::Light));
+
+    assert_eq!(MediaQuery::parse("(hover: hover)"), None);
+    assert_eq!(MediaQuery::parse("max-width: 600px"), None);
+}
@@ -19,6 +19,25 @@ pub fn init_menu_bar(menu: &DioxusMenu, window: &Window) {
     }
 }
 
+/// Shows `menu` as a native context menu over the window at `position`, or at the cursor if `position` is `None`.
+/// Returns `true` if an item in the menu was clicked.
+#[allow(unused)]
+pub fn show_context_menu(
+    menu: &DioxusMenu,
+    window: &Window,
+    position: Option<tao::dpi::LogicalPosition<f64>>,
+) -> bool {
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    {
+        desktop_platforms::show_context_menu(menu, window, position)
+    }
+
+    #[cfg(any(target_os = "ios", target_os = "android"))]
+    {
+        false
+    }
+}
+
 /// Creates a standard menu bar depending on the users platform. It may be used as a starting point
 /// to further customize the menu bar and pass it to a [`WindowBuilder`](tao::window::WindowBuilder).
 /// > Note: The default menu bar enables macOS shortcuts like cut/copy/paste.
@@ -59,6 +78,39 @@ mod desktop_platforms {
         }
     }
 
+    #[allow(unused)]
+    pub fn show_context_menu(
+        menu: &Menu,
+        window: &Window,
+        position: Option<tao::dpi::LogicalPosition<f64>>,
+    ) -> bool {
+        use muda::ContextMenu;
+
+        let position: Option<muda::dpi::Position> =
+            position.map(|position| muda::dpi::LogicalPosition::new(position.x, position.y).into());
+
+        #[cfg(target_os = "windows")]
+        unsafe {
+            use tao::platform::windows::WindowExtWindows;
+            return menu.show_context_menu_for_hwnd(window.hwnd(), position);
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            use tao::platform::unix::WindowExtUnix;
+            return menu.show_context_menu_for_gtk_window(window.gtk_window(), position);
+        }
+
+        #[cfg(target_os = "macos")]
+        unsafe {
+            use tao::platform::macos::WindowExtMacOS;
+            return menu.show_context_menu_for_nsview(window.ns_view(), position);
+        }
+
+        #[allow(unreachable_code)]
+        false
+    }
+
     pub fn default_menu_bar() -> Menu {
         let menu = Menu::new();
         // since it is uncommon on windows to have an "application menu"
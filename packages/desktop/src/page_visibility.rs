@@ -0,0 +1,51 @@
+use crate::{DesktopContext, use_window, use_wry_event_handler};
+use dioxus_core::use_hook;
+use dioxus_html::PageVisibility;
+use dioxus_signals::{ReadSignal, ReadableExt, Signal, WritableExt};
+use tao::event::{Event, WindowEvent};
+
+/// Track whether the window is visible.
+///
+/// The window is hidden while it is minimized or hidden with `set_visible(false)`. Hiding the window doesn't send an
+/// event, so the visibility is checked after every batch of events the event loop handles, including the one that
+/// hid the window. The listener is removed when the component is dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::use_page_visibility;
+/// fn app() -> Element {
+///     let visibility = use_page_visibility();
+///
+///     rsx! {
+///         // Only run the animation while someone can see it
+///         div { class: if visibility().is_visible() { "spinner" } else { "spinner paused" } }
+///     }
+/// }
+/// ```
+pub fn use_page_visibility() -> ReadSignal<PageVisibility> {
+    let window = use_window();
+    let mut visibility = use_hook(|| Signal::new(window_visibility(&window)));
+
+    use_wry_event_handler(move |event, _| {
+        if let Event::MainEventsCleared
+        | Event::WindowEvent {
+            event: WindowEvent::Resized(_) | WindowEvent::Focused(_),
+            ..
+        } = event
+        {
+            let new = window_visibility(&window);
+            if new != *visibility.peek() {
+                visibility.set(new);
+            }
+        }
+    });
+
+    visibility.into()
+}
+
+fn window_visibility(window: &DesktopContext) -> PageVisibility {
+    match window.window.is_visible() && !window.window.is_minimized() {
+        true => PageVisibility::Visible,
+        false => PageVisibility::Hidden,
+    }
+}
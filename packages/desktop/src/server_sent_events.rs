@@ -0,0 +1,371 @@
+use dioxus_core::{Runtime, ScopeId, Task, current_scope_id, use_hook};
+use dioxus_hooks::{use_effect, use_reactive};
+pub use dioxus_html::{SseEvent, SseState};
+use dioxus_signals::{CopyValue, ReadSignal, ReadableExt, Signal, WritableExt};
+use std::time::Duration;
+
+/// Subscribe to a [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events) endpoint.
+///
+/// The connection is opened when the component is created and closed when the component is dropped. Every unnamed
+/// event the server sends is stored in [`ServerSentEvents::latest`]. If the connection is lost, it is reopened after
+/// the delay the server sent in the `retry:` field (3 seconds by default) with the id of the last event in the
+/// `Last-Event-ID` header. If the server responds with an error status, the connection is closed and doesn't
+/// reconnect. If the component is rendered with a different `url`, the connection is reopened with the new url after
+/// the render.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::{SseState, use_server_sent_events};
+/// fn App() -> Element {
+///     let events = use_server_sent_events("https://example.com/notifications");
+///
+///     rsx! {
+///         if events.state() == SseState::Connecting {
+///             "Connecting..."
+///         }
+///         if let Some(event) = events.latest() {
+///             "Latest notification: {event.data}"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_server_sent_events(url: &str) -> ServerSentEvents {
+    use_named_server_sent_events(url, &["message"])
+}
+
+/// Subscribe to named events from a server-sent events endpoint.
+///
+/// This works like [`use_server_sent_events`], but only stores events sent with one of the `event:` names in
+/// `events` instead of unnamed events. Include `"message"` in the list to receive unnamed events as well. The name
+/// of each event is stored in [`SseEvent::event`]. The connection is reopened if the component is rendered with a
+/// different `url` or list of `events`.
+pub fn use_named_server_sent_events(url: &str, events: &[&str]) -> ServerSentEvents {
+    let url = url.to_string();
+    let events: Vec<String> = events.iter().map(|event| event.to_string()).collect();
+
+    let handle = use_hook(|| {
+        let latest = Signal::new(None);
+        let state = Signal::new(SseState::Connecting);
+        let scope = current_scope_id();
+        let task = spawn_connection(scope, url.clone(), events.clone(), latest, state);
+        ServerSentEvents {
+            latest,
+            state,
+            url: CopyValue::new(url.clone()),
+            events: CopyValue::new(events.clone()),
+            task: CopyValue::new(Some(task)),
+            scope,
+        }
+    });
+
+    // Reopen the connection after the render if the component passes in a new url or list of events
+    use_effect(use_reactive((&url, &events), move |(url, events)| {
+        let mut handle = handle;
+        if *handle.url.peek() != url || *handle.events.peek() != events {
+            handle.url.set(url);
+            handle.events.set(events);
+            handle.reconnect();
+        }
+    }));
+
+    handle
+}
+
+/// A handle to a server-sent events connection created with [`use_server_sent_events`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ServerSentEvents {
+    latest: Signal<Option<SseEvent>>,
+    state: Signal<SseState>,
+    url: CopyValue<String>,
+    events: CopyValue<Vec<String>>,
+    task: CopyValue<Option<Task>>,
+    scope: ScopeId,
+}
+
+impl ServerSentEvents {
+    /// Get the latest event the server sent, if any.
+    ///
+    /// Reading this will subscribe the current scope to new events.
+    pub fn latest(&self) -> Option<SseEvent> {
+        self.latest.cloned()
+    }
+
+    /// Get the state of the connection.
+    ///
+    /// Reading this will subscribe the current scope to changes in the state.
+    pub fn state(&self) -> SseState {
+        self.state.cloned()
+    }
+
+    /// Close the connection. The connection will not reconnect until [`ServerSentEvents::reconnect`] is called.
+    pub fn close(&mut self) {
+        self.cancel_task();
+        self.state.set(SseState::Closed);
+    }
+
+    /// Close the current connection and open a new one
+    pub fn reconnect(&mut self) {
+        self.cancel_task();
+        self.state.set(SseState::Connecting);
+
+        let task = spawn_connection(
+            self.scope,
+            self.url.cloned(),
+            self.events.cloned(),
+            self.latest,
+            self.state,
+        );
+        self.task.set(Some(task));
+    }
+
+    fn cancel_task(&mut self) {
+        if let Some(task) = self.task.write().take() {
+            task.cancel();
+        }
+    }
+}
+
+impl From<ServerSentEvents> for ReadSignal<Option<SseEvent>> {
+    fn from(events: ServerSentEvents) -> Self {
+        events.latest.into()
+    }
+}
+
+fn spawn_connection(
+    scope: ScopeId,
+    url: String,
+    events: Vec<String>,
+    mut latest: Signal<Option<SseEvent>>,
+    mut state: Signal<SseState>,
+) -> Task {
+    Runtime::current().spawn(scope, async move {
+        let client = reqwest::Client::new();
+        let mut parser = EventParser::default();
+        let mut retry = Duration::from_secs(3);
+
+        loop {
+            let mut request = client
+                .get(&url)
+                .header(reqwest::header::ACCEPT, "text/event-stream");
+            if let Some(id) = &parser.last_event_id {
+                request = request.header("Last-Event-ID", id);
+            }
+
+            match request.send().await {
+                Ok(mut response) if response.status().is_success() => {
+                    state.set(SseState::Open);
+                    parser.reset();
+
+                    loop {
+                        let chunk = match response.chunk().await {
+                            Ok(Some(chunk)) => chunk,
+                            Ok(None) => break,
+                            Err(err) => {
+                                tracing::warn!(
+                                    "Lost server-sent events connection to {url:?}: {err}"
+                                );
+                                break;
+                            }
+                        };
+
+                        for event in parser.feed(&chunk) {
+                            if events.contains(&event.event) {
+                                latest.set(Some(event));
+                            }
+                        }
+                        if let Some(new_retry) = parser.retry.take() {
+                            retry = new_retry;
+                        }
+                    }
+                }
+                // Like the browser's EventSource, error responses close the connection for good
+                Ok(response) => {
+                    tracing::error!(
+                        "Server-sent events endpoint {url:?} responded with {}",
+                        response.status()
+                    );
+                    state.set(SseState::Closed);
+                    return;
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed to connect to server-sent events endpoint {url:?}: {err}"
+                    )
+                }
+            }
+
+            state.set(SseState::Connecting);
+            tokio::time::sleep(retry).await;
+        }
+    })
+}
+
+/// Parses the `text/event-stream` format from chunks of bytes
+#[derive(Default)]
+struct EventParser {
+    buffer: Vec<u8>,
+    /// Whether the last line ended with a carriage return. If the next byte is a line feed, it is part of the same
+    /// line ending, even if it arrives in the next chunk.
+    after_cr: bool,
+    event: String,
+    data: String,
+    last_event_id: Option<String>,
+    retry: Option<Duration>,
+}
+
+impl EventParser {
+    /// Drop any partial event from the last connection. The last event id is kept for reconnecting.
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.after_cr = false;
+        self.event.clear();
+        self.data.clear();
+    }
+
+    /// Add a chunk of bytes and return every event that was completed
+    fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        loop {
+            if self.after_cr && !self.buffer.is_empty() {
+                self.after_cr = false;
+                if self.buffer[0] == b'\n' {
+                    self.buffer.remove(0);
+                }
+            }
+
+            // Lines can end with a line feed, a carriage return, or both
+            let Some(end) = self
+                .buffer
+                .iter()
+                .position(|byte| matches!(byte, b'\n' | b'\r'))
+            else {
+                break;
+            };
+            self.after_cr = self.buffer[end] == b'\r';
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line[..end]);
+
+            // A blank line dispatches the event
+            if line.is_empty() {
+                if let Some(event) = self.dispatch() {
+                    events.push(event);
+                }
+                continue;
+            }
+
+            let (field, value) = line.split_once(':').unwrap_or((&line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => self.event = value.to_string(),
+                "data" => {
+                    self.data.push_str(value);
+                    self.data.push('\n');
+                }
+                "id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()),
+                "retry" => {
+                    if let Ok(millis) = value.parse() {
+                        self.retry = Some(Duration::from_millis(millis));
+                    }
+                }
+                // Comments start with a colon and unknown fields are ignored
+                _ => {}
+            }
+        }
+
+        events
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = std::mem::take(&mut self.event);
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let mut data = std::mem::take(&mut self.data);
+        data.pop();
+        Some(SseEvent {
+            event: match event.is_empty() {
+                true => "message".to_string(),
+                false => event,
+            },
+            data,
+            id: self.last_event_id.clone().filter(|id| !id.is_empty()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(data: &str) -> SseEvent {
+        SseEvent {
+            event: "message".to_string(),
+            data: data.to_string(),
+            id: None,
+        }
+    }
+
+    #[test]
+    fn line_feeds() {
+        let mut parser = EventParser::default();
+        assert_eq!(
+            parser.feed(b"data: one\n\ndata: two\n\n"),
+            [message("one"), message("two")]
+        );
+    }
+
+    #[test]
+    fn carriage_return_line_feeds() {
+        let mut parser = EventParser::default();
+        assert_eq!(parser.feed(b"data: one\r\n\r\n"), [message("one")]);
+
+        // The line ending can be split between chunks
+        assert_eq!(parser.feed(b"data: two\r"), []);
+        assert_eq!(parser.feed(b"\n\r"), []);
+        assert_eq!(parser.feed(b"\n"), [message("two")]);
+    }
+
+    #[test]
+    fn bare_carriage_returns() {
+        let mut parser = EventParser::default();
+        assert_eq!(parser.feed(b"data: one\r\rdata: two\r"), [message("one")]);
+        assert_eq!(parser.feed(b"\r"), [message("two")]);
+    }
+
+    #[test]
+    fn multi_line_data() {
+        let mut parser = EventParser::default();
+        assert_eq!(
+            parser.feed(b"data: first\ndata\ndata:second\n\n"),
+            [message("first\n\nsecond")]
+        );
+    }
+
+    #[test]
+    fn comments_and_unknown_fields_are_ignored() {
+        let mut parser = EventParser::default();
+        assert_eq!(parser.feed(b": keep alive\n\n"), []);
+        assert_eq!(
+            parser.feed(b": comment\nunknown: field\ndata: one\n\n"),
+            [message("one")]
+        );
+    }
+
+    #[test]
+    fn named_events_ids_and_retry() {
+        let mut parser = EventParser::default();
+        assert_eq!(
+            parser.feed(b"event: created\nid: 7\nretry: 500\ndata: {}\n\n"),
+            [SseEvent {
+                event: "created".to_string(),
+                data: "{}".to_string(),
+                id: Some("7".to_string()),
+            }]
+        );
+        assert_eq!(parser.last_event_id.as_deref(), Some("7"));
+        assert_eq!(parser.retry, Some(Duration::from_millis(500)));
+    }
+}
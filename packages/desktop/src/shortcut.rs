@@ -132,6 +132,11 @@ impl ShortcutRegistry {
 
 pub trait IntoAccelerator {
     fn accelerator(&self) -> HotKey;
+
+    /// Try to convert this value into a [`HotKey`], returning an error instead of panicking if it is invalid.
+    fn try_accelerator(&self) -> Result<HotKey, ShortcutRegistryError> {
+        Ok(self.accelerator())
+    }
 }
 
 impl IntoAccelerator for (dioxus_html::KeyCode, ModifiersState) {
@@ -156,6 +161,20 @@ impl IntoAccelerator for &str {
     fn accelerator(&self) -> HotKey {
         HotKey::from_str(self).unwrap()
     }
+
+    fn try_accelerator(&self) -> Result<HotKey, ShortcutRegistryError> {
+        HotKey::from_str(self).map_err(|_| ShortcutRegistryError::InvalidShortcut(self.to_string()))
+    }
+}
+
+impl IntoAccelerator for String {
+    fn accelerator(&self) -> HotKey {
+        self.as_str().accelerator()
+    }
+
+    fn try_accelerator(&self) -> Result<HotKey, ShortcutRegistryError> {
+        self.as_str().try_accelerator()
+    }
 }
 
 pub trait IntoModifiersState {
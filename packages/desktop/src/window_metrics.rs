@@ -0,0 +1,109 @@
+use crate::{DesktopContext, use_window, use_wry_event_handler};
+use dioxus_core::{spawn, use_drop, use_hook};
+use dioxus_document::eval;
+use dioxus_signals::{ReadSignal, ReadableExt, Signal, WritableExt};
+use tao::event::{Event, WindowEvent};
+
+/// Track the inner size of the window in logical pixels as `(width, height)`.
+///
+/// The signal updates when the native window is resized or moved to a display with a different scale factor, and
+/// the listener is removed when the component is dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::use_window_size;
+/// fn app() -> Element {
+///     let size = use_window_size();
+///     let (width, height) = size();
+///
+///     rsx! { "The window is {width}x{height}" }
+/// }
+/// ```
+pub fn use_window_size() -> ReadSignal<(f64, f64)> {
+    let window = use_window();
+    let mut size = use_hook(|| Signal::new(logical_size(&window)));
+
+    use_wry_event_handler(move |event, _| {
+        if let Event::WindowEvent {
+            event: WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. },
+            ..
+        } = event
+        {
+            let new = logical_size(&window);
+            if new != *size.peek() {
+                size.set(new);
+            }
+        }
+    });
+
+    size.into()
+}
+
+fn logical_size(window: &DesktopContext) -> (f64, f64) {
+    let size = window
+        .window
+        .inner_size()
+        .to_logical::<f64>(window.window.scale_factor());
+    (size.width, size.height)
+}
+
+/// Track the scroll position of the page in the webview in logical pixels as `(x, y)`.
+///
+/// The webview reports the position at most once per animation frame while the page is scrolling. The signal starts
+/// at `(0.0, 0.0)` until the webview sends the first position, and the listener is removed when the component is
+/// dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_desktop::use_scroll_position;
+/// fn app() -> Element {
+///     let scroll = use_scroll_position();
+///
+///     rsx! {
+///         if scroll().1 > 200.0 {
+///             a { href: "#top", "Back to top" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_scroll_position() -> ReadSignal<(f64, f64)> {
+    let (position, listener) = use_hook(|| {
+        let mut position = Signal::new((0.0, 0.0));
+        let listener = eval(SCROLL_LISTENER);
+        // The task is dropped with the component
+        spawn(async move {
+            let mut listener = listener;
+            while let Ok(new) = listener.recv::<(f64, f64)>().await {
+                if new != *position.peek() {
+                    position.set(new);
+                }
+            }
+        });
+        (position, listener)
+    });
+
+    // Any message tells the script to remove its listener
+    use_drop(move || _ = listener.send(()));
+
+    position.into()
+}
+
+const SCROLL_LISTENER: &str = r#"
+let frame = null;
+const send = () => dioxus.send([window.scrollX, window.scrollY]);
+const listener = () => {
+    if (frame === null) {
+        frame = requestAnimationFrame(() => {
+            frame = null;
+            send();
+        });
+    }
+};
+window.addEventListener("scroll", listener, { passive: true });
+send();
+await dioxus.recv();
+window.removeEventListener("scroll", listener);
+if (frame !== null) {
+    cancelAnimationFrame(frame);
+}
+"#;
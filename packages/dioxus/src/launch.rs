@@ -57,6 +57,47 @@ pub fn launch(app: fn() -> Element) {
     LaunchBuilder::new().launch(app)
 }
 
+/// Launch your Dioxus application with app-wide context that is provided before the root component runs.
+///
+/// Every context added to the builder in `contexts` is provided at the root of the app, so any component can read it
+/// with `use_context` without wrapping the app in a provider component. This is a shortcut for
+/// [`LaunchBuilder::with_context`] and [`LaunchBuilder::with_context_provider`].
+///
+/// Contexts are created on the thread the app is launched on. In fullstack apps, the server and client are separate
+/// builds, so you can provide a different context for each of them with `cfg` attributes.
+///
+/// # Example
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct ApiClient {
+///     base_url: String,
+/// }
+///
+/// fn main() {
+///     dioxus::launch_with_context(app, |builder| {
+///         builder.with_context(ApiClient {
+///             base_url: "https://api.example.com".to_string(),
+///         })
+///     });
+/// }
+///
+/// fn app() -> Element {
+///     let client = use_context::<ApiClient>();
+///     rsx! {
+///         div { "Talking to {client.base_url}" }
+///     }
+/// }
+/// ```
+pub fn launch_with_context(
+    app: fn() -> Element,
+    contexts: impl FnOnce(LaunchBuilder) -> LaunchBuilder,
+) {
+    #[allow(deprecated)]
+    contexts(LaunchBuilder::new()).launch(app)
+}
+
 /// A builder for a fullstack app.
 #[must_use]
 pub struct LaunchBuilder {
@@ -12,6 +12,8 @@
 //! - `router`: exports the [router](https://dioxuslabs.com/learn/0.7/essentials/router/) and enables any router features for the current platform
 //! - `third-party-renderer`: Just disables warnings about no active platform when no renderers are enabled
 //! - `logger`: Enable the default tracing subscriber for Dioxus apps
+//! - `sse`: Enables the `use_server_sent_events` hook in the web and desktop renderers
+//! - `instrument`: Emit tracing spans for component renders, effects, task polls, and edits applied by the web renderer. Use [`logger::init_profiling`] to print them. The web renderer also counts and times the edits it applies, see `dioxus_web::edit_stats`
 //!
 //! Platform features (the current platform determines what platform the [`launch()`] function runs):
 //!
@@ -145,7 +147,9 @@ pub mod prelude {
     #[cfg(feature = "document")]
     #[cfg_attr(docsrs, doc(cfg(feature = "document")))]
     #[doc(inline)]
-    pub use dioxus_document::{self as document, Meta, Stylesheet, Title};
+    pub use dioxus_document::{
+        self as document, Canvas, Meta, Portal, PortalTarget, Stylesheet, Title, styled,
+    };
 
     #[cfg(feature = "document")]
     #[cfg_attr(docsrs, doc(cfg(feature = "document")))]
@@ -174,7 +178,7 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "macro")))]
     #[allow(deprecated)]
     #[doc(inline)]
-    pub use dioxus_core_macro::{Props, component, rsx};
+    pub use dioxus_core_macro::{Form, Props, component, rsx};
 
     #[cfg(feature = "launch")]
     #[cfg_attr(docsrs, doc(cfg(feature = "launch")))]
@@ -187,7 +191,10 @@ pub mod prelude {
     #[cfg(feature = "html")]
     #[cfg_attr(docsrs, doc(cfg(feature = "html")))]
     #[doc(inline)]
-    pub use dioxus_elements::{Code, Key, Location, Modifiers};
+    pub use dioxus_elements::{
+        Code, Dialog, DynamicElement, For, Key, Location, Modifiers, PageVisibility, Reorder, Show,
+        Sortable, SortableItem, SurfaceSize, VirtualList,
+    };
 
     #[cfg(feature = "html")]
     #[cfg_attr(docsrs, doc(cfg(feature = "html")))]
@@ -209,7 +216,7 @@ pub mod prelude {
     pub use dioxus_fullstack::{
         self as dioxus_fullstack, HttpError, OrHttpError, ServerFnError, ServerFnResult,
         StatusCode, delete, get, patch, post, put, server, use_loader, use_server_cached,
-        use_server_future,
+        use_server_cached_with, use_server_future,
     };
 
     #[cfg(feature = "server")]
@@ -225,8 +232,8 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "router")))]
     #[doc(inline)]
     pub use dioxus_router::{
-        GoBackButton, GoForwardButton, Link, NavigationTarget, Outlet, Routable, Router, hooks::*,
-        navigator, use_navigator,
+        GoBackButton, GoForwardButton, Link, NavigationTarget, Outlet, PrefetchStrategy, Routable,
+        Router, hooks::*, navigator, use_navigator,
     };
 
     #[cfg(feature = "asset")]
@@ -241,9 +248,9 @@ pub mod prelude {
     #[doc(inline)]
     pub use dioxus_core::{
         AnyhowContext, Attribute, Callback, Component, Element, ErrorBoundary, ErrorContext, Event,
-        EventHandler, Fragment, HasAttributes, IntoDynNode, RenderError, Result, ScopeId,
-        SuspenseBoundary, SuspenseContext, VNode, VirtualDom, consume_context, provide_context,
-        spawn, suspend, try_consume_context, use_drop, use_hook,
+        EventHandler, Fragment, HasAttributes, IntoAttributes, IntoDynNode, RenderError, Result,
+        ScopeId, SuspenseBoundary, SuspenseContext, Throw, UnsafeHtml, VNode, VirtualDom,
+        consume_context, provide_context, spawn, suspend, try_consume_context, use_drop, use_hook,
     };
 
     #[cfg(feature = "logger")]
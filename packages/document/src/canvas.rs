@@ -0,0 +1,477 @@
+use crate::{Eval, eval};
+use dioxus_core::{Attribute, Callback, Element, spawn, use_drop, use_hook};
+use dioxus_core_macro::{Props, component, rsx};
+use dioxus_hooks::{use_effect, use_signal};
+use dioxus_html as dioxus_elements;
+use dioxus_signals::*;
+use serde_json::{Value, json};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static NEXT_CANVAS_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The script that draws the frames from a [`Canvas`]. The first message is the id of the canvas. After that, it
+/// replays the commands of every frame and sends the size of the canvas when it or the device pixel ratio changes.
+const CANVAS_SCRIPT: &str = r#"
+const id = await dioxus.recv();
+const canvas = document.querySelector(`[data-dioxus-canvas="${id}"]`);
+const context = canvas.getContext("2d");
+let size = { width: 0, height: 0, ratio: window.devicePixelRatio || 1 };
+const observer = new ResizeObserver(([entry]) => {
+    size = { ...size, width: entry.contentRect.width, height: entry.contentRect.height };
+    dioxus.send({ type: "resize", ...size });
+});
+observer.observe(canvas);
+
+// Moving the window to a display with a different density or zooming changes the device pixel ratio without
+// resizing the element, so watch the resolution separately
+let resolution = null;
+function watchResolution() {
+    resolution = window.matchMedia(`(resolution: ${size.ratio}dppx)`);
+    resolution.addEventListener("change", onResolutionChange, { once: true });
+}
+function onResolutionChange() {
+    size = { ...size, ratio: window.devicePixelRatio || 1 };
+    dioxus.send({ type: "resize", ...size });
+    watchResolution();
+}
+watchResolution();
+
+while (true) {
+    const message = await dioxus.recv();
+    if (message.type === "close") {
+        break;
+    }
+
+    // Match the backing store to the device pixels so the canvas stays sharp, and draw in css pixels
+    const ratio = size.ratio;
+    const width = Math.round(size.width * ratio);
+    const height = Math.round(size.height * ratio);
+    if (canvas.width !== width || canvas.height !== height) {
+        canvas.width = width;
+        canvas.height = height;
+    }
+    context.save();
+    context.clearRect(0, 0, canvas.width, canvas.height);
+    context.setTransform(ratio, 0, 0, ratio, 0, 0);
+    for (const [method, ...args] of message.commands) {
+        if (method.startsWith("=")) {
+            context[method.slice(1)] = args[0];
+        } else {
+            context[method](...args);
+        }
+    }
+    context.restore();
+
+    if (message.animate) {
+        requestAnimationFrame((time) => dioxus.send({ type: "frame", time }));
+    }
+}
+observer.disconnect();
+resolution.removeEventListener("change", onResolutionChange);
+"#;
+
+#[derive(serde::Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum CanvasMessage {
+    Resize { width: f64, height: f64, ratio: f64 },
+    Frame { time: f64 },
+}
+
+/// The size of the canvas and the time of the frame that is being drawn
+#[derive(Clone, Copy, PartialEq)]
+struct CanvasFrame {
+    width: f64,
+    height: f64,
+    ratio: f64,
+    time: f64,
+}
+
+impl Default for CanvasFrame {
+    fn default() -> Self {
+        Self {
+            width: 0.0,
+            height: 0.0,
+            ratio: 1.0,
+            time: 0.0,
+        }
+    }
+}
+
+/// Props for the [`Canvas`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct CanvasProps {
+    /// Draw a frame. This is called when the canvas is mounted or resized, when the device pixel ratio changes, on
+    /// every animation frame if `animate` is `true`, and whenever a signal read inside of it changes.
+    ///
+    /// The context is passed by value because callback arguments can't borrow. It is a cheap handle to the commands
+    /// of the frame that is being drawn.
+    pub draw: Callback<CanvasContext>,
+    /// Redraw the canvas on every animation frame
+    #[props(default)]
+    pub animate: bool,
+    /// Attributes for the `<canvas>` element. Size the canvas with css, the resolution follows the size automatically.
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A `<canvas>` with a 2D context that is drawn from Rust.
+///
+/// The `draw` callback receives a [`CanvasContext`] with the methods of the
+/// [canvas 2D API](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D). Every frame starts
+/// with a cleared canvas. Coordinates are in css pixels, and the resolution of the canvas follows its size and the
+/// device pixel ratio, so drawings stay sharp on high density displays. The methods are named after the canvas API,
+/// so text is drawn with [`CanvasContext::fill_text`] and [`CanvasContext::stroke_text`].
+///
+/// The frames are drawn with JavaScript through [`eval`], so the canvas works on the web, desktop, and liveview
+/// renderers. Other renderers render an empty `<canvas>`.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::document::CanvasContext;
+///
+/// fn App() -> Element {
+///     let mut progress = use_signal(|| 0.25);
+///
+///     rsx! {
+///         Canvas {
+///             style: "width: 100%; height: 200px;",
+///             // Reading the progress signal redraws the canvas when it changes
+///             draw: move |ctx: CanvasContext| {
+///                 ctx.set_fill_style("#eee");
+///                 ctx.fill_rect(0.0, 0.0, ctx.width(), ctx.height());
+///                 ctx.set_fill_style("#2563eb");
+///                 ctx.fill_rect(0.0, 0.0, ctx.width() * progress(), ctx.height());
+///                 ctx.set_font("16px sans-serif");
+///                 ctx.fill_text(&format!("{:.0}%", progress() * 100.0), 8.0, 24.0);
+///             },
+///         }
+///         button { onclick: move |_| progress.set((progress() + 0.25) % 1.25), "Step" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Canvas(props: CanvasProps) -> Element {
+    let id = use_hook(|| NEXT_CANVAS_ID.fetch_add(1, Ordering::Relaxed));
+    let mut script = use_signal(|| None::<Eval>);
+    // The signal is dropped with the component before `use_drop` runs, so keep the script in a hook to close it
+    let running_script = use_hook(|| Rc::new(Cell::new(None::<Eval>)));
+    let mut frame = use_signal(CanvasFrame::default);
+    let mut animate = use_signal(|| props.animate);
+    let draw = props.draw;
+
+    // Keep the animate flag up to date if the component passes in a new value
+    if *animate.peek() != props.animate {
+        animate.set(props.animate);
+    }
+
+    use_effect(move || {
+        let Some(script) = script() else {
+            return;
+        };
+        let commands = record_commands(frame(), |context| draw.call(context));
+        _ = script.send(json!({ "type": "draw", "animate": animate(), "commands": commands }));
+    });
+
+    use_drop({
+        let running_script = running_script.clone();
+        move || {
+            if let Some(script) = running_script.take() {
+                _ = script.send(json!({ "type": "close" }));
+            }
+        }
+    });
+
+    rsx! {
+        canvas {
+            "data-dioxus-canvas": "{id}",
+            onmounted: move |_| {
+                let mut messages = eval(CANVAS_SCRIPT);
+                _ = messages.send(id);
+                // Stop drawing into the old element if the canvas was replaced
+                if let Some(old) = running_script.replace(Some(messages)) {
+                    _ = old.send(json!({ "type": "close" }));
+                }
+                script.set(Some(messages));
+                spawn(async move {
+                    while let Ok(message) = messages.recv::<CanvasMessage>().await {
+                        match message {
+                            CanvasMessage::Resize { width, height, ratio } => {
+                                frame.with_mut(|frame| {
+                                    frame.width = width;
+                                    frame.height = height;
+                                    frame.ratio = ratio;
+                                });
+                            }
+                            CanvasMessage::Frame { time } => {
+                                frame.with_mut(|frame| frame.time = time);
+                            }
+                        }
+                    }
+                });
+            },
+            ..props.attributes,
+        }
+    }
+}
+
+/// Run `draw` with a context for `frame` and collect the commands it draws
+fn record_commands(frame: CanvasFrame, draw: impl FnOnce(CanvasContext)) -> Vec<Value> {
+    let context = CanvasContext {
+        frame,
+        commands: Default::default(),
+    };
+    draw(context.clone());
+    context.commands.take()
+}
+
+/// The 2D context of a [`Canvas`] that is passed to its `draw` callback.
+///
+/// The methods match the methods of the
+/// [canvas 2D API](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D) and are drawn in the
+/// order they are called once the callback returns.
+#[derive(Clone)]
+pub struct CanvasContext {
+    frame: CanvasFrame,
+    commands: Rc<RefCell<Vec<Value>>>,
+}
+
+impl CanvasContext {
+    fn push(&self, command: Value) {
+        self.commands.borrow_mut().push(command);
+    }
+
+    /// The width of the canvas in css pixels
+    pub fn width(&self) -> f64 {
+        self.frame.width
+    }
+
+    /// The height of the canvas in css pixels
+    pub fn height(&self) -> f64 {
+        self.frame.height
+    }
+
+    /// The number of device pixels per css pixel. The canvas is scaled by this automatically.
+    #[doc(alias = "devicePixelRatio")]
+    pub fn device_pixel_ratio(&self) -> f64 {
+        self.frame.ratio
+    }
+
+    /// The time of the current animation frame in milliseconds. This is only updated when `animate` is `true`.
+    pub fn time(&self) -> f64 {
+        self.frame.time
+    }
+
+    /// Set the color, gradient, or pattern used to fill shapes, like `"red"` or `"#2563eb"`
+    #[doc(alias = "fillStyle")]
+    pub fn set_fill_style(&self, style: &str) {
+        self.push(json!(["=fillStyle", style]));
+    }
+
+    /// Set the color, gradient, or pattern used to stroke shapes
+    #[doc(alias = "strokeStyle")]
+    pub fn set_stroke_style(&self, style: &str) {
+        self.push(json!(["=strokeStyle", style]));
+    }
+
+    /// Set the width of stroked lines in css pixels
+    #[doc(alias = "lineWidth")]
+    pub fn set_line_width(&self, width: f64) {
+        self.push(json!(["=lineWidth", width]));
+    }
+
+    /// Set the font used to draw text with css font syntax, like `"16px sans-serif"`
+    pub fn set_font(&self, font: &str) {
+        self.push(json!(["=font", font]));
+    }
+
+    /// Set the horizontal alignment of text, like `"left"`, `"center"`, or `"right"`
+    #[doc(alias = "textAlign")]
+    pub fn set_text_align(&self, align: &str) {
+        self.push(json!(["=textAlign", align]));
+    }
+
+    /// Set the alpha that is applied to everything drawn after this, from `0.0` to `1.0`
+    #[doc(alias = "globalAlpha")]
+    pub fn set_global_alpha(&self, alpha: f64) {
+        self.push(json!(["=globalAlpha", alpha]));
+    }
+
+    /// Clear a rectangle
+    #[doc(alias = "clearRect")]
+    pub fn clear_rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        self.push(json!(["clearRect", x, y, width, height]));
+    }
+
+    /// Fill a rectangle with the fill style
+    #[doc(alias = "fillRect")]
+    pub fn fill_rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        self.push(json!(["fillRect", x, y, width, height]));
+    }
+
+    /// Stroke the outline of a rectangle with the stroke style
+    #[doc(alias = "strokeRect")]
+    pub fn stroke_rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        self.push(json!(["strokeRect", x, y, width, height]));
+    }
+
+    /// Fill text at a position with the fill style and font
+    #[doc(alias = "fillText")]
+    #[doc(alias = "draw_text")]
+    pub fn fill_text(&self, text: &str, x: f64, y: f64) {
+        self.push(json!(["fillText", text, x, y]));
+    }
+
+    /// Stroke the outline of text at a position with the stroke style and font
+    #[doc(alias = "strokeText")]
+    pub fn stroke_text(&self, text: &str, x: f64, y: f64) {
+        self.push(json!(["strokeText", text, x, y]));
+    }
+
+    /// Start a new path
+    #[doc(alias = "beginPath")]
+    pub fn begin_path(&self) {
+        self.push(json!(["beginPath"]));
+    }
+
+    /// Add a straight line from the current point back to the start of the path
+    #[doc(alias = "closePath")]
+    pub fn close_path(&self) {
+        self.push(json!(["closePath"]));
+    }
+
+    /// Start a new sub-path at a point
+    #[doc(alias = "moveTo")]
+    pub fn move_to(&self, x: f64, y: f64) {
+        self.push(json!(["moveTo", x, y]));
+    }
+
+    /// Add a straight line from the current point to a point
+    #[doc(alias = "lineTo")]
+    pub fn line_to(&self, x: f64, y: f64) {
+        self.push(json!(["lineTo", x, y]));
+    }
+
+    /// Add a quadratic Bézier curve from the current point to a point
+    #[doc(alias = "quadraticCurveTo")]
+    pub fn quadratic_curve_to(&self, cpx: f64, cpy: f64, x: f64, y: f64) {
+        self.push(json!(["quadraticCurveTo", cpx, cpy, x, y]));
+    }
+
+    /// Add a cubic Bézier curve from the current point to a point
+    #[doc(alias = "bezierCurveTo")]
+    pub fn bezier_curve_to(&self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
+        self.push(json!(["bezierCurveTo", cp1x, cp1y, cp2x, cp2y, x, y]));
+    }
+
+    /// Add an arc centered at a point. The angles are in radians, measured clockwise from the positive x axis.
+    pub fn arc(&self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        self.push(json!(["arc", x, y, radius, start_angle, end_angle]));
+    }
+
+    /// Add a rectangle to the path
+    pub fn rect(&self, x: f64, y: f64, width: f64, height: f64) {
+        self.push(json!(["rect", x, y, width, height]));
+    }
+
+    /// Fill the current path with the fill style
+    pub fn fill(&self) {
+        self.push(json!(["fill"]));
+    }
+
+    /// Stroke the current path with the stroke style
+    pub fn stroke(&self) {
+        self.push(json!(["stroke"]));
+    }
+
+    /// Save the current styles and transform
+    pub fn save(&self) {
+        self.push(json!(["save"]));
+    }
+
+    /// Restore the styles and transform from the last call to [`CanvasContext::save`]
+    pub fn restore(&self) {
+        self.push(json!(["restore"]));
+    }
+
+    /// Move the origin of everything drawn after this
+    pub fn translate(&self, x: f64, y: f64) {
+        self.push(json!(["translate", x, y]));
+    }
+
+    /// Rotate everything drawn after this around the origin. The angle is in radians.
+    pub fn rotate(&self, angle: f64) {
+        self.push(json!(["rotate", angle]));
+    }
+
+    /// Scale everything drawn after this
+    pub fn scale(&self, x: f64, y: f64) {
+        self.push(json!(["scale", x, y]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_commands_in_order() {
+        let frame = CanvasFrame {
+            width: 200.0,
+            height: 100.0,
+            ratio: 2.0,
+            time: 16.0,
+        };
+        let commands = record_commands(frame, |ctx| {
+            assert_eq!(ctx.width(), 200.0);
+            assert_eq!(ctx.height(), 100.0);
+            assert_eq!(ctx.device_pixel_ratio(), 2.0);
+            assert_eq!(ctx.time(), 16.0);
+
+            ctx.set_fill_style("#2563eb");
+            ctx.fill_rect(0.0, 0.0, ctx.width() / 2.0, ctx.height());
+            ctx.set_font("16px sans-serif");
+            ctx.fill_text("50%", 8.0, 24.0);
+            ctx.begin_path();
+            ctx.arc(10.0, 10.0, 5.0, 0.0, std::f64::consts::PI);
+            ctx.stroke();
+        });
+
+        assert_eq!(
+            commands,
+            [
+                json!(["=fillStyle", "#2563eb"]),
+                json!(["fillRect", 0.0, 0.0, 100.0, 100.0]),
+                json!(["=font", "16px sans-serif"]),
+                json!(["fillText", "50%", 8.0, 24.0]),
+                json!(["beginPath"]),
+                json!(["arc", 10.0, 10.0, 5.0, 0.0, std::f64::consts::PI]),
+                json!(["stroke"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn clones_of_the_context_record_into_the_same_frame() {
+        let commands = record_commands(CanvasFrame::default(), |ctx| {
+            let clone = ctx.clone();
+            ctx.save();
+            clone.translate(1.0, 2.0);
+            ctx.restore();
+        });
+
+        assert_eq!(
+            commands,
+            [
+                json!(["save"]),
+                json!(["translate", 1.0, 2.0]),
+                json!(["restore"]),
+            ]
+        );
+    }
+}
@@ -48,6 +48,33 @@ pub fn create_element_in_head(
     format!(r#"{helpers};window.createElementInHead({tag}, {attributes}, {children});"#)
 }
 
+/// Set the content of the meta tag with `key_attribute` set to `key` with javascript through the [`Document::eval`]
+/// method. The tag is created if it doesn't exist, and removed if `content` is `None`.
+///
+/// This can be used to implement [`Document::set_meta`] for most [`Document`] implementations.
+pub fn set_meta_in_head(key_attribute: &str, key: &str, content: Option<&str>) -> String {
+    let key_attribute = format_string_for_js(key_attribute);
+    let key = format_string_for_js(key);
+    let content = content
+        .map(format_string_for_js)
+        .unwrap_or("null".to_string());
+    format!(
+        r#"(() => {{
+    let meta = [...document.head.querySelectorAll("meta")].find((meta) => meta.getAttribute({key_attribute}) === {key});
+    if ({content} === null) {{
+        meta?.remove();
+        return;
+    }}
+    if (!meta) {{
+        meta = document.createElement("meta");
+        meta.setAttribute({key_attribute}, {key});
+        document.head.appendChild(meta);
+    }}
+    meta.setAttribute("content", {content});
+}})();"#
+    )
+}
+
 /// A provider for document-related functionality.
 ///
 /// Provides things like a history API, a title, a way to run JS, and some other basics/essentials used
@@ -88,6 +115,12 @@ pub trait Document: 'static {
         self.create_head_element("meta", &attributes, None);
     }
 
+    /// Set the content of the meta tag in the head with `key_attribute` set to `key`, like `name="description"`. The
+    /// tag is created if it doesn't exist yet. If `content` is `None`, the tag is removed.
+    fn set_meta(&self, key_attribute: &'static str, key: &str, content: Option<String>) {
+        self.eval(set_meta_in_head(key_attribute, key, content.as_deref()));
+    }
+
     /// Create a new script tag in the head
     fn create_script(&self, props: ScriptProps) {
         let attributes = props.attributes();
@@ -146,6 +179,7 @@ impl Document for NoOpDocument {
 
     fn set_title(&self, _: String) {}
     fn create_meta(&self, _: MetaProps) {}
+    fn set_meta(&self, _: &'static str, _: &str, _: Option<String>) {}
     fn create_script(&self, _: ScriptProps) {}
     fn create_style(&self, _: StyleProps) {}
     fn create_link(&self, _: LinkProps) {}
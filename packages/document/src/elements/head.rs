@@ -0,0 +1,67 @@
+use super::*;
+
+/// Props for the [`Head`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct HeadProps {
+    /// The title of the page
+    pub title: Option<String>,
+    /// A short description of the page. This sets both the `description` and `og:description` meta tags.
+    pub description: Option<String>,
+    /// The url of an image that represents the page when it is shared. This sets the `og:image` meta tag.
+    pub image: Option<String>,
+    /// Any other head elements to render
+    #[props(default)]
+    pub children: Element,
+}
+
+/// Set the title and the common SEO and social media meta tags of the page in one component.
+///
+/// `title` sets both the [`Title`] and the `og:title` meta tag, and `description` sets both the `description` and
+/// `og:description` meta tags. Every value is rendered with [`Title`] or [`Meta`], so the values update when the props
+/// change, and if multiple `Head` components set the same value, the one that was mounted last wins. That lets each
+/// route set its own metadata on top of the defaults from a layout. When the route is unmounted, the head goes back to
+/// the values from the layout.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     rsx! {
+///         document::Head {
+///             title: "My Blog",
+///             description: "Posts about Rust and the web",
+///         }
+///         Post { title: "Hello world" }
+///     }
+/// }
+///
+/// #[component]
+/// fn Post(title: String) -> Element {
+///     rsx! {
+///         // This overrides the title from the App component until the post is unmounted
+///         document::Head {
+///             title: title.clone(),
+///             image: "https://example.com/hello-world.png",
+///         }
+///         h1 { "{title}" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Head(props: HeadProps) -> Element {
+    rsx! {
+        if let Some(title) = props.title {
+            Title { "{title}" }
+            Meta { property: "og:title", content: title }
+        }
+        if let Some(description) = props.description {
+            Meta { name: "description", content: description.clone() }
+            Meta { property: "og:description", content: description }
+        }
+        if let Some(image) = props.image {
+            Meta { property: "og:image", content: image }
+        }
+        {props.children}
+    }
+}
@@ -0,0 +1,109 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use dioxus_core::{Runtime, ScopeId, consume_context_from_scope, use_drop, use_hook};
+
+use super::get_or_insert_root_context;
+
+/// The values components set for parts of the head that can only have one value, like the title or the content of
+/// a meta tag with a specific name.
+///
+/// Conflicts are resolved like a stack: the component that mounted last wins, and when it unmounts, the head goes
+/// back to the value of the component that mounted before it. Children mount after their parents, so deeper
+/// components override shallower ones.
+#[derive(Default, Clone)]
+struct HeadStack(Rc<RefCell<HeadStackInner>>);
+
+#[derive(Default)]
+struct HeadStackInner {
+    next_id: usize,
+    entries: HashMap<String, Vec<(usize, Option<String>)>>,
+}
+
+struct HeadEntry {
+    stack: HeadStack,
+    key: String,
+    id: usize,
+    /// Update the head with the value that should be visible, or clear it if there is no value left for the key
+    apply: Box<dyn Fn(Option<String>)>,
+}
+
+impl HeadEntry {
+    fn is_top(&self, entries: &[(usize, Option<String>)]) -> bool {
+        entries.last().is_some_and(|(id, _)| *id == self.id)
+    }
+
+    fn update(&self, value: Option<String>) {
+        let mut stack = self.stack.0.borrow_mut();
+        let Some(entries) = stack.entries.get_mut(&self.key) else {
+            return;
+        };
+        let is_top = self.is_top(entries);
+        let Some((_, current)) = entries.iter_mut().find(|(id, _)| *id == self.id) else {
+            return;
+        };
+        if *current == value {
+            return;
+        }
+        *current = value.clone();
+        drop(stack);
+
+        if is_top {
+            (self.apply)(value);
+        }
+    }
+
+    fn remove(&self) {
+        let mut stack = self.stack.0.borrow_mut();
+        let Some(entries) = stack.entries.get_mut(&self.key) else {
+            return;
+        };
+        let was_top = self.is_top(entries);
+        entries.retain(|(id, _)| *id != self.id);
+        let next = entries.last().and_then(|(_, value)| value.clone());
+        drop(stack);
+
+        // Don't touch the head if the whole virtual dom is being dropped
+        let dropping_dom = Runtime::try_current().is_none()
+            || consume_context_from_scope::<HeadStack>(ScopeId::ROOT).is_none();
+        if was_top && !dropping_dom {
+            (self.apply)(next);
+        }
+    }
+}
+
+/// Set `value` for the part of the head identified by `key`, and call `apply` with the value that should be in the
+/// head whenever it changes. A value of `None` clears that part of the head while this component is on top. See
+/// [`HeadStack`] for how conflicts between components are resolved.
+pub(crate) fn use_head_entry(
+    key: impl FnOnce() -> String,
+    value: Option<String>,
+    apply: impl Fn(Option<String>) + 'static,
+) {
+    let entry = use_hook(|| {
+        let stack = get_or_insert_root_context::<HeadStack>();
+        let key = key();
+        let id = {
+            let mut inner = stack.0.borrow_mut();
+            let id = inner.next_id;
+            inner.next_id += 1;
+            inner
+                .entries
+                .entry(key.clone())
+                .or_default()
+                .push((id, value.clone()));
+            id
+        };
+        apply(value.clone());
+
+        Rc::new(HeadEntry {
+            stack,
+            key,
+            id,
+            apply: Box::new(apply),
+        })
+    });
+
+    entry.update(value);
+
+    use_drop(move || entry.remove());
+}
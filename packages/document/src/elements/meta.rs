@@ -1,3 +1,4 @@
+use super::head_stack::use_head_entry;
 use super::*;
 use crate::document;
 use dioxus_core::{VNode, use_hook};
@@ -42,6 +43,24 @@ impl MetaProps {
         }
         attributes
     }
+
+    /// The attribute and value that identify this meta tag in the head, like `name="description"`. Only meta tags
+    /// with a name or property and content, and no other attributes, are identified by a key.
+    fn head_key(&self) -> Option<(&'static str, String)> {
+        if self.content.is_none()
+            || self.charset.is_some()
+            || self.http_equiv.is_some()
+            || self.data.is_some()
+            || !self.additional_attributes.is_empty()
+        {
+            return None;
+        }
+        match (&self.name, &self.property) {
+            (Some(name), None) => Some(("name", name.clone())),
+            (None, Some(property)) => Some(("property", property.clone())),
+            _ => None,
+        }
+    }
 }
 
 /// Render a [`<meta>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/meta) tag into the head of the page.
@@ -62,14 +81,48 @@ impl MetaProps {
 /// }
 /// ```
 ///
+/// ## Meta tags with a name or property
+///
+/// A meta tag with only a `name` or `property` and `content`, like a description, can only have one value in the
+/// head. These tags are deduplicated by their name or property: if multiple components render a meta tag with the
+/// same name, the one that was mounted last wins. Children mount after their parents, so the description of a nested
+/// route overrides the description of its layout. When that meta tag is unmounted, the content goes back to the meta
+/// tag that was mounted before it, or the tag is removed if there is none left.
+///
+/// The content of these meta tags updates when the props change, and is rendered on the server. If the content
+/// changes to `None`, the tag is removed from the head until the content is set again.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn BlogPost(title: String, summary: String) -> Element {
+///     rsx! {
+///         document::Title { "{title}" }
+///         document::Meta { name: "description", content: summary }
+///     }
+/// }
+/// ```
+///
 /// <div class="warning">
 ///
-/// Any updates to the props after the first render will not be reflected in the head.
+/// Any updates to the props of other meta tags after the first render will not be reflected in the head.
 ///
 /// </div>
 #[component]
 #[doc(alias = "<meta>")]
 pub fn Meta(props: MetaProps) -> Element {
+    let key = use_hook(|| props.head_key());
+    if let Some((key_attribute, key)) = key {
+        let document = use_hook(document);
+        let entry_key = format!("meta[{key_attribute}={key}]");
+        use_head_entry(
+            move || entry_key,
+            props.content,
+            move |content| document.set_meta(key_attribute, &key, content),
+        );
+        return VNode::empty();
+    }
+
     use_update_warning(&props, "Meta {}");
 
     use_hook(|| {
@@ -15,8 +15,13 @@ mod script;
 pub use script::*;
 mod style;
 pub use style::*;
+mod scoped_style;
+pub use scoped_style::*;
 mod title;
 pub use title::*;
+mod head;
+pub use head::*;
+mod head_stack;
 
 /// Warn the user if they try to change props on a element that is injected into the head
 #[allow(unused)]
@@ -0,0 +1,323 @@
+use super::*;
+use crate::document;
+use dioxus_core::{Template, VNode, VText, use_hook};
+
+/// Scope a block of css to a class name that is unique to the call site and insert it into the head of the page once.
+///
+/// `styled!` returns the generated class name. Declarations at the top level of the css apply to the element with the
+/// class. Nested rules are expanded relative to the class: `&` is replaced with the class selector and rules without
+/// `&` match descendants. A nested rule under a selector list like `a, b` applies to every selector in the list.
+/// `@media`, `@supports`, and `@container` blocks are scoped the same way.
+///
+/// The class name is derived from the location of the macro, so it is stable between the server and the client. The
+/// stylesheet is only inserted the first time any component renders the macro, no matter how many times it is used.
+///
+/// # Example
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Button() -> Element {
+///     let class = document::styled!(r#"
+///         padding: 8px 16px;
+///         border-radius: 4px;
+///
+///         &:hover {
+///             background-color: lightblue;
+///         }
+///
+///         span {
+///             font-weight: bold;
+///         }
+///
+///         @media (max-width: 600px) {
+///             padding: 4px 8px;
+///         }
+///     "#);
+///
+///     rsx! {
+///         button { class, span { "Click me" } }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! styled {
+    ($css:literal $(,)?) => {{
+        const HASH: u32 = $crate::scoped_style_hash(concat!(
+            module_path!(),
+            ":",
+            line!(),
+            ":",
+            column!(),
+            ":",
+            $css
+        ));
+        $crate::use_scoped_style(HASH, $css)
+    }};
+}
+
+/// Hash a string at compile time. Used by [`styled!`] to generate a class name.
+#[doc(hidden)]
+pub const fn scoped_style_hash(source: &str) -> u32 {
+    // FNV-1a
+    let bytes = source.as_bytes();
+    let mut hash: u32 = 0x811c9dc5;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x01000193);
+        i += 1;
+    }
+    hash
+}
+
+/// Insert the scoped css for a [`styled!`] call into the head and return its class name.
+#[doc(hidden)]
+pub fn use_scoped_style(hash: u32, css: &'static str) -> String {
+    use_hook(|| {
+        let class = format!("dx-{hash:08x}");
+        let document = document();
+        if document.create_head_component() && should_insert_scoped_style(&class) {
+            let contents = scope_css(css, &format!(".{class}"));
+            document.create_style(StyleProps {
+                href: None,
+                media: None,
+                nonce: None,
+                title: None,
+                children: text_node(contents),
+                additional_attributes: Vec::new(),
+            });
+        }
+        class
+    })
+}
+
+/// Create an element with a single dynamic text node that [`extract_single_text_node`] can read
+fn text_node(text: String) -> Element {
+    Ok(VNode::new(
+        None,
+        Template::new(&[TemplateNode::Dynamic { id: 0 }], &[&[0]], &[]),
+        Box::new([DynamicNode::Text(VText::new(text))]),
+        Box::new([]),
+    ))
+}
+
+#[derive(Default, Clone)]
+struct ScopedStyleContext(DeduplicationContext);
+
+fn should_insert_scoped_style(class: &str) -> bool {
+    get_or_insert_root_context::<ScopedStyleContext>()
+        .0
+        .should_insert(class)
+}
+
+/// Rewrite nested css so every rule is scoped under `selector`
+fn scope_css(css: &str, selector: &str) -> String {
+    scope_block(&strip_comments(css), selector)
+}
+
+fn scope_block(css: &str, selector: &str) -> String {
+    let mut declarations = String::new();
+    let mut rules = String::new();
+    let mut rest = css;
+
+    loop {
+        let Some((end, _)) = code_chars(rest).find(|(_, c)| matches!(c, ';' | '{')) else {
+            // A trailing declaration without a semicolon
+            push_declaration(&mut declarations, rest);
+            break;
+        };
+
+        let prelude = rest[..end].trim();
+        if rest.as_bytes()[end] == b';' {
+            push_declaration(&mut declarations, prelude);
+            rest = &rest[end + 1..];
+            continue;
+        }
+
+        // Find the matching closing brace for this block
+        let body_start = end + 1;
+        let mut depth = 1;
+        let mut body_end = rest.len();
+        for (i, c) in code_chars(&rest[body_start..]) {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                body_end = body_start + i;
+                break;
+            }
+        }
+        let body = &rest[body_start..body_end];
+        rest = rest.get(body_end + 1..).unwrap_or_default();
+
+        if prelude.starts_with("@media")
+            || prelude.starts_with("@supports")
+            || prelude.starts_with("@container")
+        {
+            rules.push_str(&format!("{prelude}{{{}}}", scope_block(body, selector)));
+        } else if prelude.starts_with('@') {
+            // Other at rules like @keyframes and @font-face are left untouched
+            rules.push_str(&format!("{prelude}{{{body}}}"));
+        } else {
+            // Every selector in the rule is nested under every selector of the parent
+            let parents = split_selectors(selector);
+            let nested = split_selectors(prelude)
+                .into_iter()
+                .flat_map(|part| {
+                    parents.iter().map(move |parent| {
+                        if part.contains('&') {
+                            part.replace('&', parent)
+                        } else {
+                            format!("{parent} {part}")
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            rules.push_str(&scope_block(body, &nested));
+        }
+    }
+
+    if declarations.is_empty() {
+        rules
+    } else {
+        format!("{selector}{{{declarations}}}{rules}")
+    }
+}
+
+/// Split a selector list at the commas that aren't inside parentheses, brackets, or strings
+fn split_selectors(selectors: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in code_chars(selectors) {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(selectors[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(selectors[start..].trim());
+    parts
+}
+
+/// The characters of `css` outside of strings with their byte offsets, so braces and semicolons in `content: "{"`
+/// aren't mistaken for the structure of the stylesheet
+fn code_chars(css: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    let mut chars = css.char_indices();
+    std::iter::from_fn(move || {
+        let (i, c) = chars.next()?;
+        if matches!(c, '"' | '\'') {
+            skip_string(&mut chars, c);
+        }
+        Some((i, c))
+    })
+}
+
+/// Skip past the closing `quote` of a string, ignoring escaped quotes
+fn skip_string(chars: &mut impl Iterator<Item = (usize, char)>, quote: char) {
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '\\' => _ = chars.next(),
+            _ if c == quote => break,
+            _ => {}
+        }
+    }
+}
+
+/// Remove the comments from `css`, keeping anything that looks like a comment inside of a string
+fn strip_comments(css: &str) -> String {
+    let mut stripped = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some((start, c)) = code_chars(rest)
+        .find(|(i, c)| *c == '"' || *c == '\'' || (*c == '/' && rest[i + 1..].starts_with('*')))
+    {
+        if c == '/' {
+            stripped.push_str(&rest[..start]);
+            let end = rest[start + 2..]
+                .find("*/")
+                .map(|end| start + 2 + end + 2)
+                .unwrap_or(rest.len());
+            rest = &rest[end..];
+        } else {
+            // Copy the whole string, including any `/*` inside of it
+            let mut chars = rest[start + 1..]
+                .char_indices()
+                .map(|(i, c)| (start + 1 + i, c));
+            skip_string(&mut chars, c);
+            let end = chars.next().map(|(i, _)| i).unwrap_or(rest.len());
+            stripped.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+fn push_declaration(declarations: &mut String, declaration: &str) {
+    let declaration = declaration.trim();
+    if !declaration.is_empty() {
+        declarations.push_str(declaration);
+        declarations.push(';');
+    }
+}
+
+#[test]
+fn scopes_nested_css() {
+    let css = r#"
+        color: red;
+        &:hover, &.active { color: blue; }
+        span { font-weight: bold; a { color: green } }
+        @media (max-width: 600px) { color: black; }
+        @keyframes fade { from { opacity: 0; } to { opacity: 1; } }
+    "#;
+    assert_eq!(
+        scope_css(css, ".dx"),
+        ".dx{color: red;}\
+         .dx:hover,.dx.active{color: blue;}\
+         .dx span{font-weight: bold;}\
+         .dx span a{color: green;}\
+         @media (max-width: 600px){.dx{color: black;}}\
+         @keyframes fade{ from { opacity: 0; } to { opacity: 1; } }"
+    );
+}
+
+#[test]
+fn scopes_nested_css_under_selector_lists() {
+    let css = r#"
+        a, b {
+            &:hover { color: red; }
+            span, em { color: blue; }
+        }
+    "#;
+    assert_eq!(
+        scope_css(css, ".dx"),
+        ".dx a:hover,.dx b:hover{color: red;}\
+         .dx a span,.dx b span,.dx a em,.dx b em{color: blue;}"
+    );
+
+    // Commas inside of parentheses don't split the selector
+    assert_eq!(
+        scope_css(":is(a, b) { &:hover { color: red; } }", ".dx"),
+        ".dx :is(a, b):hover{color: red;}"
+    );
+}
+
+#[test]
+fn ignores_braces_and_semicolons_in_strings_and_comments() {
+    let css = r#"
+        content: "{;}";
+        /* span { color: red; } */
+        &::after { content: '}'; font-family: "a;b"; background: url("/*.png"); }
+        span { /* } ; { */ color: blue; }
+    "#;
+    assert_eq!(
+        scope_css(css, ".dx"),
+        r#".dx{content: "{;}";}.dx::after{content: '}';font-family: "a;b";background: url("/*.png");}.dx span{color: blue;}"#
+    );
+}
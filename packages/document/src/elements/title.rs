@@ -2,6 +2,8 @@ use dioxus_core::{VNode, use_hook};
 
 use crate::document;
 
+use super::head_stack::use_head_entry;
+
 use super::*;
 
 #[derive(Clone, Props, PartialEq)]
@@ -12,7 +14,7 @@ pub struct TitleProps {
 
 /// Render the title of the page. On web renderers, this will set the [`<title>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/title) in the head. On desktop, it will set the window title.
 ///
-/// Unlike most head components, the Title can be modified after the first render. Only the latest update to the title will be reflected if multiple title components are rendered.
+/// Unlike most head components, the Title can be modified after the first render. If multiple title components are rendered, the one that was mounted last wins. Children mount after their parents, so the title of a nested route overrides the title of its layout. When that title is unmounted, the title goes back to the title that was mounted before it.
 ///
 ///
 /// The children of the title component must be a single static or formatted string. If there are more children or the children contain components, conditionals, loops, or fragments, the title will not be updated.
@@ -42,18 +44,15 @@ pub fn Title(props: TitleProps) -> Element {
 
     // Update the title as it changes. NOTE: We don't use use_effect here because we need this to run on the server
     let document = use_hook(document);
-    let last_text = use_hook(|| {
-        // Set the title initially
-        document.set_title(text.clone());
-        Rc::new(RefCell::new(text.clone()))
-    });
-
-    // If the text changes, update the title
-    let mut last_text = last_text.borrow_mut();
-    if text != *last_text {
-        document.set_title(text.clone());
-        *last_text = text;
-    }
+    use_head_entry(
+        || "title".to_string(),
+        Some(text),
+        move |title| {
+            if let Some(title) = title {
+                document.set_title(title);
+            }
+        },
+    );
 
     VNode::empty()
 }
@@ -0,0 +1,83 @@
+use dioxus_core::Element;
+use dioxus_core_macro::{Props, component, rsx};
+use dioxus_html as dioxus_elements;
+
+/// The element a [`Portal`] renders its children into
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum PortalTarget {
+    /// The `<body>` of the document
+    #[default]
+    Body,
+    /// The element with this `id`. The element must exist before the portal is rendered.
+    Id(String),
+}
+
+impl From<&str> for PortalTarget {
+    fn from(id: &str) -> Self {
+        Self::Id(id.to_string())
+    }
+}
+
+impl From<String> for PortalTarget {
+    fn from(id: String) -> Self {
+        Self::Id(id)
+    }
+}
+
+#[derive(Clone, Props, PartialEq)]
+pub struct PortalProps {
+    /// The element to render the children into. Defaults to the body of the document.
+    #[props(default, into)]
+    target: PortalTarget,
+    children: Element,
+}
+
+/// Render the children into a different element of the document, like the `<body>`. This lets modals, tooltips, and
+/// dropdowns escape containers with `overflow: hidden` or their own stacking context.
+///
+/// The children stay in the same place in the component tree. They can read contexts from the components around the
+/// portal and events bubble to the elements around the portal, not the elements around the target.
+///
+/// Portals are supported by the web, desktop, and liveview renderers. Other renderers, and server side rendering
+/// before hydration, render the children in place.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut open = use_signal(|| false);
+///
+///     rsx! {
+///         div { overflow: "hidden", height: "20px",
+///             button { onclick: move |_| open.set(true), "Open" }
+///             if open() {
+///                 Portal {
+///                     div { class: "modal",
+///                         "The modal is rendered at the end of the body"
+///                         button { onclick: move |_| open.set(false), "Close" }
+///                     }
+///                 }
+///             }
+///         }
+///         // Or render into an element by id
+///         div { id: "tooltips" }
+///         Portal { target: "tooltips", "Tooltip" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Portal(props: PortalProps) -> Element {
+    let target = match props.target {
+        PortalTarget::Body => String::new(),
+        PortalTarget::Id(id) => id,
+    };
+
+    rsx! {
+        // The outer element stays where the portal is rendered, so nodes around the portal can still be inserted
+        // next to it. The renderer moves the inner element into the target.
+        div { display: "contents",
+            div { "dioxus-portal": target, {props.children} }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_document::{Document, Eval, MetaProps, NoOpDocument};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
+
+/// A document that records the head it would render
+#[derive(Clone, Default)]
+struct RecordingDocument(Rc<RefCell<RecordedHead>>);
+
+#[derive(Default)]
+struct RecordedHead {
+    title: Option<String>,
+    /// Meta tags set with `set_meta`, keyed by `(key attribute, key)`
+    meta: HashMap<(&'static str, String), String>,
+    /// The attributes of meta tags created with `create_meta`
+    created_meta: Vec<Vec<(&'static str, String)>>,
+}
+
+impl Document for RecordingDocument {
+    fn eval(&self, js: String) -> Eval {
+        NoOpDocument.eval(js)
+    }
+
+    fn set_title(&self, title: String) {
+        self.0.borrow_mut().title = Some(title);
+    }
+
+    fn create_meta(&self, props: MetaProps) {
+        self.0.borrow_mut().created_meta.push(props.attributes());
+    }
+
+    fn set_meta(&self, key_attribute: &'static str, key: &str, content: Option<String>) {
+        let mut head = self.0.borrow_mut();
+        let key = (key_attribute, key.to_string());
+        match content {
+            Some(content) => _ = head.meta.insert(key, content),
+            None => _ = head.meta.remove(&key),
+        }
+    }
+}
+
+impl RecordingDocument {
+    fn title(&self) -> Option<String> {
+        self.0.borrow().title.clone()
+    }
+
+    fn meta(&self, key_attribute: &'static str, key: &str) -> Option<String> {
+        self.0
+            .borrow()
+            .meta
+            .get(&(key_attribute, key.to_string()))
+            .cloned()
+    }
+}
+
+thread_local! {
+    static SHOW_PAGE: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    static LAYOUT_TEXT: Cell<Option<Signal<String>>> = const { Cell::new(None) };
+    static PAGE_TEXT: Cell<Option<Signal<Option<String>>>> = const { Cell::new(None) };
+}
+
+fn launch(app: fn() -> Element) -> (VirtualDom, RecordingDocument) {
+    let document = RecordingDocument::default();
+    let mut dom =
+        VirtualDom::new(app).with_root_context(Rc::new(document.clone()) as Rc<dyn Document>);
+    dom.rebuild_in_place();
+    (dom, document)
+}
+
+fn update(dom: &mut VirtualDom, update: impl FnOnce()) {
+    dom.in_runtime(update);
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+fn show_page(show: bool) {
+    SHOW_PAGE.get().unwrap().set(show);
+}
+
+fn set_layout_text(text: &str) {
+    LAYOUT_TEXT.get().unwrap().set(text.to_string());
+}
+
+fn set_page_text(text: Option<&str>) {
+    PAGE_TEXT.get().unwrap().set(text.map(str::to_string));
+}
+
+#[test]
+fn the_last_mounted_title_wins_and_reverts_on_unmount() {
+    fn app() -> Element {
+        let show = use_signal(|| true);
+        SHOW_PAGE.set(Some(show));
+        let layout = use_signal(|| "Layout".to_string());
+        LAYOUT_TEXT.set(Some(layout));
+
+        rsx! {
+            document::Title { "{layout}" }
+            if show() {
+                Page {}
+            }
+        }
+    }
+
+    #[component]
+    fn Page() -> Element {
+        rsx! {
+            document::Title { "Page" }
+        }
+    }
+
+    let (mut dom, document) = launch(app);
+    assert_eq!(document.title().as_deref(), Some("Page"));
+
+    // Updating a title that is covered by another one doesn't change the head
+    update(&mut dom, || set_layout_text("New layout"));
+    assert_eq!(document.title().as_deref(), Some("Page"));
+
+    update(&mut dom, || show_page(false));
+    assert_eq!(document.title().as_deref(), Some("New layout"));
+
+    update(&mut dom, || show_page(true));
+    assert_eq!(document.title().as_deref(), Some("Page"));
+}
+
+#[test]
+fn the_last_mounted_meta_wins_and_reverts_on_unmount() {
+    fn app() -> Element {
+        let show = use_signal(|| true);
+        SHOW_PAGE.set(Some(show));
+        let page = use_signal(|| Some("Page".to_string()));
+        PAGE_TEXT.set(Some(page));
+
+        rsx! {
+            document::Meta { name: "description", content: "Layout" }
+            if show() {
+                Page { content: page() }
+            }
+        }
+    }
+
+    #[component]
+    fn Page(content: Option<String>) -> Element {
+        rsx! {
+            document::Meta { name: "description", content }
+        }
+    }
+
+    let (mut dom, document) = launch(app);
+    assert_eq!(
+        document.meta("name", "description").as_deref(),
+        Some("Page")
+    );
+
+    update(&mut dom, || set_page_text(Some("Updated page")));
+    assert_eq!(
+        document.meta("name", "description").as_deref(),
+        Some("Updated page")
+    );
+
+    // Clearing the content of the top meta tag removes it from the head
+    update(&mut dom, || set_page_text(None));
+    assert_eq!(document.meta("name", "description"), None);
+
+    update(&mut dom, || show_page(false));
+    assert_eq!(
+        document.meta("name", "description").as_deref(),
+        Some("Layout")
+    );
+}
+
+#[test]
+fn meta_without_content_omits_the_content_attribute() {
+    fn app() -> Element {
+        rsx! {
+            document::Meta { name: "robots" }
+        }
+    }
+
+    let (_dom, document) = launch(app);
+    let head = document.0.borrow();
+    assert!(head.meta.is_empty());
+    assert_eq!(head.created_meta, [vec![("name", "robots".to_string())]]);
+}
@@ -36,6 +36,11 @@ impl<D: Document> Document for FullstackWebDocument<D> {
         self.document.create_meta(props);
     }
 
+    /// Set the content of a meta tag in the head
+    fn set_meta(&self, key_attribute: &'static str, key: &str, content: Option<String>) {
+        self.document.set_meta(key_attribute, key, content);
+    }
+
     /// Create a new script tag in the head
     fn create_script(&self, props: ScriptProps) {
         self.document.create_script(props);
@@ -1,5 +1,6 @@
 use crate::{Transportable, transport::SerializeContextEntry};
 use dioxus_core::use_hook;
+use serde::{Serialize, de::DeserializeOwned};
 
 /// This allows you to send data from the server to the client *during hydration*.
 /// - When compiled as server, the closure is ran and the resulting data is serialized on the server and sent to the client.
@@ -10,6 +11,9 @@ use dioxus_core::use_hook;
 ///
 /// If Dioxus fullstack cannot find the data on the client, it will run the closure again to get the data.
 ///
+/// Any type that implements `Serialize` and `DeserializeOwned` can be cached. For other types, either implement
+/// [`Transportable`] or use [`use_server_cached_with`] to convert the value to and from a serializable type.
+///
 /// # Example
 /// ```rust
 /// use dioxus::prelude::*;
@@ -32,6 +36,75 @@ where
     use_hook(|| server_cached(server_fn, location))
 }
 
+/// Like [`use_server_cached`], but for values that can't be serialized directly.
+///
+/// On the server, `serialize` converts the value into a serializable type that is sent to the client. During
+/// hydration, `deserialize` converts it back into the value instead of running the closure again.
+///
+/// # Example
+/// ```rust
+/// use dioxus::prelude::*;
+/// use std::rc::Rc;
+///
+/// fn app() -> Element {
+///     // Rc<str> isn't serializable, but we can send it as a String
+///     let greeting: Rc<str> = use_server_cached_with(
+///         || Rc::from("Hello from the server"),
+///         |greeting| greeting.to_string(),
+///         |greeting: String| Rc::from(greeting),
+///     );
+///
+///     rsx! { "{greeting}" }
+/// }
+/// ```
+#[track_caller]
+pub fn use_server_cached_with<O, S>(
+    server_fn: impl Fn() -> O,
+    serialize: impl Fn(&O) -> S,
+    deserialize: impl Fn(S) -> O,
+) -> O
+where
+    O: Clone + 'static,
+    S: Serialize + DeserializeOwned + 'static,
+{
+    let location = std::panic::Location::caller();
+    use_hook(|| server_cached_with(server_fn, serialize, deserialize, location))
+}
+
+#[allow(unused)]
+fn server_cached_with<O, S>(
+    value: impl FnOnce() -> O,
+    serialize: impl FnOnce(&O) -> S,
+    deserialize: impl FnOnce(S) -> O,
+    location: &'static std::panic::Location<'static>,
+) -> O
+where
+    S: Serialize + DeserializeOwned + 'static,
+{
+    let context = crate::transport::serialize_context();
+    let entry: SerializeContextEntry<S> = context.create_entry();
+
+    #[cfg(feature = "server")]
+    {
+        let data = value();
+        entry.insert(&serialize(&data), location);
+        data
+    }
+
+    #[cfg(all(not(feature = "server"), feature = "web"))]
+    {
+        match entry.get() {
+            Ok(serialized) => deserialize(serialized),
+            Err(_) => value(),
+        }
+    }
+
+    #[cfg(not(any(feature = "server", feature = "web")))]
+    {
+        value()
+    }
+}
+
 pub(crate) fn server_cached<O, M>(
     value: impl FnOnce() -> O,
     #[allow(unused)] location: &'static std::panic::Location<'static>,
@@ -57,6 +57,26 @@ use syn::{LitBool, LitInt, Pat, PatType, spanned::Spanned};
 /// - `client`: a custom `Client` implementation that will be used for this server function. This allows
 ///   customization of the client-side behavior if needed.
 ///
+/// ## Extracting data from the request
+///
+/// Server functions can read data from the request with any axum extractor that implements
+/// [`FromRequestParts`](https://docs.rs/axum/latest/axum/extract/trait.FromRequestParts.html), like headers,
+/// cookies, or an authenticated user. Mark the argument with `#[extract]` to extract it on the server. Extracted
+/// arguments are not part of the function on the client, so callers only pass the remaining arguments.
+///
+/// ```rust,ignore
+/// # use dioxus::prelude::*;
+/// #[server]
+/// async fn me(#[extract] user: AuthUser, #[extract] headers: HeaderMap) -> Result<Profile> {
+///     Ok(user.profile().await?)
+/// }
+///
+/// // On the client, `me` takes no arguments
+/// let profile = me().await?;
+/// ```
+///
+/// Extractors can also be listed in the attribute instead: `#[server(user: AuthUser)]`.
+///
 /// ## Advanced Usage of `input` and `output` Fields
 ///
 /// The `input` and `output` fields allow you to customize how arguments and responses are encoded and decoded.
@@ -196,7 +216,7 @@ fn route_impl(
 }
 
 fn route_impl_with_route(
-    route: Route,
+    mut route: Route,
     item: TokenStream,
     method_from_macro: Option<Method>,
 ) -> syn::Result<TokenStream2> {
@@ -225,6 +245,25 @@ fn route_impl_with_route(
         .attrs
         .retain(|attr| !attr.path().is_ident("middleware"));
 
+    // Arguments marked with `#[extract]` are server-only extractors, just like the `name: Type` arguments in the
+    // attribute. Move them out of the client-side signature.
+    for mut arg in std::mem::take(&mut function.sig.inputs) {
+        let is_extractor = match &mut arg {
+            FnArg::Typed(pat_type) => {
+                let len = pat_type.attrs.len();
+                pat_type
+                    .attrs
+                    .retain(|attr| !attr.path().is_ident("extract"));
+                pat_type.attrs.len() != len
+            }
+            FnArg::Receiver(_) => false,
+        };
+        match is_extractor {
+            true => route.server_args.push(arg),
+            false => function.sig.inputs.push(arg),
+        }
+    }
+
     // Attach `#[allow(unused_mut)]` to all original inputs to avoid warnings
     let outer_inputs = function
         .sig
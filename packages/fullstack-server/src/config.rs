@@ -28,6 +28,18 @@ pub enum StreamingMode {
 
     /// Out of order streaming is enabled; server futures are resolved out of order and streamed to the client
     /// as they resolve
+    ///
+    /// The server sends the head and the rest of the page right away, with the fallback of every suspended
+    /// [`SuspenseBoundary`](dioxus_core::SuspenseBoundary) in place of its children. As each boundary resolves, the
+    /// server sends its html in a hidden element along with a script that swaps it in for the fallback and queues
+    /// the boundary's server data for hydration. The client hydrates each boundary as its chunk arrives, so a slow
+    /// boundary doesn't block the rest of the page from becoming interactive.
+    ///
+    /// The status code and headers are sent with the first chunk, so they can only be set before any boundary
+    /// suspends. An error thrown inside a boundary after the first chunk is sent is serialized with the boundary's
+    /// data and thrown again on the client, where it renders the closest error boundary.
+    ///
+    /// Streaming requires javascript. Without it, suspended boundaries keep showing their fallback.
     OutOfOrder,
 }
 
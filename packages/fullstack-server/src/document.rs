@@ -26,6 +26,8 @@ struct ServerDocumentInner {
     streaming: bool,
     title: Option<String>,
     meta: Vec<Element>,
+    /// Meta tags set with [`Document::set_meta`] as `(key attribute, key, content)`
+    keyed_meta: Vec<(&'static str, String, String)>,
     link: Vec<Element>,
     script: Vec<Element>,
 }
@@ -46,8 +48,16 @@ impl ServerDocument {
 
     pub(crate) fn render(&self, to: &mut impl std::fmt::Write) -> std::fmt::Result {
         let myself = self.0.borrow();
+        let keyed_meta = myself
+            .keyed_meta
+            .iter()
+            .map(|(key_attribute, key, content)| match *key_attribute {
+                "property" => rsx! { meta { property: "{key}", content: "{content}" } },
+                _ => rsx! { meta { name: "{key}", content: "{content}" } },
+            });
         let element = rsx! {
             {myself.meta.iter().map(|m| rsx! { {m} })}
+            {keyed_meta}
             {myself.link.iter().map(|l| rsx! { {l} })}
             {myself.script.iter().map(|s| rsx! { {s} })}
         };
@@ -106,6 +116,19 @@ impl Document for ServerDocument {
         });
     }
 
+    fn set_meta(&self, key_attribute: &'static str, key: &str, content: Option<String>) {
+        self.warn_if_streaming();
+        let mut myself = self.0.borrow_mut();
+        myself
+            .keyed_meta
+            .retain(|(attribute, existing, _)| *attribute != key_attribute || existing != key);
+        if let Some(content) = content {
+            myself
+                .keyed_meta
+                .push((key_attribute, key.to_string(), content));
+        }
+    }
+
     fn create_script(&self, props: ScriptProps) {
         let children = props.script_contents().ok();
         self.0.borrow_mut().script.push(rsx! {
@@ -35,6 +35,7 @@ pub mod config;
 
 pub(crate) mod document;
 pub(crate) mod ssr;
+pub use ssr::hydration_data_script;
 pub(crate) mod streaming;
 
 pub use launch::router;
@@ -725,30 +725,7 @@ impl SsrRendererPool {
         to: &mut R,
         virtual_dom: &VirtualDom,
     ) -> Result<(), IncrementalRendererError> {
-        // Collect the initial server data from the root node. For most apps, no use_server_futures will be resolved initially, so this will be full on `None`s.
-        // Sending down those Nones are still important to tell the client not to run the use_server_futures that are already running on the backend
-        let resolved_data = SsrRendererPool::serialize_server_data(virtual_dom, ScopeId::ROOT);
-        // We always send down the data required to hydrate components on the client
-        let raw_data = resolved_data.data;
-        write!(
-            to,
-            r#"<script>window.initial_dioxus_hydration_data="{raw_data}";"#,
-        )?;
-        #[cfg(debug_assertions)]
-        {
-            // In debug mode, we also send down the type names and locations of the serialized data
-            let debug_types = &resolved_data.debug_types;
-            let debug_locations = &resolved_data.debug_locations;
-            write!(
-                to,
-                r#"window.initial_dioxus_hydration_debug_types={debug_types};"#,
-            )?;
-            write!(
-                to,
-                r#"window.initial_dioxus_hydration_debug_locations={debug_locations};"#,
-            )?;
-        }
-        write!(to, r#"</script>"#,)?;
+        to.write_str(&hydration_data_script(virtual_dom))?;
         to.write_str(&cfg.index.post_main)?;
 
         Ok(())
@@ -764,3 +741,45 @@ impl SsrRendererPool {
         Ok(())
     }
 }
+
+/// Serialize the data resolved while rendering `virtual_dom` into the `<script>` tag the web renderer reads when it
+/// hydrates the page.
+///
+/// Use this with [`dioxus_ssr::render_with_hydration`] to hydrate an app from a custom server. Include the script after
+/// the rendered html and before the client's wasm bundle. It sets `window.initial_dioxus_hydration_data` to the
+/// base64 encoded state of every server future and loader in the page, so the client can reuse those values instead
+/// of running the futures again. Wait for [`VirtualDom::wait_for_suspense`] before rendering so the futures have resolved.
+pub fn hydration_data_script(virtual_dom: &VirtualDom) -> String {
+    let mut script = String::new();
+    _ = write_hydration_data(virtual_dom, &mut script);
+    script
+}
+
+fn write_hydration_data(virtual_dom: &VirtualDom, to: &mut String) -> std::fmt::Result {
+    // Collect the initial server data from the root node. For most apps, no use_server_futures will be resolved initially, so this will be full on `None`s.
+    // Sending down those Nones are still important to tell the client not to run the use_server_futures that are already running on the backend
+    let resolved_data = SsrRendererPool::serialize_server_data(virtual_dom, ScopeId::ROOT);
+    // We always send down the data required to hydrate components on the client
+    let raw_data = resolved_data.data;
+    write!(
+        to,
+        r#"<script>window.initial_dioxus_hydration_data="{raw_data}";"#,
+    )?;
+    #[cfg(debug_assertions)]
+    {
+        // In debug mode, we also send down the type names and locations of the serialized data
+        let debug_types = &resolved_data.debug_types;
+        let debug_locations = &resolved_data.debug_locations;
+        write!(
+            to,
+            r#"window.initial_dioxus_hydration_debug_types={debug_types};"#,
+        )?;
+        write!(
+            to,
+            r#"window.initial_dioxus_hydration_debug_locations={debug_locations};"#,
+        )?;
+    }
+    write!(to, r#"</script>"#,)?;
+
+    Ok(())
+}
@@ -128,6 +128,46 @@ impl<T: 'static + Send, E> Streaming<T, E> {
         }
     }
 
+    /// Creates a new stream from a stream of results.
+    ///
+    /// The stream ends at the first error. The error is logged on the server and the client receives
+    /// [`StreamingError::Failed`] for the interrupted response.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # use dioxus::fullstack::{JsonEncoding, Streaming};
+    /// use futures_util::StreamExt;
+    ///
+    /// #[get("/api/logs")]
+    /// async fn tail_logs() -> Result<Streaming<String, JsonEncoding>> {
+    ///     let lines = futures_util::stream::iter(["starting", "listening"])
+    ///         .map(|line| Ok::<_, ServerFnError>(line.to_string()));
+    ///     Ok(Streaming::try_new(lines))
+    /// }
+    /// ```
+    pub fn try_new<Err: std::fmt::Display>(
+        value: impl Stream<Item = Result<T, Err>> + Send + 'static,
+    ) -> Self {
+        let stream = value
+            .map(|item| {
+                item.map_err(|err| {
+                    tracing::error!("Streaming response failed: {err}");
+                    StreamingError::Failed
+                })
+            })
+            // Yield the error and then stop the stream
+            .scan(false, |failed, item| {
+                let next = (!*failed).then_some(item);
+                *failed = next.as_ref().is_some_and(|item| item.is_err());
+                futures::future::ready(next)
+            });
+
+        Self {
+            stream: Box::pin(stream),
+            encoding: PhantomData,
+        }
+    }
+
     /// Spawns a new task that produces items for the stream.
     ///
     /// The callback is provided an `UnboundedSender` that can be used to send items to the stream.
@@ -628,3 +668,21 @@ fn offset_payload_len(frame: &Bytes) -> Option<Result<(usize, usize), StreamingE
     }
     Some(Ok((offset, payload_len)))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn try_new_ends_at_first_error() {
+        futures::executor::block_on(async {
+            let stream = TextStream::try_new(futures::stream::iter(vec![
+                Ok("one".to_string()),
+                Err(ServerFnError::new("failed")),
+                Ok("two".to_string()),
+            ]));
+            let items: Vec<_> = stream.into_inner().collect().await;
+            assert_eq!(items, [Ok("one".to_string()), Err(StreamingError::Failed)]);
+        });
+    }
+}
@@ -28,7 +28,7 @@ use axum::{
 };
 use axum_core::response::{IntoResponse, Response};
 use bytes::Bytes;
-use dioxus_core::{CapturedError, Result, use_hook};
+use dioxus_core::{CapturedError, Result, spawn_forever, use_drop, use_hook};
 use dioxus_fullstack_core::{HttpError, RequestError};
 use dioxus_hooks::{Resource, UseWaker, use_resource};
 use dioxus_hooks::{use_signal, use_waker};
@@ -63,6 +63,9 @@ use {
 ///
 /// This hook takes a function that returns a future which resolves to a `Websocket<In, Out, E>` -
 /// usually a server function.
+///
+/// The connection is closed with a normal close frame when the component is unmounted. If the connection
+/// is lost, call [`UseWebsocket::reconnect`] to open a new one.
 pub fn use_websocket<
     In: 'static,
     Out: 'static,
@@ -76,10 +79,26 @@ pub fn use_websocket<
     let mut status = use_signal(|| WebsocketState::Connecting);
     let status_read = use_hook(|| ReadSignal::new(status));
 
+    // Keep the latest socket outside of the signal system so it can still be closed while the
+    // component's hooks are being dropped
+    let latest = use_hook(|| Rc::new(std::cell::RefCell::new(None)));
+    use_drop({
+        let latest = latest.clone();
+        move || {
+            if let Some(socket) = latest.borrow_mut().take() {
+                spawn_forever(async move {
+                    _ = Websocket::close(&socket).await;
+                });
+            }
+        }
+    });
+
     let connection = use_resource(move || {
         let connection = connect_to_websocket().map_err(|e| e.into());
+        let latest = latest.clone();
         async move {
-            let connection = connection.await;
+            let connection = connection.await.map(Rc::new);
+            *latest.borrow_mut() = connection.as_ref().ok().cloned();
 
             // Update the status based on the result of the connection attempt
             match connection.as_ref() {
@@ -90,9 +109,9 @@ pub fn use_websocket<
             // Wake up the `.recv()` calls waiting for the connection to be established
             waker.wake(());
 
-            // Wrap in Rc so we can clone it out of the Resource without holding
+            // The socket is wrapped in an Rc so we can clone it out of the Resource without holding
             // a borrow guard across await points
-            connection.map(Rc::new)
+            connection
         }
     });
 
@@ -240,6 +259,31 @@ impl<In, Out, E> UseWebsocket<In, Out, E> {
         }
     }
 
+    /// Close the current connection and open a new one by calling the connection function again.
+    ///
+    /// Any pending `.recv()` calls return an error so their loops can pick up the new connection.
+    pub fn reconnect(&mut self) {
+        if let Ok(ws) = self.get_connection() {
+            spawn_forever(async move {
+                _ = ws.close().await;
+            });
+        }
+
+        self.status.set(WebsocketState::Connecting);
+        self.connection.restart();
+        self.waker.wake(());
+    }
+
+    /// Close the connection with a normal close frame.
+    pub async fn close(&self) -> Result<(), WebsocketError> {
+        let ws = self.get_connection()?;
+        let mut status = self.status;
+        status.set(WebsocketState::Closing);
+        let result = ws.close().await;
+        self.received_shutdown();
+        result
+    }
+
     /// Set the WebSocket connection.
     ///
     /// This method takes a `Result<Websocket<In, Out, E>, Err>`, allowing you to drive the connection
@@ -457,6 +501,15 @@ impl<I, O, E> Websocket<I, O, E> {
         unimplemented!("Non web wasm32 clients are not supported yet")
     }
 
+    /// Close the connection with a normal close frame.
+    pub async fn close(&self) -> Result<(), WebsocketError> {
+        self.send_raw(Message::Close {
+            code: CloseCode::Normal,
+            reason: String::new(),
+        })
+        .await
+    }
+
     pub fn protocol(&self) -> Option<&str> {
         self.protocol.as_deref()
     }
@@ -7,7 +7,7 @@ use axum::response::IntoResponse;
 use axum::{Json, response::Html};
 use bytes::Bytes;
 use dioxus::prelude::*;
-use dioxus_fullstack::{FileStream, ServerFnError, Text, TextStream, Websocket, get};
+use dioxus_fullstack::{FileStream, JsonStream, ServerFnError, Text, TextStream, Websocket, get};
 use futures::StreamExt;
 use http::HeaderMap;
 use http::StatusCode;
@@ -72,6 +72,16 @@ mod simple_extractors {
         ])))
     }
 
+    /// Streams of results end at the first error
+    #[get("/hello")]
+    async fn six_4() -> Result<JsonStream<i32>> {
+        Ok(JsonStream::try_new(futures::stream::iter(vec![
+            Ok(1),
+            Ok(2),
+            Err(ServerFnError::new("the log was rotated")),
+        ])))
+    }
+
     /// We can return a Result with anything that implements IntoResponse
     #[get("/hello")]
     async fn seven() -> Result<Bytes> {
@@ -336,6 +346,18 @@ mod input_types {
         Ok(())
     }
 
+    /// Server extensions can also be marked inline on the arguments
+    #[post("/")]
+    async fn four_inline(#[extract] headers: HeaderMap, name: String) -> Result<()> {
+        _ = headers;
+        Ok(())
+    }
+
+    /// Inline extractors are not part of the client-side signature
+    async fn call_four_inline() -> Result<()> {
+        four_inline("name".to_string()).await
+    }
+
     /// We can even accept string in the final position.
     #[post("/")]
     async fn five(age: u32, name: String) -> Result<()> {
@@ -0,0 +1,116 @@
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+};
+
+/// A cancellation token passed to the future of [`crate::use_resource_with_abort`].
+///
+/// The signal is aborted when the resource is restarted or the component that owns it is dropped before the future
+/// finishes. Awaiting the signal resolves once it is aborted, which lets you race it against work that isn't
+/// cancelled by simply dropping the future, like a request running in another task or a browser `fetch`.
+///
+/// If the future finishes normally, the signal is never aborted.
+#[derive(Clone, Default)]
+pub struct AbortSignal {
+    inner: Rc<AbortInner>,
+}
+
+#[derive(Default)]
+struct AbortInner {
+    aborted: Cell<bool>,
+    wakers: RefCell<Vec<Waker>>,
+    callbacks: RefCell<Vec<Box<dyn FnOnce()>>>,
+}
+
+impl AbortSignal {
+    /// Create a new signal and the guard that aborts it when dropped
+    pub(crate) fn new() -> (Self, AbortGuard) {
+        let signal = Self::default();
+        let guard = AbortGuard {
+            signal: Some(signal.clone()),
+        };
+        (signal, guard)
+    }
+
+    /// Check if the signal has been aborted
+    pub fn aborted(&self) -> bool {
+        self.inner.aborted.get()
+    }
+
+    /// Run a callback when the signal is aborted. If the signal is already aborted, the callback runs immediately.
+    pub fn on_abort(&self, callback: impl FnOnce() + 'static) {
+        match self.aborted() {
+            true => callback(),
+            false => self.inner.callbacks.borrow_mut().push(Box::new(callback)),
+        }
+    }
+
+    /// Create a [`web_sys::AbortSignal`] that is aborted along with this signal. Pass it to `fetch` to cancel the
+    /// request when the resource restarts.
+    #[cfg(target_arch = "wasm32")]
+    pub fn web_signal(&self) -> web_sys::AbortSignal {
+        let controller = web_sys::AbortController::new().expect("AbortController is supported");
+        let signal = controller.signal();
+        self.on_abort(move || controller.abort());
+        signal
+    }
+
+    fn abort(&self) {
+        if self.inner.aborted.replace(true) {
+            return;
+        }
+        let callbacks = std::mem::take(&mut *self.inner.callbacks.borrow_mut());
+        for callback in callbacks {
+            callback();
+        }
+        for waker in self.inner.wakers.borrow_mut().drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+impl Future for AbortSignal {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.aborted() {
+            return Poll::Ready(());
+        }
+        let mut wakers = self.inner.wakers.borrow_mut();
+        if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl std::fmt::Debug for AbortSignal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AbortSignal")
+            .field("aborted", &self.aborted())
+            .finish()
+    }
+}
+
+/// Aborts the signal when dropped unless the work finished first
+pub(crate) struct AbortGuard {
+    signal: Option<AbortSignal>,
+}
+
+impl AbortGuard {
+    /// Mark the work as finished so the signal is never aborted
+    pub(crate) fn finish(mut self) {
+        self.signal.take();
+    }
+}
+
+impl Drop for AbortGuard {
+    fn drop(&mut self) {
+        if let Some(signal) = self.signal.take() {
+            signal.abort();
+        }
+    }
+}
@@ -54,6 +54,11 @@ macro_rules! to_owned {
     };
 }
 
+mod timer;
+
+mod abort_signal;
+pub use abort_signal::*;
+
 mod use_callback;
 pub use use_callback::*;
 
@@ -63,6 +68,9 @@ pub use use_on_destroy::*;
 mod use_context;
 pub use use_context::*;
 
+mod use_custom_event;
+pub use use_custom_event::*;
+
 mod use_coroutine;
 pub use use_coroutine::*;
 
@@ -78,12 +86,27 @@ pub use use_reactive::*;
 mod use_resource;
 pub use use_resource::*;
 
+mod use_query_client;
+pub use use_query_client::*;
+
 mod use_effect;
 pub use use_effect::*;
 
 mod use_memo;
 pub use use_memo::*;
 
+mod use_selector;
+pub use use_selector::*;
+
+mod use_memo_async;
+pub use use_memo_async::*;
+
+mod use_reducer;
+pub use use_reducer::*;
+
+mod use_history;
+pub use use_history::*;
+
 mod use_root_context;
 pub use use_root_context::*;
 
@@ -93,6 +116,9 @@ pub use use_hook_did_run::*;
 mod use_signal;
 pub use use_signal::*;
 
+mod use_signal_map;
+pub use use_signal_map::*;
+
 mod use_set_compare;
 pub use use_set_compare::*;
 
@@ -104,3 +130,24 @@ pub use use_action::*;
 
 mod use_waker;
 pub use use_waker::*;
+
+mod use_debounce;
+pub use use_debounce::*;
+
+mod use_throttle;
+pub use use_throttle::*;
+
+mod use_interval;
+pub use use_interval::*;
+
+mod use_timeout;
+pub use use_timeout::*;
+
+mod use_local_storage;
+pub use use_local_storage::*;
+
+mod use_persistent_signal;
+pub use use_persistent_signal::*;
+
+mod use_transition;
+pub use use_transition::*;
@@ -0,0 +1,18 @@
+//! A small platform agnostic timer used by the hooks that need to wait for a duration.
+//!
+//! On the web this uses the browser's `setTimeout` through gloo-timers. Everywhere else it uses
+//! tokio's timer which is available inside of every renderer's runtime.
+
+use std::time::Duration;
+
+/// Wait for the given duration before resolving
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await
+}
+
+/// Wait for the given duration before resolving
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
@@ -1,6 +1,6 @@
-use crate::{use_callback, use_signal};
-use dioxus_core::{Callback, CapturedError, Result, Task, use_hook};
-use dioxus_signals::{ReadSignal, ReadableBoxExt, ReadableExt, Signal, WritableExt};
+use crate::{QueryClient, use_callback, use_signal};
+use dioxus_core::{Callback, CapturedError, Result, Task, try_consume_context, use_hook};
+use dioxus_signals::{CopyValue, ReadSignal, ReadableBoxExt, ReadableExt, Signal, WritableExt};
 use futures_channel::oneshot::Receiver;
 use futures_util::{FutureExt, future::Shared};
 use std::{marker::PhantomData, pin::Pin, prelude::rust_2024::Future, task::Poll};
@@ -60,6 +60,39 @@ use std::{marker::PhantomData, pin::Pin, prelude::rust_2024::Future, task::Poll}
 /// save.reset();  // same — cancel and clear the value
 /// ```
 ///
+/// If earlier calls should finish instead, for example when every submit needs to reach the server, turn off
+/// cancellation with [`Action::set_cancel_previous`]. Only the result of the most recent call is kept either way.
+///
+/// ## Optimistic updates
+///
+/// Call [`Action::optimistic`] before dispatching to update local state right away. If the call fails or is cancelled,
+/// the update is undone with the inverse you pass alongside it, so changes made to the state while the call was in
+/// flight are kept. Use [`Action::set_invalidates`] to restart resources created with
+/// [`use_resource_keyed`](crate::use_resource_keyed) when a call succeeds:
+///
+/// ```rust, ignore
+/// let mut todos = use_signal(Vec::new);
+/// let mut add_todo = use_action(save_todo);
+/// add_todo.set_invalidates(["todos"]);
+///
+/// rsx! {
+///     button {
+///         onclick: move |_| {
+///             let todo = Todo::new("Buy milk");
+///             let id = todo.id;
+///             add_todo
+///                 .optimistic(
+///                     todos,
+///                     |todos| todos.push(todo.clone()),
+///                     move |todos| todos.retain(|todo| todo.id != id),
+///                 )
+///                 .call(todo);
+///         },
+///         "Add"
+///     }
+/// }
+/// ```
+///
 /// ## When to use `use_action` vs `use_resource`
 ///
 /// | | `use_action` | `use_resource` |
@@ -67,6 +100,9 @@ use std::{marker::PhantomData, pin::Pin, prelude::rust_2024::Future, task::Poll}
 /// | **Runs** | When you call it | Automatically on mount / dependency change |
 /// | **Good for** | Mutations, form submits, button clicks | Loading data to display |
 /// | **Cancellation** | Auto-cancels previous call | Restarts on dependency change |
+#[doc(alias = "use_async_callback")]
+#[doc(alias = "use_server_action")]
+#[doc(alias = "use_mutation")]
 pub fn use_action<E, C, M>(mut user_fn: C) -> Action<C::Input, C::Output>
 where
     E: Into<CapturedError> + 'static,
@@ -80,12 +116,25 @@ where
     let mut error = use_signal(|| None as Option<CapturedError>);
     let mut task = use_signal(|| None as Option<Task>);
     let mut state = use_signal(|| ActionState::Unset);
+    let cancel_previous = use_hook(|| CopyValue::new(true));
+    let mut latest_call = use_hook(|| CopyValue::new(0usize));
+    let mut rollback = use_hook(|| CopyValue::new(None::<Rollback>));
+    let invalidates = use_hook(|| CopyValue::new(Vec::<String>::new()));
     let callback = use_callback(move |input: C::Input| {
         // Cancel any existing task
-        if let Some(task) = task.take() {
+        if *cancel_previous.peek()
+            && let Some(task) = task.take()
+        {
             task.cancel();
         }
 
+        let call = *latest_call.peek() + 1;
+        latest_call.set(call);
+
+        // Take the optimistic update that was applied for this call, if there was one. It is undone when the task is
+        // dropped unless the call succeeds, so cancelled calls are rolled back too
+        let mut rollback = rollback.take();
+
         let (tx, rx) = futures_channel::oneshot::channel();
         let rx = rx.shared();
 
@@ -97,11 +146,31 @@ where
 
             // Create a new task
             let result = result.await;
+
+            // Undo the optimistic update even if a newer call replaced this one. The server never applied it
+            if let Some(rollback) = rollback.take() {
+                match result.is_ok() {
+                    true => rollback.keep(),
+                    false => drop(rollback),
+                }
+            }
+
+            // A newer call was made while this one was running, so its result replaces ours
+            if *latest_call.peek() != call {
+                tx.send(()).ok();
+                return;
+            }
+
             match result {
                 Ok(res) => {
                     error.set(None);
                     value.set(Some(res));
                     state.set(ActionState::Ready);
+                    if let Some(client) = try_consume_context::<QueryClient>() {
+                        for key in invalidates.read().iter() {
+                            client.invalidate(key);
+                        }
+                    }
                 }
                 Err(err) => {
                     error.set(Some(err.into()));
@@ -130,6 +199,9 @@ where
         reader,
         _phantom: PhantomData,
         state,
+        cancel_previous,
+        rollback,
+        invalidates,
     }
 }
 
@@ -145,9 +217,30 @@ pub struct Action<I, T: 'static> {
     task: Signal<Option<Task>>,
     callback: Callback<I, Shared<Receiver<()>>>,
     state: Signal<ActionState>,
+    cancel_previous: CopyValue<bool>,
+    rollback: CopyValue<Option<Rollback>>,
+    invalidates: CopyValue<Vec<String>>,
     _phantom: PhantomData<*const I>,
 }
 
+/// An optimistic update that is undone when it is dropped, unless the call it was applied for succeeds
+struct Rollback(Option<Box<dyn FnOnce()>>);
+
+impl Rollback {
+    /// Keep the optimistic update because the call succeeded
+    fn keep(mut self) {
+        self.0.take();
+    }
+}
+
+impl Drop for Rollback {
+    fn drop(&mut self) {
+        if let Some(revert) = self.0.take() {
+            revert();
+        }
+    }
+}
+
 /// The internal state of an action
 ///
 /// We can never reset the state to Unset, only to Reset, otherwise the value reader would panic.
@@ -199,6 +292,44 @@ impl<I: 'static, O: 'static> Action<I, O> {
         }
     }
 
+    /// Choose whether calling the action while a previous call is pending cancels the previous call. Defaults to `true`.
+    ///
+    /// When this is `false`, earlier calls keep running until they finish, but only the result of the most recent call
+    /// is stored. [`Action::cancel`] and [`Action::reset`] only cancel the most recent call.
+    pub fn set_cancel_previous(&mut self, cancel_previous: bool) {
+        self.cancel_previous.set(cancel_previous);
+    }
+
+    /// Apply an optimistic update to `state` before the next call.
+    ///
+    /// `update` runs immediately. If the next call to the action fails or is cancelled before it finishes, `revert`
+    /// runs to undo the update. `revert` should only undo what `update` changed, like removing the item that was
+    /// added, so other changes made to `state` while the call was in flight are kept. Call this right before
+    /// dispatching the action.
+    pub fn optimistic<S: 'static>(
+        &mut self,
+        mut state: Signal<S>,
+        update: impl FnOnce(&mut S),
+        revert: impl FnOnce(&mut S) + 'static,
+    ) -> &mut Self {
+        update(&mut state.write());
+        let revert = move || {
+            // The state may already be dropped if the call was cancelled because the component was unmounted
+            if let Ok(mut state) = state.try_write() {
+                revert(&mut state);
+            }
+        };
+        self.rollback.set(Some(Rollback(Some(Box::new(revert)))));
+        self
+    }
+
+    /// Set the keys in the [`QueryClient`] to invalidate every time a call succeeds. Resources created with
+    /// [`use_resource_keyed`](crate::use_resource_keyed) under those keys restart to fetch the new data.
+    pub fn set_invalidates(&mut self, keys: impl IntoIterator<Item = impl ToString>) {
+        self.invalidates
+            .set(keys.into_iter().map(|key| key.to_string()).collect());
+    }
+
     /// Cancel the in-flight task without clearing the previous result's state.
     pub fn cancel(&mut self) {
         if let Some(t) = self.task.take() {
@@ -1,4 +1,6 @@
+use crate::use_memo;
 use dioxus_core::{consume_context, provide_context, try_consume_context, use_hook};
+use dioxus_signals::{ReadSignal, ReadableExt, Signal};
 
 /// Try to consume some context in the tree, returning `None` if it is not found.
 ///
@@ -48,6 +50,51 @@ pub fn use_context<T: 'static + Clone>() -> T {
     use_hook(|| consume_context::<T>())
 }
 
+/// Consume a [`Signal<T>`] from context and subscribe to only part of it, panicking if it is not found.
+///
+/// The selector reruns whenever the context signal changes, but the component only reruns when the
+/// selected value is different from the previous one (by [`PartialEq`]). This avoids rerendering every
+/// consumer of a large shared state when an unrelated field changes.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, PartialEq)]
+/// struct AppState {
+///     user: String,
+///     count: i32,
+/// }
+///
+/// fn app() -> Element {
+///     let mut state = use_context_provider(|| {
+///         Signal::new(AppState {
+///             user: "dioxus".to_string(),
+///             count: 0,
+///         })
+///     });
+///     rsx! {
+///         button { onclick: move |_| state.write().count += 1, "increment" }
+///         User {}
+///     }
+/// }
+///
+/// #[component]
+/// fn User() -> Element {
+///     // This component will not rerun when the count changes
+///     let user = use_context_selector(|state: &AppState| state.user.clone());
+///     rsx! { "{user}" }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[doc = include_str!("../docs/moving_state_around.md")]
+#[track_caller]
+#[must_use]
+pub fn use_context_selector<T: 'static, U: PartialEq + 'static>(
+    selector: impl Fn(&T) -> U + 'static,
+) -> ReadSignal<U> {
+    let signal = use_context::<Signal<T>>();
+    use_memo(move || selector(&signal.read())).into()
+}
+
 /// Provide some context via the tree and return a reference to it
 ///
 /// Once the context has been provided, it is immutable. Mutations should be done via interior mutability.
@@ -3,7 +3,12 @@ use dioxus_core::Task;
 use dioxus_core::{consume_context, use_hook};
 use dioxus_signals::*;
 pub use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender};
-use std::future::Future;
+use futures_util::StreamExt;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 /// Maintain a handle over a future that can be paused, resumed, and canceled.
 ///
@@ -75,20 +80,65 @@ where
     G: FnMut(UnboundedReceiver<M>) -> F + 'static,
     F: Future<Output = ()> + 'static,
 {
-    let mut tx_copy_value = use_hook(|| CopyValue::new(None));
+    // The channel lives as long as the component so messages survive restarts of the coroutine
+    let (tx, inbox) = use_hook(|| {
+        let (tx, rx) = futures_channel::mpsc::unbounded();
+        (CopyValue::new(Some(tx)), CopyValue::new(rx))
+    });
+    let cancelled = use_hook(|| CopyValue::new(false));
 
     let future = use_future(move || {
-        let (tx, rx) = futures_channel::mpsc::unbounded();
-        tx_copy_value.set(Some(tx));
-        init(rx)
+        let (run_tx, run_rx) = futures_channel::mpsc::unbounded();
+        ForwardMessages {
+            task: Box::pin(init(run_rx)),
+            inbox,
+            run_tx,
+        }
     });
 
     use_context_provider(|| Coroutine {
-        tx: tx_copy_value,
+        tx,
+        inbox,
+        cancelled,
         future,
     })
 }
 
+/// Runs one instance of a coroutine. Each run gets its own channel and messages are moved over from the shared inbox
+/// one at a time once the run has read everything it was given. When the run is dropped, only the message it was
+/// handed is lost and the rest stay in the inbox for the next run.
+struct ForwardMessages<M: 'static, F> {
+    task: Pin<Box<F>>,
+    inbox: CopyValue<UnboundedReceiver<M>>,
+    run_tx: UnboundedSender<M>,
+}
+
+impl<M, F: Future<Output = ()>> Future for ForwardMessages<M, F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if self.task.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(());
+            }
+            if !self.run_tx.is_empty() {
+                return Poll::Pending;
+            }
+            let Ok(mut inbox) = self.inbox.try_write_unchecked() else {
+                return Poll::Pending;
+            };
+            match inbox.poll_next_unpin(cx) {
+                // Hand the message to the task and poll it again so it can read the message
+                Poll::Ready(Some(msg)) => {
+                    drop(inbox);
+                    let _ = self.run_tx.unbounded_send(msg);
+                }
+                _ => return Poll::Pending,
+            }
+        }
+    }
+}
+
 /// Get a handle to a coroutine higher in the tree
 /// Analogous to use_context_provider and use_context,
 /// but used for coroutines specifically
@@ -101,6 +151,8 @@ pub fn use_coroutine_handle<M: 'static>() -> Coroutine<M> {
 
 pub struct Coroutine<T: 'static> {
     tx: CopyValue<Option<UnboundedSender<T>>>,
+    inbox: CopyValue<UnboundedReceiver<T>>,
+    cancelled: CopyValue<bool>,
     future: UseFuture,
 }
 
@@ -110,8 +162,13 @@ impl<T> Coroutine<T> {
         self.future.task()
     }
 
-    /// Send a message to the coroutine
+    /// Send a message to the coroutine.
+    ///
+    /// If the coroutine was stopped with [`Coroutine::cancel`], the message is silently dropped.
     pub fn send(&self, msg: T) {
+        if *self.cancelled.peek() {
+            return;
+        }
         let _ = self.tx.read().as_ref().unwrap().unbounded_send(msg);
     }
 
@@ -119,10 +176,38 @@ impl<T> Coroutine<T> {
         self.tx.read().as_ref().unwrap().clone()
     }
 
-    /// Restart this coroutine
+    /// Restart this coroutine.
+    ///
+    /// The current task is dropped along with any state it was holding and a fresh task is spawned with the same
+    /// channel. Messages that the old task had not received yet are delivered to the new task. This is useful for
+    /// reconnecting event loops like a websocket client.
     pub fn restart(&mut self) {
+        if self.cancelled.replace(false) {
+            self.drain();
+        }
         self.future.restart();
     }
+
+    /// Stop the coroutine by dropping the current task.
+    ///
+    /// Messages sent after the coroutine is cancelled are silently dropped. Call [`Coroutine::restart`] to start
+    /// it again with an empty channel.
+    pub fn cancel(&mut self) {
+        self.cancelled.set(true);
+        self.future.cancel();
+    }
+
+    /// Check if the coroutine was stopped with [`Coroutine::cancel`]
+    pub fn cancelled(&self) -> bool {
+        *self.cancelled.peek()
+    }
+
+    /// Drop any messages left in the channel, including messages sent with a cloned [`Coroutine::tx`] while the
+    /// coroutine was cancelled
+    fn drain(&mut self) {
+        let mut inbox = self.inbox.write();
+        while inbox.try_recv().is_ok() {}
+    }
 }
 
 // manual impl since deriving doesn't work with generics
@@ -0,0 +1,124 @@
+use crate::use_callback;
+use dioxus_core::{
+    Callback, Event, ScopeId, consume_context_from_scope, parent_scope, provide_context, use_hook,
+};
+use std::{marker::PhantomData, rc::Rc};
+
+/// A custom event emitted with [`use_custom_event`]. Call [`Event::stop_propagation`] to keep it from bubbling to
+/// handlers further up the tree.
+pub type CustomEvent<T> = Event<T>;
+
+/// The handler a component registered for custom events of type `T`
+#[derive(Clone)]
+struct CustomEventListener<T: 'static> {
+    callback: Callback<CustomEvent<T>>,
+    /// The parent of the component that registered the handler. Bubbling continues from here.
+    parent: Option<ScopeId>,
+}
+
+/// Handle custom events of type `T` emitted by any component below this one.
+///
+/// Events bubble from the component that emitted them up through its ancestors. Every ancestor with a handler for
+/// the same type is called in order, until a handler calls [`Event::stop_propagation`]. Each component can have one
+/// handler per event type. Registering another handler for the same type in the same component replaces it.
+///
+/// See [`use_custom_event`] for an example.
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_custom_event_handler<T: 'static>(handler: impl FnMut(CustomEvent<T>) + 'static) {
+    let callback = use_callback(handler);
+    use_hook(|| {
+        provide_context(CustomEventListener {
+            callback,
+            parent: parent_scope(),
+        })
+    });
+}
+
+/// Create an emitter that sends custom events of type `T` to the closest ancestors that handle them with
+/// [`use_custom_event_handler`].
+///
+/// This lets deeply nested components notify their ancestors without passing callbacks through every level in
+/// between.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Color(u8, u8, u8);
+///
+/// fn App() -> Element {
+///     let mut color = use_signal(|| Color(0, 0, 0));
+///     use_custom_event_handler(move |event: CustomEvent<Color>| color.set(*event.data()));
+///
+///     rsx! {
+///         "Selected {color:?}"
+///         Toolbar {}
+///     }
+/// }
+///
+/// #[component]
+/// fn Toolbar() -> Element {
+///     // Toolbar doesn't need to know about colors
+///     rsx! { ColorPicker {} }
+/// }
+///
+/// #[component]
+/// fn ColorPicker() -> Element {
+///     let emit = use_custom_event::<Color>();
+///
+///     rsx! {
+///         button { onclick: move |_| { emit.emit(Color(255, 0, 0)); }, "Red" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_custom_event<T: 'static>() -> CustomEventEmitter<T> {
+    use_hook(|| CustomEventEmitter {
+        origin: parent_scope(),
+        _marker: PhantomData,
+    })
+}
+
+/// Emits custom events to ancestor components. Created with [`use_custom_event`].
+pub struct CustomEventEmitter<T: 'static> {
+    origin: Option<ScopeId>,
+    _marker: PhantomData<fn(T)>,
+}
+
+impl<T: 'static> CustomEventEmitter<T> {
+    /// Bubble an event up the tree, starting with the closest ancestor that handles events of type `T`.
+    ///
+    /// Returns `true` if at least one handler was called.
+    pub fn emit(&self, value: T) -> bool {
+        let event = CustomEvent::new(Rc::new(value), true);
+        let mut handled = false;
+        let mut scope = self.origin;
+
+        while let Some(id) = scope {
+            let Some(listener) = consume_context_from_scope::<CustomEventListener<T>>(id) else {
+                break;
+            };
+            listener.callback.call(event.clone());
+            handled = true;
+            if !event.propagates() {
+                break;
+            }
+            scope = listener.parent;
+        }
+
+        handled
+    }
+}
+
+impl<T: 'static> Clone for CustomEventEmitter<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for CustomEventEmitter<T> {}
+
+impl<T: 'static> PartialEq for CustomEventEmitter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{timer::sleep, use_signal};
+use dioxus_core::{Runtime, ScopeId, Subscribers, Task, current_scope_id, use_hook};
+use dioxus_signals::*;
+use std::{ops::Deref, time::Duration};
+
+/// A hook that debounces updates to a value. Calling [`Debounced::set`] schedules the value to be committed
+/// after `delay` has passed without any further calls to `set`.
+///
+/// Reading the [`Debounced`] handle returns the last committed value. The value that is waiting to be
+/// committed can be read with [`Debounced::pending`].
+///
+/// Any pending update is dropped when the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let mut search = use_debounce(Duration::from_millis(300), String::new());
+///
+///     // The resource only reruns once the user stops typing for 300ms
+///     let results = use_resource(move || async move {
+///         reqwest::get(format!("https://dog.ceo/api/breed/{search}/images"))
+///             .await?
+///             .text()
+///             .await
+///     });
+///
+///     rsx! {
+///         input {
+///             oninput: move |event| search.set(event.value()),
+///         }
+///         if search.pending().is_some() {
+///             "Waiting for you to stop typing..."
+///         }
+///         "{results:?}"
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_debounce<T: 'static>(delay: Duration, initial: T) -> Debounced<T> {
+    let mut initial = Some(initial);
+    let value = use_signal(|| initial.take().unwrap());
+    let pending = use_signal(|| None);
+    let task = use_hook(|| CopyValue::new(None));
+    let scope = use_hook(current_scope_id);
+    let mut delay_value = use_hook(|| CopyValue::new(delay));
+
+    // Keep the delay up to date if the component passes in a new value
+    if *delay_value.peek() != delay {
+        delay_value.set(delay);
+    }
+
+    Debounced {
+        value,
+        pending,
+        task,
+        scope,
+        delay: delay_value,
+    }
+}
+
+/// A debounced value created with [`use_debounce`].
+pub struct Debounced<T: 'static> {
+    value: Signal<T>,
+    pending: Signal<Option<T>>,
+    task: CopyValue<Option<Task>>,
+    scope: ScopeId,
+    delay: CopyValue<Duration>,
+}
+
+impl<T: 'static> Debounced<T> {
+    /// Schedule a new value to be committed once the delay has passed without any other calls to `set`.
+    ///
+    /// Calling this method again before the delay has passed replaces the pending value and restarts the timer.
+    pub fn set(&mut self, value: T) {
+        self.pending.set(Some(value));
+        self.cancel_timer();
+
+        let mut committed = self.value;
+        let mut pending = self.pending;
+        let delay = *self.delay.peek();
+        let task = Runtime::current().spawn(self.scope, async move {
+            sleep(delay).await;
+            if let Some(value) = pending.take() {
+                committed.set(value);
+            }
+        });
+        self.task.set(Some(task));
+    }
+
+    /// Get the value that is waiting to be committed, if any.
+    ///
+    /// Reading this will subscribe the current scope to changes in the pending value.
+    pub fn pending(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.pending.cloned()
+    }
+
+    /// Drop the pending value without committing it.
+    pub fn cancel(&mut self) {
+        self.cancel_timer();
+        self.pending.set(None);
+    }
+
+    /// Commit the pending value immediately instead of waiting for the delay.
+    pub fn flush(&mut self) {
+        self.cancel_timer();
+        if let Some(value) = self.pending.take() {
+            self.value.set(value);
+        }
+    }
+
+    fn cancel_timer(&mut self) {
+        if let Some(task) = self.task.write().take() {
+            task.cancel();
+        }
+    }
+}
+
+impl<T> Clone for Debounced<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Debounced<T> {}
+
+impl<T> PartialEq for Debounced<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.pending == other.pending
+    }
+}
+
+impl<T> From<Debounced<T>> for ReadSignal<T> {
+    fn from(val: Debounced<T>) -> Self {
+        val.value.into()
+    }
+}
+
+impl<T> Readable for Debounced<T> {
+    type Target = T;
+    type Storage = UnsyncStorage;
+
+    #[track_caller]
+    fn try_read_unchecked(
+        &self,
+    ) -> Result<ReadableRef<'static, Self>, generational_box::BorrowError> {
+        self.value.try_read_unchecked()
+    }
+
+    #[track_caller]
+    fn try_peek_unchecked(
+        &self,
+    ) -> Result<ReadableRef<'static, Self>, generational_box::BorrowError> {
+        self.value.try_peek_unchecked()
+    }
+
+    fn subscribers(&self) -> Subscribers {
+        self.value.subscribers()
+    }
+}
+
+/// Allow calling a debounced value with debounced() syntax
+///
+/// Currently only limited to copy types, though could probably specialize for string/arc/rc
+impl<T: Clone> Deref for Debounced<T> {
+    type Target = dyn Fn() -> T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { ReadableExt::deref_impl(self) }
+    }
+}
+
+impl<T: std::fmt::Display + 'static> std::fmt::Display for Debounced<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.with(|value| std::fmt::Display::fmt(value, f))
+    }
+}
+
+impl<T: std::fmt::Debug + 'static> std::fmt::Debug for Debounced<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.with(|value| std::fmt::Debug::fmt(value, f))
+    }
+}
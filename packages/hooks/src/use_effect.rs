@@ -50,6 +50,41 @@ pub fn use_effect(mut callback: impl FnMut() + 'static) -> Effect {
     })
 }
 
+/// Like [`use_effect`], but the effect returns the values it depends on and receives the values from the previous run.
+///
+/// The values are `None` the first time the effect runs. Just like [`use_effect`], only the signals read inside of the
+/// closure are tracked. The previous values are only borrowed for the duration of the call.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut scroll = use_signal(|| 0.0);
+///
+///     use_effect_with_prev(move |prev: Option<&f64>| {
+///         let current = scroll();
+///         if let Some(prev) = prev {
+///             println!("Animate from {prev} to {current}");
+///         }
+///         current
+///     });
+///
+///     rsx! {
+///         button { onclick: move |_| scroll += 100.0, "Scroll down" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_effect_with_prev<D: 'static>(
+    mut callback: impl FnMut(Option<&D>) -> D + 'static,
+) -> Effect {
+    let mut prev = None;
+    use_effect(move || {
+        let current = callback(prev.as_ref());
+        prev = Some(current);
+    })
+}
+
 /// A handle to an effect.
 #[derive(Clone, Copy)]
 pub struct Effect {
@@ -11,7 +11,16 @@ use std::ops::Deref;
 /// This future will **not** run on the server. To run a future on the server, you should use [`dioxus_core::spawn_isomorphic`] directly.
 ///
 ///
-/// `use_future` **won't return a value**. If you want to return a value from a future, use [`crate::use_resource()`] instead.
+/// ## `use_future` vs `use_resource`
+///
+/// `use_future` runs the future **exactly once** when the component is created. Reading signals inside the closure
+/// or the future does not subscribe to them, so the future never restarts on its own. Call [`UseFuture::restart`] to
+/// run it again. This makes it a good fit for initialization tasks and long running loops.
+///
+/// [`crate::use_resource()`] tracks the signals read in its closure and restarts the future when they change. Use it
+/// to load data that depends on other state.
+///
+/// The value the future returns is available with [`UseFuture::value`] after it finishes.
 ///
 /// ## Example
 ///
@@ -39,20 +48,42 @@ use std::ops::Deref;
 ///     }
 /// }
 /// ```
+///
+/// Reading the value of the future after it finishes:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # async fn load_config() -> String { String::new() }
+/// fn app() -> Element {
+///     let mut config = use_future(load_config);
+///
+///     rsx! {
+///         if let Some(loaded) = config.value().cloned() {
+///             "Loaded {loaded}"
+///         } else {
+///             "Loading..."
+///         }
+///         button { onclick: move |_| config.restart(), "Reload" }
+///     }
+/// }
+/// ```
 #[doc = include_str!("../docs/rules_of_hooks.md")]
 #[doc = include_str!("../docs/moving_state_around.md")]
 #[doc(alias = "use_async")]
-pub fn use_future<F>(mut future: impl FnMut() -> F + 'static) -> UseFuture
+pub fn use_future<F>(mut future: impl FnMut() -> F + 'static) -> UseFuture<F::Output>
 where
     F: Future + 'static,
+    F::Output: 'static,
 {
     let mut state = use_signal(|| UseFutureState::Pending);
+    let mut value = use_signal(|| None);
 
     let callback = use_callback(move |_| {
         let fut = future();
         dioxus_core::spawn(async move {
             state.set(UseFutureState::Pending);
-            fut.await;
+            let output = fut.await;
+            value.set(Some(output));
             state.set(UseFutureState::Ready);
         })
     });
@@ -74,17 +105,36 @@ where
     UseFuture {
         task,
         state,
+        value,
         callback,
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
-pub struct UseFuture {
+/// A handle to a future spawned with [`use_future`]. `T` is the value the future returns.
+pub struct UseFuture<T = ()> {
     task: CopyValue<Task>,
     state: Signal<UseFutureState>,
+    value: Signal<Option<T>>,
     callback: Callback<(), Task>,
 }
 
+impl<T: 'static> Clone for UseFuture<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for UseFuture<T> {}
+
+impl<T: 'static> PartialEq for UseFuture<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.task == other.task
+            && self.state == other.state
+            && self.value == other.value
+            && self.callback == other.callback
+    }
+}
+
 /// A signal that represents the state of a future
 // we might add more states (panicked, etc)
 #[derive(Clone, Copy, PartialEq, Hash, Eq, Debug)]
@@ -102,11 +152,10 @@ pub enum UseFutureState {
     Ready,
 }
 
-impl UseFuture {
-    /// Restart the future with new dependencies.
+impl<T: 'static> UseFuture<T> {
+    /// Cancel the current future and run the future again.
     ///
-    /// Will not cancel the previous future, but will ignore any values that it
-    /// generates.
+    /// The last value is kept until the new future finishes.
     pub fn restart(&mut self) {
         self.task.write().cancel();
         let new_task = self.callback.call(());
@@ -155,15 +204,20 @@ impl UseFuture {
     pub fn state(&self) -> ReadSignal<UseFutureState> {
         self.state.into()
     }
+
+    /// Get the value the future returned, or `None` if it hasn't finished yet.
+    pub fn value(&self) -> ReadSignal<Option<T>> {
+        self.value.into()
+    }
 }
 
-impl From<UseFuture> for ReadSignal<UseFutureState> {
-    fn from(val: UseFuture) -> Self {
+impl<T: 'static> From<UseFuture<T>> for ReadSignal<UseFutureState> {
+    fn from(val: UseFuture<T>) -> Self {
         val.state.into()
     }
 }
 
-impl Readable for UseFuture {
+impl<T: 'static> Readable for UseFuture<T> {
     type Target = UseFutureState;
     type Storage = UnsyncStorage;
 
@@ -189,7 +243,7 @@ impl Readable for UseFuture {
 /// Allow calling a signal with signal() syntax
 ///
 /// Currently only limited to copy types, though could probably specialize for string/arc/rc
-impl Deref for UseFuture {
+impl<T: 'static> Deref for UseFuture<T> {
     type Target = dyn Fn() -> UseFutureState;
 
     fn deref(&self) -> &Self::Target {
@@ -0,0 +1,208 @@
+use dioxus_core::use_hook;
+use dioxus_signals::*;
+use std::{collections::VecDeque, time::Duration};
+use web_time::Instant;
+
+/// Options for the history created with [`use_history_with_options`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HistoryOptions {
+    /// The maximum number of states that can be undone. The oldest states are dropped once the history is full.
+    /// Defaults to 100
+    pub max_depth: usize,
+    /// Changes made less than this long after the previous change are merged into one undo step, so typing a word
+    /// is undone all at once instead of one keystroke at a time. Defaults to `None`, which makes every change its
+    /// own step
+    pub coalesce: Option<Duration>,
+}
+
+impl Default for HistoryOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 100,
+            coalesce: None,
+        }
+    }
+}
+
+/// A hook that keeps track of the past values of some state so changes can be undone and redone.
+///
+/// [`HistoryHandle::set`] replaces the current value and remembers the old one. [`HistoryHandle::undo`] and
+/// [`HistoryHandle::redo`] move back and forward through the remembered values. The current value is exposed as a
+/// signal with [`HistoryHandle::current`], so components that read it rerun when it changes.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut text = use_history(String::new);
+///
+///     rsx! {
+///         input {
+///             value: "{text.current()}",
+///             oninput: move |event| text.set(event.value()),
+///         }
+///         button { disabled: !text.can_undo(), onclick: move |_| _ = text.undo(), "Undo" }
+///         button { disabled: !text.can_redo(), onclick: move |_| _ = text.redo(), "Redo" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_history<T: 'static>(initial: impl FnOnce() -> T) -> HistoryHandle<T> {
+    use_history_with_options(initial, HistoryOptions::default())
+}
+
+/// Like [`use_history`], but with options to limit the number of undo steps and merge rapid changes into one step.
+/// The options are captured the first time the hook runs.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     // Undo everything typed within half a second at once
+///     let mut text = use_history_with_options(
+///         String::new,
+///         HistoryOptions {
+///             max_depth: 50,
+///             coalesce: Some(Duration::from_millis(500)),
+///         },
+///     );
+///
+///     rsx! {
+///         textarea {
+///             value: "{text.current()}",
+///             oninput: move |event| text.set(event.value()),
+///         }
+///         button { onclick: move |_| _ = text.undo(), "Undo" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_history_with_options<T: 'static>(
+    initial: impl FnOnce() -> T,
+    options: HistoryOptions,
+) -> HistoryHandle<T> {
+    use_hook(|| HistoryHandle {
+        current: Signal::new(initial()),
+        stacks: Signal::new(HistoryStacks {
+            past: VecDeque::new(),
+            future: Vec::new(),
+        }),
+        last_change: CopyValue::new(None),
+        options,
+    })
+}
+
+struct HistoryStacks<T> {
+    /// The states that can be undone, with the most recent state at the back
+    past: VecDeque<T>,
+    /// The states that can be redone, with the next state at the back
+    future: Vec<T>,
+}
+
+/// A value with undo and redo history, created with [`use_history`]
+pub struct HistoryHandle<T: 'static> {
+    current: Signal<T>,
+    stacks: Signal<HistoryStacks<T>>,
+    /// When the last change that the next change can be merged with was made
+    last_change: CopyValue<Option<Instant>>,
+    options: HistoryOptions,
+}
+
+impl<T: 'static> HistoryHandle<T> {
+    /// Get the current value as a signal
+    pub fn current(&self) -> ReadSignal<T> {
+        self.current.into()
+    }
+
+    /// Replace the current value and remember the old value so it can be undone. Anything that could be redone is
+    /// discarded.
+    pub fn set(&mut self, value: T) {
+        let now = Instant::now();
+        let mut stacks = self.stacks.write();
+        stacks.future.clear();
+        let old = std::mem::replace(&mut *self.current.write(), value);
+
+        let merge = match (self.options.coalesce, self.last_change.replace(Some(now))) {
+            (Some(window), Some(last_change)) => now.duration_since(last_change) < window,
+            _ => false,
+        };
+        // When merging, the state before the first change in the group is already in the history
+        if !merge {
+            stacks.past.push_back(old);
+            if stacks.past.len() > self.options.max_depth {
+                stacks.past.pop_front();
+            }
+        }
+    }
+
+    /// Go back to the previous value. Returns false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let mut stacks = self.stacks.write();
+        let Some(previous) = stacks.past.pop_back() else {
+            return false;
+        };
+        let current = std::mem::replace(&mut *self.current.write(), previous);
+        stacks.future.push(current);
+        self.last_change.set(None);
+        true
+    }
+
+    /// Go forward to the value that was last undone. Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let mut stacks = self.stacks.write();
+        let Some(next) = stacks.future.pop() else {
+            return false;
+        };
+        let current = std::mem::replace(&mut *self.current.write(), next);
+        stacks.past.push_back(current);
+        self.last_change.set(None);
+        true
+    }
+
+    /// Check if there is a value to undo.
+    ///
+    /// Reading this will subscribe the current scope to changes in the history.
+    pub fn can_undo(&self) -> bool {
+        !self.stacks.read().past.is_empty()
+    }
+
+    /// Check if there is a value to redo.
+    ///
+    /// Reading this will subscribe the current scope to changes in the history.
+    pub fn can_redo(&self) -> bool {
+        !self.stacks.read().future.is_empty()
+    }
+
+    /// End the current group of merged changes, so the next change is its own undo step even if it is made right
+    /// away. Call this when the user does something that should be undone separately, like moving the cursor.
+    pub fn checkpoint(&mut self) {
+        self.last_change.set(None);
+    }
+
+    /// Forget all past and future values while keeping the current value
+    pub fn clear(&mut self) {
+        let mut stacks = self.stacks.write();
+        stacks.past.clear();
+        stacks.future.clear();
+        self.last_change.set(None);
+    }
+}
+
+impl<T> Clone for HistoryHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for HistoryHandle<T> {}
+
+impl<T> PartialEq for HistoryHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.current == other.current
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{timer::sleep, use_callback};
+use dioxus_core::{Callback, Runtime, ScopeId, Task, current_scope_id, use_hook};
+use dioxus_signals::*;
+use std::time::Duration;
+
+/// A hook that calls `callback` every `period` until the component is unmounted.
+///
+/// The interval runs on the platform's timer: `setTimeout` on the web and tokio's timer everywhere else. The callback
+/// runs inside of the component's scope, so writing to signals from inside of it will rerender any components that read them.
+///
+/// The returned [`IntervalHandle`] can be used to pause, resume, or change the period of the interval. If the component
+/// is rendered with a different `period`, the interval will switch to the new period.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let mut seconds = use_signal(|| 0);
+///     let mut interval = use_interval(Duration::from_secs(1), move || seconds += 1);
+///
+///     rsx! {
+///         "Elapsed: {seconds}s"
+///         button {
+///             onclick: move |_| match interval.paused() {
+///                 true => interval.resume(),
+///                 false => interval.pause(),
+///             },
+///             "Toggle"
+///         }
+///         button {
+///             onclick: move |_| interval.set_period(Duration::from_millis(100)),
+///             "Faster!"
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_interval(period: Duration, callback: impl FnMut() + 'static) -> IntervalHandle {
+    let mut callback = callback;
+    let callback = use_callback(move |_| callback());
+
+    let mut handle = use_hook(|| {
+        let scope = current_scope_id();
+        IntervalHandle {
+            task: CopyValue::new(spawn_interval(scope, period, callback)),
+            period: CopyValue::new(period),
+            paused: Signal::new(false),
+            scope,
+            callback,
+        }
+    });
+
+    // Keep the period up to date if the component passes in a new value
+    if *handle.period.peek() != period {
+        handle.set_period(period);
+    }
+
+    handle
+}
+
+fn spawn_interval(scope: ScopeId, period: Duration, callback: Callback) -> Task {
+    Runtime::current().spawn(scope, async move {
+        loop {
+            sleep(period).await;
+            callback.call(());
+        }
+    })
+}
+
+/// A handle to an interval created with [`use_interval`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct IntervalHandle {
+    task: CopyValue<Task>,
+    period: CopyValue<Duration>,
+    paused: Signal<bool>,
+    scope: ScopeId,
+    callback: Callback,
+}
+
+impl IntervalHandle {
+    /// Stop calling the callback until [`IntervalHandle::resume`] is called
+    pub fn pause(&mut self) {
+        self.paused.set(true);
+        self.task.peek().pause();
+    }
+
+    /// Resume an interval that was paused with [`IntervalHandle::pause`]
+    pub fn resume(&mut self) {
+        self.paused.set(false);
+        self.task.peek().resume();
+    }
+
+    /// Check if the interval is paused.
+    ///
+    /// Reading this will subscribe the current scope to changes in the paused state.
+    pub fn paused(&self) -> bool {
+        self.paused.cloned()
+    }
+
+    /// Get the current period of the interval
+    pub fn period(&self) -> Duration {
+        *self.period.peek()
+    }
+
+    /// Change how often the callback is called. The next tick will happen `period` after this method is called.
+    pub fn set_period(&mut self, period: Duration) {
+        self.period.set(period);
+        self.task.peek().cancel();
+
+        let task = spawn_interval(self.scope, period, self.callback);
+        if *self.paused.peek() {
+            task.pause();
+        }
+        self.task.set(task);
+    }
+
+    /// Get a handle to the inner task backing this interval
+    pub fn task(&self) -> Task {
+        *self.task.peek()
+    }
+}
@@ -0,0 +1,276 @@
+//! A signal that is persisted to local storage.
+//!
+//! On the web this uses the browser's `localStorage`. Everywhere else the values are stored in a json
+//! file in the platform's local data directory so the same code works on every renderer.
+
+use crate::{use_effect, use_signal};
+use dioxus_core::use_hook;
+use dioxus_signals::*;
+use serde::{Serialize, de::DeserializeOwned};
+
+/// A hook that creates a signal which is persisted to local storage under `key`.
+///
+/// The signal starts with the value stored under `key`. If there is no stored value or it fails to
+/// deserialize, the signal starts with `default` instead. Every time the signal is written to, the new
+/// value is serialized as json and saved.
+///
+/// On the web, the signal also listens for changes to the same key from other tabs and updates when
+/// they write a new value.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut dark_mode = use_local_storage("dark_mode", || false);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| dark_mode.toggle(),
+///             if dark_mode() { "Use light mode" } else { "Use dark mode" }
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_local_storage<T>(key: &str, default: impl FnOnce() -> T) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let key = use_hook(|| key.to_string());
+    let signal = use_signal(|| {
+        backend::get_item(&key)
+            .and_then(|stored| match serde_json::from_str(&stored) {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    tracing::warn!("Failed to deserialize local storage key {key:?}: {err}");
+                    None
+                }
+            })
+            .unwrap_or_else(default)
+    });
+
+    // Save the value every time the signal changes
+    use_effect({
+        let key = key.clone();
+        move || match serde_json::to_string(&*signal.read()) {
+            Ok(serialized) => backend::set_item(&key, &serialized),
+            Err(err) => tracing::error!("Failed to serialize local storage key {key:?}: {err}"),
+        }
+    });
+
+    #[cfg(target_arch = "wasm32")]
+    use_hook(|| std::rc::Rc::new(backend::StorageListener::new(key, signal)));
+
+    signal
+}
+
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    use dioxus_signals::{Signal, WritableExt};
+    use serde::de::DeserializeOwned;
+    use wasm_bindgen::{JsCast, closure::Closure};
+    use web_sys::StorageEvent;
+
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok().flatten()
+    }
+
+    pub(super) fn get_item(key: &str) -> Option<String> {
+        storage()?.get_item(key).ok().flatten()
+    }
+
+    pub(super) fn set_item(key: &str, value: &str) {
+        if let Some(storage) = storage() {
+            if let Err(err) = storage.set_item(key, value) {
+                tracing::error!("Failed to write local storage key {key:?}: {err:?}");
+            }
+        }
+    }
+
+    /// Updates a signal when another tab changes the same key. The listener is removed when this is dropped.
+    pub(super) struct StorageListener {
+        closure: Closure<dyn FnMut(StorageEvent)>,
+    }
+
+    impl StorageListener {
+        pub(super) fn new<T: DeserializeOwned + 'static>(
+            key: String,
+            mut signal: Signal<T>,
+        ) -> Self {
+            let closure = Closure::<dyn FnMut(StorageEvent)>::new(move |event: StorageEvent| {
+                if event.key().as_deref() != Some(key.as_str()) {
+                    return;
+                }
+                let Some(new_value) = event.new_value() else {
+                    return;
+                };
+                match serde_json::from_str(&new_value) {
+                    Ok(value) => signal.set(value),
+                    Err(err) => {
+                        tracing::warn!("Failed to deserialize local storage key {key:?}: {err}")
+                    }
+                }
+            });
+
+            if let Some(window) = web_sys::window() {
+                _ = window
+                    .add_event_listener_with_callback("storage", closure.as_ref().unchecked_ref());
+            }
+
+            Self { closure }
+        }
+    }
+
+    impl Drop for StorageListener {
+        fn drop(&mut self) {
+            if let Some(window) = web_sys::window() {
+                _ = window.remove_event_listener_with_callback(
+                    "storage",
+                    self.closure.as_ref().unchecked_ref(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod backend {
+    use std::{collections::BTreeMap, path::PathBuf};
+
+    #[cfg(test)]
+    thread_local! {
+        /// A storage file for tests so they don't write to the real local data directory
+        pub(super) static TEST_STORAGE_PATH: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+    }
+
+    /// The storage file lives in a folder named after the current executable inside the local data directory
+    fn storage_path() -> Option<PathBuf> {
+        #[cfg(test)]
+        if let Some(path) = TEST_STORAGE_PATH.with(|path| path.borrow().clone()) {
+            return Some(path);
+        }
+
+        let exe = std::env::current_exe().ok()?;
+        let app_name = exe.file_stem()?.to_string_lossy().into_owned();
+        Some(
+            dirs::data_local_dir()?
+                .join(app_name)
+                .join("local_storage.json"),
+        )
+    }
+
+    fn read_all() -> BTreeMap<String, String> {
+        storage_path()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub(super) fn get_item(key: &str) -> Option<String> {
+        read_all().remove(key)
+    }
+
+    pub(super) fn set_item(key: &str, value: &str) {
+        let Some(path) = storage_path() else {
+            tracing::error!("Failed to find a local data directory to store {key:?}");
+            return;
+        };
+
+        let mut items = read_all();
+        if items.get(key).map(String::as_str) == Some(value) {
+            return;
+        }
+        items.insert(key.to_string(), value.to_string());
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| {
+                let contents = serde_json::to_string(&items).map_err(std::io::Error::other)?;
+                std::fs::write(&path, contents)
+            });
+        if let Err(err) = result {
+            tracing::error!("Failed to write local storage key {key:?} to {path:?}: {err}");
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use dioxus_core::{NoOpMutations, VNode, VirtualDom};
+    use std::{cell::Cell, path::PathBuf};
+
+    thread_local! {
+        static COUNT: Cell<Option<Signal<u32>>> = const { Cell::new(None) };
+    }
+
+    /// Store the values for this test in a fresh file
+    fn storage_file(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "dioxus-local-storage-{}-{test}",
+            std::process::id()
+        ));
+        _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("local_storage.json");
+        backend::TEST_STORAGE_PATH.with(|cell| *cell.borrow_mut() = Some(path.clone()));
+        path
+    }
+
+    /// Render a component with the `count` key and return the signal
+    fn render_count() -> (VirtualDom, Signal<u32>) {
+        let mut dom = VirtualDom::new(|| {
+            let count = use_local_storage("count", || 7);
+            COUNT.with(|cell| cell.set(Some(count)));
+            VNode::empty()
+        });
+        dom.rebuild_in_place();
+        dom.render_immediate(&mut NoOpMutations);
+        let count = COUNT.with(Cell::get).unwrap();
+        (dom, count)
+    }
+
+    #[test]
+    fn values_round_trip_through_the_storage_file() {
+        let path = storage_file("round_trip");
+
+        let (mut dom, mut count) = render_count();
+        assert_eq!(dom.in_runtime(|| *count.peek()), 7);
+        dom.in_runtime(|| count.set(5));
+        dom.render_immediate(&mut NoOpMutations);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, r#"{"count":"5"}"#);
+
+        // A new app starts with the stored value
+        drop(dom);
+        let (dom, count) = render_count();
+        assert_eq!(dom.in_runtime(|| *count.peek()), 5);
+    }
+
+    #[test]
+    fn corrupt_values_fall_back_to_the_default() {
+        let path = storage_file("corrupt");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, r#"{"count":"not a number","other":"1"}"#).unwrap();
+
+        let (dom, count) = render_count();
+        assert_eq!(dom.in_runtime(|| *count.peek()), 7);
+
+        // The default replaces the corrupt value without touching the other keys
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, r#"{"count":"7","other":"1"}"#);
+    }
+
+    #[test]
+    fn corrupt_storage_files_fall_back_to_the_default() {
+        let path = storage_file("corrupt_file");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not json").unwrap();
+
+        let (dom, count) = render_count();
+        assert_eq!(dom.in_runtime(|| *count.peek()), 7);
+    }
+}
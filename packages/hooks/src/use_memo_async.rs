@@ -0,0 +1,119 @@
+use crate::{Resource, UseResourceState, use_resource, use_signal};
+use dioxus_signals::*;
+use std::future::Future;
+
+/// A hook that reruns an async computation when its dependencies change while keeping the last
+/// successful value around until the new one resolves.
+///
+/// [`use_resource`] stores the latest output of the future, so a failed rerun replaces the previous
+/// value. `use_memo_async` instead keeps the last `Ok` value in [`AsyncMemo::value`] and stores the
+/// latest failure in [`AsyncMemo::error`]. The error is cleared once the computation succeeds again.
+/// This lets you keep showing stale data (stale-while-revalidate) instead of flashing a loading state.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn BreedPic(breed: ReadSignal<String>) -> Element {
+///     let pic = use_memo_async(move || async move {
+///         reqwest::get(format!("https://dog.ceo/api/breed/{breed}/images/random"))
+///             .await?
+///             .text()
+///             .await
+///     });
+///
+///     rsx! {
+///         // The previous image stays visible while the next one loads
+///         if let Some(url) = pic.value().cloned() {
+///             img { src: url, opacity: if pic.is_revalidating() { "0.5" } else { "1" } }
+///         }
+///         if let Some(err) = pic.error().read().as_ref() {
+///             "Failed to load the image: {err}"
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[doc = include_str!("../docs/moving_state_around.md")]
+#[track_caller]
+pub fn use_memo_async<T, E, F>(mut future: impl FnMut() -> F + 'static) -> AsyncMemo<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Future<Output = Result<T, E>> + 'static,
+{
+    let mut value = use_signal(|| None);
+    let mut error = use_signal(|| None);
+
+    let resource = use_resource(move || {
+        let fut = future();
+        async move {
+            match fut.await {
+                Ok(new_value) => {
+                    value.set(Some(new_value));
+                    if error.peek().is_some() {
+                        error.set(None);
+                    }
+                }
+                Err(err) => error.set(Some(err)),
+            }
+        }
+    });
+
+    AsyncMemo {
+        value,
+        error,
+        resource,
+    }
+}
+
+/// A handle to an async computation created with [`use_memo_async`]
+pub struct AsyncMemo<T: 'static, E: 'static> {
+    value: Signal<Option<T>>,
+    error: Signal<Option<E>>,
+    resource: Resource<()>,
+}
+
+impl<T, E> AsyncMemo<T, E> {
+    /// Get the last value the computation resolved to successfully. This is `None` until the first run succeeds.
+    pub fn value(&self) -> ReadSignal<Option<T>> {
+        self.value.into()
+    }
+
+    /// Get the error from the latest run of the computation, if it failed.
+    pub fn error(&self) -> ReadSignal<Option<E>> {
+        self.error.into()
+    }
+
+    /// Check if the computation is currently running. This is also `true` during the first run.
+    ///
+    /// Reading this will subscribe the current scope to changes in the state of the computation.
+    pub fn is_revalidating(&self) -> bool {
+        *self.resource.state().read() == UseResourceState::Pending
+    }
+
+    /// Rerun the computation while keeping the current value.
+    pub fn restart(&mut self) {
+        self.resource.restart();
+    }
+
+    /// Get the resource that drives this computation
+    pub fn resource(&self) -> Resource<()> {
+        self.resource
+    }
+}
+
+impl<T, E> Clone for AsyncMemo<T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for AsyncMemo<T, E> {}
+
+impl<T, E> PartialEq for AsyncMemo<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.error == other.error && self.resource == other.resource
+    }
+}
@@ -1,4 +1,4 @@
-use dioxus_core::use_drop;
+pub use dioxus_core::use_drop;
 
 #[deprecated(note = "Use `use_drop` instead, which has the same functionality.")]
 pub fn use_on_unmount<D: FnOnce() + 'static>(destroy: D) {
@@ -0,0 +1,234 @@
+use crate::use_signal;
+use dioxus_signals::Signal;
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Creates a signal that keeps its value when the component is hot-patched during development.
+///
+/// Hot-patching a component with `dx serve --hotpatch` remounts it, which normally resets all of its hooks. A
+/// persistent signal saves its value as json to a side channel every time it changes, and restores the saved value
+/// when the component is mounted again after a patch. If there is no saved value or it fails to deserialize (for
+/// example because you changed the type of the signal), the signal starts with `init` instead.
+///
+/// Saved values are matched to hooks by the file the hook is called from, the type of the value, and the order the
+/// mounted hooks were created in. Values of hooks that are unmounted without a patch are discarded. Adding or removing
+/// other persistent signals of the same type in the same file may restore a value into the wrong signal once.
+///
+/// This only changes the behavior of debug builds. In release builds, this is the same as [`use_signal`].
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Editor() -> Element {
+///     // Keep the text across hot-patches while tweaking the rest of the component
+///     let mut text = use_persistent_signal(String::new);
+///
+///     rsx! {
+///         textarea { value: "{text}", oninput: move |event| text.set(event.value()) }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+#[must_use]
+pub fn use_persistent_signal<T>(init: impl FnOnce() -> T) -> Signal<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    #[cfg(debug_assertions)]
+    {
+        use dioxus_core::use_hook_with_cleanup;
+        use dioxus_signals::ReadableExt;
+
+        let file = std::panic::Location::caller().file();
+        let slot = use_hook_with_cleanup(|| side_channel::Slot::new::<T>(file), |slot| slot.free());
+        let signal = use_signal(|| slot.restore().unwrap_or_else(init));
+
+        // Save the value every time the signal changes so it can be restored after the next patch
+        crate::use_effect(move || slot.save(&*signal.read()));
+
+        signal
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        use_signal(init)
+    }
+}
+
+#[cfg(debug_assertions)]
+mod side_channel {
+    use serde::{Serialize, de::DeserializeOwned};
+    use std::{
+        cell::RefCell,
+        collections::{BTreeMap, HashMap, VecDeque},
+        sync::{
+            Arc, Once,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    /// The number of patches that were applied to the process
+    static PATCHES: AtomicUsize = AtomicUsize::new(0);
+
+    thread_local! {
+        static SIDE_CHANNEL: RefCell<SideChannel> = RefCell::new(SideChannel::default());
+    }
+
+    #[derive(Default)]
+    struct SideChannel {
+        /// The patch the hooks are currently being created for
+        patch: usize,
+        /// The id of the next hook that is created
+        next_id: u64,
+        slots: HashMap<String, Slots>,
+    }
+
+    #[derive(Default)]
+    struct Slots {
+        /// The saved value of each mounted hook, ordered by when the hook was created
+        mounted: BTreeMap<u64, Option<String>>,
+        /// The values of the hooks that were mounted before the last patch, waiting for the remounted hooks
+        restoring: VecDeque<Option<String>>,
+    }
+
+    impl SideChannel {
+        /// Create a slot for a new hook and take the value to restore into it, if there is one
+        fn create(&mut self, key: &str, patch: usize) -> (u64, Option<String>) {
+            // After a patch, components are remounted in the same order they were created in, so hand the values
+            // of the hooks that were mounted before the patch to the new hooks in that order
+            if self.patch != patch {
+                self.patch = patch;
+                for slots in self.slots.values_mut() {
+                    let mounted = std::mem::take(&mut slots.mounted);
+                    slots.restoring = mounted.into_values().collect();
+                }
+            }
+
+            let id = self.next_id;
+            self.next_id += 1;
+            let slots = self.slots.entry(key.to_string()).or_default();
+            let restored = slots.restoring.pop_front().flatten();
+            slots.mounted.insert(id, None);
+            (id, restored)
+        }
+
+        fn save(&mut self, key: &str, id: u64, value: String) {
+            if let Some(slot) = self
+                .slots
+                .get_mut(key)
+                .and_then(|slots| slots.mounted.get_mut(&id))
+            {
+                *slot = Some(value);
+            }
+        }
+
+        /// Remove the slot of a hook that was unmounted. Hooks that are unmounted by a patch keep their value so it
+        /// can be restored when they are remounted.
+        fn free(&mut self, key: &str, id: u64, patch: usize) {
+            if self.patch != patch {
+                return;
+            }
+            if let Some(slots) = self.slots.get_mut(key) {
+                slots.mounted.remove(&id);
+            }
+        }
+    }
+
+    /// The place a persistent signal saves its value
+    #[derive(Clone)]
+    pub(super) struct Slot {
+        key: String,
+        id: u64,
+        restored: Option<String>,
+    }
+
+    impl Slot {
+        pub(super) fn new<T>(file: &str) -> Self {
+            static REGISTER: Once = Once::new();
+            REGISTER.call_once(|| {
+                subsecond::register_handler(Arc::new(|| {
+                    PATCHES.fetch_add(1, Ordering::Relaxed);
+                }));
+            });
+
+            let key = format!("{file}::{}", std::any::type_name::<T>());
+            let patch = PATCHES.load(Ordering::Relaxed);
+            let (id, restored) =
+                SIDE_CHANNEL.with_borrow_mut(|channel| channel.create(&key, patch));
+            Self { key, id, restored }
+        }
+
+        /// Read the value saved in this slot before the last patch
+        pub(super) fn restore<T: DeserializeOwned>(&self) -> Option<T> {
+            let saved = self.restored.as_ref()?;
+            match serde_json::from_str(saved) {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed to restore persistent signal {}: {err}. Using the initial value instead.",
+                        self.key
+                    );
+                    None
+                }
+            }
+        }
+
+        pub(super) fn save<T: Serialize>(&self, value: &T) {
+            let serialized = match serde_json::to_string(value) {
+                Ok(serialized) => serialized,
+                Err(err) => {
+                    tracing::error!("Failed to save persistent signal {}: {err}", self.key);
+                    return;
+                }
+            };
+
+            SIDE_CHANNEL.with_borrow_mut(|channel| channel.save(&self.key, self.id, serialized));
+        }
+
+        pub(super) fn free(self) {
+            let patch = PATCHES.load(Ordering::Relaxed);
+            SIDE_CHANNEL.with_borrow_mut(|channel| channel.free(&self.key, self.id, patch));
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const KEY: &str = "app.rs::i32";
+
+        #[test]
+        fn unmounted_hooks_are_freed() {
+            let mut channel = SideChannel::default();
+            for value in 0..100 {
+                let (id, restored) = channel.create(KEY, 0);
+                assert_eq!(restored, None);
+                channel.save(KEY, id, value.to_string());
+                channel.free(KEY, id, 0);
+            }
+            assert!(channel.slots[KEY].mounted.is_empty());
+        }
+
+        #[test]
+        fn patches_restore_mounted_hooks_in_order() {
+            let mut channel = SideChannel::default();
+            let (first, _) = channel.create(KEY, 0);
+            let (dead, _) = channel.create(KEY, 0);
+            let (second, _) = channel.create(KEY, 0);
+            channel.save(KEY, first, "1".to_string());
+            channel.save(KEY, dead, "2".to_string());
+            channel.save(KEY, second, "3".to_string());
+            channel.free(KEY, dead, 0);
+
+            // The old hooks are unmounted by the patch, which keeps their values
+            channel.free(KEY, first, 1);
+            channel.free(KEY, second, 1);
+
+            assert_eq!(channel.create(KEY, 1).1.as_deref(), Some("1"));
+            assert_eq!(channel.create(KEY, 1).1.as_deref(), Some("3"));
+            assert_eq!(channel.create(KEY, 1).1, None);
+            assert_eq!(channel.slots[KEY].mounted.len(), 3);
+        }
+    }
+}
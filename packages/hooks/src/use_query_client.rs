@@ -0,0 +1,312 @@
+use crate::{Resource, use_resource, use_root_context};
+use dioxus_core::{use_drop, use_hook};
+use dioxus_signals::WritableExt;
+use std::{any::Any, cell::RefCell, collections::HashMap, future::Future, rc::Rc, time::Duration};
+use web_time::Instant;
+
+/// How long data fetched with [`use_resource_keyed`] stays usable
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QueryOptions {
+    /// How long fetched data is considered fresh. Resources that mount or rerun within this time reuse the cached
+    /// value instead of running their future again. Defaults to zero, so data is always refetched.
+    pub stale_time: Duration,
+    /// How long cached data is kept after the last resource using the key unmounts. Defaults to five minutes.
+    pub cache_time: Duration,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        Self {
+            stale_time: Duration::ZERO,
+            cache_time: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+/// A shared cache for resources created with [`use_resource_keyed`].
+///
+/// Resources register under a key. Calling [`QueryClient::invalidate`] with that key drops the cached value and
+/// restarts every resource that uses it. The client is a cheap handle that can be cloned and moved into event handlers.
+///
+/// [`use_query_client`] creates a client with the default [`QueryOptions`] at the root of the app if one isn't
+/// provided. Provide your own client with [`use_context_provider`](crate::use_context_provider) to change the options.
+#[derive(Clone, Default)]
+pub struct QueryClient {
+    inner: Rc<RefCell<QueryClientInner>>,
+}
+
+#[derive(Default)]
+struct QueryClientInner {
+    options: QueryOptions,
+    queries: HashMap<String, Query>,
+    next_id: usize,
+}
+
+#[derive(Default)]
+struct Query {
+    value: Option<(Rc<dyn Any>, Instant)>,
+    subscribers: Vec<(usize, Box<dyn FnMut()>)>,
+    unused_since: Option<Instant>,
+}
+
+impl PartialEq for QueryClient {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl QueryClient {
+    /// Create a new query client with the given options
+    pub fn new(options: QueryOptions) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(QueryClientInner {
+                options,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Get the options this client was created with
+    pub fn options(&self) -> QueryOptions {
+        self.inner.borrow().options
+    }
+
+    /// Drop the cached value for `key` and restart every resource registered under it
+    pub fn invalidate(&self, key: impl ToString) {
+        let key = key.to_string();
+        // Take the subscribers out while they run so restarting a resource can't borrow the client again
+        let mut subscribers = match self.inner.borrow_mut().queries.get_mut(&key) {
+            Some(query) => {
+                query.value = None;
+                std::mem::take(&mut query.subscribers)
+            }
+            None => return,
+        };
+
+        for (_, restart) in &mut subscribers {
+            restart();
+        }
+
+        let mut inner = self.inner.borrow_mut();
+        let query = inner.queries.entry(key).or_default();
+        subscribers.append(&mut query.subscribers);
+        query.subscribers = subscribers;
+    }
+
+    /// Drop every cached value and restart every registered resource
+    pub fn invalidate_all(&self) {
+        let keys: Vec<_> = self.inner.borrow().queries.keys().cloned().collect();
+        for key in keys {
+            self.invalidate(key);
+        }
+    }
+
+    /// Get the cached value for `key` if it was fetched within `stale_time`, or the stale time of the client if it is
+    /// `None`
+    fn fresh<T: Clone + 'static>(&self, key: &str, stale_time: Option<Duration>) -> Option<T> {
+        let inner = self.inner.borrow();
+        let stale_time = stale_time.unwrap_or(inner.options.stale_time);
+        let (value, fetched_at) = inner.queries.get(key)?.value.as_ref()?;
+        if fetched_at.elapsed() >= stale_time {
+            return None;
+        }
+        value.downcast_ref::<T>().cloned()
+    }
+
+    /// Get the cached value for `key`, even if it is stale
+    fn cached<T: Clone + 'static>(&self, key: &str) -> Option<T> {
+        let inner = self.inner.borrow();
+        let (value, _) = inner.queries.get(key)?.value.as_ref()?;
+        value.downcast_ref::<T>().cloned()
+    }
+
+    fn store<T: 'static>(&self, key: &str, value: T) {
+        let mut inner = self.inner.borrow_mut();
+        let query = inner.queries.entry(key.to_string()).or_default();
+        query.value = Some((Rc::new(value), Instant::now()));
+    }
+
+    fn subscribe(&self, key: String, restart: Box<dyn FnMut()>) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        inner.collect_garbage();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        let query = inner.queries.entry(key).or_default();
+        query.unused_since = None;
+        query.subscribers.push((id, restart));
+        id
+    }
+
+    fn unsubscribe(&self, key: &str, id: usize) {
+        let mut inner = self.inner.borrow_mut();
+        if let Some(query) = inner.queries.get_mut(key) {
+            query.subscribers.retain(|(other, _)| *other != id);
+            if query.subscribers.is_empty() {
+                query.unused_since = Some(Instant::now());
+            }
+        }
+    }
+}
+
+impl QueryClientInner {
+    /// Remove queries that haven't been used for longer than the cache time
+    fn collect_garbage(&mut self) {
+        let cache_time = self.options.cache_time;
+        self.queries.retain(|_, query| {
+            query
+                .unused_since
+                .is_none_or(|unused_since| unused_since.elapsed() < cache_time)
+        });
+    }
+}
+
+/// Get the [`QueryClient`] for the current component, creating one at the root of the app if none was provided.
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_query_client() -> QueryClient {
+    use_root_context(QueryClient::default)
+}
+
+/// Like [`use_resource`], but the value is cached in the [`QueryClient`] under `key`.
+///
+/// Calling [`QueryClient::invalidate`] with the same key restarts the resource. If the cached value is younger than
+/// [`QueryOptions::stale_time`], the resource uses it instead of waiting on the future again. This avoids refetching
+/// data when a component remounts.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let breeds = use_resource_keyed("breeds", move || async move {
+///         reqwest::get("https://dog.ceo/api/breeds/list/all")
+///             .await
+///             .unwrap()
+///             .text()
+///             .await
+///             .unwrap()
+///     });
+///     let client = use_query_client();
+///
+///     rsx! {
+///         button {
+///             // Any other resource registered under "breeds" restarts too
+///             onclick: move |_| client.invalidate("breeds"),
+///             "Refresh"
+///         }
+///         "{breeds:?}"
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_resource_keyed<T, F>(
+    key: impl ToString,
+    future: impl FnMut() -> F + 'static,
+) -> Resource<T>
+where
+    T: Clone + 'static,
+    F: Future<Output = T> + 'static,
+{
+    use_query_resource(key, None, future)
+}
+
+/// Like [`use_resource_keyed`], but the last value cached under `key` is restored right away when the component
+/// mounts, even if it is older than `stale_time`.
+///
+/// The cached value is kept in the [`QueryClient`] after the component unmounts, so navigating away from a route and
+/// back shows the old data instantly instead of a loading state. If the value is older than `stale_time`, the resource
+/// refetches it in the background. The resource stays [pending](Resource::pending) with the old value until the new
+/// value arrives. Values are dropped once no resource has used the key for [`QueryOptions::cache_time`].
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// #[component]
+/// fn Inbox() -> Element {
+///     // Switching to another tab and back shows the messages right away, and refetches them if they are older than
+///     // 30 seconds
+///     let messages = use_resource_cached("inbox", Duration::from_secs(30), move || async move {
+///         reqwest::get("https://example.com/api/inbox")
+///             .await
+///             .unwrap()
+///             .text()
+///             .await
+///             .unwrap()
+///     });
+///
+///     match messages() {
+///         Some(messages) => rsx! { "{messages}" },
+///         None => rsx! { "Loading..." },
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_resource_cached<T, F>(
+    key: impl ToString,
+    stale_time: Duration,
+    future: impl FnMut() -> F + 'static,
+) -> Resource<T>
+where
+    T: Clone + 'static,
+    F: Future<Output = T> + 'static,
+{
+    let client = use_query_client();
+    let key = use_hook(|| key.to_string());
+    let mut resource = use_query_resource(key.clone(), Some(stale_time), future);
+
+    // Restore the last value while the resource checks if it is stale
+    use_hook(|| {
+        if let Some(value) = client.cached::<T>(&key) {
+            resource.set(Some(value));
+        }
+    });
+
+    resource
+}
+
+/// Create a resource cached under `key`. Values younger than `stale_time`, or the stale time of the client if it is
+/// `None`, are reused instead of running the future.
+#[track_caller]
+fn use_query_resource<T, F>(
+    key: impl ToString,
+    stale_time: Option<Duration>,
+    mut future: impl FnMut() -> F + 'static,
+) -> Resource<T>
+where
+    T: Clone + 'static,
+    F: Future<Output = T> + 'static,
+{
+    let client = use_query_client();
+    let key = use_hook(|| key.to_string());
+
+    let resource = use_resource({
+        let client = client.clone();
+        let key = key.clone();
+        move || {
+            // Always create the future so any signals it reads are still tracked
+            let fut = future();
+            let cached = client.fresh::<T>(&key, stale_time);
+            let client = client.clone();
+            let key = key.clone();
+            async move {
+                if let Some(value) = cached {
+                    return value;
+                }
+                let value = fut.await;
+                client.store(&key, value.clone());
+                value
+            }
+        }
+    });
+
+    let id = use_hook(|| {
+        let mut resource = resource;
+        client.subscribe(key.clone(), Box::new(move || resource.restart()))
+    });
+    use_drop(move || client.unsubscribe(&key, id));
+
+    resource
+}
@@ -0,0 +1,169 @@
+use crate::use_signal;
+use dioxus_core::{Callback, Runtime, current_scope_id, use_hook};
+use dioxus_signals::*;
+use std::collections::VecDeque;
+
+/// A hook that manages state with a reducer function, similar to React's `useReducer`.
+///
+/// Instead of writing to the state directly, you send actions through the returned [`Dispatch`] handle. Each action
+/// runs `reducer` with the current state and the state is replaced with the result. Keeping every transition in one
+/// function makes state machines easier to follow than writes scattered across event handlers.
+///
+/// Actions dispatched while a component is rendering are applied after the render finishes. This prevents a component
+/// that dispatches during render from rerendering itself forever.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// enum Action {
+///     Increment,
+///     Decrement,
+///     Reset,
+/// }
+///
+/// fn reducer(count: &i32, action: Action) -> i32 {
+///     match action {
+///         Action::Increment => count + 1,
+///         Action::Decrement => count - 1,
+///         Action::Reset => 0,
+///     }
+/// }
+///
+/// fn App() -> Element {
+///     let (count, dispatch) = use_reducer(reducer, || 0);
+///
+///     rsx! {
+///         "Count: {count}"
+///         button { onclick: move |_| dispatch.call(Action::Increment), "+" }
+///         button { onclick: move |_| dispatch.call(Action::Decrement), "-" }
+///         button { onclick: move |_| dispatch.call(Action::Reset), "Reset" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_reducer<S: 'static, A: 'static>(
+    reducer: fn(&S, A) -> S,
+    init: impl FnOnce() -> S,
+) -> (ReadSignal<S>, Dispatch<A>) {
+    let mut state = use_signal(init);
+    let dispatch = use_hook(|| {
+        Dispatch::new(move |action: A| {
+            let new = reducer(&state.peek(), action);
+            state.set(new);
+        })
+    });
+    (state.into(), dispatch)
+}
+
+/// Like [`use_reducer`], but `middleware` observes every `(action, old_state, new_state)` after the reducer runs.
+///
+/// This is useful for logging or persisting the state. The middleware is captured the first time the hook runs. The
+/// state is already updated when the middleware runs, so it can dispatch follow up actions. Those actions are applied
+/// after the middleware returns.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let (count, dispatch) = use_reducer_with_middleware(
+///         |count: &i32, amount: i32| count + amount,
+///         || 0,
+///         |amount, old, new| println!("{old} + {amount} = {new}"),
+///     );
+///
+///     rsx! {
+///         "Count: {count}"
+///         button { onclick: move |_| dispatch.call(1), "+1" }
+///         button { onclick: move |_| dispatch.call(10), "+10" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_reducer_with_middleware<S: 'static, A: Clone + 'static>(
+    reducer: fn(&S, A) -> S,
+    init: impl FnOnce() -> S,
+    mut middleware: impl FnMut(&A, &S, &S) + 'static,
+) -> (ReadSignal<S>, Dispatch<A>) {
+    let mut state = use_signal(init);
+    let dispatch = use_hook(|| {
+        Dispatch::new(move |action: A| {
+            let new = reducer(&state.peek(), action.clone());
+            // Release the write borrow before running the middleware so it can read the state
+            let old = std::mem::replace(&mut *state.write(), new);
+            middleware(&action, &old, &state.peek());
+        })
+    });
+    (state.into(), dispatch)
+}
+
+/// A handle to send actions to a reducer created with [`use_reducer`]
+pub struct Dispatch<A: 'static> {
+    callback: Callback<A>,
+}
+
+impl<A: 'static> Dispatch<A> {
+    /// Create a dispatch handle that runs `apply` for every action
+    fn new(apply: impl FnMut(A) + 'static) -> Self {
+        let scope = current_scope_id();
+        let mut apply = CopyValue::new(apply);
+        let mut queue = CopyValue::new(VecDeque::new());
+        let mut run = move |action: A| {
+            queue.write().push_back(action);
+            // If an action is already being applied, this action was dispatched from inside of the middleware. The
+            // outer call applies it once the current action is finished.
+            let Ok(mut apply) = apply.try_write() else {
+                return;
+            };
+            loop {
+                // Pop in its own statement so the queue isn't borrowed while the action is applied
+                let next = queue.write().pop_front();
+                let Some(action) = next else {
+                    break;
+                };
+                (*apply)(action);
+            }
+        };
+
+        Self {
+            callback: Callback::new(move |action: A| {
+                let runtime = Runtime::current();
+                if runtime.vdom_is_rendering() {
+                    // Writing to the state during a render could rerender the component that dispatched the
+                    // action in a loop, so apply the action once the render is finished instead
+                    runtime.spawn(scope, async move { run(action) });
+                } else {
+                    run(action);
+                }
+            }),
+        }
+    }
+
+    /// Send an action to the reducer
+    pub fn call(&self, action: A) {
+        self.callback.call(action);
+    }
+}
+
+impl<A> Clone for Dispatch<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for Dispatch<A> {}
+
+impl<A> PartialEq for Dispatch<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.callback == other.callback
+    }
+}
+
+impl<A> From<Dispatch<A>> for Callback<A> {
+    fn from(dispatch: Dispatch<A>) -> Self {
+        dispatch.callback
+    }
+}
@@ -1,10 +1,10 @@
 #![allow(missing_docs)]
 
-use crate::{UseWaker, use_callback, use_signal, use_waker};
+use crate::{AbortSignal, Dependency, UseWaker, timer::sleep, use_callback, use_signal, use_waker};
 
 use dioxus_core::{
     Callback, IntoAttributeValue, IntoDynNode, ReactiveContext, RenderError, Subscribers,
-    SuspendedFuture, Task, spawn, use_hook,
+    SuspendedFuture, Task, current_scope_id, spawn, use_hook,
 };
 use dioxus_signals::*;
 use futures_util::{
@@ -12,53 +12,416 @@ use futures_util::{
     future::{self},
     pin_mut,
 };
-use std::{cell::Cell, future::Future, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    rc::Rc,
+    time::Duration,
+};
 use std::{fmt::Debug, ops::Deref};
 
 #[doc = include_str!("../docs/use_resource.md")]
 #[doc = include_str!("../docs/rules_of_hooks.md")]
 #[doc = include_str!("../docs/moving_state_around.md")]
 #[doc(alias = "use_async_memo")]
-#[doc(alias = "use_memo_async")]
 #[track_caller]
-pub fn use_resource<T, F>(mut future: impl FnMut() -> F + 'static) -> Resource<T>
+pub fn use_resource<T, F>(future: impl FnMut() -> F + 'static) -> Resource<T>
 where
     T: 'static,
     F: Future<Output = T> + 'static,
 {
     let location = std::panic::Location::caller();
+    let mut future = future;
+    use_resource_inner(
+        location,
+        move |_| future(),
+        ResourceOptions::default(),
+        |_| false,
+        true,
+    )
+}
 
+/// A variant of [`use_resource`] that passes an [`AbortSignal`] to the future.
+///
+/// The signal is aborted when the resource is restarted, either manually or because one of its dependencies changed,
+/// and when the component that owns the resource is dropped. Restarting a resource already drops the old future, but
+/// work that lives outside of the future, like a browser `fetch` or a task spawned elsewhere, keeps running. Use the
+/// signal to cancel that work and avoid racing stale responses.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn BreedPic(breed: ReadSignal<String>) -> Element {
+///     let pic = use_resource_with_abort(move |abort| async move {
+///         let request = reqwest::get(format!("https://dog.ceo/api/breed/{breed}/images/random"));
+///         tokio::select! {
+///             response = request => Some(response.ok()?.text().await.ok()?),
+///             // Stop waiting as soon as the breed changes
+///             _ = abort => None,
+///         }
+///     });
+///
+///     rsx! { "{pic:?}" }
+/// }
+/// ```
+///
+/// On the web, [`AbortSignal::web_signal`] creates a browser `AbortSignal` you can pass to `fetch`.
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_resource_with_abort<T, F>(future: impl FnMut(AbortSignal) -> F + 'static) -> Resource<T>
+where
+    T: 'static,
+    F: Future<Output = T> + 'static,
+{
+    let location = std::panic::Location::caller();
+    use_resource_inner(
+        location,
+        future,
+        ResourceOptions::default(),
+        |_| false,
+        true,
+    )
+}
+
+/// A variant of [`use_resource`] that retries the future when it resolves to an `Err`.
+///
+/// The retry behavior is controlled with [`ResourceOptions`]. Every time the future fails, the resource
+/// waits for the delay computed by the [`BackoffStrategy`] and then reruns the future. Once the future succeeds
+/// or the number of retries reaches [`ResourceOptions::max_retries`], the last result is stored in the resource.
+///
+/// The retry counter is reset whenever the resource is restarted, either manually or because one of its
+/// dependencies changed. You can read the current attempt with [`Resource::retry_count`].
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let options = ResourceOptions {
+///         max_retries: 5,
+///         backoff: BackoffStrategy::Exponential {
+///             initial: Duration::from_millis(100),
+///             max: Duration::from_secs(5),
+///             jitter: true,
+///         },
+///     };
+///     let breeds = use_resource_with_options(options, move || async move {
+///         reqwest::get("https://dog.ceo/api/breeds/list/all")
+///             .await?
+///             .text()
+///             .await
+///     });
+///
+///     match &*breeds.read_unchecked() {
+///         Some(Ok(breeds)) => rsx! { "{breeds}" },
+///         Some(Err(err)) => rsx! { "Failed after {breeds.retry_count()} retries: {err}" },
+///         None if breeds.retry_count() > 0 => rsx! { "Retrying ({breeds.retry_count()}/5)..." },
+///         None => rsx! { "Loading..." },
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_resource_with_options<T, E, F>(
+    options: ResourceOptions,
+    future: impl FnMut() -> F + 'static,
+) -> Resource<Result<T, E>>
+where
+    T: 'static,
+    E: 'static,
+    F: Future<Output = Result<T, E>> + 'static,
+{
+    let location = std::panic::Location::caller();
+    let mut future = future;
+    use_resource_inner(location, move |_| future(), options, Result::is_err, true)
+}
+
+/// A variant of [`use_resource`] that also reruns the future every `interval`.
+///
+/// The resource still reruns when any of its dependencies change. When the interval elapses while the previous
+/// future is still running, paused, or was cancelled, the tick is skipped instead of restarting the future.
+///
+/// Polling can be paused and resumed with [`Resource::pause_polling`] and [`Resource::resume_polling`]. The timer
+/// is tied to the component and stops when the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let mut stats = use_resource_polled(Duration::from_secs(5), move || async move {
+///         reqwest::get("https://dog.ceo/api/breeds/image/random")
+///             .await?
+///             .text()
+///             .await
+///     });
+///
+///     rsx! {
+///         "{stats:?}"
+///         button { onclick: move |_| stats.pause_polling(), "Pause" }
+///         button { onclick: move |_| stats.resume_polling(), "Resume" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_resource_polled<T, F>(
+    interval: Duration,
+    future: impl FnMut() -> F + 'static,
+) -> Resource<T>
+where
+    T: 'static,
+    F: Future<Output = T> + 'static,
+{
+    let location = std::panic::Location::caller();
+    let mut future = future;
+    let mut resource = use_resource_inner(
+        location,
+        move |_| future(),
+        ResourceOptions::default(),
+        |_| false,
+        true,
+    );
+
+    let mut current_interval = use_hook(|| CopyValue::new(interval));
+    if *current_interval.peek() != interval {
+        current_interval.set(interval);
+    }
+
+    let poll_task = use_hook(|| {
+        spawn(async move {
+            loop {
+                sleep(*current_interval.peek()).await;
+
+                // Skip this tick if the last run hasn't finished yet or the resource was stopped
+                if *resource.state.peek() == UseResourceState::Ready {
+                    resource.restart();
+                }
+            }
+        })
+    });
+    resource.poll_task = Some(poll_task);
+
+    resource
+}
+
+/// A variant of [`use_resource`] that only restarts when `dependencies` change.
+///
+/// Signals read inside of the future are not tracked, so changing them doesn't restart the resource. Instead, the
+/// dependencies are compared with the dependencies from the last render every time the component renders, and the
+/// resource restarts if any of them changed. The current dependencies are passed into the closure. This works like
+/// the dependency array of `useEffect` in React.
+///
+/// Dependencies are a tuple of references to values that implement `Clone` and `PartialEq`, like the dependencies
+/// of [`use_reactive`](crate::use_reactive()).
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(Clone)]
+/// struct Config {
+///     api_url: String,
+///     theme: String,
+/// }
+///
+/// #[component]
+/// fn BreedPic(breed: String, config: Signal<Config>) -> Element {
+///     // Only refetch when the breed changes. Changing the theme in the config doesn't restart the resource.
+///     let pic = use_resource_with_deps((&breed,), move |(breed,)| async move {
+///         let api_url = config.read().api_url.clone();
+///         reqwest::get(format!("{api_url}/breed/{breed}/images/random"))
+///             .await?
+///             .text()
+///             .await
+///     });
+///
+///     rsx! { "{pic:?}" }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_resource_with_deps<T, D, F>(
+    dependencies: D,
+    mut future: impl FnMut(D::Out) -> F + 'static,
+) -> Resource<T>
+where
+    T: 'static,
+    D: Dependency,
+    F: Future<Output = T> + 'static,
+{
+    let location = std::panic::Location::caller();
+    let mut current = use_hook(|| CopyValue::new(dependencies.out()));
+    let mut resource = use_resource_inner(
+        location,
+        move |_| future(current.cloned()),
+        ResourceOptions::default(),
+        |_| false,
+        false,
+    );
+
+    if dependencies.changed(&*current.peek()) {
+        current.set(dependencies.out());
+        resource.restart();
+    }
+
+    resource
+}
+
+/// A variant of [`use_resource_with_deps`] that waits until the dependencies stop changing for `delay` before
+/// running the future.
+///
+/// The future runs right away when the component is first rendered. After that, every change to the dependencies
+/// restarts the resource, which cancels the pending delay or a future that is still running, and then waits `delay`
+/// before running the future with the latest dependencies. This is the search-as-you-type pattern without an
+/// intermediate debounced signal. Calling [`Resource::restart`] yourself runs the future right away.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let mut search = use_signal(String::new);
+///
+///     // Only search once the user stops typing for 300ms
+///     let results = use_debounced_resource(Duration::from_millis(300), (&search(),), |(search,)| async move {
+///         reqwest::get(format!("https://dog.ceo/api/breed/{search}/images"))
+///             .await?
+///             .text()
+///             .await
+///     });
+///
+///     rsx! {
+///         input { oninput: move |event| search.set(event.value()) }
+///         "{results:?}"
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_debounced_resource<T, D, F>(
+    delay: Duration,
+    dependencies: D,
+    mut future: impl FnMut(D::Out) -> F + 'static,
+) -> Resource<T>
+where
+    T: 'static,
+    D: Dependency,
+    F: Future<Output = T> + 'static,
+{
+    let location = std::panic::Location::caller();
+    let mut current = use_hook(|| CopyValue::new(dependencies.out()));
+    // Set when the dependencies change so only the run they cause waits for the delay
+    let mut debounce = use_hook(|| CopyValue::new(false));
+    let mut resource = use_resource_inner(
+        location,
+        move |_| {
+            let wait = *debounce.peek();
+            debounce.set(false);
+            let future = future(current.cloned());
+            async move {
+                if wait {
+                    sleep(delay).await;
+                }
+                future.await
+            }
+        },
+        ResourceOptions::default(),
+        |_| false,
+        false,
+    );
+
+    if dependencies.changed(&*current.peek()) {
+        current.set(dependencies.out());
+        debounce.set(true);
+        resource.restart();
+    }
+
+    resource
+}
+
+fn use_resource_inner<T, F>(
+    location: &'static std::panic::Location<'static>,
+    future: impl FnMut(AbortSignal) -> F + 'static,
+    options: ResourceOptions,
+    should_retry: fn(&T) -> bool,
+    track_reads: bool,
+) -> Resource<T>
+where
+    T: 'static,
+    F: Future<Output = T> + 'static,
+{
     let mut value = use_signal(|| None);
     let mut state = use_signal(|| UseResourceState::Pending);
-    let (rc, changed) = use_hook(|| {
-        let (rc, changed) = ReactiveContext::new_with_origin(location);
-        (rc, Rc::new(Cell::new(Some(changed))))
+    let mut retry_count = use_signal(|| 0);
+    let (rc, changed) = use_hook(|| match track_reads {
+        true => {
+            let (rc, changed) = ReactiveContext::new_with_origin(location);
+            (rc, Rc::new(Cell::new(Some(changed))))
+        }
+        // Signals read in the future still subscribe to this context, but changing them does nothing
+        false => {
+            let rc = ReactiveContext::new_with_callback(|| {}, current_scope_id(), location);
+            (rc, Rc::new(Cell::new(None)))
+        }
     });
 
     let mut waker = use_waker::<()>();
 
+    // The future factory is shared with the spawned task so it can be rerun when a retry is needed
+    let make_future = Rc::new(RefCell::new(future));
+
     let cb = use_callback(move |_| {
         // Set the state to Pending when the task is restarted
         state.set(UseResourceState::Pending);
+        retry_count.set(0);
 
-        // Create the user's task
-        let fut = rc.reset_and_run_in(&mut future);
+        // Create the user's task. The abort signal is shared between every retry of this run and is aborted if the
+        // wrapper task is dropped before it finishes
+        let (abort, abort_guard) = AbortSignal::new();
+        let first = rc.reset_and_run_in(|| (make_future.borrow_mut())(abort.clone()));
+        let make_future = make_future.clone();
 
         // Spawn a wrapper task that polls the inner future and watches its dependencies
         spawn(async move {
-            // Move the future here and pin it so we can poll it
-            let fut = fut;
-            pin_mut!(fut);
-
-            // Run each poll in the context of the reactive scope
-            // This ensures the scope is properly subscribed to the future's dependencies
-            let res = future::poll_fn(|cx| {
-                rc.run_in(|| {
-                    tracing::trace_span!("polling resource", location = %location)
-                        .in_scope(|| fut.poll_unpin(cx))
+            let mut first = Some(first);
+            let mut attempt = 0;
+
+            let res = loop {
+                // Move the future here and pin it so we can poll it
+                let fut = match first.take() {
+                    Some(fut) => fut,
+                    None => rc.reset_and_run_in(|| (make_future.borrow_mut())(abort.clone())),
+                };
+                pin_mut!(fut);
+
+                // Run each poll in the context of the reactive scope
+                // This ensures the scope is properly subscribed to the future's dependencies
+                let res = future::poll_fn(|cx| {
+                    rc.run_in(|| {
+                        tracing::trace_span!("polling resource", location = %location)
+                            .in_scope(|| fut.poll_unpin(cx))
+                    })
                 })
-            })
-            .await;
+                .await;
+
+                if attempt >= options.max_retries || !should_retry(&res) {
+                    break res;
+                }
+
+                // Wait for the backoff before trying the future again
+                attempt += 1;
+                retry_count.set(attempt);
+                tracing::trace!("retrying resource at {location} (attempt {attempt})");
+                sleep(options.backoff.delay(attempt)).await;
+            };
+
+            // The future finished, so there is nothing left to abort
+            abort_guard.finish();
 
             // Set the value and state
             state.set(UseResourceState::Ready);
@@ -72,18 +435,19 @@ where
     let mut task = use_hook(|| Signal::new(cb(())));
 
     use_hook(|| {
-        let mut changed = changed.take().unwrap();
-        spawn(async move {
-            loop {
-                // Wait for the dependencies to change
-                let _ = changed.next().await;
+        changed.take().map(|mut changed| {
+            spawn(async move {
+                loop {
+                    // Wait for the dependencies to change
+                    let _ = changed.next().await;
 
-                // Stop the old task
-                task.write().cancel();
+                    // Stop the old task
+                    task.write().cancel();
 
-                // Start a new task
-                task.set(cb(()));
-            }
+                    // Start a new task
+                    task.set(cb(()));
+                }
+            })
         })
     });
 
@@ -91,11 +455,91 @@ where
         task,
         value,
         state,
+        retry_count,
+        poll_task: None,
         waker,
         callback: cb,
     }
 }
 
+/// Options that control how a resource created with [`use_resource_with_options`] is retried
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ResourceOptions {
+    /// The maximum number of times the future will be retried after it resolves to an error.
+    /// A value of `0` disables retries.
+    pub max_retries: usize,
+
+    /// How long to wait between each retry
+    pub backoff: BackoffStrategy,
+}
+
+/// The strategy used to compute the delay before retrying a failed resource
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BackoffStrategy {
+    /// Wait the same amount of time before every retry
+    Fixed(Duration),
+
+    /// Wait `n * delay` before the `n`th retry
+    Linear(Duration),
+
+    /// Double the delay before every retry, starting with `initial` and never waiting longer than `max`
+    Exponential {
+        /// The delay before the first retry
+        initial: Duration,
+        /// The upper bound for the delay
+        max: Duration,
+        /// Randomize the delay between half and the full computed value to avoid retrying many resources at the same time
+        jitter: bool,
+    },
+}
+
+impl Default for BackoffStrategy {
+    fn default() -> Self {
+        Self::Fixed(Duration::ZERO)
+    }
+}
+
+impl BackoffStrategy {
+    /// Get the delay before the given retry attempt. Attempts start at `1` for the first retry.
+    pub fn delay(&self, attempt: usize) -> Duration {
+        let attempt = attempt.max(1);
+        match *self {
+            BackoffStrategy::Fixed(delay) => delay,
+            BackoffStrategy::Linear(delay) => {
+                delay.saturating_mul(u32::try_from(attempt).unwrap_or(u32::MAX))
+            }
+            BackoffStrategy::Exponential {
+                initial,
+                max,
+                jitter,
+            } => {
+                let exponent = u32::try_from(attempt - 1).unwrap_or(u32::MAX).min(31);
+                let delay = initial.saturating_mul(1 << exponent).min(max);
+                match jitter {
+                    true => delay.mul_f64(0.5 + random_unit() / 2.0),
+                    false => delay,
+                }
+            }
+        }
+    }
+}
+
+/// A cheap random number in the range `[0, 1)` used to jitter backoff delays. This doesn't need to be
+/// cryptographically secure, it just needs to spread retries out.
+fn random_unit() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+    thread_local! {
+        static COUNTER: Cell<u64> = const { Cell::new(0) };
+    }
+    let count = COUNTER.with(|c| {
+        c.set(c.get().wrapping_add(1));
+        c.get()
+    });
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u64(count);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
 /// A handle to a reactive future spawned with [`use_resource`] that can be used to modify or read the result of the future.
 ///
 /// ## Example
@@ -127,6 +571,8 @@ pub struct Resource<T: 'static> {
     value: Signal<Option<T>>,
     task: Signal<Task>,
     state: Signal<UseResourceState>,
+    retry_count: Signal<usize>,
+    poll_task: Option<Task>,
     callback: Callback<(), Task>,
 }
 
@@ -135,6 +581,8 @@ impl<T> PartialEq for Resource<T> {
         self.value == other.value
             && self.state == other.state
             && self.task == other.task
+            && self.retry_count == other.retry_count
+            && self.poll_task == other.poll_task
             && self.callback == other.callback
     }
 }
@@ -364,6 +812,37 @@ impl<T> Resource<T> {
         )
     }
 
+    /// Stop rerunning the resource's future on an interval. This does nothing for resources that were not created with [`use_resource_polled`].
+    ///
+    /// The current future keeps running and the resource still reruns when its dependencies change.
+    pub fn pause_polling(&mut self) {
+        if let Some(task) = self.poll_task {
+            task.pause();
+        }
+    }
+
+    /// Resume polling after it was paused with [`Resource::pause_polling`].
+    pub fn resume_polling(&mut self) {
+        if let Some(task) = self.poll_task {
+            task.resume();
+        }
+    }
+
+    /// Check if the resource is currently rerunning its future on an interval.
+    pub fn polling(&self) -> bool {
+        self.poll_task.is_some_and(|task| !task.paused())
+    }
+
+    /// Get the number of times the resource's future has been retried since it was last restarted.
+    ///
+    /// This is always `0` for resources created with [`use_resource`]. Resources created with
+    /// [`use_resource_with_options`] increment this counter every time the future fails and is scheduled to run again.
+    ///
+    /// Reading this will subscribe the current scope to changes in the retry count.
+    pub fn retry_count(&self) -> usize {
+        self.retry_count.cloned()
+    }
+
     /// Get the current state of the resource's future. This method returns a [`ReadSignal`] which can be read to get the current state of the resource or passed to other hooks and components.
     ///
     /// ## Example
@@ -430,6 +909,34 @@ impl<T> Resource<T> {
     }
 
     /// Suspend the resource's future and only continue rendering when the future is ready
+    ///
+    /// While the future is pending, this returns [`RenderError::Suspended`] which the closest
+    /// `SuspenseBoundary` above the component catches to render its fallback.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// fn App() -> Element {
+    ///     rsx! {
+    ///         SuspenseBoundary {
+    ///             fallback: |_| rsx! { "Loading..." },
+    ///             Breeds {}
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[component]
+    /// fn Breeds() -> Element {
+    ///     let breeds = use_resource(move || async move {
+    ///         reqwest::get("https://dog.ceo/api/breeds/list/all").await?.text().await
+    ///     })
+    ///     .suspend()?;
+    ///
+    ///     match &*breeds.read() {
+    ///         Ok(breeds) => rsx! { "{breeds}" },
+    ///         Err(err) => rsx! { "Error: {err}" },
+    ///     }
+    /// }
+    /// ```
     pub fn suspend(&self) -> std::result::Result<MappedSignal<T, Signal<Option<T>>>, RenderError> {
         match self.state.cloned() {
             UseResourceState::Stopped | UseResourceState::Paused | UseResourceState::Pending => {
@@ -445,7 +952,77 @@ impl<T> Resource<T> {
     }
 }
 
+/// The status of a [`Resource`] that returns a [`Result`], returned by [`Resource::status`].
+///
+/// The values are signals that point into the resource, so matching on the status doesn't clone the value.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ResourceStatus<T, E> {
+    /// The future is running and there is no successful value to show yet
+    Pending,
+    /// The future is running again and the last successful value is still available
+    Reloading(T),
+    /// The future finished successfully
+    Ready(T),
+    /// The future finished with an error
+    Failed(E),
+}
+
 impl<T, E> Resource<Result<T, E>> {
+    /// Get the status of the resource as a single enum instead of a nested `Option<Result<T, E>>`.
+    ///
+    /// Reading the status subscribes to both the state and the value of the resource. If the resource restarts after it
+    /// succeeded, the status is [`ResourceStatus::Reloading`] with the previous value until the new future finishes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// fn App() -> Element {
+    ///     let breeds = use_resource(move || async move {
+    ///         reqwest::get("https://dog.ceo/api/breeds/list/all").await?.text().await
+    ///     });
+    ///
+    ///     match breeds.status() {
+    ///         ResourceStatus::Pending => rsx! { "Loading..." },
+    ///         ResourceStatus::Reloading(breeds) => rsx! { "Refreshing {breeds}" },
+    ///         ResourceStatus::Ready(breeds) => rsx! { "{breeds}" },
+    ///         ResourceStatus::Failed(err) => rsx! { "Error: {err}" },
+    ///     }
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn status(
+        &self,
+    ) -> ResourceStatus<
+        MappedSignal<T, Signal<Option<Result<T, E>>>>,
+        MappedSignal<E, Signal<Option<Result<T, E>>>>,
+    > {
+        let running = *self.state.read() == UseResourceState::Pending;
+        let result = match &*self.value.read() {
+            Some(Ok(_)) => Some(true),
+            Some(Err(_)) => Some(false),
+            None => None,
+        };
+
+        match result {
+            Some(true) => {
+                let value = self.value.map(|v| match v {
+                    Some(Ok(res)) => res,
+                    _ => panic!("Resource is not ready"),
+                });
+                match running {
+                    true => ResourceStatus::Reloading(value),
+                    false => ResourceStatus::Ready(value),
+                }
+            }
+            Some(false) if !running => ResourceStatus::Failed(self.value.map(|v| match v {
+                Some(Err(err)) => err,
+                _ => panic!("Resource is not ready"),
+            })),
+            _ => ResourceStatus::Pending,
+        }
+    }
+
     /// Convert the `Resource<Result<T, E>>` into an `Option<Result<MappedSignal<T>, MappedSignal<E>>>`
     #[allow(clippy::type_complexity)]
     pub fn result(
@@ -0,0 +1,68 @@
+use crate::use_memo;
+use dioxus_core::{ReactiveContext, current_scope_id, use_hook};
+use dioxus_signals::{Memo, ReadableExt};
+
+/// Creates a memo that only recomputes when its dependencies change.
+///
+/// [`use_memo`] reruns whenever any signal it reads changes. A selector splits that into two closures: `deps` reads
+/// the signals the value depends on, and `compute` derives the value from the output of `deps`. Signals read inside of
+/// `compute` are not tracked, so only changes to the output of `deps` (compared with `PartialEq`) rerun `compute`.
+///
+/// Like any memo, the selector only notifies its subscribers when the computed value changes, so components reading
+/// it only rerun if the output is different.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// const WORDS: &[&str] = &["apple", "apricot", "banana", "blueberry", "cherry"];
+///
+/// fn App() -> Element {
+///     let mut query = use_signal(String::new);
+///
+///     // Only search again when the normalized query changes. Adding spaces or changing the case of the query
+///     // doesn't rerun the search, and the list only rerenders if the results are different.
+///     let results = use_selector(
+///         move || query().trim().to_lowercase(),
+///         |query| {
+///             WORDS
+///                 .iter()
+///                 .filter(|word| word.starts_with(query.as_str()))
+///                 .map(|word| word.to_string())
+///                 .collect::<Vec<_>>()
+///         },
+///     );
+///
+///     rsx! {
+///         input { value: "{query}", oninput: move |event| query.set(event.value()) }
+///         for word in results() {
+///             div { "{word}" }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Everything `compute` uses that can change should come from `deps`. If `compute` reads a signal directly, changes
+/// to that signal are ignored until `deps` changes.
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_selector<D, R>(
+    deps: impl FnMut() -> D + 'static,
+    mut compute: impl FnMut(&D) -> R + 'static,
+) -> Memo<R>
+where
+    D: PartialEq + 'static,
+    R: PartialEq + 'static,
+{
+    let location = std::panic::Location::caller();
+    let deps = use_memo(deps);
+
+    // Signals read while computing the value subscribe to this context instead of the memo, so they don't rerun it
+    let untracked =
+        use_hook(|| ReactiveContext::new_with_callback(|| {}, current_scope_id(), location));
+
+    use_memo(move || {
+        let deps = deps.read();
+        untracked.reset_and_run_in(|| compute(&deps))
+    })
+}
@@ -1,5 +1,5 @@
 use dioxus_core::use_hook;
-use dioxus_signals::{Signal, SignalData, Storage, SyncStorage, UnsyncStorage};
+use dioxus_signals::{Signal, SignalData, Storage, SyncSignal, UnsyncStorage};
 
 /// Creates a new Signal. Signals are a Copy state management solution with automatic dependency tracking.
 ///
@@ -72,13 +72,73 @@ pub fn use_signal<T: 'static>(f: impl FnOnce() -> T) -> Signal<T, UnsyncStorage>
 ///     }
 /// }
 /// ```
+///
+/// Sync signals can also be written from plain `std` threads outside of any async runtime, like the callback thread
+/// of a blocking library or a hardware driver. The write happens immediately, and the components that read the signal
+/// are rerendered on the thread the app runs on. The thread can outlive the component, so use `try_write` to stop
+/// once the signal was dropped instead of panicking:
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+/// use std::time::Duration;
+///
+/// fn App() -> Element {
+///     let mut temperature = use_signal_sync(|| 0.0);
+///
+///     use_hook(|| {
+///         std::thread::spawn(move || loop {
+///             // Pretend this is a blocking call into a sensor library
+///             std::thread::sleep(Duration::from_secs(1));
+///             // Stop reading the sensor once the component is unmounted
+///             let Ok(mut temperature) = temperature.try_write() else {
+///                 break;
+///             };
+///             *temperature = 21.5;
+///         })
+///     });
+///
+///     rsx! { "Temperature: {temperature}°C" }
+/// }
+/// ```
 #[doc(alias = "use_rw")]
 #[must_use]
 #[track_caller]
-pub fn use_signal_sync<T: Send + Sync + 'static>(f: impl FnOnce() -> T) -> Signal<T, SyncStorage> {
+pub fn use_signal_sync<T: Send + Sync + 'static>(f: impl FnOnce() -> T) -> SyncSignal<T> {
     use_maybe_signal_sync(f)
 }
 
+/// Creates a new Signal that only notifies subscribers when a write changes its value.
+///
+/// Writes that leave the value equal to what it was before, like setting a signal to its current value in an event
+/// handler, will not rerun any components or effects that read the signal.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// fn App() -> Element {
+///     let mut breed = use_signal_eq(|| "husky".to_string());
+///
+///     rsx! {
+///         // Clicking this button again after the breed is already "husky" will not rerender the component
+///         button { onclick: move |_| breed.set("husky".to_string()), "Husky" }
+///         button { onclick: move |_| breed.set("poodle".to_string()), "Poodle" }
+///         "{breed}"
+///     }
+/// }
+/// ```
+///
+/// Every write clones the old value and compares it to the new value. For large values that change on most writes,
+/// prefer [`use_signal`] with [`WritableExt::set_if_changed`](dioxus_signals::WritableExt::set_if_changed) where it matters.
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+#[must_use]
+pub fn use_signal_eq<T: Clone + PartialEq + 'static>(
+    f: impl FnOnce() -> T,
+) -> Signal<T, UnsyncStorage> {
+    let caller = std::panic::Location::caller();
+    use_hook(|| Signal::new_eq_with_caller(f(), caller))
+}
+
 #[must_use]
 #[track_caller]
 fn use_maybe_signal_sync<T: 'static, U: Storage<SignalData<T>>>(
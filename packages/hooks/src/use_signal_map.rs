@@ -0,0 +1,38 @@
+use dioxus_core::use_hook;
+use dioxus_signals::{BiMappedSignal, Signal};
+
+/// Creates a writable projection of a signal that is always in sync with it.
+///
+/// Reading the projection gives you the value of `source` converted with `to`. Writing the projection converts the
+/// new value back with `from` and writes it to `source`. The projection is computed from `source` whenever it is read,
+/// so it never holds a stale value. This is useful for unit conversions, or to pass a signal to a child component that expects a different type.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut celsius = use_signal(|| 20.0);
+///     let mut fahrenheit = use_signal_map(celsius, |c| c * 9.0 / 5.0 + 32.0, |f| (f - 32.0) * 5.0 / 9.0);
+///
+///     rsx! {
+///         "{celsius}°C is {fahrenheit}°F"
+///         button { onclick: move |_| celsius += 1.0, "+1°C" }
+///         button { onclick: move |_| fahrenheit += 1.0, "+1°F" }
+///     }
+/// }
+/// ```
+///
+/// See [`Signal::bimap`] for more details.
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+#[track_caller]
+pub fn use_signal_map<T, U>(
+    source: Signal<T>,
+    to: impl Fn(&T) -> U + 'static,
+    from: impl Fn(&U) -> T + 'static,
+) -> BiMappedSignal<U, T>
+where
+    T: PartialEq + 'static,
+    U: PartialEq + 'static,
+{
+    let location = std::panic::Location::caller();
+    use_hook(|| source.bimap_with_location(to, from, location))
+}
@@ -0,0 +1,96 @@
+use crate::{timer::sleep, use_callback};
+use dioxus_core::{Callback, Runtime, ScopeId, Task, current_scope_id, use_hook};
+use dioxus_signals::*;
+use std::time::Duration;
+
+/// A hook that creates a callback which calls `callback` at most once per `interval`.
+///
+/// The first call runs immediately. Calls that happen while the interval is running replace each other, so only the
+/// latest value is kept. Once the interval passes, the callback runs with that latest value and a new interval starts.
+/// This means a burst of calls always ends with a trailing call that sees the last value, while everything in between
+/// is dropped instead of queued.
+///
+/// The returned [`Callback`] can be passed directly to event handlers like `onmousemove`. Any pending trailing call is
+/// dropped when the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let mut position = use_signal(|| (0.0, 0.0));
+///     // Update the position at most once per frame
+///     let onmousemove = use_throttled_callback(Duration::from_millis(16), move |event: MouseEvent| {
+///         let point = event.client_coordinates();
+///         position.set((point.x, point.y));
+///     });
+///
+///     rsx! {
+///         div { onmousemove, "The mouse is at {position:?}" }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_throttled_callback<T: 'static>(
+    interval: Duration,
+    callback: impl FnMut(T) + 'static,
+) -> Callback<T> {
+    let callback = use_callback(callback);
+    let mut state = use_hook(|| CopyValue::new(Throttle::<T>::default()));
+    let scope = use_hook(current_scope_id);
+    let mut interval_value = use_hook(|| CopyValue::new(interval));
+
+    // Keep the interval up to date if the component passes in a new value
+    if *interval_value.peek() != interval {
+        interval_value.set(interval);
+    }
+
+    use_hook(|| {
+        Callback::new(move |value: T| {
+            // If the interval is still running, replace the trailing value and wait
+            if state.peek().task.is_some() {
+                state.write().pending = Some(value);
+                return;
+            }
+
+            callback.call(value);
+            start_interval(scope, interval_value, state, callback);
+        })
+    })
+}
+
+struct Throttle<T> {
+    pending: Option<T>,
+    task: Option<Task>,
+}
+
+impl<T> Default for Throttle<T> {
+    fn default() -> Self {
+        Self {
+            pending: None,
+            task: None,
+        }
+    }
+}
+
+fn start_interval<T: 'static>(
+    scope: ScopeId,
+    interval: CopyValue<Duration>,
+    mut state: CopyValue<Throttle<T>>,
+    callback: Callback<T>,
+) {
+    let task = Runtime::current().spawn(scope, async move {
+        // Keep delivering the latest value once per interval until a full interval passes without any calls
+        loop {
+            sleep(*interval.peek()).await;
+            let pending = state.write().pending.take();
+            match pending {
+                Some(value) => callback.call(value),
+                None => break,
+            }
+        }
+        state.write().task = None;
+    });
+    state.write().task = Some(task);
+}
@@ -0,0 +1,134 @@
+use crate::timer::sleep;
+use dioxus_core::{Runtime, ScopeId, Task, current_scope_id, use_hook};
+use dioxus_signals::*;
+use std::time::Duration;
+
+/// A hook that calls `callback` once after `delay` has passed.
+///
+/// The timeout starts when the component is first rendered and is canceled if the component is unmounted before it
+/// fires. The callback runs inside of the component's scope, so writing to signals from inside of it will rerender any
+/// components that read them.
+///
+/// The returned [`TimeoutHandle`] can be used to cancel the timeout or reset it to start the countdown again.
+/// The handle always calls the callback passed in the latest render. If the component is rendered with a different
+/// `delay`, the new delay is used the next time the timeout is reset.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let mut visible = use_signal(|| true);
+///     let mut timeout = use_timeout(Duration::from_secs(3), move || visible.set(false));
+///
+///     rsx! {
+///         if visible() {
+///             div { class: "toast", "Saved!" }
+///         }
+///         button {
+///             onclick: move |_| {
+///                 visible.set(true);
+///                 timeout.reset();
+///             },
+///             "Save again"
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_timeout(delay: Duration, callback: impl FnMut() + 'static) -> TimeoutHandle {
+    let mut handle = use_hook(|| {
+        let scope = current_scope_id();
+        let callback = CopyValue::new(None);
+        let pending = Signal::new(true);
+        TimeoutHandle {
+            task: CopyValue::new(Some(spawn_timeout(scope, delay, callback, pending))),
+            delay: CopyValue::new(delay),
+            pending,
+            scope,
+            callback,
+        }
+    });
+
+    // Always call the callback from the latest render
+    handle.callback.set(Some(Box::new(callback)));
+
+    // Keep the delay up to date if the component passes in a new value
+    if *handle.delay.peek() != delay {
+        handle.delay.set(delay);
+    }
+
+    handle
+}
+
+type TimeoutCallback = CopyValue<Option<Box<dyn FnMut()>>>;
+
+fn spawn_timeout(
+    scope: ScopeId,
+    delay: Duration,
+    mut callback: TimeoutCallback,
+    mut pending: Signal<bool>,
+) -> Task {
+    Runtime::current().spawn(scope, async move {
+        sleep(delay).await;
+        pending.set(false);
+        // Take the callback out while it runs so it can reset or cancel the timeout
+        let taken = callback.write().take();
+        if let Some(mut taken) = taken {
+            taken();
+            // Put it back so the timeout can be reset, unless a rerender already stored a newer callback
+            let mut callback = callback.write();
+            if callback.is_none() {
+                *callback = Some(taken);
+            }
+        }
+    })
+}
+
+/// A handle to a timeout created with [`use_timeout`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct TimeoutHandle {
+    task: CopyValue<Option<Task>>,
+    delay: CopyValue<Duration>,
+    pending: Signal<bool>,
+    scope: ScopeId,
+    callback: TimeoutCallback,
+}
+
+impl TimeoutHandle {
+    /// Stop the timeout from firing. The timeout can be started again with [`TimeoutHandle::reset`].
+    pub fn cancel(&mut self) {
+        self.cancel_task();
+        self.pending.set(false);
+    }
+
+    /// Restart the countdown. The callback will be called once the delay has passed from when this method is called,
+    /// even if the timeout already fired or was canceled.
+    pub fn reset(&mut self) {
+        self.cancel_task();
+        self.pending.set(true);
+
+        let delay = *self.delay.peek();
+        let task = spawn_timeout(self.scope, delay, self.callback, self.pending);
+        self.task.set(Some(task));
+    }
+
+    /// Check if the timeout is still waiting to fire.
+    ///
+    /// Reading this will subscribe the current scope to changes in the pending state.
+    pub fn is_pending(&self) -> bool {
+        self.pending.cloned()
+    }
+
+    /// Get the delay of the timeout
+    pub fn delay(&self) -> Duration {
+        *self.delay.peek()
+    }
+
+    fn cancel_task(&mut self) {
+        if let Some(task) = self.task.write().take() {
+            task.cancel();
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{timer::sleep, use_callback, use_context_provider, use_effect_with_prev};
+use dioxus_core::{
+    Element, EventHandler, Runtime, ScopeId, Task, VNode, current_scope_id, use_hook,
+};
+use dioxus_core_macro::Props;
+use dioxus_signals::*;
+use std::time::Duration;
+
+/// The phase of a transition created with [`use_transition`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransitionPhase {
+    /// The content was just shown and the enter animation is running
+    Entering,
+    /// The enter animation finished and the content is fully shown
+    Entered,
+    /// The content was just hidden and the exit animation is running
+    Exiting,
+    /// The exit animation finished and the content should be removed
+    Exited,
+}
+
+impl TransitionPhase {
+    /// Get a lowercase name for the phase that can be used as a css class
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransitionPhase::Entering => "entering",
+            TransitionPhase::Entered => "entered",
+            TransitionPhase::Exiting => "exiting",
+            TransitionPhase::Exited => "exited",
+        }
+    }
+}
+
+impl std::fmt::Display for TransitionPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How long each part of a transition created with [`use_transition`] takes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransitionOptions {
+    /// How long the [`TransitionPhase::Entering`] phase lasts. Defaults to 150ms
+    pub enter: Duration,
+    /// How long the [`TransitionPhase::Exiting`] phase lasts. Defaults to 150ms
+    pub exit: Duration,
+}
+
+impl TransitionOptions {
+    /// Use the same duration for both the enter and exit phases
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            enter: duration,
+            exit: duration,
+        }
+    }
+}
+
+impl Default for TransitionOptions {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(150))
+    }
+}
+
+/// A hook that tracks the enter and exit phases of some content that is shown while `show` is true.
+///
+/// When `show` becomes true the transition moves to [`TransitionPhase::Entering`] and then to
+/// [`TransitionPhase::Entered`] after [`TransitionOptions::enter`]. When `show` becomes false the transition moves
+/// to [`TransitionPhase::Exiting`] and then to [`TransitionPhase::Exited`] after [`TransitionOptions::exit`]. If
+/// `show` changes in the middle of a phase, the running timer is cancelled and the new phase starts immediately.
+///
+/// Keep rendering the content while [`Transition::is_present`] is true so the exit animation can play before the
+/// nodes are removed. [`AnimatePresence`] does this for you.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut open = use_signal(|| false);
+///     let transition = use_transition(open(), TransitionOptions::default());
+///
+///     rsx! {
+///         button { onclick: move |_| open.toggle(), "Toggle" }
+///         if transition.is_present() {
+///             // Style .fade.entering, .fade.exiting, etc. in your css
+///             div { class: "fade {transition.phase()}", "Hello!" }
+///         }
+///     }
+/// }
+/// ```
+#[doc = include_str!("../docs/rules_of_hooks.md")]
+pub fn use_transition(show: bool, options: TransitionOptions) -> Transition {
+    let mut transition = use_hook(|| {
+        let mut transition = Transition {
+            phase: Signal::new(match show {
+                true => TransitionPhase::Entering,
+                false => TransitionPhase::Exited,
+            }),
+            show: CopyValue::new(show),
+            task: CopyValue::new(None),
+            scope: current_scope_id(),
+        };
+        if show {
+            transition.start(TransitionPhase::Entering, options);
+        }
+        transition
+    });
+
+    // Start a new phase if the component passes in a different value
+    if *transition.show.peek() != show {
+        transition.show.set(show);
+        let phase = match show {
+            true => TransitionPhase::Entering,
+            false => TransitionPhase::Exiting,
+        };
+        transition.start(phase, options);
+    }
+
+    transition
+}
+
+/// A handle to a transition created with [`use_transition`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Transition {
+    phase: Signal<TransitionPhase>,
+    show: CopyValue<bool>,
+    task: CopyValue<Option<Task>>,
+    scope: ScopeId,
+}
+
+impl Transition {
+    /// Get the current phase of the transition. Reading the phase subscribes to it.
+    pub fn phase(&self) -> TransitionPhase {
+        (self.phase)()
+    }
+
+    /// Check if the content should still be rendered. This is true unless the transition has fully exited.
+    pub fn is_present(&self) -> bool {
+        *self.show.peek() || self.phase() != TransitionPhase::Exited
+    }
+
+    /// Move into `phase` immediately and spawn a timer that settles the phase once its duration has passed
+    fn start(&mut self, phase: TransitionPhase, options: TransitionOptions) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        }
+
+        let (duration, settled) = match phase {
+            TransitionPhase::Entering | TransitionPhase::Entered => {
+                (options.enter, TransitionPhase::Entered)
+            }
+            TransitionPhase::Exiting | TransitionPhase::Exited => {
+                (options.exit, TransitionPhase::Exited)
+            }
+        };
+
+        let mut signal = self.phase;
+        let task = Runtime::current().spawn(self.scope, async move {
+            signal.set(phase);
+            sleep(duration).await;
+            signal.set(settled);
+        });
+        self.task.set(Some(task));
+    }
+}
+
+/// Props for the [`AnimatePresence`] component
+#[derive(Props, Clone, PartialEq)]
+pub struct AnimatePresenceProps {
+    /// Whether the children should be shown
+    pub show: bool,
+    /// How long the enter and exit phases last
+    #[props(default)]
+    pub options: TransitionOptions,
+    /// Called once the exit phase finishes and the children are removed
+    #[props(default)]
+    pub onexited: EventHandler,
+    pub children: Element,
+}
+
+/// Keep children mounted while they animate out.
+///
+/// The children are rendered while `show` is true and stay in the tree for [`TransitionOptions::exit`] after `show`
+/// becomes false. The [`Transition`] is provided as context so any child component can read the current phase with
+/// `use_context::<Transition>()` and apply the matching css classes.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::time::Duration;
+/// fn App() -> Element {
+///     let mut open = use_signal(|| true);
+///
+///     rsx! {
+///         button { onclick: move |_| open.toggle(), "Toggle" }
+///         AnimatePresence {
+///             show: open(),
+///             options: TransitionOptions::new(Duration::from_millis(300)),
+///             onexited: move |_| println!("The modal is gone"),
+///             Modal {}
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn Modal() -> Element {
+///     let transition = use_context::<Transition>();
+///     rsx! {
+///         div { class: "modal {transition.phase()}", "Hello!" }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn AnimatePresence(props: AnimatePresenceProps) -> Element {
+    let transition = use_transition(props.show, props.options);
+    use_context_provider(|| transition);
+
+    // Keep the latest handler around so the effect below always calls the newest one
+    let onexited = props.onexited;
+    let onexited = use_callback(move |_| onexited.call(()));
+    use_effect_with_prev(move |prev: Option<&TransitionPhase>| {
+        let phase = transition.phase();
+        if prev == Some(&TransitionPhase::Exiting) && phase == TransitionPhase::Exited {
+            onexited.call(());
+        }
+        phase
+    });
+
+    if !transition.is_present() {
+        return VNode::empty();
+    }
+
+    props.children
+}
@@ -0,0 +1,163 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+thread_local! {
+    static TODOS: RefCell<Vec<Vec<&'static str>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn remove(todo: &'static str) -> impl FnOnce(&mut Vec<&'static str>) {
+    move |todos| todos.retain(|other| *other != todo)
+}
+
+async fn run(dom: &mut VirtualDom) {
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    }
+}
+
+#[tokio::test]
+async fn optimistic_updates_roll_back_on_error() {
+    fn app() -> Element {
+        let todos = use_signal(|| vec!["first"]);
+        let mut save = use_action(|todo: &'static str| async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            if todo == "fails" {
+                Err(dioxus_core::anyhow!("failed to save"))
+            } else {
+                Ok(todo)
+            }
+        });
+
+        use_hook(move || {
+            spawn(async move {
+                save.optimistic(todos, |todos| todos.push("second"), remove("second"))
+                    .call("second")
+                    .await;
+                save.optimistic(todos, |todos| todos.push("fails"), remove("fails"))
+                    .call("fails")
+                    .await;
+            })
+        });
+
+        TODOS.with(|renders| renders.borrow_mut().push(todos()));
+        rsx! {}
+    }
+
+    TODOS.with(|renders| renders.borrow_mut().clear());
+    run(&mut VirtualDom::new(app)).await;
+
+    TODOS.with(|renders| {
+        let renders = renders.borrow();
+        assert!(renders.contains(&vec!["first", "second", "fails"]));
+        assert_eq!(renders.last().unwrap(), &vec!["first", "second"]);
+    });
+}
+
+#[tokio::test]
+async fn rollback_keeps_changes_made_during_the_call() {
+    fn app() -> Element {
+        let mut todos = use_signal(|| vec!["first"]);
+        let mut save = use_action(|_: &'static str| async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Err::<(), _>(dioxus_core::anyhow!("failed to save"))
+        });
+
+        use_hook(move || {
+            save.optimistic(todos, |todos| todos.push("fails"), remove("fails"))
+                .call("fails");
+            // Another change lands while the call is in flight
+            todos.write().push("local");
+        });
+
+        TODOS.with(|renders| renders.borrow_mut().push(todos()));
+        rsx! {}
+    }
+
+    TODOS.with(|renders| renders.borrow_mut().clear());
+    run(&mut VirtualDom::new(app)).await;
+
+    TODOS.with(|renders| {
+        assert_eq!(renders.borrow().last().unwrap(), &vec!["first", "local"]);
+    });
+}
+
+#[tokio::test]
+async fn cancelled_calls_roll_back() {
+    fn app() -> Element {
+        let todos = use_signal(|| vec!["first"]);
+        let mut save = use_action(|todo: &'static str| async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok::<_, dioxus_core::CapturedError>(todo)
+        });
+
+        use_hook(move || {
+            spawn(async move {
+                save.optimistic(todos, |todos| todos.push("second"), remove("second"))
+                    .call("second");
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                save.cancel();
+            })
+        });
+
+        TODOS.with(|renders| renders.borrow_mut().push(todos()));
+        rsx! {}
+    }
+
+    TODOS.with(|renders| renders.borrow_mut().clear());
+    run(&mut VirtualDom::new(app)).await;
+
+    TODOS.with(|renders| {
+        let renders = renders.borrow();
+        assert!(renders.contains(&vec!["first", "second"]));
+        assert_eq!(renders.last().unwrap(), &vec!["first"]);
+    });
+}
+
+#[tokio::test]
+async fn earlier_calls_keep_running_without_cancel_previous() {
+    thread_local! {
+        static FINISHED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        static VALUES: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        let mut save = use_action(|todo: &'static str| async move {
+            let delay = if todo == "slow" { 40 } else { 10 };
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            FINISHED.with(|finished| finished.borrow_mut().push(todo));
+            Ok::<_, dioxus_core::CapturedError>(todo)
+        });
+
+        use_hook(move || {
+            save.set_cancel_previous(false);
+            save.call("slow");
+            save.call("fast");
+        });
+
+        if let Some(Ok(value)) = save.value() {
+            VALUES.with(|values| values.borrow_mut().push(value()));
+        }
+        rsx! {}
+    }
+
+    run(&mut VirtualDom::new(app)).await;
+
+    // Both calls finish, but the slow call's result doesn't replace the result of the newer call
+    FINISHED.with(|finished| assert_eq!(*finished.borrow(), ["fast", "slow"]));
+    VALUES.with(|values| {
+        let values = values.borrow();
+        assert!(!values.contains(&"slow"));
+        assert_eq!(values.last(), Some(&"fast"));
+    });
+}
@@ -0,0 +1,64 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+#[derive(Clone, PartialEq)]
+struct AppState {
+    user: &'static str,
+    count: i32,
+}
+
+thread_local! {
+    static CHILD_RENDERS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+#[tokio::test]
+async fn context_selector_only_reruns_on_selected_change() {
+    fn app() -> Element {
+        let mut state = use_context_provider(|| {
+            Signal::new(AppState {
+                user: "a",
+                count: 0,
+            })
+        });
+
+        use_hook(move || {
+            spawn(async move {
+                for _ in 0..3 {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    state.write().count += 1;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                state.write().user = "b";
+            })
+        });
+
+        rsx! {
+            Child {}
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        let user = use_context_selector(|state: &AppState| state.user);
+        CHILD_RENDERS.with(|renders| renders.borrow_mut().push(user()));
+        rsx! { "{user}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    CHILD_RENDERS.with(|renders| assert_eq!(*renders.borrow(), ["a", "b"]));
+}
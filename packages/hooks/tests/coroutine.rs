@@ -0,0 +1,68 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+
+#[tokio::test]
+async fn coroutine_restart_keeps_pending_messages() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |log: Rc<RefCell<Vec<(usize, usize)>>>| {
+            let mut runs = use_hook(|| Rc::new(RefCell::new(0)));
+            let mut coroutine = use_coroutine({
+                to_owned![log];
+                move |mut rx: UnboundedReceiver<usize>| {
+                    to_owned![log];
+                    let run = *runs.borrow();
+                    *runs.borrow_mut() += 1;
+                    async move {
+                        while let Some(message) = rx.next().await {
+                            log.borrow_mut().push((run, message));
+                        }
+                    }
+                }
+            });
+
+            use_hook(move || {
+                spawn(async move {
+                    coroutine.send(1);
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+
+                    // The first run never sees these messages, but the restarted run does
+                    coroutine.send(2);
+                    coroutine.send(3);
+                    coroutine.restart();
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+
+                    // Messages sent while the coroutine is cancelled are dropped
+                    coroutine.cancel();
+                    coroutine.send(4);
+                    coroutine.tx().unbounded_send(5).unwrap();
+                    coroutine.restart();
+                    coroutine.send(6);
+                })
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        log.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(*log.borrow(), [(0, 1), (1, 2), (1, 3), (2, 6)]);
+}
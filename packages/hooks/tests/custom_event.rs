@@ -0,0 +1,53 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+
+use dioxus::prelude::*;
+
+thread_local! {
+    static HANDLED: RefCell<Vec<(&'static str, u32)>> = const { RefCell::new(Vec::new()) };
+}
+
+#[test]
+fn custom_events_bubble_until_stopped() {
+    fn app() -> Element {
+        use_custom_event_handler(|event: CustomEvent<u32>| {
+            HANDLED.with(|handled| handled.borrow_mut().push(("app", *event.data())));
+        });
+        rsx! { Middle {} }
+    }
+
+    #[component]
+    fn Middle() -> Element {
+        use_custom_event_handler(|event: CustomEvent<u32>| {
+            HANDLED.with(|handled| handled.borrow_mut().push(("middle", *event.data())));
+            if *event.data() > 1 {
+                event.stop_propagation();
+            }
+        });
+        rsx! { Child {} }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        // The emitting component doesn't receive its own events
+        use_custom_event_handler(|_: CustomEvent<u32>| panic!("handled by the emitter"));
+        let emit = use_custom_event::<u32>();
+        let emit_other = use_custom_event::<String>();
+        use_hook(move || {
+            assert!(emit.emit(1));
+            assert!(emit.emit(2));
+            assert!(!emit_other.emit("unhandled".to_string()));
+        });
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    HANDLED.with(|handled| {
+        assert_eq!(
+            *handled.borrow(),
+            [("middle", 1), ("app", 1), ("middle", 2)]
+        );
+    });
+}
@@ -0,0 +1,81 @@
+use std::cell::Cell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+thread_local! {
+    static DEBOUNCED: Cell<Option<Debounced<u32>>> = const { Cell::new(None) };
+}
+
+async fn run_for(dom: &mut VirtualDom, duration: Duration) {
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(duration) => {}
+    };
+}
+
+fn debounced_dom() -> (VirtualDom, Debounced<u32>) {
+    let mut dom = VirtualDom::new(|| {
+        let debounced = use_debounce(Duration::from_millis(50), 0);
+        DEBOUNCED.with(|cell| cell.set(Some(debounced)));
+        rsx! {}
+    });
+    dom.rebuild_in_place();
+    let debounced = DEBOUNCED.with(Cell::get).unwrap();
+    (dom, debounced)
+}
+
+fn state(dom: &VirtualDom, debounced: Debounced<u32>) -> (u32, Option<u32>) {
+    dom.in_runtime(|| (*debounced.peek(), debounced.pending()))
+}
+
+#[tokio::test]
+async fn debounce_commits_after_the_delay() {
+    let (mut dom, mut debounced) = debounced_dom();
+
+    dom.in_runtime(|| debounced.set(1));
+    run_for(&mut dom, Duration::from_millis(20)).await;
+    assert_eq!(state(&dom, debounced), (0, Some(1)));
+
+    // Setting a new value restarts the delay
+    dom.in_runtime(|| debounced.set(2));
+    run_for(&mut dom, Duration::from_millis(35)).await;
+    assert_eq!(state(&dom, debounced), (0, Some(2)));
+
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(state(&dom, debounced), (2, None));
+}
+
+#[tokio::test]
+async fn cancel_drops_the_pending_value() {
+    let (mut dom, mut debounced) = debounced_dom();
+
+    dom.in_runtime(|| {
+        debounced.set(1);
+        debounced.cancel();
+    });
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(state(&dom, debounced), (0, None));
+}
+
+#[tokio::test]
+async fn flush_commits_right_away() {
+    let (mut dom, mut debounced) = debounced_dom();
+
+    dom.in_runtime(|| {
+        debounced.set(1);
+        debounced.flush();
+    });
+    assert_eq!(state(&dom, debounced), (1, None));
+
+    // The timer was cancelled, so nothing changes once the delay passes
+    dom.in_runtime(|| debounced.set(2));
+    dom.in_runtime(|| debounced.flush());
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(state(&dom, debounced), (2, None));
+}
@@ -103,3 +103,50 @@ async fn effects_rerun_without_rerender() {
     assert_eq!(current_counter.component, 1);
     assert_eq!(current_counter.effect, 11);
 }
+
+#[tokio::test]
+async fn effects_receive_previous_dependencies() {
+    type Runs = Rc<RefCell<Vec<(Option<i32>, i32)>>>;
+
+    let runs: Runs = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |runs: Runs| {
+            let mut signal = use_signal(|| 0);
+            use_effect_with_prev({
+                to_owned![runs];
+                move |prev: Option<&i32>| {
+                    let current = signal();
+                    runs.borrow_mut().push((prev.copied(), current));
+                    current
+                }
+            });
+
+            use_hook(move || {
+                spawn(async move {
+                    for _ in 0..2 {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        signal += 1;
+                    }
+                })
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        runs.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(*runs.borrow(), [(None, 0), (Some(0), 1), (Some(1), 2)]);
+}
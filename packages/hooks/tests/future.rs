@@ -0,0 +1,62 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+#[derive(Default)]
+struct FutureRuns {
+    runs: usize,
+    value: Option<i32>,
+}
+
+#[tokio::test]
+async fn future_runs_once_and_stores_its_value() {
+    let state = Rc::new(RefCell::new(FutureRuns::default()));
+    let mut dom = VirtualDom::new_with_props(
+        |state: Rc<RefCell<FutureRuns>>| {
+            let mut signal = use_signal(|| 1);
+            let future = use_future({
+                let state = state.clone();
+                move || {
+                    let state = state.clone();
+                    async move {
+                        state.borrow_mut().runs += 1;
+                        // Reading a signal inside the future doesn't subscribe to it
+                        signal() * 2
+                    }
+                }
+            });
+
+            // Writing to the signal after the future finishes doesn't restart the future
+            use_hook(|| {
+                spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    signal += 1;
+                })
+            });
+
+            state.borrow_mut().value = future.value().cloned();
+
+            rsx! {
+                div {}
+            }
+        },
+        state.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    let state = state.borrow();
+    assert_eq!(state.runs, 1);
+    assert_eq!(state.value, Some(2));
+}
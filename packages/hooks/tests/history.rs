@@ -0,0 +1,85 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+fn current<T: Clone + 'static>(history: HistoryHandle<T>) -> T {
+    history.current().cloned()
+}
+
+#[test]
+fn history_undoes_and_redoes() {
+    let mut dom = VirtualDom::new(|| {
+        let mut history = use_history(|| 0);
+        use_hook(|| {
+            history.set(1);
+            history.set(2);
+            assert!(history.undo());
+            assert_eq!(current(history), 1);
+            assert!(history.undo());
+            assert_eq!(current(history), 0);
+            assert!(!history.undo());
+
+            assert!(history.redo());
+            assert_eq!(current(history), 1);
+
+            // Setting a new value discards the values that could be redone
+            history.set(3);
+            assert!(!history.redo());
+            assert!(history.undo());
+            assert_eq!(current(history), 1);
+        });
+
+        rsx! {
+            div {}
+        }
+    });
+
+    dom.rebuild_in_place();
+}
+
+#[test]
+fn history_limits_depth_and_merges_changes() {
+    let mut dom = VirtualDom::new(|| {
+        let mut limited = use_history_with_options(
+            || 0,
+            HistoryOptions {
+                max_depth: 2,
+                coalesce: None,
+            },
+        );
+        let mut merged = use_history_with_options(
+            String::new,
+            HistoryOptions {
+                max_depth: 100,
+                coalesce: Some(Duration::from_secs(60)),
+            },
+        );
+        use_hook(|| {
+            for value in 1..=5 {
+                limited.set(value);
+            }
+            assert!(limited.undo());
+            assert!(limited.undo());
+            assert!(!limited.undo());
+            assert_eq!(current(limited), 3);
+
+            // Changes made right after each other are undone at once
+            merged.set("h".to_string());
+            merged.set("hi".to_string());
+            merged.checkpoint();
+            merged.set("hi!".to_string());
+            assert!(merged.undo());
+            assert_eq!(current(merged), "hi");
+            assert!(merged.undo());
+            assert_eq!(current(merged), "");
+            assert!(!merged.undo());
+        });
+
+        rsx! {
+            div {}
+        }
+    });
+
+    dom.rebuild_in_place();
+}
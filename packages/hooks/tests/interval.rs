@@ -0,0 +1,46 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+#[tokio::test]
+async fn interval_ticks_until_paused() {
+    let ticks = Rc::new(RefCell::new(0));
+    let mut dom = VirtualDom::new_with_props(
+        |ticks: Rc<RefCell<usize>>| {
+            let mut count = use_signal(|| 0);
+            let mut interval = use_interval(Duration::from_millis(10), {
+                to_owned![ticks];
+                move || {
+                    *ticks.borrow_mut() += 1;
+                    count += 1;
+                }
+            });
+
+            if count() >= 3 && !interval.paused() {
+                interval.pause();
+            }
+
+            rsx! {
+                div {}
+            }
+        },
+        ticks.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(200)) => {}
+    };
+
+    // The interval is paused during the render after the third tick
+    assert_eq!(*ticks.borrow(), 3);
+}
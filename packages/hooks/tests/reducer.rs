@@ -0,0 +1,80 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+#[tokio::test]
+async fn dispatch_during_render_is_deferred() {
+    let renders = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |renders: Rc<RefCell<Vec<i32>>>| {
+            let (count, dispatch) = use_reducer(|count: &i32, amount: i32| count + amount, || 0);
+
+            // Dispatching while rendering must not change the state until the render is finished
+            let value = count();
+            if value < 3 {
+                dispatch.call(1);
+            }
+            assert_eq!(*count.peek(), value);
+            renders.borrow_mut().push(value);
+
+            rsx! {
+                div {}
+            }
+        },
+        renders.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(*renders.borrow(), [0, 1, 2, 3]);
+}
+
+#[test]
+fn middleware_can_dispatch_follow_up_actions() {
+    type Handles = Rc<RefCell<Option<(ReadSignal<i32>, Dispatch<i32>)>>>;
+    let handles: Handles = Rc::new(RefCell::new(None));
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut dom = VirtualDom::new_with_props(
+        |(handles, log): (Handles, Rc<RefCell<Vec<(i32, i32, i32)>>>)| {
+            let follow_up = use_hook(|| Rc::new(RefCell::new(None::<Dispatch<i32>>)));
+            let (count, dispatch) =
+                use_reducer_with_middleware(|count: &i32, amount: i32| count + amount, || 0, {
+                    let follow_up = follow_up.clone();
+                    move |amount: &i32, old: &i32, new: &i32| {
+                        log.borrow_mut().push((*amount, *old, *new));
+                        // Round the count up to the next multiple of ten
+                        if new % 10 != 0 {
+                            let dispatch = follow_up.borrow().unwrap();
+                            dispatch.call(10 - new % 10);
+                        }
+                    }
+                });
+            follow_up.borrow_mut().replace(dispatch);
+            handles.borrow_mut().replace((count, dispatch));
+
+            rsx! {}
+        },
+        (handles.clone(), log.clone()),
+    );
+    dom.rebuild_in_place();
+
+    let (count, dispatch) = handles.borrow().unwrap();
+    dom.in_runtime(|| {
+        dispatch.call(3);
+        assert_eq!(*count.peek(), 10);
+    });
+    assert_eq!(*log.borrow(), [(3, 0, 3), (7, 3, 10)]);
+}
@@ -0,0 +1,595 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+#[tokio::test]
+async fn resource_retries_until_success() {
+    #[derive(Default)]
+    struct RunCounter {
+        attempts: usize,
+        retry_count: usize,
+        value: Option<Result<usize, ()>>,
+    }
+
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(
+        |counter: Rc<RefCell<RunCounter>>| {
+            let resource = use_resource_with_options(
+                ResourceOptions {
+                    max_retries: 5,
+                    backoff: BackoffStrategy::Fixed(Duration::from_millis(1)),
+                },
+                {
+                    to_owned![counter];
+                    move || {
+                        to_owned![counter];
+                        async move {
+                            let mut counter = counter.borrow_mut();
+                            counter.attempts += 1;
+                            if counter.attempts < 3 {
+                                Err(())
+                            } else {
+                                Ok(counter.attempts)
+                            }
+                        }
+                    }
+                },
+            );
+
+            let mut counter = counter.borrow_mut();
+            counter.retry_count = resource.retry_count();
+            counter.value = resource.cloned();
+
+            rsx! {
+                div {}
+            }
+        },
+        counter.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(200)) => {}
+    };
+
+    let counter = counter.borrow();
+    assert_eq!(counter.attempts, 3);
+    assert_eq!(counter.retry_count, 2);
+    assert_eq!(counter.value, Some(Ok(3)));
+}
+
+#[tokio::test]
+async fn resource_stops_after_max_retries() {
+    let attempts = Rc::new(RefCell::new(0));
+    let mut dom = VirtualDom::new_with_props(
+        |attempts: Rc<RefCell<usize>>| {
+            use_resource_with_options(
+                ResourceOptions {
+                    max_retries: 2,
+                    backoff: BackoffStrategy::Linear(Duration::from_millis(1)),
+                },
+                move || {
+                    to_owned![attempts];
+                    async move {
+                        *attempts.borrow_mut() += 1;
+                        Err::<(), ()>(())
+                    }
+                },
+            );
+
+            rsx! {
+                div {}
+            }
+        },
+        attempts.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(200)) => {}
+    };
+
+    // The first attempt plus two retries
+    assert_eq!(*attempts.borrow(), 3);
+}
+
+#[test]
+fn backoff_delays() {
+    let base = Duration::from_millis(100);
+    assert_eq!(BackoffStrategy::Fixed(base).delay(3), base);
+    assert_eq!(BackoffStrategy::Linear(base).delay(3), base * 3);
+
+    let exponential = BackoffStrategy::Exponential {
+        initial: base,
+        max: Duration::from_millis(500),
+        jitter: false,
+    };
+    assert_eq!(exponential.delay(1), base);
+    assert_eq!(exponential.delay(3), base * 4);
+    assert_eq!(exponential.delay(10), Duration::from_millis(500));
+
+    let jittered = BackoffStrategy::Exponential {
+        initial: base,
+        max: Duration::from_millis(500),
+        jitter: true,
+    };
+    for attempt in 1..10 {
+        let delay = jittered.delay(attempt);
+        let max = exponential.delay(attempt);
+        assert!(delay <= max && delay >= max / 2);
+    }
+}
+
+#[tokio::test]
+async fn polled_resource_reruns_until_paused() {
+    let runs = Rc::new(RefCell::new(0));
+    let mut dom = VirtualDom::new_with_props(
+        |runs: Rc<RefCell<usize>>| {
+            let mut resource = use_resource_polled(Duration::from_millis(10), {
+                to_owned![runs];
+                move || {
+                    to_owned![runs];
+                    async move {
+                        *runs.borrow_mut() += 1;
+                        *runs.borrow()
+                    }
+                }
+            });
+
+            if resource().is_some_and(|runs| runs >= 3) && resource.polling() {
+                resource.pause_polling();
+            }
+
+            rsx! {
+                div {}
+            }
+        },
+        runs.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(200)) => {}
+    };
+
+    assert_eq!(*runs.borrow(), 3);
+}
+
+#[tokio::test]
+async fn restarting_resource_aborts_previous_run() {
+    let aborted = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |aborted: Rc<RefCell<Vec<usize>>>| {
+            let mut count = use_signal(|| 0);
+            use_resource_with_abort(move |abort| {
+                to_owned![aborted];
+                let run = count();
+                abort.on_abort(move || aborted.borrow_mut().push(run));
+                async move {
+                    // Never finish so the run is aborted by the restart
+                    std::future::pending::<()>().await;
+                }
+            });
+
+            use_hook(move || {
+                spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    count += 1;
+                })
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        aborted.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    // Only the first run was aborted, the second is still in flight
+    assert_eq!(*aborted.borrow(), [0]);
+}
+
+#[tokio::test]
+async fn memo_async_keeps_last_value_on_error() {
+    type Seen = Rc<RefCell<Vec<(Option<usize>, Option<&'static str>)>>>;
+
+    let seen: Seen = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |seen: Seen| {
+            let mut count = use_signal(|| 0);
+            let memo = use_memo_async(move || async move {
+                match count() {
+                    1 => Err("failed"),
+                    count => Ok(count),
+                }
+            });
+
+            use_hook(move || {
+                spawn(async move {
+                    for _ in 0..2 {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        count += 1;
+                    }
+                })
+            });
+
+            if !memo.is_revalidating() {
+                seen.borrow_mut()
+                    .push((memo.value().cloned(), memo.error().cloned()));
+            }
+
+            rsx! {
+                div {}
+            }
+        },
+        seen.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    let seen = seen.borrow();
+    assert_eq!(seen.first(), Some(&(Some(0), None)));
+    assert!(seen.contains(&(Some(0), Some("failed"))));
+    assert_eq!(seen.last(), Some(&(Some(2), None)));
+}
+
+#[tokio::test]
+async fn invalidating_a_key_restarts_keyed_resources() {
+    let runs = Rc::new(RefCell::new(0));
+    let mut dom = VirtualDom::new_with_props(
+        |runs: Rc<RefCell<usize>>| {
+            let client = use_query_client();
+            let resource = use_resource_keyed("count", {
+                to_owned![runs];
+                move || {
+                    to_owned![runs];
+                    async move {
+                        *runs.borrow_mut() += 1;
+                        *runs.borrow()
+                    }
+                }
+            });
+
+            // Invalidate the key once after the first fetch finishes
+            let mut invalidated = use_signal(|| false);
+            if resource() == Some(1) && !invalidated() {
+                invalidated.set(true);
+                client.invalidate("count");
+            }
+
+            rsx! {
+                div {}
+            }
+        },
+        runs.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    assert_eq!(*runs.borrow(), 2);
+}
+
+#[tokio::test]
+async fn cached_resources_restore_their_value_when_remounted() {
+    type Seen = Rc<RefCell<Vec<(usize, Option<usize>)>>>;
+
+    #[component]
+    fn Child(
+        generation: usize,
+        remount: Signal<usize>,
+        runs: Rc<RefCell<usize>>,
+        seen: Seen,
+    ) -> Element {
+        let resource = use_resource_cached("count", Duration::ZERO, {
+            to_owned![runs];
+            move || {
+                to_owned![runs];
+                async move {
+                    *runs.borrow_mut() += 1;
+                    *runs.borrow()
+                }
+            }
+        });
+
+        let value = resource();
+        let mut seen = seen.borrow_mut();
+        if seen.last() != Some(&(generation, value)) {
+            seen.push((generation, value));
+        }
+
+        // Unmount the component and mount a new one once the first fetch finishes
+        if generation == 0 && value == Some(1) {
+            let mut remount = remount;
+            remount.set(1);
+        }
+
+        rsx! {}
+    }
+
+    let seen = Seen::default();
+    let mut dom = VirtualDom::new_with_props(
+        |seen: Seen| {
+            let remount = use_signal(|| 0);
+            let runs = use_hook(|| Rc::new(RefCell::new(0)));
+
+            if remount() == 0 {
+                rsx! { Child { generation: 0, remount, runs, seen } }
+            } else {
+                rsx! { Child { generation: 1, remount, runs, seen } }
+            }
+        },
+        seen.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+
+    // The new component starts with the cached value and refetches it in the background because it is stale
+    assert_eq!(
+        *seen.borrow(),
+        [(0, None), (0, Some(1)), (1, Some(1)), (1, Some(2))]
+    );
+}
+
+#[tokio::test]
+async fn resource_status_tracks_reloading_and_errors() {
+    #[derive(Default)]
+    struct Statuses {
+        seen: Vec<String>,
+    }
+
+    let statuses = Rc::new(RefCell::new(Statuses::default()));
+    let mut dom = VirtualDom::new_with_props(
+        |statuses: Rc<RefCell<Statuses>>| {
+            let mut attempt = use_signal(|| 0);
+            let mut resource = use_resource(move || async move {
+                match attempt() {
+                    0 => Ok(1),
+                    _ => {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        Err("failed")
+                    }
+                }
+            });
+
+            let status = match resource.status() {
+                ResourceStatus::Pending => "pending".to_string(),
+                ResourceStatus::Reloading(value) => format!("reloading {value}"),
+                ResourceStatus::Ready(value) => format!("ready {value}"),
+                ResourceStatus::Failed(err) => format!("failed {err}"),
+            };
+            let mut statuses = statuses.borrow_mut();
+            if statuses.seen.last() != Some(&status) {
+                statuses.seen.push(status);
+            }
+
+            // Rerun the resource once it succeeds the first time
+            use_effect(move || {
+                if resource.finished() && attempt() == 0 && resource.value().read().is_some() {
+                    attempt.set(1);
+                }
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        statuses.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(200)) => {}
+    };
+
+    assert_eq!(
+        statuses.borrow().seen,
+        ["pending", "ready 1", "reloading 1", "failed failed"]
+    );
+}
+
+#[tokio::test]
+async fn resource_with_deps_only_restarts_when_deps_change() {
+    let runs = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |runs: Rc<RefCell<Vec<(u32, u32)>>>| {
+            let mut dependency = use_signal(|| 0);
+            let mut untracked = use_signal(|| 0);
+
+            use_resource_with_deps((&dependency(),), move |(dependency,)| {
+                to_owned![runs];
+                async move {
+                    runs.borrow_mut().push((dependency, untracked()));
+                }
+            });
+
+            use_hook(|| {
+                spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    // Signals read inside of the future don't restart the resource
+                    untracked += 1;
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    dependency += 1;
+                })
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        runs.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(200)) => {}
+    };
+
+    assert_eq!(*runs.borrow(), vec![(0, 0), (1, 1)]);
+}
+
+#[tokio::test]
+async fn debounced_resource_waits_for_deps_to_settle() {
+    let runs = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |runs: Rc<RefCell<Vec<u32>>>| {
+            let mut dependency = use_signal(|| 0);
+
+            use_debounced_resource(
+                Duration::from_millis(50),
+                (&dependency(),),
+                move |(dependency,)| {
+                    to_owned![runs];
+                    async move {
+                        runs.borrow_mut().push(dependency);
+                    }
+                },
+            );
+
+            use_hook(|| {
+                spawn(async move {
+                    // Changes within the delay cancel the pending run
+                    for _ in 0..3 {
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        dependency += 1;
+                    }
+                })
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        runs.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(300)) => {}
+    };
+
+    assert_eq!(*runs.borrow(), vec![0, 3]);
+}
+
+#[tokio::test]
+async fn debounced_resource_restarts_right_away() {
+    let runs = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |runs: Rc<RefCell<Vec<u32>>>| {
+            let dependency = use_signal(|| 0);
+
+            let mut resource = use_debounced_resource(
+                Duration::from_millis(100),
+                (&dependency(),),
+                move |(dependency,)| {
+                    to_owned![runs];
+                    async move {
+                        runs.borrow_mut().push(dependency);
+                    }
+                },
+            );
+
+            use_hook(|| {
+                spawn(async move {
+                    // Restarting the resource yourself doesn't wait for the delay
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    resource.restart();
+                })
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        runs.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(50)) => {}
+    };
+
+    assert_eq!(*runs.borrow(), vec![0, 0]);
+}
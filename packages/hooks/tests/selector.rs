@@ -0,0 +1,94 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::Cell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+const WORDS: &[&str] = &["rust", "ruby", "go"];
+
+thread_local! {
+    static QUERY: Cell<Option<Signal<&'static str>>> = const { Cell::new(None) };
+    static COMPUTES: Cell<usize> = const { Cell::new(0) };
+    static RENDERS: Cell<usize> = const { Cell::new(0) };
+}
+
+async fn set_query(dom: &mut VirtualDom, value: &'static str) {
+    let mut query = QUERY.with(Cell::get).unwrap();
+    dom.in_runtime(|| query.set(value));
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(20)) => {}
+    };
+}
+
+fn counts() -> (usize, usize) {
+    (COMPUTES.with(Cell::get), RENDERS.with(Cell::get))
+}
+
+#[tokio::test]
+async fn selector_recomputes_only_when_the_dependency_output_changes() {
+    let mut dom = VirtualDom::new(|| {
+        let query = use_signal(|| "");
+        QUERY.with(|cell| cell.set(Some(query)));
+        RENDERS.with(|renders| renders.set(renders.get() + 1));
+
+        let matches = use_selector(
+            move || query().trim().to_lowercase(),
+            |query| {
+                COMPUTES.with(|computes| computes.set(computes.get() + 1));
+                WORDS
+                    .iter()
+                    .filter(|word| word.starts_with(query.as_str()))
+                    .count()
+            },
+        );
+
+        rsx! { "{matches} matches" }
+    });
+    dom.rebuild_in_place();
+    assert_eq!(counts(), (1, 1));
+
+    set_query(&mut dom, "R").await;
+    assert_eq!(counts(), (2, 2));
+
+    // The query changed, but the output of the dependencies is the same, so nothing is computed
+    set_query(&mut dom, " r ").await;
+    assert_eq!(counts(), (2, 2));
+
+    // The output of the dependencies changed, but the result is the same, so the component doesn't rerun
+    set_query(&mut dom, "ru").await;
+    assert_eq!(counts(), (3, 2));
+
+    set_query(&mut dom, "g").await;
+    assert_eq!(counts(), (4, 3));
+}
+
+#[tokio::test]
+async fn signals_read_while_computing_are_not_tracked() {
+    let mut dom = VirtualDom::new(|| {
+        let query = use_signal(|| "");
+        QUERY.with(|cell| cell.set(Some(query)));
+        let length = use_signal(|| 0);
+
+        let selected = use_selector(
+            move || length(),
+            move |length| {
+                COMPUTES.with(|computes| computes.set(computes.get() + 1));
+                format!("{}:{length}", query())
+            },
+        );
+        _ = selected();
+
+        rsx! {}
+    });
+    dom.rebuild_in_place();
+    assert_eq!(COMPUTES.with(Cell::get), 1);
+
+    set_query(&mut dom, "rust").await;
+    assert_eq!(COMPUTES.with(Cell::get), 1);
+}
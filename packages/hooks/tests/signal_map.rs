@@ -0,0 +1,67 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+fn to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+#[tokio::test]
+async fn signal_map_syncs_both_directions() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |seen: Rc<RefCell<Vec<(f64, f64)>>>| {
+            let mut celsius = use_signal(|| 0.0);
+            let mut fahrenheit =
+                use_signal_map(celsius, |c| to_fahrenheit(*c), |f| (f - 32.0) * 5.0 / 9.0);
+            seen.borrow_mut().push((celsius(), fahrenheit()));
+
+            use_hook(|| {
+                spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    // Both sides are updated as soon as either one is written
+                    celsius.set(100.0);
+                    assert_eq!(fahrenheit(), 212.0);
+                    assert_eq!(*fahrenheit.peek(), 212.0);
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    fahrenheit.set(50.0);
+                    assert_eq!(celsius(), 10.0);
+                    assert_eq!(fahrenheit(), 50.0);
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    fahrenheit += 18.0;
+                    assert_eq!(celsius(), 20.0);
+                })
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        seen.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(200)) => {}
+    };
+
+    let seen = seen.borrow();
+    // No render ever sees one side updated without the other
+    for &(celsius, fahrenheit) in seen.iter() {
+        assert!(
+            (to_fahrenheit(celsius) - fahrenheit).abs() < 1e-9,
+            "{celsius}°C rendered as {fahrenheit}°F"
+        );
+    }
+    let celsius: Vec<f64> = seen.iter().map(|(c, _)| *c).collect();
+    assert_eq!(celsius, [0.0, 100.0, 10.0, 20.0]);
+}
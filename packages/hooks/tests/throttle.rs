@@ -0,0 +1,61 @@
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+thread_local! {
+    static THROTTLED: Cell<Option<Callback<u32>>> = const { Cell::new(None) };
+    static CALLS: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+}
+
+async fn run_for(dom: &mut VirtualDom, duration: Duration) {
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(duration) => {}
+    };
+}
+
+fn calls() -> Vec<u32> {
+    CALLS.with(|calls| calls.borrow().clone())
+}
+
+#[tokio::test]
+async fn throttle_runs_leading_and_trailing_calls() {
+    let mut dom = VirtualDom::new(|| {
+        let throttled = use_throttled_callback(Duration::from_millis(50), |value: u32| {
+            CALLS.with(|calls| calls.borrow_mut().push(value))
+        });
+        THROTTLED.with(|cell| cell.set(Some(throttled)));
+        rsx! {}
+    });
+    dom.rebuild_in_place();
+    let throttled = THROTTLED.with(Cell::get).unwrap();
+
+    // The first call runs right away and the calls inside the interval wait
+    dom.in_runtime(|| {
+        throttled.call(1);
+        throttled.call(2);
+        throttled.call(3);
+    });
+    assert_eq!(calls(), [1]);
+
+    // Only the latest call inside the interval runs once it passes
+    run_for(&mut dom, Duration::from_millis(75)).await;
+    assert_eq!(calls(), [1, 3]);
+
+    // The trailing call starts another interval
+    dom.in_runtime(|| throttled.call(4));
+    assert_eq!(calls(), [1, 3]);
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(calls(), [1, 3, 4]);
+
+    // Once a full interval passes without calls, the next call runs right away again
+    run_for(&mut dom, Duration::from_millis(100)).await;
+    dom.in_runtime(|| throttled.call(5));
+    assert_eq!(calls(), [1, 3, 4, 5]);
+}
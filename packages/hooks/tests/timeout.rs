@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+async fn run_for(dom: &mut VirtualDom, duration: Duration) {
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(duration) => {}
+    };
+}
+
+#[tokio::test]
+async fn timeout_fires_once() {
+    let fired = Rc::new(RefCell::new(0));
+    let mut dom = VirtualDom::new_with_props(
+        |fired: Rc<RefCell<usize>>| {
+            let mut hidden = use_signal(|| false);
+            use_timeout(Duration::from_millis(10), move || {
+                *fired.borrow_mut() += 1;
+                hidden.set(true);
+            });
+
+            rsx! {
+                if !hidden() {
+                    "Saved!"
+                }
+            }
+        },
+        fired.clone(),
+    );
+
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    assert_eq!(*fired.borrow(), 1);
+}
+
+#[tokio::test]
+async fn canceled_timeout_does_not_fire() {
+    let fired = Rc::new(RefCell::new(false));
+    let mut dom = VirtualDom::new_with_props(
+        |fired: Rc<RefCell<bool>>| {
+            let mut timeout = use_timeout(Duration::from_millis(10), move || {
+                *fired.borrow_mut() = true;
+            });
+            use_effect(move || timeout.cancel());
+
+            rsx! {
+                div {}
+            }
+        },
+        fired.clone(),
+    );
+
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    assert!(!*fired.borrow());
+}
+
+#[tokio::test]
+async fn timeout_can_be_reset_after_firing() {
+    let fired = Rc::new(RefCell::new(0));
+    let mut dom = VirtualDom::new_with_props(
+        |fired: Rc<RefCell<usize>>| {
+            // The callback doesn't write to any signals, so the component never reruns
+            let mut timeout = use_timeout(Duration::from_millis(10), move || {
+                *fired.borrow_mut() += 1;
+            });
+            use_hook(|| {
+                spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(30)).await;
+                    assert!(!timeout.is_pending());
+                    timeout.reset();
+                })
+            });
+
+            rsx! {
+                div {}
+            }
+        },
+        fired.clone(),
+    );
+
+    dom.rebuild_in_place();
+    run_for(&mut dom, Duration::from_millis(100)).await;
+
+    assert_eq!(*fired.borrow(), 2);
+}
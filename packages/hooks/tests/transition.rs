@@ -0,0 +1,55 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+
+#[tokio::test]
+async fn transition_enters_then_exits() {
+    let phases = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        |phases: Rc<RefCell<Vec<(TransitionPhase, bool)>>>| {
+            let mut show = use_signal(|| true);
+            let transition =
+                use_transition(show(), TransitionOptions::new(Duration::from_millis(10)));
+
+            let phase = transition.phase();
+            let mut phases = phases.borrow_mut();
+            if phases.last().map(|(last, _)| *last) != Some(phase) {
+                phases.push((phase, transition.is_present()));
+            }
+
+            // Hide the content once it has fully entered
+            if phase == TransitionPhase::Entered && *show.peek() {
+                spawn(async move { show.set(false) });
+            }
+
+            rsx! {
+                div {}
+            }
+        },
+        phases.clone(),
+    );
+
+    dom.rebuild_in_place();
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(Duration::from_millis(200)) => {}
+    };
+
+    assert_eq!(
+        *phases.borrow(),
+        [
+            (TransitionPhase::Entering, true),
+            (TransitionPhase::Entered, true),
+            (TransitionPhase::Exiting, true),
+            (TransitionPhase::Exited, false),
+        ]
+    );
+}
@@ -42,8 +42,13 @@ impl ToTokens for ImplExtensionAttributes {
 
         let impls = self.attrs.iter().map(|ident| {
             let d = quote! { #name::#ident };
+            // Raw HTML has to be wrapped in UnsafeHtml
+            let value = match ident == "dangerous_inner_html" {
+                true => quote! { impl dioxus_core::InnerHtmlValue },
+                false => quote! { impl IntoAttributeValue },
+            };
             quote! {
-                fn #ident(self, value: impl IntoAttributeValue) -> Self {
+                fn #ident(self, value: #value) -> Self {
                     let d = #d;
                     self.push_attribute(d.0, d.1, value, d.2)
                 }
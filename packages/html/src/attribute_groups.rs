@@ -359,7 +359,11 @@ mod_methods! {
     /// dangerous_inner_html is Dioxus's replacement for using innerHTML in the browser DOM. In general, setting
     /// HTML from code is risky because it’s easy to inadvertently expose your users to a cross-site scripting (XSS)
     /// attack. So, you can set HTML directly from Dioxus, but you have to type out dangerous_inner_html to remind
-    /// yourself that it’s dangerous
+    /// yourself that it’s dangerous.
+    ///
+    /// The value replaces the contents of the element and is not escaped, so never pass it untrusted input. Anything
+    /// other than a string literal must be wrapped in [`UnsafeHtml`](dioxus_core::UnsafeHtml) to mark where the raw
+    /// HTML comes from.
     dangerous_inner_html;
 
     // This macro creates an explicit method call for each of the style attributes.
@@ -1763,7 +1767,11 @@ mod_methods! {
     /// dangerous_inner_html is Dioxus's replacement for using innerHTML in the browser DOM. In general, setting
     /// HTML from code is risky because it’s easy to inadvertently expose your users to a cross-site scripting (XSS)
     /// attack. So, you can set HTML directly from Dioxus, but you have to type out dangerous_inner_html to remind
-    /// yourself that it’s dangerous
+    /// yourself that it’s dangerous.
+    ///
+    /// The value replaces the contents of the element and is not escaped, so never pass it untrusted input. Anything
+    /// other than a string literal must be wrapped in [`UnsafeHtml`](dioxus_core::UnsafeHtml) to mark where the raw
+    /// HTML comes from.
     dangerous_inner_html;
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accent-height>
@@ -0,0 +1,19 @@
+/// Whether a `use_before_unload` handler should stop the user from leaving the page or closing the window
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShouldPrevent {
+    /// Stop the page from unloading. The web renderer asks the browser to show its confirmation dialog and the
+    /// desktop renderer keeps the window open.
+    Yes,
+    /// Let the page unload
+    No,
+}
+
+impl From<bool> for ShouldPrevent {
+    /// `true` prevents unloading and `false` allows it
+    fn from(prevent: bool) -> Self {
+        match prevent {
+            true => ShouldPrevent::Yes,
+            false => ShouldPrevent::No,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Helpers for two way binding with the `bind:value` and `bind:checked` attributes in rsx.
+//!
+//! `bind:value: signal` sets the value of an input to the signal and updates the signal when the input changes.
+//! The text of the input is parsed into the type of the signal with [`FromStr`], so a `Signal<f64>` bound to a
+//! number input always holds a number. If the text can't be parsed, the signal is left unchanged and a warning is
+//! logged. `bind:checked: signal` does the same for the checked state of a checkbox with a `bool` signal.
+//!
+//! ```rust, no_run
+//! # use dioxus::prelude::*;
+//! fn App() -> Element {
+//!     let name = use_signal(String::new);
+//!     let age = use_signal(|| 18u32);
+//!     let subscribed = use_signal(|| false);
+//!
+//!     rsx! {
+//!         input { bind:value: name }
+//!         input { r#type: "number", bind:value: age }
+//!         input { r#type: "checkbox", bind:checked: subscribed }
+//!         "{name} is {age} years old"
+//!     }
+//! }
+//! ```
+//!
+//! The bound expression is used twice, so it should be a `Copy` handle like a [`Signal`](dioxus_signals::Signal).
+//! Binding adds an `oninput` listener to the element, so you shouldn't add another `oninput` listener to the same
+//! element.
+
+use crate::FormEvent;
+use dioxus_signals::{Readable, ReadableExt, Writable, WritableExt};
+use std::{fmt::Display, str::FromStr};
+
+/// Read the value of a signal bound with `bind:value`
+#[doc(hidden)]
+pub fn value<R>(signal: &R) -> String
+where
+    R: Readable,
+    R::Target: Display + 'static,
+{
+    signal.read().to_string()
+}
+
+/// Create the input listener for a signal bound with `bind:value`
+#[doc(hidden)]
+pub fn on_value_input<W>(mut signal: W) -> impl FnMut(FormEvent) + 'static
+where
+    W: Writable + 'static,
+    W::Target: FromStr + PartialEq + Sized + 'static,
+    <W::Target as FromStr>::Err: Display,
+{
+    move |event: FormEvent| {
+        let text = event.value();
+        match text.parse::<W::Target>() {
+            // Only write to the signal if the value changed. Otherwise, text like `1.` that parses to the current
+            // value would be replaced with `1` when the component reruns
+            Ok(new) => {
+                if *signal.peek() != new {
+                    signal.set(new);
+                }
+            }
+            Err(err) => tracing::warn!("Failed to parse {text:?} for bind:value: {err}"),
+        }
+    }
+}
+
+/// Read the checked state of a signal bound with `bind:checked`
+#[doc(hidden)]
+pub fn checked<R>(signal: &R) -> bool
+where
+    R: Readable<Target = bool>,
+{
+    *signal.read()
+}
+
+/// Create the input listener for a signal bound with `bind:checked`
+#[doc(hidden)]
+pub fn on_checked_input<W>(mut signal: W) -> impl FnMut(FormEvent) + 'static
+where
+    W: Writable<Target = bool> + 'static,
+{
+    move |event: FormEvent| signal.set(event.checked())
+}
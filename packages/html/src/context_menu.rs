@@ -0,0 +1,118 @@
+use crate::geometry::{ClientPoint, PixelsRect, PixelsSize};
+use crate::{InteractionLocation, MouseEvent};
+use dioxus_core::{Attribute, use_hook};
+use dioxus_signals::*;
+
+/// Track the open state and position of a custom right click menu.
+///
+/// Spread [`ContextMenu::attributes`] onto the element that opens the menu. Right clicking the element prevents the
+/// browser's menu and opens the context menu at the pointer. Render your menu with `position: fixed` at
+/// [`ContextMenu::position`] while it is open, and call [`ContextMenu::close`] when an item is picked or the user
+/// clicks outside of it.
+///
+/// Menus opened near the edge of the window can be flipped back into view with [`ContextMenu::fit`] once you know
+/// the size of the menu.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::html::use_context_menu;
+///
+/// fn App() -> Element {
+///     let mut menu = use_context_menu();
+///
+///     rsx! {
+///         div { class: "canvas", onclick: move |_| menu.close(), ..menu.attributes(),
+///             "Right click anywhere"
+///         }
+///         if let Some(position) = menu.position() {
+///             ul {
+///                 role: "menu",
+///                 style: "position: fixed; left: {position.x}px; top: {position.y}px",
+///                 li { onclick: move |_| menu.close(), "Copy" }
+///                 li { onclick: move |_| menu.close(), "Paste" }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_context_menu() -> ContextMenu {
+    use_hook(|| ContextMenu {
+        position: Signal::new(None),
+    })
+}
+
+/// A context menu created with [`use_context_menu`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ContextMenu {
+    position: Signal<Option<ClientPoint>>,
+}
+
+impl ContextMenu {
+    /// The attributes to spread onto the element that opens the menu when it is right clicked
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let mut menu = *self;
+        vec![crate::events::oncontextmenu(move |event: MouseEvent| {
+            event.prevent_default();
+            menu.open(event.client_coordinates());
+        })]
+    }
+
+    /// Open the menu at `position` in client coordinates
+    pub fn open(&mut self, position: ClientPoint) {
+        self.position.set(Some(position));
+    }
+
+    /// Close the menu
+    pub fn close(&mut self) {
+        if self.position.peek().is_some() {
+            self.position.set(None);
+        }
+    }
+
+    /// Check if the menu is open.
+    ///
+    /// Reading this will subscribe the current scope to changes in the menu.
+    pub fn is_open(&self) -> bool {
+        self.position.read().is_some()
+    }
+
+    /// Get the position the menu was opened at in client coordinates, or `None` if the menu is closed.
+    ///
+    /// Reading this will subscribe the current scope to changes in the menu.
+    pub fn position(&self) -> Option<ClientPoint> {
+        self.position.cloned()
+    }
+
+    /// Get the position of a menu with the size `menu` that keeps it inside of `bounds`, or `None` if the menu is
+    /// closed. See [`fit_context_menu`] for details.
+    ///
+    /// Reading this will subscribe the current scope to changes in the menu.
+    pub fn fit(&self, menu: PixelsSize, bounds: PixelsRect) -> Option<ClientPoint> {
+        self.position()
+            .map(|position| fit_context_menu(position, menu, bounds))
+    }
+}
+
+/// Position a menu with the size `menu` that was opened at `anchor` so it stays inside of `bounds`.
+///
+/// The menu normally opens below and to the right of the anchor. If it would overflow the right or bottom edge of
+/// `bounds`, it flips to the other side of the anchor. If the menu doesn't fit on either side, it is pushed against
+/// the top or left edge of `bounds`. Both the anchor and `bounds` should be in client coordinates. The size of the
+/// menu and the bounds of its container can be measured with
+/// [`MountedData::get_client_rect`](crate::MountedData::get_client_rect).
+pub fn fit_context_menu(anchor: ClientPoint, menu: PixelsSize, bounds: PixelsRect) -> ClientPoint {
+    fn fit_axis(anchor: f64, size: f64, min: f64, max: f64) -> f64 {
+        let position = match anchor + size > max {
+            true => anchor - size,
+            false => anchor,
+        };
+        position.max(min)
+    }
+
+    ClientPoint::new(
+        fit_axis(anchor.x, menu.width, bounds.min_x(), bounds.max_x()),
+        fit_axis(anchor.y, menu.height, bounds.min_y(), bounds.max_y()),
+    )
+}
@@ -0,0 +1,115 @@
+use dioxus_core::{Callback, Element};
+use dioxus_core_macro::{Props, rsx};
+use dioxus_signals::*;
+
+/// Props for the [`Show`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct ShowProps {
+    /// Whether to render the children. The fallback is rendered instead when this is `false`.
+    pub when: ReadSignal<bool>,
+    /// What to render when `when` is `false`. Nothing is rendered by default.
+    #[props(default)]
+    pub fallback: Element,
+    /// What to render when `when` is `true`
+    pub children: Element,
+}
+
+/// Render the children when a condition is true, and an optional fallback when it is false.
+///
+/// The condition is read inside of `Show`, so passing a signal to `when` only reruns `Show` when the signal changes
+/// instead of the component that renders it.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut logged_in = use_signal(|| false);
+///
+///     rsx! {
+///         Show {
+///             when: logged_in,
+///             fallback: rsx! { button { onclick: move |_| logged_in.set(true), "Log in" } },
+///             "Welcome back!"
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Show(props: ShowProps) -> Element {
+    match (props.when)() {
+        true => props.children,
+        false => props.fallback,
+    }
+}
+
+/// Props for the [`For`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct ForProps<T: Clone + PartialEq + 'static> {
+    /// The items to render
+    pub each: ReadSignal<Vec<T>>,
+    /// Get the unique key of an item. Keys let the list move existing items instead of rerendering them when items
+    /// are added, removed, or reordered.
+    pub key: Callback<T, String>,
+    /// Render an item
+    pub render: Callback<T, Element>,
+}
+
+/// Render a keyed list of items.
+///
+/// Each item is rendered in its own component, so when the list changes, only items that were added or whose value
+/// changed are rerendered. Items that stayed the same are kept as they are, even if they moved to a new position in
+/// the list. Like [`Show`], the list is read inside of `For`, so changing it doesn't rerun the component that renders
+/// `For`.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, PartialEq)]
+/// struct Todo {
+///     id: u32,
+///     text: String,
+/// }
+///
+/// fn App() -> Element {
+///     let todos = use_signal(|| {
+///         vec![
+///             Todo { id: 0, text: "Write docs".to_string() },
+///             Todo { id: 1, text: "Ship it".to_string() },
+///         ]
+///     });
+///
+///     rsx! {
+///         ul {
+///             For {
+///                 each: todos,
+///                 key: |todo: Todo| todo.id.to_string(),
+///                 render: |todo: Todo| rsx! { li { "{todo.text}" } },
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn For<T: Clone + PartialEq + 'static>(props: ForProps<T>) -> Element {
+    let render = props.render;
+    let items = props.each.read();
+    let keyed = items
+        .iter()
+        .map(|item| (props.key.call(item.clone()), item.clone()));
+
+    rsx! {
+        for (key, item) in keyed {
+            ForItem::<T> { key: "{key}", item, render }
+        }
+    }
+}
+
+#[derive(Clone, Props, PartialEq)]
+struct ForItemProps<T: Clone + PartialEq + 'static> {
+    item: T,
+    render: Callback<T, Element>,
+}
+
+/// A single item in a [`For`] list. Items are memoized by their props, so they only rerun when their value changes.
+#[allow(non_snake_case)]
+fn ForItem<T: Clone + PartialEq + 'static>(props: ForItemProps<T>) -> Element {
+    props.render.call(props.item)
+}
@@ -33,6 +33,11 @@ impl DataTransfer {
         self.get_data("text/plain")
     }
 
+    /// The formats of the string data held by this transfer, like `text/plain` or `text/html`
+    pub fn types(&self) -> Vec<String> {
+        self.inner.types()
+    }
+
     pub fn set_data(&self, format: &str, data: &str) -> Result<(), String> {
         self.inner.set_data(format, data)
     }
@@ -64,6 +69,12 @@ impl DataTransfer {
 
 pub trait NativeDataTransfer: Send + Sync {
     fn get_data(&self, format: &str) -> Option<String>;
+    /// The formats of the string data held by this transfer. Renderers that can't enumerate formats only report `text/plain`.
+    fn types(&self) -> Vec<String> {
+        self.get_data("text/plain")
+            .map(|_| vec!["text/plain".to_string()])
+            .unwrap_or_default()
+    }
     fn set_data(&self, format: &str, data: &str) -> Result<(), String>;
     fn clear_data(&self, format: Option<&str>) -> Result<(), String>;
     fn effect_allowed(&self) -> String;
@@ -139,6 +150,14 @@ mod ser {
                 .map(|item| item.data.clone())
         }
 
+        fn types(&self) -> Vec<String> {
+            self.items
+                .iter()
+                .filter(|item| item.kind == "string")
+                .map(|item| item.type_.clone())
+                .collect()
+        }
+
         fn set_data(&self, _format: &str, _data: &str) -> Result<(), String> {
             // todo!()
             // Err("Cannot set data on serialized DataTransfer".into())
@@ -0,0 +1,43 @@
+use crate as dioxus_elements;
+use dioxus_core::{Attribute, Element, VNode};
+use dioxus_core_macro::Props;
+
+/// Props for the [`DynamicElement`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct DynamicElementProps {
+    /// The tag name of the element
+    #[props(into)]
+    pub tag: String,
+    /// The namespace of the element, for example `http://www.w3.org/2000/svg` for svg elements
+    #[props(into)]
+    pub namespace: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+    pub children: Element,
+}
+
+/// Render an element with a tag name chosen at runtime.
+///
+/// Changing the tag replaces the element instead of diffing it in place.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[component]
+/// fn Heading(level: u8, text: String) -> Element {
+///     rsx! {
+///         DynamicElement {
+///             tag: format!("h{level}"),
+///             class: "heading",
+///             "{text}"
+///         }
+///     }
+/// }
+/// ```
+pub fn DynamicElement(props: DynamicElementProps) -> Element {
+    Ok(VNode::dynamic_element(
+        &props.tag,
+        props.namespace.as_deref(),
+        props.attributes,
+        props.children,
+    ))
+}
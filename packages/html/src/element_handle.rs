@@ -0,0 +1,225 @@
+use crate::{MountedData, MountedEvent};
+use dioxus_core::{Attribute, use_drop, use_hook};
+use dioxus_signals::*;
+use std::{cell::RefCell, rc::Rc};
+
+/// Attach state to an element for an imperative library that needs to own part of the DOM, like a chart or map
+/// library.
+///
+/// `setup` runs every time the element is mounted and returns the state for the element, like the chart instance.
+/// `cleanup` runs with that state before the element is replaced by a new one and when the component is dropped, so
+/// the library can release the element. The state is kept across renders and can be read with
+/// [`ElementHandle::data`].
+///
+/// On web, you can get the `web_sys::Element` to pass to the library with `WebEventExt::as_web_event` from
+/// `dioxus-web`. The element stays in the DOM until Dioxus removes it, so it is safe to use until `cleanup` runs.
+/// There is no event when an element is removed while the component stays mounted, so render the element
+/// unconditionally or move it into its own component if it can be removed.
+///
+/// Spread [`ElementHandle::attributes`] onto the element, or pass the element to [`ElementHandle::attach`] from your
+/// own `onmounted` handler.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::html::use_element_handle;
+///
+/// struct Chart {
+///     // The handle to the chart from the JS library
+/// }
+///
+/// impl Chart {
+///     fn new(element: &MountedData) -> Self {
+///         // Create the chart in the element
+///         Chart {}
+///     }
+///
+///     fn update(&mut self, points: &[f64]) {}
+///
+///     fn destroy(&mut self) {}
+/// }
+///
+/// fn App() -> Element {
+///     let points = use_signal(|| vec![1.0, 4.0, 2.0]);
+///     let chart = use_element_handle(|element| Chart::new(&element), |chart| chart.destroy());
+///
+///     // Update the chart when the points change
+///     use_effect(move || {
+///         if let Some(chart) = chart.data() {
+///             chart.borrow_mut().update(&points.read());
+///         }
+///     });
+///
+///     rsx! {
+///         div { ..chart.attributes() }
+///     }
+/// }
+/// ```
+pub fn use_element_handle<T: 'static>(
+    setup: impl FnMut(Rc<MountedData>) -> T + 'static,
+    cleanup: impl FnMut(&mut T) + 'static,
+) -> ElementHandle<T> {
+    let handle = use_hook(|| ElementHandle {
+        element: Signal::new(None),
+        data: Signal::new(None),
+        setup: CopyValue::new(Box::new(setup)),
+        cleanup: CopyValue::new(Box::new(cleanup)),
+    });
+
+    use_drop(move || handle.clean_up());
+
+    handle
+}
+
+/// A handle to an element and the state attached to it, created with [`use_element_handle`]
+pub struct ElementHandle<T: 'static> {
+    element: Signal<Option<Rc<MountedData>>>,
+    data: Signal<Option<Rc<RefCell<T>>>>,
+    setup: CopyValue<Box<dyn FnMut(Rc<MountedData>) -> T>>,
+    cleanup: CopyValue<Box<dyn FnMut(&mut T)>>,
+}
+
+impl<T: 'static> Clone for ElementHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for ElementHandle<T> {}
+
+impl<T: 'static> PartialEq for ElementHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.element == other.element
+    }
+}
+
+impl<T: 'static> ElementHandle<T> {
+    /// The attributes to spread onto the element
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let handle = *self;
+        vec![crate::events::onmounted(move |event: MountedEvent| {
+            handle.attach(event.data())
+        })]
+    }
+
+    /// Use `element` as the element of the handle. The state of the previous element is cleaned up and `setup` runs
+    /// for the new element.
+    pub fn attach(&self, element: Rc<MountedData>) {
+        self.clean_up();
+        let data = {
+            let mut setup = self.setup.write_unchecked();
+            (*setup)(element.clone())
+        };
+        let mut handle = *self;
+        handle.data.set(Some(Rc::new(RefCell::new(data))));
+        handle.element.set(Some(element));
+    }
+
+    /// Get the mounted element, or `None` if it hasn't been mounted yet.
+    ///
+    /// Reading this will subscribe the current scope to changes in the element.
+    pub fn element(&self) -> Option<Rc<MountedData>> {
+        self.element.cloned()
+    }
+
+    /// Get the state attached to the element, or `None` if it hasn't been mounted yet.
+    ///
+    /// Reading this will subscribe the current scope to changes in the state, so effects that read it rerun when the
+    /// element is replaced.
+    pub fn data(&self) -> Option<Rc<RefCell<T>>> {
+        self.data.cloned()
+    }
+
+    /// Run `cleanup` for the state of the current element, if there is one
+    fn clean_up(&self) {
+        // The signals may already be dropped if the component is being dropped
+        if !self.data.try_peek().is_ok_and(|data| data.is_some()) {
+            return;
+        }
+        let mut handle = *self;
+        let Some(data) = handle.data.take() else {
+            return;
+        };
+        let mut cleanup = self.cleanup.write_unchecked();
+        (*cleanup)(&mut data.borrow_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RenderedElementBacking;
+    use dioxus_core::{Element, VNode, VirtualDom};
+    use dioxus_core_macro::rsx;
+    use std::cell::Cell;
+
+    thread_local! {
+        static SHOW: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+        static HANDLE: Cell<Option<ElementHandle<&'static str>>> = const { Cell::new(None) };
+        static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// A fake element with a name
+    struct Named(&'static str);
+
+    impl RenderedElementBacking for Named {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    fn record(call: String) {
+        CALLS.with(|calls| calls.borrow_mut().push(call));
+    }
+
+    fn calls() -> Vec<String> {
+        CALLS.with(|calls| calls.take())
+    }
+
+    fn Chart() -> Element {
+        let handle = use_element_handle(
+            |element| {
+                let name = element.downcast::<Named>().unwrap().0;
+                record(format!("setup {name}"));
+                name
+            },
+            |name| record(format!("cleanup {name}")),
+        );
+        HANDLE.with(|cell| cell.set(Some(handle)));
+        VNode::empty()
+    }
+
+    #[test]
+    fn cleanup_runs_before_reattaching_and_on_drop() {
+        fn app() -> Element {
+            let show = use_signal(|| true);
+            SHOW.with(|cell| cell.set(Some(show)));
+            rsx! {
+                if show() {
+                    Chart {}
+                }
+            }
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let handle = HANDLE.with(Cell::get).unwrap();
+        let attach = |dom: &VirtualDom, name| {
+            dom.in_runtime(|| handle.attach(Rc::new(MountedData::from(Named(name)))))
+        };
+
+        attach(&dom, "first");
+        assert_eq!(calls(), ["setup first"]);
+        dom.in_runtime(|| assert_eq!(*handle.data().unwrap().borrow(), "first"));
+
+        // The old element is cleaned up before the new one is set up
+        attach(&dom, "second");
+        assert_eq!(calls(), ["cleanup first", "setup second"]);
+        dom.in_runtime(|| assert_eq!(*handle.data().unwrap().borrow(), "second"));
+
+        dom.in_runtime(|| SHOW.with(Cell::get).unwrap().set(false));
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        assert_eq!(calls(), ["cleanup second"]);
+    }
+}
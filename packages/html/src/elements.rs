@@ -1430,6 +1430,9 @@ builder_constructors! {
         autocorrect: OnOff DEFAULT,
         autofocus: Bool DEFAULT,
         capture: String DEFAULT,
+        /// Whether the checkbox or radio button is checked. Setting this from state makes the input controlled: it
+        /// is reset to the state every time the component reruns, so update the state in an `onchange` handler.
+        /// Debug builds warn about controlled inputs without a handler.
         checked: Bool DEFAULT,
         directory: Bool "webkitdirectory",
         disabled: Bool DEFAULT,
@@ -1440,6 +1443,8 @@ builder_constructors! {
         formnovalidate: Bool DEFAULT,
         formtarget: Target DEFAULT,
         height: isize DEFAULT,
+        /// Only set whether the input starts checked, and leave it up to the user after that. Use this instead of
+        /// `checked` to opt out of controlling the input.
         initial_checked: Bool DEFAULT,
         list: Id DEFAULT,
         max: String DEFAULT,
@@ -1490,7 +1495,12 @@ builder_constructors! {
 
         r#type: InputType "type",
         // value: String,
+        /// The value of the input. Setting this from state makes the input controlled: it is reset to the state every
+        /// time the component reruns, so update the state in an `oninput` handler. Debug builds warn about
+        /// controlled inputs without a handler, except for buttons and hidden inputs which the user can't edit.
         value: String volatile,
+        /// Only set the starting value of the input, and leave it up to the user after that. Use this instead of
+        /// `value` to opt out of controlling the input.
         initial_value: String DEFAULT,
     };
 
@@ -1596,8 +1606,13 @@ builder_constructors! {
         rows: usize DEFAULT,
         spellcheck: BoolOrDefault DEFAULT,
         wrap: Wrap DEFAULT,
+        /// The value of the textarea. Setting this from state makes the textarea controlled: it is reset to the state
+        /// every time the component reruns, so update the state in an `oninput` handler. Debug builds warn about
+        /// controlled textareas without a handler.
         value: String volatile,
 
+        /// Only set the starting value of the textarea, and leave it up to the user after that. Use this instead of
+        /// `value` to opt out of controlling the textarea.
         initial_value: String DEFAULT,
     };
 
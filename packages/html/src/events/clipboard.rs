@@ -1,4 +1,5 @@
-use dioxus_core::Event;
+use bytes::Bytes;
+use dioxus_core::{CapturedError, Event};
 
 use crate::data_transfer::{DataTransfer, HasDataTransferData};
 use crate::file_data::{FileData, HasFileData};
@@ -59,6 +60,49 @@ impl ClipboardData {
         self.inner.data_transfer()
     }
 
+    /// The plain text carried by the clipboard event, if any.
+    ///
+    /// This is a shortcut for reading the `text/plain` format from [`ClipboardData::data_transfer`].
+    pub fn text(&self) -> Option<String> {
+        self.data_transfer().get_as_text()
+    }
+
+    /// Every item carried by the clipboard event: one item for each string format followed by one item for each file.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// rsx! {
+    ///     div {
+    ///         contenteditable: true,
+    ///         onpaste: move |event| async move {
+    ///             // Block the default paste so we can insert the content ourselves
+    ///             event.prevent_default();
+    ///             for item in event.items() {
+    ///                 let bytes = item.read_bytes().await;
+    ///                 println!("pasted {} ({:?} bytes)", item.mime_type(), bytes.map(|b| b.len()));
+    ///             }
+    ///         },
+    ///     }
+    /// };
+    /// ```
+    pub fn items(&self) -> Vec<ClipboardItem> {
+        let data_transfer = self.data_transfer();
+        let strings = data_transfer.types().into_iter().filter_map(|mime_type| {
+            let data = data_transfer.get_data(&mime_type)?;
+            Some(ClipboardItem {
+                mime_type,
+                kind: ClipboardItemKind::String(data),
+            })
+        });
+        let files = self.files().into_iter().map(|file| ClipboardItem {
+            mime_type: file
+                .content_type()
+                .unwrap_or_else(|| "application/octet-stream".to_string()),
+            kind: ClipboardItemKind::File(file),
+        });
+        strings.chain(files).collect()
+    }
+
     /// Downcast this event to a concrete event type
     #[inline(always)]
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
@@ -66,6 +110,64 @@ impl ClipboardData {
     }
 }
 
+/// A single item carried by a clipboard event. Items are either a string in some format, or a file.
+#[derive(Clone, PartialEq)]
+pub struct ClipboardItem {
+    mime_type: String,
+    kind: ClipboardItemKind,
+}
+
+#[derive(Clone, PartialEq)]
+enum ClipboardItemKind {
+    String(String),
+    File(FileData),
+}
+
+impl ClipboardItem {
+    /// The MIME type of the item, like `text/plain`, `text/html` or `image/png`
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// Check if the item is a file
+    pub fn is_file(&self) -> bool {
+        matches!(self.kind, ClipboardItemKind::File(_))
+    }
+
+    /// Get the file backing this item, if it is a file
+    pub fn as_file(&self) -> Option<&FileData> {
+        match &self.kind {
+            ClipboardItemKind::File(file) => Some(file),
+            ClipboardItemKind::String(_) => None,
+        }
+    }
+
+    /// Read the contents of the item as bytes
+    pub async fn read_bytes(&self) -> Result<Bytes, CapturedError> {
+        match &self.kind {
+            ClipboardItemKind::String(data) => Ok(Bytes::from(data.clone())),
+            ClipboardItemKind::File(file) => file.read_bytes().await,
+        }
+    }
+
+    /// Read the contents of the item as a string
+    pub async fn read_string(&self) -> Result<String, CapturedError> {
+        match &self.kind {
+            ClipboardItemKind::String(data) => Ok(data.clone()),
+            ClipboardItemKind::File(file) => file.read_string().await,
+        }
+    }
+}
+
+impl std::fmt::Debug for ClipboardItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClipboardItem")
+            .field("mime_type", &self.mime_type)
+            .field("is_file", &self.is_file())
+            .finish()
+    }
+}
+
 impl HasFileData for ClipboardData {
     fn files(&self) -> Vec<FileData> {
         self.inner.files()
@@ -168,6 +270,29 @@ mod tests {
         assert!(data.files().is_empty());
     }
 
+    #[test]
+    fn paste_items_expose_every_string_format() {
+        let payload = r#"{
+            "data_transfer": {
+                "items": [
+                    { "kind": "string", "type_": "text/plain", "data": "hello" },
+                    { "kind": "string", "type_": "text/html", "data": "<b>hello</b>" }
+                ],
+                "files": [],
+                "effect_allowed": "uninitialized",
+                "drop_effect": "none"
+            }
+        }"#;
+
+        let data: ClipboardData = serde_json::from_str(payload).unwrap();
+        assert_eq!(data.text().as_deref(), Some("hello"));
+
+        let items = data.items();
+        let mime_types: Vec<_> = items.iter().map(|item| item.mime_type()).collect();
+        assert_eq!(mime_types, ["text/plain", "text/html"]);
+        assert!(!items[1].is_file());
+    }
+
     #[test]
     fn paste_text_round_trips_through_serde() {
         let payload = r#"{
@@ -59,6 +59,13 @@ impl DragData {
         self.inner.data_transfer()
     }
 
+    /// Get the files being dragged. On desktop, dropped files carry their OS path.
+    ///
+    /// Browsers only fire `ondrop` if the `ondragover` handler calls [`Event::prevent_default`].
+    pub fn files(&self) -> Vec<FileData> {
+        self.inner.files()
+    }
+
     /// Downcast this event data to a specific type
     #[inline(always)]
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
@@ -1,6 +1,6 @@
 use crate::FileData;
 use crate::file_data::HasFileData;
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 use dioxus_core::Event;
 
@@ -40,7 +40,8 @@ impl FormData {
         self.value().parse().unwrap_or(false)
     }
 
-    /// Collect all the named form values from the containing form.
+    /// Collect all the named form values from the containing form. Inputs that share a name have one entry per value.
+    /// Use [`FormData::grouped_values`] to group them by name.
     ///
     /// Every input must be named!
     pub fn values(&self) -> Vec<(String, FormValue)> {
@@ -62,6 +63,16 @@ impl FormData {
             .collect()
     }
 
+    /// Collect the named form values grouped by their name. Inputs that share a name, like a group of checkboxes or
+    /// the selected options of a `<select multiple>`, are collected in the order they appear in the form.
+    pub fn grouped_values(&self) -> HashMap<String, Vec<FormValue>> {
+        let mut grouped: HashMap<String, Vec<FormValue>> = HashMap::new();
+        for (name, value) in self.values() {
+            grouped.entry(name).or_default().push(value);
+        }
+        grouped
+    }
+
     /// Get the files of the form event
     pub fn files(&self) -> Vec<FileData> {
         self.inner.files()
@@ -80,7 +91,39 @@ impl FormData {
 }
 
 impl FormData {
-    /// Parse the values into a struct with one field per value
+    /// Parse the values into a struct with one field per name in the form.
+    ///
+    /// Inputs that share a name, like a group of checkboxes or a `<select multiple>`, can be collected into a `Vec`
+    /// field. The field is deserialized as a list even if only one value was selected. Unchecked checkboxes are not
+    /// included in the form, so give those fields a `#[serde(default)]`.
+    ///
+    /// Numbers and booleans are parsed from the text of the input. A checkbox without a value is submitted as `"on"`,
+    /// which parses as `true`. An empty input is `None` if the field is an `Option`.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// #[derive(serde::Deserialize)]
+    /// struct Order {
+    ///     name: String,
+    ///     #[serde(default)]
+    ///     toppings: Vec<String>,
+    /// }
+    ///
+    /// fn App() -> Element {
+    ///     rsx! {
+    ///         form {
+    ///             onsubmit: move |event| {
+    ///                 let order: Order = event.parsed_values().unwrap();
+    ///                 println!("{} ordered {:?}", order.name, order.toppings);
+    ///             },
+    ///             input { name: "name" }
+    ///             input { r#type: "checkbox", name: "toppings", value: "cheese" }
+    ///             input { r#type: "checkbox", name: "toppings", value: "olives" }
+    ///             input { r#type: "submit" }
+    ///         }
+    ///     }
+    /// }
+    /// ```
     #[cfg(feature = "serialize")]
     pub fn parsed_values<T>(&self) -> Result<T, serde_json::Error>
     where
@@ -88,54 +131,169 @@ impl FormData {
     {
         use crate::SerializedFileData;
 
-        let values = &self.values();
-
-        let mut map = serde_json::Map::new();
-        for (key, value) in values {
-            let entry = map
-                .entry(key.clone())
-                .or_insert_with(|| serde_json::Value::Array(Vec::new()));
-
-            match value {
-                FormValue::Text(text) => {
-                    entry
-                        .as_array_mut()
-                        .expect("entry should be an array")
-                        .push(serde_json::Value::String(text.clone()));
-                }
+        let mut fields: Vec<(String, FormFieldDeserializer)> = Vec::new();
+        for (key, value) in self.values() {
+            let value = match value {
+                FormValue::Text(text) => serde_json::Value::String(text),
                 // we create the serialized variant with no bytes
                 // SerializedFileData, if given a real path, will read the bytes from disk (synchronously)
                 FormValue::File(Some(file_data)) => {
-                    let serialized = SerializedFileData::from_file_data(file_data);
-                    entry
-                        .as_array_mut()
-                        .expect("entry should be an array")
-                        .push(serde_json::to_value(&serialized).unwrap_or(serde_json::Value::Null));
-                }
-                FormValue::File(None) => {
-                    entry
-                        .as_array_mut()
-                        .expect("entry should be an array")
-                        .push(
-                            serde_json::to_value(SerializedFileData::empty())
-                                .unwrap_or(serde_json::Value::Null),
-                        );
+                    let serialized = SerializedFileData::from_file_data(&file_data);
+                    serde_json::to_value(&serialized).unwrap_or(serde_json::Value::Null)
                 }
+                FormValue::File(None) => serde_json::to_value(SerializedFileData::empty())
+                    .unwrap_or(serde_json::Value::Null),
+            };
+
+            match fields.iter_mut().find(|(existing, _)| *existing == key) {
+                Some((_, field)) => field.0.push(value),
+                None => fields.push((key, FormFieldDeserializer(vec![value]))),
             }
         }
 
-        // Go through the map and convert single-element arrays to just the element
-        let map = map
-            .into_iter()
-            .map(|(k, v)| match v {
-                serde_json::Value::Array(arr) if arr.len() == 1 => {
-                    (k, arr.into_iter().next().unwrap())
+        T::deserialize(serde::de::value::MapDeserializer::new(fields.into_iter()))
+    }
+}
+
+/// Deserializes all of the values with the same name in a form. The values are deserialized as a list if the field
+/// is a sequence, and as a single value otherwise. Form values are always text, so numbers and booleans are parsed
+/// from the text, and an empty value is `None` for optional fields.
+#[cfg(feature = "serialize")]
+struct FormFieldDeserializer(Vec<serde_json::Value>);
+
+#[cfg(feature = "serialize")]
+impl FormFieldDeserializer {
+    fn into_value(mut self) -> serde_json::Value {
+        match self.0.len() {
+            1 => self.0.pop().expect("there is one value"),
+            _ => serde_json::Value::Array(self.0),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::de::IntoDeserializer<'de, serde_json::Error> for FormFieldDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "serialize")]
+macro_rules! forward_to_value {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: serde::de::Visitor<'de>>(
+                self,
+                $($arg: $ty,)*
+                visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                serde::Deserializer::$method(self.into_value(), $($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serialize")]
+macro_rules! parse_text {
+    ($($method:ident => $visit:ident;)*) => {
+        $(
+            fn $method<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.into_value() {
+                    serde_json::Value::String(text) => match text.trim().parse() {
+                        Ok(value) => visitor.$visit(value),
+                        Err(_) => Err(serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Str(&text),
+                            &visitor,
+                        )),
+                    },
+                    value => serde::Deserializer::$method(value, visitor),
                 }
-                _ => (k, v),
-            })
-            .collect::<serde_json::Map<String, serde_json::Value>>();
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> serde::Deserializer<'de> for FormFieldDeserializer {
+    type Error = serde_json::Error;
+
+    fn deserialize_option<V: serde::de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.0.as_slice() {
+            [] => visitor.visit_none(),
+            [serde_json::Value::String(text)] if text.is_empty() => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_bool<V: serde::de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.into_value() {
+            // Checkboxes without a value are submitted as "on"
+            serde_json::Value::String(text) => match text.as_str() {
+                "true" | "on" => visitor.visit_bool(true),
+                "false" | "off" => visitor.visit_bool(false),
+                _ => Err(serde::de::Error::invalid_value(
+                    serde::de::Unexpected::Str(&text),
+                    &visitor,
+                )),
+            },
+            value => serde::Deserializer::deserialize_bool(value, visitor),
+        }
+    }
+
+    parse_text! {
+        deserialize_i8 => visit_i8;
+        deserialize_i16 => visit_i16;
+        deserialize_i32 => visit_i32;
+        deserialize_i64 => visit_i64;
+        deserialize_i128 => visit_i128;
+        deserialize_u8 => visit_u8;
+        deserialize_u16 => visit_u16;
+        deserialize_u32 => visit_u32;
+        deserialize_u64 => visit_u64;
+        deserialize_u128 => visit_u128;
+        deserialize_f32 => visit_f32;
+        deserialize_f64 => visit_f64;
+    }
+
+    fn deserialize_seq<V: serde::de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        serde::Deserializer::deserialize_seq(serde_json::Value::Array(self.0), visitor)
+    }
+
+    fn deserialize_tuple<V: serde::de::Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        serde::Deserializer::deserialize_tuple(serde_json::Value::Array(self.0), len, visitor)
+    }
 
-        serde_json::from_value(serde_json::Value::Object(map))
+    forward_to_value! {
+        deserialize_any();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
     }
 }
 
@@ -11,6 +11,9 @@ macro_rules! with_html_event_groups {
                     onanimationstart => animationstart,
                     onanimationend => animationend,
                     onanimationiteration => animationiteration,
+                    /// The `animationcancel` event fires when a css animation stops before it
+                    /// finishes, for example because the animation was removed from the element.
+                    onanimationcancel => animationcancel,
                 ]]
                 Animation(AnimationData),
 
@@ -52,7 +55,13 @@ macro_rules! with_html_event_groups {
 
                 #[convert = convert_cancel_data]
                 #[events = [
+                    /// The `cancel` event fires on a `<dialog>` when the user asks to dismiss it, for example by
+                    /// pressing escape. Calling `event.prevent_default()` keeps the dialog open.
                     oncancel => cancel,
+                    /// The `close` event fires on a `<dialog>` after it closes, no matter what closed it.
+                    ///
+                    /// See <https://developer.mozilla.org/en-US/docs/Web/API/HTMLDialogElement/close_event>.
+                    onclose => close,
                 ]]
                 Cancel(CancelData),
 
@@ -143,7 +152,38 @@ macro_rules! with_html_event_groups {
 
                 #[convert = convert_image_data]
                 #[events = [
+                    /// The `onerror` event is fired when an `<img>`, `<iframe>`, `<script>`, `<link>`, or media element
+                    /// fails to load its resource. For images, this includes urls that respond with an error status and
+                    /// files that can't be decoded. [`ImageData::load_error`](crate::ImageData::load_error) is `true` for this event.
                     onerror => error,
+                    /// The `onload` event is fired when an `<img>`, `<iframe>`, `<script>`, or `<link>` element finishes
+                    /// loading its resource.
+                    ///
+                    /// ```rust
+                    /// use dioxus::prelude::*;
+                    ///
+                    /// #[component]
+                    /// fn Photo(src: String) -> Element {
+                    ///     let mut loaded = use_signal(|| false);
+                    ///     let mut failed = use_signal(|| false);
+                    ///
+                    ///     rsx! {
+                    ///         if !loaded() && !failed() {
+                    ///             div { class: "spinner" }
+                    ///         }
+                    ///         if failed() {
+                    ///             div { class: "placeholder", "Image unavailable" }
+                    ///         } else {
+                    ///             img {
+                    ///                 src,
+                    ///                 hidden: !loaded(),
+                    ///                 onload: move |_| loaded.set(true),
+                    ///                 onerror: move |_| failed.set(true),
+                    ///             }
+                    ///         }
+                    ///     }
+                    /// }
+                    /// ```
                     onload => load,
                 ]]
                 Image(ImageData),
@@ -348,7 +388,16 @@ macro_rules! with_html_event_groups {
 
                 #[convert = convert_transition_data]
                 #[events = [
+                    /// The `transitionrun` event fires when a css transition is created, before
+                    /// any delay.
+                    ontransitionrun => transitionrun,
+                    /// The `transitionstart` event fires when a css transition starts, after any
+                    /// delay.
+                    ontransitionstart => transitionstart,
                     ontransitionend => transitionend,
+                    /// The `transitioncancel` event fires when a css transition stops before it
+                    /// finishes. `transitionend` does not fire for canceled transitions.
+                    ontransitioncancel => transitioncancel,
                 ]]
                 Transition(TransitionData),
 
@@ -1,6 +1,8 @@
 use dioxus_core::Event;
 
 pub type ImageEvent = Event<ImageData>;
+
+/// The data for the `onload` and `onerror` events of images, iframes, scripts, and media elements
 pub struct ImageData {
     inner: Box<dyn HasImageData>,
 }
@@ -31,7 +33,8 @@ impl ImageData {
         Self { inner: Box::new(e) }
     }
 
-    /// If the renderer encountered an error while loading the image
+    /// If the renderer encountered an error while loading the image. This is `true` for `onerror` events and `false`
+    /// for `onload` events.
     pub fn load_error(&self) -> bool {
         self.inner.load_error()
     }
@@ -95,6 +95,115 @@ impl ModifiersInteraction for KeyboardData {
     }
 }
 
+/// A key combination that a keyboard event can be checked against.
+///
+/// The filter only matches events with exactly the modifiers it was built with, so `ctrl+s` does not match
+/// `ctrl+shift+s`. Character keys are compared case insensitively since holding shift changes the reported key.
+///
+/// # Example
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Editor() -> Element {
+///     rsx! {
+///         textarea {
+///             // Only fires for ctrl+shift+s
+///             onkeydown: KeyFilter::char('s').ctrl().shift().handler(move |_| println!("Save as...")),
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyFilter {
+    key: Option<Key>,
+    code: Option<Code>,
+    modifiers: Modifiers,
+}
+
+impl KeyFilter {
+    /// Match events for a logical key like [`Key::Enter`]
+    pub fn key(key: Key) -> Self {
+        Self {
+            key: Some(key),
+            code: None,
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    /// Match events for a key that types a character. `KeyFilter::char('s')` matches the s key.
+    pub fn char(character: char) -> Self {
+        Self::key(Key::Character(character.to_string()))
+    }
+
+    /// Match events for a physical key, regardless of the keyboard layout
+    pub fn code(code: Code) -> Self {
+        Self {
+            key: None,
+            code: Some(code),
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    /// Require the control key to be held
+    pub fn ctrl(self) -> Self {
+        self.with_modifiers(Modifiers::CONTROL)
+    }
+
+    /// Require the shift key to be held
+    pub fn shift(self) -> Self {
+        self.with_modifiers(Modifiers::SHIFT)
+    }
+
+    /// Require the alt (option) key to be held
+    pub fn alt(self) -> Self {
+        self.with_modifiers(Modifiers::ALT)
+    }
+
+    /// Require the meta (command or windows) key to be held
+    pub fn meta(self) -> Self {
+        self.with_modifiers(Modifiers::META)
+    }
+
+    /// Require all of `modifiers` to be held
+    pub fn with_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers |= modifiers;
+        self
+    }
+
+    /// Check if the event matches the key and the exact set of modifiers in this filter
+    pub fn matches(&self, data: &KeyboardData) -> bool {
+        let tracked = Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::ALT | Modifiers::META;
+        if data.modifiers() & tracked != self.modifiers {
+            return false;
+        }
+
+        if let Some(code) = &self.code
+            && data.code() != *code
+        {
+            return false;
+        }
+
+        match (&self.key, data.key()) {
+            (None, _) => true,
+            (Some(Key::Character(expected)), Key::Character(actual)) => {
+                expected.eq_ignore_ascii_case(&actual)
+            }
+            (Some(expected), actual) => *expected == actual,
+        }
+    }
+
+    /// Wrap `handler` so it only runs for events that match this filter
+    pub fn handler(
+        self,
+        mut handler: impl FnMut(KeyboardEvent) + 'static,
+    ) -> impl FnMut(KeyboardEvent) {
+        move |event: KeyboardEvent| {
+            if self.matches(&event.data()) {
+                handler(event);
+            }
+        }
+    }
+}
+
 #[cfg(feature = "serialize")]
 /// A serialized version of KeyboardData
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
@@ -741,3 +850,43 @@ impl KeyCode {
         *self as u32
     }
 }
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use super::*;
+
+    fn key_event(key: Key, modifiers: Modifiers) -> KeyboardData {
+        KeyboardData::new(SerializedKeyboardData::new(
+            key,
+            Code::KeyS,
+            Location::Standard,
+            false,
+            modifiers,
+            false,
+        ))
+    }
+
+    #[test]
+    fn key_filter_requires_exact_modifiers() {
+        let filter = KeyFilter::char('s').ctrl();
+        assert!(filter.matches(&key_event(
+            Key::Character("s".to_string()),
+            Modifiers::CONTROL
+        )));
+        assert!(!filter.matches(&key_event(
+            Key::Character("s".to_string()),
+            Modifiers::empty()
+        )));
+        assert!(!filter.matches(&key_event(
+            Key::Character("S".to_string()),
+            Modifiers::CONTROL | Modifiers::SHIFT
+        )));
+
+        // Shift changes the reported character, but the filter still matches
+        let filter = filter.shift();
+        assert!(filter.matches(&key_event(
+            Key::Character("S".to_string()),
+            Modifiers::CONTROL | Modifiers::SHIFT
+        )));
+    }
+}
@@ -52,6 +52,42 @@ pub trait RenderedElementBacking: std::any::Any {
     fn set_focus(&self, _focus: bool) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
         Box::pin(async { Err(MountedError::NotSupported) })
     }
+
+    /// Open the element as a modal if it is a `<dialog>`
+    fn show_modal(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Close the element if it is a `<dialog>`
+    fn close(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Send all future events from the pointer to the element
+    fn set_pointer_capture(
+        &self,
+        _pointer_id: i32,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Stop sending all events from the pointer to the element
+    fn release_pointer_capture(
+        &self,
+        _pointer_id: i32,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Keep keyboard focus inside of the element
+    fn trap_focus(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Stop keeping keyboard focus inside of the element and restore the focus from before it was trapped
+    fn release_focus_trap(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
 }
 
 impl RenderedElementBacking for () {
@@ -118,6 +154,32 @@ impl Default for ScrollToOptions {
 /// An Element that has been rendered and allows reading and modifying information about it.
 ///
 /// Different platforms will have different implementations and different levels of support for this trait. Renderers that do not support specific features will return `None` for those queries.
+///
+/// You can get a handle to an element with the `onmounted` event. [`Event::data`](dioxus_core::Event::data) returns an
+/// `Rc<MountedData>` which is cheap to clone and can be stored in a signal to measure or focus the element later:
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use std::rc::Rc;
+/// fn App() -> Element {
+///     let mut input = use_signal(|| None::<Rc<MountedData>>);
+///
+///     rsx! {
+///         input { onmounted: move |event| input.set(Some(event.data())) }
+///         button {
+///             onclick: move |_| async move {
+///                 if let Some(input) = input() {
+///                     if let Ok(rect) = input.get_client_rect().await {
+///                         println!("The input is {}px wide", rect.width());
+///                     }
+///                     _ = input.set_focus(true).await;
+///                 }
+///             },
+///             "Focus the input"
+///         }
+///     }
+/// }
+/// ```
 pub struct MountedData {
     inner: Box<dyn RenderedElementBacking>,
 }
@@ -200,6 +262,57 @@ impl MountedData {
         self.inner.set_focus(focus)
     }
 
+    /// Open a `<dialog>` element as a modal. The rest of the page becomes inert and focus is kept inside the dialog
+    /// until it is closed. Does nothing if the dialog is already open.
+    #[doc(alias = "showModal")]
+    pub fn show_modal(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        self.inner.show_modal()
+    }
+
+    /// Close a `<dialog>` element. This fires the `onclose` event on the dialog.
+    pub fn close(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        self.inner.close()
+    }
+
+    /// Capture the pointer with the given [`pointer_id`](crate::PointerData::pointer_id). All future pointer events
+    /// from the pointer are sent to this element, even if the pointer moves outside of it, until the pointer is
+    /// released or [`release_pointer_capture`](Self::release_pointer_capture) is called. This is useful for dragging
+    /// and drawing.
+    ///
+    /// The pointer must be active, so this is usually called from an `onpointerdown` handler.
+    #[doc(alias = "setPointerCapture")]
+    pub fn set_pointer_capture(
+        &self,
+        pointer_id: i32,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        self.inner.set_pointer_capture(pointer_id)
+    }
+
+    /// Release a pointer captured with [`set_pointer_capture`](Self::set_pointer_capture)
+    #[doc(alias = "releasePointerCapture")]
+    pub fn release_pointer_capture(
+        &self,
+        pointer_id: i32,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        self.inner.release_pointer_capture(pointer_id)
+    }
+
+    /// Trap keyboard focus inside of the element. Tab and Shift+Tab cycle through the focusable elements inside of
+    /// it, and focus that moves outside of it is moved back to the first focusable element. If focus is outside of the
+    /// element when the trap starts, the first focusable element is focused.
+    ///
+    /// The trap lasts until [`release_focus_trap`](Self::release_focus_trap) is called or the element is removed.
+    /// See [`use_focus_trap`](crate::use_focus_trap) for a hook that manages the trap for you.
+    pub fn trap_focus(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        self.inner.trap_focus()
+    }
+
+    /// Release a focus trap started with [`trap_focus`](Self::trap_focus) and move focus back to the element that
+    /// was focused before the trap started
+    pub fn release_focus_trap(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        self.inner.release_focus_trap()
+    }
+
     /// Downcast this event to a concrete event type
     #[inline(always)]
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
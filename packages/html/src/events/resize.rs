@@ -1,5 +1,27 @@
 use std::fmt::{Display, Formatter};
 
+/// Data for the `onresize` event, which fires whenever the size of the element changes.
+///
+/// Unlike a window resize listener, this tracks the element itself, so components can adapt to the size of
+/// their container. The renderer observes the element with a `ResizeObserver` while the handler is attached and
+/// stops observing it when the handler is removed.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn Chart() -> Element {
+///     let mut width = use_signal(|| 0.0);
+///     rsx! {
+///         div {
+///             onresize: move |event| {
+///                 if let Ok(size) = event.data().get_content_box_size() {
+///                     width.set(size.width);
+///                 }
+///             },
+///             if width() < 400.0 { "Compact chart" } else { "Full chart" }
+///         }
+///     }
+/// }
+/// ```
 pub struct ResizeData {
     inner: Box<dyn HasResizeData>,
 }
@@ -19,11 +41,13 @@ impl ResizeData {
     }
 
     /// Get the border box size of the observed element
+    #[doc(alias = "border_box_size")]
     pub fn get_border_box_size(&self) -> ResizeResult<PixelsSize> {
         self.inner.get_border_box_size()
     }
 
     /// Get the content box size of the observed element
+    #[doc(alias = "content_box_size")]
     pub fn get_content_box_size(&self) -> ResizeResult<PixelsSize> {
         self.inner.get_content_box_size()
     }
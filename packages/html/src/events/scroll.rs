@@ -26,29 +26,72 @@ impl ScrollData {
         self.inner.as_any().downcast_ref::<T>()
     }
 
+    /// Get the number of pixels the element is scrolled vertically when the event fired
+    #[doc(alias = "scrollTop")]
     pub fn scroll_top(&self) -> f64 {
         self.inner.scroll_top()
     }
 
+    /// Get the number of pixels the element is scrolled horizontally when the event fired
+    #[doc(alias = "scrollLeft")]
     pub fn scroll_left(&self) -> f64 {
         self.inner.scroll_left()
     }
 
+    /// Get the width of the element's content, including content that is scrolled out of view
+    #[doc(alias = "scrollWidth")]
     pub fn scroll_width(&self) -> i32 {
         self.inner.scroll_width()
     }
 
+    /// Get the height of the element's content, including content that is scrolled out of view
+    #[doc(alias = "scrollHeight")]
     pub fn scroll_height(&self) -> i32 {
         self.inner.scroll_height()
     }
 
+    /// Get the width of the visible part of the element
+    #[doc(alias = "clientWidth")]
     pub fn client_width(&self) -> i32 {
         self.inner.client_width()
     }
 
+    /// Get the height of the visible part of the element
+    #[doc(alias = "clientHeight")]
     pub fn client_height(&self) -> i32 {
         self.inner.client_height()
     }
+
+    /// Get the number of pixels between the bottom of the visible part of the element and the bottom of its content.
+    ///
+    /// This is `0.0` once the element is scrolled all the way down which makes it useful for infinite scrolling:
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # let mut page = use_signal(|| 0);
+    /// rsx! {
+    ///     div {
+    ///         overflow: "auto",
+    ///         height: "400px",
+    ///         onscrollend: move |event| {
+    ///             if event.distance_to_bottom() < 100.0 {
+    ///                 page += 1;
+    ///             }
+    ///         },
+    ///     }
+    /// };
+    /// ```
+    pub fn distance_to_bottom(&self) -> f64 {
+        let bottom = self.scroll_height() as f64 - self.client_height() as f64;
+        (bottom - self.scroll_top()).max(0.0)
+    }
+
+    /// Get the number of pixels between the right edge of the visible part of the element and the right edge of its
+    /// content.
+    pub fn distance_to_right(&self) -> f64 {
+        let right = self.scroll_width() as f64 - self.client_width() as f64;
+        (right - self.scroll_left()).max(0.0)
+    }
 }
 
 impl std::fmt::Debug for ScrollData {
@@ -171,3 +214,29 @@ pub trait HasScrollData: std::any::Any {
     /// Get the viewport height
     fn client_height(&self) -> i32;
 }
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_to_edges() {
+        let data = ScrollData::new(SerializedScrollData {
+            scroll_top: 500.0,
+            scroll_left: 0.0,
+            scroll_width: 300,
+            scroll_height: 1000,
+            client_width: 200,
+            client_height: 400,
+        });
+        assert_eq!(data.distance_to_bottom(), 100.0);
+        assert_eq!(data.distance_to_right(), 100.0);
+
+        // Overscrolling on touch devices can scroll past the bottom
+        let overscrolled = ScrollData::new(SerializedScrollData {
+            scroll_top: 650.0,
+            ..SerializedScrollData::from(&data)
+        });
+        assert_eq!(overscrolled.distance_to_bottom(), 0.0);
+    }
+}
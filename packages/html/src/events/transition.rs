@@ -38,6 +38,21 @@ impl TransitionData {
         }
     }
 
+    /// The name of the css property the transition is running on
+    pub fn property_name(&self) -> String {
+        self.inner.property_name()
+    }
+
+    /// The name of the pseudo-element the transition runs on
+    pub fn pseudo_element(&self) -> String {
+        self.inner.pseudo_element()
+    }
+
+    /// The amount of time the transition has been running in seconds, excluding any delay
+    pub fn elapsed_time(&self) -> f32 {
+        self.inner.elapsed_time()
+    }
+
     /// Downcast this event to a concrete event type
     #[inline(always)]
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
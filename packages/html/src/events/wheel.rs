@@ -56,6 +56,34 @@ impl WheelData {
         self.inner.delta()
     }
 
+    /// The amount of wheel movement converted to pixels
+    ///
+    /// Browsers and operating systems report wheel movement in pixels, lines or pages. This converts lines and pages
+    /// with [`WheelDelta::to_pixels`] so zoom and pan logic can use one unit on every platform.
+    pub fn pixel_delta(&self) -> PixelsVector3D {
+        self.delta().to_pixels()
+    }
+
+    /// The unit the raw delta is expressed in
+    pub fn delta_mode(&self) -> WheelDeltaMode {
+        self.delta().mode()
+    }
+
+    /// The raw horizontal movement in the units of [`WheelData::delta_mode`]
+    pub fn delta_x(&self) -> f64 {
+        self.delta().strip_units().x
+    }
+
+    /// The raw vertical movement in the units of [`WheelData::delta_mode`]
+    pub fn delta_y(&self) -> f64 {
+        self.delta().strip_units().y
+    }
+
+    /// The raw movement along the z axis in the units of [`WheelData::delta_mode`]
+    pub fn delta_z(&self) -> f64 {
+        self.delta().strip_units().z
+    }
+
     /// Downcast this event to a concrete event type
     #[inline(always)]
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
@@ -121,15 +149,10 @@ pub struct SerializedWheelData {
 impl SerializedWheelData {
     /// Create a new SerializedWheelData
     pub fn new(wheel: &WheelData) -> Self {
-        let delta_mode = match wheel.delta() {
-            WheelDelta::Pixels(_) => 0,
-            WheelDelta::Lines(_) => 1,
-            WheelDelta::Pages(_) => 2,
-        };
         let delta_raw = wheel.delta().strip_units();
         Self {
             mouse: crate::point_interaction::SerializedPointInteraction::from(wheel),
-            delta_mode,
+            delta_mode: wheel.delta_mode() as u32,
             delta_x: delta_raw.x,
             delta_y: delta_raw.y,
             delta_z: delta_raw.z,
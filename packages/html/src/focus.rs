@@ -0,0 +1,437 @@
+use crate::{FocusEvent, Key, KeyboardEvent, MountedData, MountedEvent};
+use dioxus_core::{
+    Attribute, spawn, spawn_forever, use_after_render, use_before_render, use_drop, use_hook,
+};
+use dioxus_hooks::{use_effect, use_signal};
+use dioxus_signals::*;
+use std::rc::Rc;
+
+/// Trap keyboard focus inside of a container while `enabled` is true.
+///
+/// While the trap is enabled, Tab and Shift+Tab cycle through the focusable elements inside of the container instead
+/// of leaving it, and the first focusable element is focused. When the trap is disabled or the component is dropped,
+/// focus moves back to the element that was focused before the trap started. This is the focus pattern for
+/// [modal dialogs](https://www.w3.org/WAI/ARIA/apg/patterns/dialog-modal/) and menus.
+///
+/// Spread [`FocusTrap::attributes`] onto the container, or pass the container to [`FocusTrap::attach`] from your own
+/// `onmounted` handler.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::html::use_focus_trap;
+///
+/// fn App() -> Element {
+///     let mut open = use_signal(|| false);
+///     let trap = use_focus_trap(open);
+///
+///     rsx! {
+///         button { onclick: move |_| open.set(true), "Open menu" }
+///         if open() {
+///             div { role: "menu", ..trap.attributes(),
+///                 button { "Copy" }
+///                 button { "Paste" }
+///                 button { onclick: move |_| open.set(false), "Close" }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_focus_trap(enabled: impl Into<ReadSignal<bool>>) -> FocusTrap {
+    let enabled = use_hook(|| enabled.into());
+    let element = use_signal(|| None::<Rc<MountedData>>);
+
+    // Start or release the trap whenever the container or the enabled state changes
+    use_effect(move || {
+        let enabled = enabled();
+        let Some(element) = element() else {
+            return;
+        };
+        spawn(async move {
+            let result = match enabled {
+                true => element.trap_focus().await,
+                false => element.release_focus_trap().await,
+            };
+            if let Err(err) = result {
+                tracing::error!("Failed to update focus trap: {err}");
+            }
+        });
+    });
+
+    // Restore the focus if the component is dropped while the trap is active
+    use_drop(move || {
+        if let Some(element) = element.peek().clone() {
+            spawn_forever(async move {
+                _ = element.release_focus_trap().await;
+            });
+        }
+    });
+
+    FocusTrap { element }
+}
+
+/// A focus trap created with [`use_focus_trap`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct FocusTrap {
+    element: Signal<Option<Rc<MountedData>>>,
+}
+
+impl FocusTrap {
+    /// The attributes to spread onto the container that keeps the focus
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let trap = *self;
+        vec![crate::events::onmounted(move |event: MountedEvent| {
+            trap.attach(event.data())
+        })]
+    }
+
+    /// Use `element` as the container that keeps the focus
+    pub fn attach(&self, element: Rc<MountedData>) {
+        let mut container = self.element;
+        container.set(Some(element));
+    }
+}
+
+/// The direction of the arrow keys that move focus in a [`use_roving_tabindex`] group
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RovingOrientation {
+    /// The left and right arrow keys move the focus, like in a toolbar or tab list
+    #[default]
+    Horizontal,
+    /// The up and down arrow keys move the focus, like in a menu or list box
+    Vertical,
+    /// All of the arrow keys move the focus, like in a radio group
+    Both,
+}
+
+/// Manage focus in a group of items with the
+/// [roving tabindex](https://www.w3.org/WAI/ARIA/apg/practices/keyboard-interface/#kbd_roving_tabindex) pattern.
+///
+/// Only the active item in the group can be reached with Tab. The arrow keys move the focus to the previous or next
+/// item and wrap around at the ends, Home moves it to the first item, and End moves it to the last item. Focusing an
+/// item with the mouse also makes it the active item.
+///
+/// Spread [`RovingTabindex::item`] onto each item in the group with its index. The group is sized by the items that the
+/// component calling this hook renders: when it renders fewer items, the removed items are dropped from the group and the
+/// active item moves to the new last item if it was removed.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::html::{RovingOrientation, use_roving_tabindex};
+///
+/// fn Toolbar() -> Element {
+///     let roving = use_roving_tabindex(RovingOrientation::Horizontal);
+///
+///     rsx! {
+///         div { role: "toolbar",
+///             for (index, label) in ["Bold", "Italic", "Underline"].into_iter().enumerate() {
+///                 button { ..roving.item(index), "{label}" }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_roving_tabindex(orientation: RovingOrientation) -> RovingTabindex {
+    let mut roving = use_hook(|| RovingTabindex {
+        active: Signal::new(0),
+        items: CopyValue::new(Vec::new()),
+        declared: CopyValue::new(0),
+        orientation: CopyValue::new(orientation),
+    });
+
+    // Count the items that are declared while the component renders
+    use_before_render(move || roving.declared.set(0));
+
+    // Drop the items that were not rendered again. If the items are rendered by child components, none are declared
+    // while this component renders and the group is left as it is
+    use_after_render(move || {
+        let len = *roving.declared.peek();
+        if len == 0 {
+            return;
+        }
+        roving.items.write().truncate(len);
+        if *roving.active.peek() >= len {
+            roving.active.set(len - 1);
+        }
+    });
+
+    // Keep the orientation up to date if the component passes in a new value
+    if *roving.orientation.peek() != orientation {
+        roving.orientation.set(orientation);
+    }
+
+    roving
+}
+
+/// A roving tabindex group created with [`use_roving_tabindex`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct RovingTabindex {
+    active: Signal<usize>,
+    items: CopyValue<Vec<Option<Rc<MountedData>>>>,
+    // The number of items declared in the current render
+    declared: CopyValue<usize>,
+    orientation: CopyValue<RovingOrientation>,
+}
+
+impl RovingTabindex {
+    /// The attributes to spread onto the item at `index` in the group
+    pub fn item(&self, index: usize) -> Vec<Attribute> {
+        let roving = *self;
+        let mut declared = self.declared;
+        if *declared.peek() <= index {
+            declared.set(index + 1);
+        }
+        let tabindex = match self.active() == index {
+            true => "0",
+            false => "-1",
+        };
+
+        vec![
+            Attribute::new("tabindex", tabindex, None, false),
+            crate::events::onmounted(move |event: MountedEvent| {
+                let mut items = roving.items;
+                let mut items = items.write();
+                if items.len() <= index {
+                    items.resize(index + 1, None);
+                }
+                items[index] = Some(event.data());
+            }),
+            crate::events::onfocus(move |_: FocusEvent| {
+                let mut active = roving.active;
+                if *active.peek() != index {
+                    active.set(index);
+                }
+            }),
+            crate::events::onkeydown(move |event: KeyboardEvent| {
+                let mut roving = roving;
+                let orientation = *roving.orientation.peek();
+                let horizontal = orientation != RovingOrientation::Vertical;
+                let vertical = orientation != RovingOrientation::Horizontal;
+                let target = match event.key() {
+                    Key::ArrowRight if horizontal => roving.step(index, true),
+                    Key::ArrowDown if vertical => roving.step(index, true),
+                    Key::ArrowLeft if horizontal => roving.step(index, false),
+                    Key::ArrowUp if vertical => roving.step(index, false),
+                    Key::Home => roving.first(),
+                    Key::End => roving.last(),
+                    _ => None,
+                };
+                if let Some(target) = target {
+                    event.prevent_default();
+                    roving.focus(target);
+                }
+            }),
+        ]
+    }
+
+    /// Get the index of the item that can be reached with Tab.
+    ///
+    /// Reading this will subscribe the current scope to changes in the active item.
+    pub fn active(&self) -> usize {
+        self.active.cloned()
+    }
+
+    /// Make the item at `index` active and move the focus to it
+    pub fn focus(&mut self, index: usize) {
+        self.active.set(index);
+        if let Some(Some(item)) = self.items.peek().get(index).cloned() {
+            spawn(async move {
+                if let Err(err) = item.set_focus(true).await {
+                    tracing::error!("Failed to focus item: {err}");
+                }
+            });
+        }
+    }
+
+    fn first(&self) -> Option<usize> {
+        self.items.peek().iter().position(Option::is_some)
+    }
+
+    fn last(&self) -> Option<usize> {
+        self.items.peek().iter().rposition(Option::is_some)
+    }
+
+    /// Find the next or previous mounted item from `index`, wrapping around at the ends
+    fn step(&self, index: usize, forward: bool) -> Option<usize> {
+        let items = self.items.peek();
+        let len = items.len();
+        (1..=len)
+            .map(|offset| match forward {
+                true => (index + offset) % len,
+                false => (index + len * 2 - offset) % len,
+            })
+            .find(|candidate| items[*candidate].is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RenderedElementBacking;
+    use crate::events::MountedResult;
+    use dioxus_core::{Element, VNode, VirtualDom};
+    use dioxus_core_macro::rsx;
+    use std::cell::{Cell, RefCell};
+    use std::future::Future;
+    use std::pin::Pin;
+
+    thread_local! {
+        static COUNT: Cell<Option<Signal<usize>>> = const { Cell::new(None) };
+        static ROVING: Cell<Option<RovingTabindex>> = const { Cell::new(None) };
+        static SHOW: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+        static ENABLED: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+        static TRAP: Cell<Option<FocusTrap>> = const { Cell::new(None) };
+        static CALLS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// An element that records the focus trap calls made on it
+    struct Recorder;
+
+    impl Recorder {
+        fn record(call: &'static str) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+            CALLS.with(|calls| calls.borrow_mut().push(call));
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    impl RenderedElementBacking for Recorder {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn trap_focus(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+            Self::record("trap")
+        }
+
+        fn release_focus_trap(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+            Self::record("release")
+        }
+    }
+
+    fn calls() -> Vec<&'static str> {
+        CALLS.with(|calls| calls.take())
+    }
+
+    /// Run the effects and tasks that are ready without waiting for more work
+    fn run_ready_work(dom: &mut VirtualDom) {
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        let work = std::pin::pin!(dom.wait_for_work());
+        _ = work.poll(&mut cx);
+    }
+
+    fn roving_group() -> Element {
+        let count = use_signal(|| 4);
+        let roving = use_roving_tabindex(RovingOrientation::Vertical);
+        for index in 0..count() {
+            roving.item(index);
+        }
+        COUNT.with(|cell| cell.set(Some(count)));
+        ROVING.with(|cell| cell.set(Some(roving)));
+        VNode::empty()
+    }
+
+    /// Render the group and mount every item except the ones in `unmounted`
+    fn mounted_group(unmounted: &[usize]) -> (VirtualDom, RovingTabindex) {
+        let mut dom = VirtualDom::new(roving_group);
+        dom.rebuild_in_place();
+        let roving = ROVING.with(Cell::get).unwrap();
+        dom.in_runtime(|| {
+            let mut items = roving.items;
+            *items.write() = (0..4)
+                .map(|index| (!unmounted.contains(&index)).then(|| Rc::new(MountedData::from(()))))
+                .collect();
+        });
+        (dom, roving)
+    }
+
+    fn set_count(dom: &mut VirtualDom, count: usize) {
+        dom.in_runtime(|| COUNT.with(Cell::get).unwrap().set(count));
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    }
+
+    #[test]
+    fn arrow_keys_wrap_and_skip_unmounted_items() {
+        let (dom, roving) = mounted_group(&[2]);
+        dom.in_runtime(|| {
+            assert_eq!(roving.step(1, true), Some(3));
+            assert_eq!(roving.step(3, true), Some(0));
+            assert_eq!(roving.step(0, false), Some(3));
+            assert_eq!(roving.first(), Some(0));
+            assert_eq!(roving.last(), Some(3));
+        });
+    }
+
+    #[test]
+    fn removed_items_leave_the_group() {
+        let (mut dom, roving) = mounted_group(&[]);
+        dom.in_runtime(|| {
+            let mut active = roving.active;
+            active.set(3);
+        });
+        set_count(&mut dom, 2);
+        dom.in_runtime(|| {
+            assert_eq!(roving.items.peek().len(), 2);
+            assert_eq!(*roving.active.peek(), 1);
+            assert_eq!(roving.first(), Some(0));
+            assert_eq!(roving.last(), Some(1));
+            assert_eq!(roving.step(1, true), Some(0));
+            assert_eq!(roving.step(0, false), Some(1));
+        });
+
+        // The active item stays where it is if it is still rendered
+        set_count(&mut dom, 1);
+        dom.in_runtime(|| {
+            assert_eq!(roving.last(), Some(0));
+            assert_eq!(roving.step(0, true), Some(0));
+            assert_eq!(*roving.active.peek(), 0);
+        });
+    }
+
+    fn Trapped() -> Element {
+        let enabled = use_signal(|| true);
+        let trap = use_focus_trap(enabled);
+        ENABLED.with(|cell| cell.set(Some(enabled)));
+        TRAP.with(|cell| cell.set(Some(trap)));
+        VNode::empty()
+    }
+
+    #[test]
+    fn focus_trap_follows_enabled_and_releases_on_drop() {
+        fn app() -> Element {
+            let show = use_signal(|| true);
+            SHOW.with(|cell| cell.set(Some(show)));
+            rsx! {
+                if show() {
+                    Trapped {}
+                }
+            }
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        run_ready_work(&mut dom);
+        // Nothing is trapped until the container is mounted
+        assert!(calls().is_empty());
+
+        let trap = TRAP.with(Cell::get).unwrap();
+        dom.in_runtime(|| trap.attach(Rc::new(MountedData::from(Recorder))));
+        run_ready_work(&mut dom);
+        assert_eq!(calls(), ["trap"]);
+
+        dom.in_runtime(|| ENABLED.with(Cell::get).unwrap().set(false));
+        run_ready_work(&mut dom);
+        assert_eq!(calls(), ["release"]);
+
+        dom.in_runtime(|| ENABLED.with(Cell::get).unwrap().set(true));
+        run_ready_work(&mut dom);
+        assert_eq!(calls(), ["trap"]);
+
+        dom.in_runtime(|| SHOW.with(Cell::get).unwrap().set(false));
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        run_ready_work(&mut dom);
+        assert_eq!(calls(), ["release"]);
+    }
+}
@@ -50,6 +50,19 @@ pub struct Pages;
 /// A vector expressed in Pages
 pub type PagesVector = Vector3D<f64, Pages>;
 
+/// The unit a [`WheelDelta`] is expressed in. This mirrors the web
+/// [`deltaMode`](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent/deltaMode) attribute.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub enum WheelDeltaMode {
+    /// The delta is in pixels
+    Pixels = 0,
+    /// The delta is in lines
+    Lines = 1,
+    /// The delta is in pages
+    Pages = 2,
+}
+
 /// A vector representing the amount the mouse wheel was moved
 ///
 /// This may be expressed in Pixels, Lines or Pages
@@ -65,6 +78,12 @@ pub enum WheelDelta {
 }
 
 impl WheelDelta {
+    /// The number of pixels one line is converted to in [`WheelDelta::to_pixels`]
+    pub const LINE_HEIGHT: f64 = 16.0;
+
+    /// The number of pixels one page is converted to in [`WheelDelta::to_pixels`]
+    pub const PAGE_HEIGHT: f64 = 800.0;
+
     /// Construct from the attributes of the web wheel event
     pub fn from_web_attributes(delta_mode: u32, delta_x: f64, delta_y: f64, delta_z: f64) -> Self {
         match delta_mode {
@@ -90,6 +109,27 @@ impl WheelDelta {
         WheelDelta::Pages(PagesVector::new(x, y, z))
     }
 
+    /// The unit this delta is expressed in
+    pub fn mode(&self) -> WheelDeltaMode {
+        match self {
+            WheelDelta::Pixels(_) => WheelDeltaMode::Pixels,
+            WheelDelta::Lines(_) => WheelDeltaMode::Lines,
+            WheelDelta::Pages(_) => WheelDeltaMode::Pages,
+        }
+    }
+
+    /// Convert the delta to pixels
+    ///
+    /// Lines are converted with [`WheelDelta::LINE_HEIGHT`] and pages with [`WheelDelta::PAGE_HEIGHT`]. The real size
+    /// of a line or page depends on the platform, but this keeps scrolling speed roughly consistent across devices.
+    pub fn to_pixels(&self) -> PixelsVector3D {
+        match self {
+            WheelDelta::Pixels(v) => *v,
+            WheelDelta::Lines(v) => (*v * Self::LINE_HEIGHT).cast_unit(),
+            WheelDelta::Pages(v) => (*v * Self::PAGE_HEIGHT).cast_unit(),
+        }
+    }
+
     /// Returns true iff there is no wheel movement
     ///
     /// i.e. the x, y and z delta is zero (disregards units)
@@ -158,3 +198,20 @@ impl Coordinates {
         self.page
     }
 }
+
+#[test]
+fn wheel_delta_to_pixels() {
+    assert_eq!(
+        WheelDelta::pixels(1., 2., 3.).to_pixels(),
+        PixelsVector3D::new(1., 2., 3.)
+    );
+    assert_eq!(
+        WheelDelta::lines(0., 3., 0.).to_pixels(),
+        PixelsVector3D::new(0., 3. * WheelDelta::LINE_HEIGHT, 0.)
+    );
+    assert_eq!(
+        WheelDelta::pages(0., -1., 0.).to_pixels(),
+        PixelsVector3D::new(0., -WheelDelta::PAGE_HEIGHT, 0.)
+    );
+    assert_eq!(WheelDelta::lines(0., 1., 0.).mode(), WheelDeltaMode::Lines);
+}
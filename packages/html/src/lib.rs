@@ -21,11 +21,40 @@ pub mod elements;
 pub use elements::HtmlCtx;
 #[cfg(feature = "html-to-rsx")]
 pub use elements::{map_html_attribute_to_rsx, map_html_element_to_rsx};
+pub mod bind;
 pub mod events;
 pub(crate) mod file_data;
 pub use file_data::*;
 mod attribute_groups;
 mod data_transfer;
+mod dynamic_element;
+pub use dynamic_element::*;
+mod modal;
+pub use modal::*;
+mod focus;
+pub use focus::*;
+mod element_handle;
+pub use element_handle::*;
+mod page_visibility;
+pub use page_visibility::*;
+mod surface_size;
+pub use surface_size::*;
+mod context_menu;
+pub use context_menu::*;
+mod control_flow;
+pub use control_flow::*;
+mod virtual_list;
+pub use virtual_list::*;
+mod sortable;
+pub use sortable::*;
+mod server_sent_events;
+pub use server_sent_events::*;
+mod before_unload;
+pub use before_unload::*;
+#[cfg(feature = "serialize")]
+mod use_form;
+#[cfg(feature = "serialize")]
+pub use use_form::*;
 pub mod geometry;
 pub mod input_data;
 pub mod point_interaction;
@@ -0,0 +1,261 @@
+use crate as dioxus_elements;
+use crate::{CancelEvent, InteractionLocation, MountedData, MountedEvent, MouseEvent};
+use dioxus_core::{Attribute, Element, EventHandler, spawn};
+use dioxus_core_macro::{Props, rsx};
+use dioxus_hooks::{use_effect, use_reactive, use_signal};
+use dioxus_signals::*;
+use std::rc::Rc;
+
+/// Props for the [`Dialog`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct DialogProps {
+    /// Whether the dialog is open. Changing this opens or closes the dialog.
+    pub open: bool,
+    /// Called when the dialog closes itself, for example when the user presses escape or clicks the backdrop. Set
+    /// `open` to `false` in this handler to keep it in sync with the dialog.
+    #[props(default)]
+    pub onclose: EventHandler,
+    /// Close the dialog when the user clicks on the backdrop outside of it
+    #[props(default = true)]
+    pub close_on_backdrop: bool,
+    /// Attributes for the `<dialog>` element
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+    /// The contents of the dialog
+    pub children: Element,
+}
+
+/// A modal `<dialog>` that is opened and closed by the `open` prop.
+///
+/// The dialog is opened with [`MountedData::show_modal`], so the browser keeps focus inside of it and makes the rest of
+/// the page inert while it is open. Pressing escape or clicking the backdrop closes the dialog and calls `onclose`.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     let mut open = use_signal(|| false);
+///
+///     rsx! {
+///         button { onclick: move |_| open.set(true), "Open" }
+///         Dialog {
+///             open: open(),
+///             onclose: move |_| open.set(false),
+///             h2 { "Hello from a modal" }
+///             button { onclick: move |_| open.set(false), "Close" }
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Dialog(props: DialogProps) -> Element {
+    let mut element = use_signal(|| None::<Rc<MountedData>>);
+
+    // Sync the dialog with the open prop once it is mounted
+    use_effect(use_reactive((&props.open,), move |(open,)| {
+        let Some(element) = element() else {
+            return;
+        };
+        spawn(async move {
+            let result = match open {
+                true => element.show_modal().await,
+                false => element.close().await,
+            };
+            if let Err(err) = result {
+                tracing::error!("Failed to update dialog: {err}");
+            }
+        });
+    }));
+
+    let open = props.open;
+    let onclose = props.onclose;
+    let close_on_backdrop = props.close_on_backdrop;
+
+    rsx! {
+        dialog {
+            onmounted: move |event: MountedEvent| element.set(Some(event.data())),
+            onclose: move |_: CancelEvent| {
+                // Only report closes that didn't come from the open prop
+                if open {
+                    onclose.call(());
+                }
+            },
+            onclick: move |event: MouseEvent| async move {
+                if !close_on_backdrop {
+                    return;
+                }
+                let Some(element) = element() else {
+                    return;
+                };
+                // Clicks on the backdrop land on the dialog element itself, outside of its box
+                let Ok(rect) = element.get_client_rect().await else {
+                    return;
+                };
+                let point = event.client_coordinates();
+                // Clicks triggered from the keyboard don't have a position
+                if point.x == 0.0 && point.y == 0.0 {
+                    return;
+                }
+                if !rect.contains(point.cast_unit()) {
+                    onclose.call(());
+                }
+            },
+            ..props.attributes,
+            {props.children}
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use super::*;
+    use crate::events::MountedResult;
+    use crate::{
+        HtmlEventConverter, PlatformEventData, RenderedElementBacking, SerializedCancelData,
+        SerializedHtmlEventConverter,
+    };
+    use dioxus_core::{ElementId, Event, Mutation, VirtualDom};
+    use std::any::Any;
+    use std::cell::{Cell, RefCell};
+    use std::future::Future;
+    use std::pin::Pin;
+
+    thread_local! {
+        static OPEN: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+        static CALLS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(call: &'static str) {
+        CALLS.with(|calls| calls.borrow_mut().push(call));
+    }
+
+    fn calls() -> Vec<&'static str> {
+        CALLS.with(|calls| calls.take())
+    }
+
+    /// A dialog element that records the calls made on it
+    struct Recorder;
+
+    impl RenderedElementBacking for Recorder {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn show_modal(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+            record("show_modal");
+            Box::pin(async { Ok(()) })
+        }
+
+        fn close(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+            record("close");
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    /// Converts serialized events, except for events that carry a function that builds the data directly
+    struct TestConverter;
+
+    macro_rules! expand_test_converter {
+        (
+            enum Event {
+                $(
+                    #[convert = $converter:ident]
+                    #[events = [
+                        $(
+                            $( #[$attr:meta] )*
+                            $name:ident => $raw:ident,
+                        )*
+                    ]]
+                    $(#[raw = [$($raw_only:ident),* $(,)?]])?
+                    $group:ident($data:ident),
+                )*
+            }
+        ) => {
+            impl HtmlEventConverter for TestConverter {
+                $(
+                    fn $converter(&self, event: &PlatformEventData) -> crate::$data {
+                        match event.downcast::<fn() -> crate::$data>() {
+                            Some(build) => build(),
+                            None => SerializedHtmlEventConverter.$converter(event),
+                        }
+                    }
+                )*
+            }
+        };
+    }
+
+    crate::with_html_event_groups!(expand_test_converter);
+
+    /// Run the effects and tasks that are ready without waiting for more work
+    fn run_ready_work(dom: &mut VirtualDom) {
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        let work = std::pin::pin!(dom.wait_for_work());
+        _ = work.poll(&mut cx);
+    }
+
+    fn send(dom: &VirtualDom, name: &str, data: Box<dyn Any>, element: ElementId) {
+        let event = Event::new(Rc::new(PlatformEventData::new(data)) as Rc<dyn Any>, false);
+        dom.runtime().handle_event(name, event, element);
+    }
+
+    fn set_open(dom: &mut VirtualDom, open: bool) {
+        dom.in_runtime(|| OPEN.with(Cell::get).unwrap().set(open));
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        run_ready_work(dom);
+    }
+
+    #[test]
+    fn dialog_follows_open_and_reports_closes() {
+        fn app() -> Element {
+            let mut open = use_signal(|| false);
+            OPEN.with(|cell| cell.set(Some(open)));
+            rsx! {
+                Dialog {
+                    open: open(),
+                    onclose: move |_| {
+                        record("onclose");
+                        open.set(false);
+                    },
+                    "Hello"
+                }
+            }
+        }
+
+        crate::set_event_converter(Box::new(TestConverter));
+        let mut dom = VirtualDom::new(app);
+        let dialog = dom
+            .rebuild_to_vec()
+            .edits
+            .into_iter()
+            .find_map(|edit| match edit {
+                Mutation::NewEventListener { name, id } if name == "close" => Some(id),
+                _ => None,
+            })
+            .unwrap();
+        run_ready_work(&mut dom);
+        // Nothing happens until the dialog is mounted
+        assert!(calls().is_empty());
+
+        let mount: fn() -> MountedData = || MountedData::from(Recorder);
+        send(&dom, "mounted", Box::new(mount), dialog);
+        run_ready_work(&mut dom);
+        assert_eq!(calls(), ["close"]);
+
+        set_open(&mut dom, true);
+        assert_eq!(calls(), ["show_modal"]);
+
+        // The user closing the dialog reports the close, and the handler closes it through the prop
+        send(&dom, "close", Box::new(SerializedCancelData {}), dialog);
+        assert_eq!(calls(), ["onclose"]);
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        run_ready_work(&mut dom);
+        assert_eq!(calls(), ["close"]);
+
+        // Closing the dialog through the prop doesn't call onclose
+        set_open(&mut dom, true);
+        assert_eq!(calls(), ["show_modal"]);
+        set_open(&mut dom, false);
+        assert_eq!(calls(), ["close"]);
+        send(&dom, "close", Box::new(SerializedCancelData {}), dialog);
+        assert!(calls().is_empty());
+    }
+}
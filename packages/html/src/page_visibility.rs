@@ -0,0 +1,24 @@
+/// Whether the page can be seen by the user. Renderers provide a `use_page_visibility` hook that tracks it.
+///
+/// Pages are hidden when the tab is in the background, the window is minimized, or the screen is locked. Hidden pages
+/// can pause work the user won't see, like animations, timers, or polling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PageVisibility {
+    /// At least part of the page is visible
+    #[default]
+    Visible,
+    /// The page is not visible to the user
+    Hidden,
+}
+
+impl PageVisibility {
+    /// Check if the page is visible
+    pub fn is_visible(&self) -> bool {
+        *self == Self::Visible
+    }
+
+    /// Check if the page is hidden
+    pub fn is_hidden(&self) -> bool {
+        *self == Self::Hidden
+    }
+}
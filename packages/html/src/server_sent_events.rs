@@ -0,0 +1,30 @@
+/// A message received from a [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events)
+/// endpoint
+#[derive(Clone, Debug, PartialEq)]
+pub struct SseEvent {
+    /// The name of the event from the `event:` field. Events without a name are called `message`.
+    pub event: String,
+    /// The data of the event. Multiple `data:` fields are joined with newlines.
+    pub data: String,
+    /// The id of the last event the server sent, if any
+    pub id: Option<String>,
+}
+
+impl SseEvent {
+    /// Deserialize the data of the event from JSON
+    #[cfg(feature = "serialize")]
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.data)
+    }
+}
+
+/// The state of the connection to a server-sent events endpoint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SseState {
+    /// The connection is being opened or reopened after it was lost
+    Connecting,
+    /// The connection is open and receiving events
+    Open,
+    /// The connection was closed and will not reconnect
+    Closed,
+}
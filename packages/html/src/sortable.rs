@@ -0,0 +1,702 @@
+use crate as dioxus_elements;
+use crate::geometry::PixelsRect;
+use crate::{
+    FocusEvent, InteractionLocation, Key, KeyboardEvent, MountedData, MountedEvent, PointerEvent,
+};
+use dioxus_core::{Attribute, Callback, Element, EventHandler, spawn, use_hook};
+use dioxus_core_macro::{Props, rsx};
+use dioxus_hooks::use_signal;
+use dioxus_signals::*;
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+/// Instructions screen readers read for the drag handle of every item
+const INSTRUCTIONS: &str = "Press space to pick up the item. Use the arrow keys to move it, space to drop it, or escape to cancel.";
+
+/// An item moved to a new position in a [`Sortable`] list
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reorder {
+    /// The index the item was at before it moved
+    pub from: usize,
+    /// The index the item is at now
+    pub to: usize,
+}
+
+/// An item passed to the render callback of a [`Sortable`] list
+pub struct SortableItem<T> {
+    /// The value of the item
+    pub value: T,
+    /// The position of the item in the list
+    pub index: usize,
+    /// Whether the item is being dragged with the pointer or moved with the keyboard
+    pub dragging: bool,
+    /// The attributes to spread onto the element the user drags the item with. The handle is focusable and starts
+    /// moving the item with the keyboard when the user presses space.
+    pub handle: Vec<Attribute>,
+}
+
+/// Props for the [`Sortable`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct SortableProps<T: Clone + PartialEq + 'static> {
+    /// The items in the list. The list is updated when the user drops an item at a new position.
+    pub items: Signal<Vec<T>>,
+    /// Get the unique key of an item. Keys let the list move the existing elements when the items are reordered.
+    pub key: Callback<T, String>,
+    /// Render an item. Spread [`SortableItem::handle`] onto the element the user should drag the item with.
+    pub render: Callback<SortableItem<T>, Element>,
+    /// Called after an item is dropped at a new position and the list is updated
+    #[props(default)]
+    pub onreorder: EventHandler<Reorder>,
+    /// Attributes for the element that contains the list
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A vertical list that the user can reorder by dragging items with the pointer or moving them with the keyboard.
+///
+/// Each item renders a drag handle by spreading [`SortableItem::handle`] onto an element. Dragging the handle moves
+/// the item with the pointer, and the other items slide out of the way to show where it will be dropped. The handle
+/// captures the pointer, so the drag keeps going if the pointer leaves the list. The handle is also focusable:
+/// pressing space picks the item up, the arrow keys move it, space drops it, and escape puts it back. Moves are
+/// announced to screen readers in a live region.
+///
+/// The list in `items` is only updated when the item is dropped, and [`SortableProps::onreorder`] is called with the
+/// old and new index of the item.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, PartialEq)]
+/// struct Task {
+///     id: u32,
+///     title: String,
+/// }
+///
+/// fn App() -> Element {
+///     let tasks = use_signal(|| {
+///         vec![
+///             Task { id: 0, title: "Write docs".to_string() },
+///             Task { id: 1, title: "Fix bugs".to_string() },
+///             Task { id: 2, title: "Ship it".to_string() },
+///         ]
+///     });
+///
+///     rsx! {
+///         Sortable {
+///             items: tasks,
+///             key: |task: Task| task.id.to_string(),
+///             render: |item: SortableItem<Task>| rsx! {
+///                 div { class: if item.dragging { "task dragging" } else { "task" },
+///                     span { aria_label: "Move {item.value.title}", ..item.handle, "⠿" }
+///                     "{item.value.title}"
+///                 }
+///             },
+///             onreorder: |reorder: Reorder| println!("Moved {} to {}", reorder.from, reorder.to),
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Sortable<T: Clone + PartialEq + 'static>(props: SortableProps<T>) -> Element {
+    let drag = use_signal(|| None::<DragState>);
+    let announcement = use_signal(String::new);
+    let rows = use_hook(|| CopyValue::new(HashMap::new()));
+    let handles = use_hook(|| CopyValue::new(HashMap::new()));
+    let keys = use_hook(|| CopyValue::new(Vec::new()));
+    let state = SortableState {
+        items: props.items,
+        drag,
+        announcement,
+        rows,
+        handles,
+        keys,
+        onreorder: props.onreorder,
+    };
+
+    let items: Vec<(String, T)> = props
+        .items
+        .read()
+        .iter()
+        .map(|item| (props.key.call(item.clone()), item.clone()))
+        .collect();
+    state.update_keys(items.iter().map(|(key, _)| key.clone()).collect());
+
+    let drag_state = drag.read();
+    let rows: Vec<_> = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, (key, value))| {
+            let dragging = drag_state.as_ref().is_some_and(|drag| drag.from == index);
+            let node = props.render.call(SortableItem {
+                value,
+                index,
+                dragging,
+                handle: state.handle(index, key.clone(), dragging),
+            });
+            let row = state.row(index, key.clone(), drag_state.as_ref());
+            (key, row, node)
+        })
+        .collect();
+    drop(drag_state);
+
+    rsx! {
+        div {
+            onpointermove: move |event: PointerEvent| {
+                state.move_pointer(event.client_coordinates().y);
+            },
+            onpointerup: move |_: PointerEvent| {
+                if state.is_dragging_pointer() {
+                    state.drop_item();
+                }
+            },
+            onpointercancel: move |_: PointerEvent| {
+                if state.is_dragging_pointer() {
+                    state.cancel();
+                }
+            },
+            ..props.attributes,
+            for (key, row, node) in rows {
+                div { key: "{key}", ..row, {node} }
+            }
+            // Visually hidden, but read by screen readers whenever the text changes
+            div {
+                role: "status",
+                "aria-live": "assertive",
+                position: "absolute",
+                width: "1px",
+                height: "1px",
+                overflow: "hidden",
+                clip: "rect(0 0 0 0)",
+                white_space: "nowrap",
+                "{announcement}"
+            }
+        }
+    }
+}
+
+/// An item that was picked up and hasn't been dropped yet
+#[derive(Clone, PartialEq)]
+struct DragState {
+    /// The index of the item that is moving
+    from: usize,
+    /// The index the item will be dropped at
+    target: usize,
+    /// The vertical position of the pointer when the drag started and now, or `None` if the item is moved with the
+    /// keyboard
+    pointer: Option<(f64, f64)>,
+    /// The bounds of every item when the drag started. Empty until the items are measured.
+    rects: Vec<PixelsRect>,
+}
+
+impl DragState {
+    /// How far the item at `index` is moved from its position in the list while the drag is in progress
+    fn offset(&self, index: usize) -> f64 {
+        let rects = &self.rects;
+        let (from, target) = (self.from, self.target);
+        if rects.len() <= from.max(target).max(index) {
+            return 0.0;
+        }
+
+        // The moving item follows the pointer, or jumps to the target slot when moved with the keyboard
+        if index == from {
+            return match self.pointer {
+                Some((start, current)) => current - start,
+                None if target > from => rects[target].max_y() - rects[from].max_y(),
+                None => rects[target].min_y() - rects[from].min_y(),
+            };
+        }
+
+        // The items between the old and new position slide over to make room for the moving item
+        let gap = match rects.len() > 1 {
+            true => rects[1].min_y() - rects[0].max_y(),
+            false => 0.0,
+        };
+        let shift = rects[from].height() + gap;
+        if from < index && index <= target {
+            -shift
+        } else if target <= index && index < from {
+            shift
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The state of a [`Sortable`] list shared by the event handlers
+struct SortableState<T: 'static> {
+    items: Signal<Vec<T>>,
+    drag: Signal<Option<DragState>>,
+    announcement: Signal<String>,
+    /// The elements of the items by key, used to measure the items when a drag starts
+    rows: CopyValue<HashMap<String, Rc<MountedData>>>,
+    /// The drag handles of the items by key, used to restore the focus after an item is dropped
+    handles: CopyValue<HashMap<String, Rc<MountedData>>>,
+    /// The keys of the items in the order they were last rendered
+    keys: CopyValue<Vec<String>>,
+    onreorder: EventHandler<Reorder>,
+}
+
+impl<T> Clone for SortableState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SortableState<T> {}
+
+impl<T: 'static> SortableState<T> {
+    /// Remember the keys of the items and forget the elements of items that were removed
+    fn update_keys(mut self, keys: Vec<String>) {
+        let current: HashSet<&String> = keys.iter().collect();
+        self.rows.write().retain(|key, _| current.contains(key));
+        self.handles.write().retain(|key, _| current.contains(key));
+        self.keys.set(keys);
+    }
+
+    /// The attributes for the element that wraps the item at `index`
+    fn row(self, index: usize, key: String, drag: Option<&DragState>) -> Vec<Attribute> {
+        let offset = drag.map(|drag| drag.offset(index)).unwrap_or_default();
+        // The item under the pointer follows it directly. Everything else animates into place while an item is
+        // moving, but not when it is dropped because the elements are reordered at the same time.
+        let animate = drag.is_some_and(|drag| drag.from != index || drag.pointer.is_none());
+        let mut rows = self.rows;
+
+        vec![
+            Attribute::new("position", "relative", Some("style"), false),
+            Attribute::new(
+                "transform",
+                format!("translateY({offset}px)"),
+                Some("style"),
+                false,
+            ),
+            Attribute::new(
+                "transition",
+                match animate {
+                    true => "transform 150ms ease",
+                    false => "none",
+                },
+                Some("style"),
+                false,
+            ),
+            Attribute::new(
+                "z-index",
+                match drag.is_some_and(|drag| drag.from == index) {
+                    true => "1",
+                    false => "auto",
+                },
+                Some("style"),
+                false,
+            ),
+            crate::events::onmounted(move |event: MountedEvent| {
+                rows.write().insert(key.clone(), event.data());
+            }),
+        ]
+    }
+
+    /// The attributes for the drag handle of the item at `index`
+    fn handle(self, index: usize, key: String, dragging: bool) -> Vec<Attribute> {
+        let mut handles = self.handles;
+        let handle_key = key.clone();
+
+        vec![
+            Attribute::new("tabindex", "0", None, false),
+            Attribute::new("role", "button", None, false),
+            Attribute::new("aria-roledescription", "sortable item", None, false),
+            Attribute::new("aria-description", INSTRUCTIONS, None, false),
+            Attribute::new("aria-pressed", dragging.to_string(), None, false),
+            Attribute::new(
+                "cursor",
+                match dragging {
+                    true => "grabbing",
+                    false => "grab",
+                },
+                Some("style"),
+                false,
+            ),
+            // Keep touch devices from scrolling the page instead of dragging the item
+            Attribute::new("touch-action", "none", Some("style"), false),
+            Attribute::new("user-select", "none", Some("style"), false),
+            crate::events::onmounted(move |event: MountedEvent| {
+                handles.write().insert(key.clone(), event.data());
+            }),
+            crate::events::onpointerdown(move |event: PointerEvent| {
+                if event.is_primary() && self.drag.peek().is_none() {
+                    event.prevent_default();
+                    self.pick_up(index, Some(event.client_coordinates().y));
+                    self.capture_pointer(&handle_key, event.pointer_id());
+                }
+            }),
+            crate::events::onkeydown(move |event: KeyboardEvent| {
+                if self.key_down(index, event.key()) {
+                    event.prevent_default();
+                }
+            }),
+            crate::events::onblur(move |_: FocusEvent| {
+                let lifted = self
+                    .drag
+                    .peek()
+                    .as_ref()
+                    .is_some_and(|drag| drag.from == index && drag.pointer.is_none());
+                if lifted {
+                    self.cancel();
+                }
+            }),
+        ]
+    }
+
+    /// Move the item at `index` with the keyboard. Returns `true` if the key was handled.
+    fn key_down(self, index: usize, key: Key) -> bool {
+        let lifted = self
+            .drag
+            .peek()
+            .as_ref()
+            .is_some_and(|drag| drag.from == index && drag.pointer.is_none());
+        match key {
+            Key::Character(character) if character == " " => {
+                match lifted {
+                    true => self.drop_item(),
+                    false if self.drag.peek().is_none() => self.pick_up(index, None),
+                    false => {}
+                }
+                true
+            }
+            Key::ArrowUp if lifted => {
+                self.step(false);
+                true
+            }
+            Key::ArrowDown if lifted => {
+                self.step(true);
+                true
+            }
+            Key::Escape if lifted => {
+                self.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Send the events of the pointer to the handle of the item with `key` until it is released, so the drag keeps
+    /// going when the pointer leaves the list
+    fn capture_pointer(self, key: &str, pointer_id: i32) {
+        let Some(handle) = self.handles.peek().get(key).cloned() else {
+            return;
+        };
+        spawn(async move {
+            _ = handle.set_pointer_capture(pointer_id).await;
+        });
+    }
+
+    fn is_dragging_pointer(self) -> bool {
+        self.drag
+            .peek()
+            .as_ref()
+            .is_some_and(|drag| drag.pointer.is_some())
+    }
+
+    /// Start moving the item at `index`, either with the pointer at `pointer` or with the keyboard
+    fn pick_up(mut self, index: usize, pointer: Option<f64>) {
+        let count = self.keys.peek().len();
+        self.drag.set(Some(DragState {
+            from: index,
+            target: index,
+            pointer: pointer.map(|y| (y, y)),
+            rects: Vec::new(),
+        }));
+        self.announce(format!(
+            "Picked up item {} of {count}. {INSTRUCTIONS}",
+            index + 1
+        ));
+
+        // Measure the items so the other items know how far to move out of the way
+        let rows: Option<Vec<Rc<MountedData>>> = {
+            let rows = self.rows.peek();
+            self.keys
+                .peek()
+                .iter()
+                .map(|key| rows.get(key).cloned())
+                .collect()
+        };
+        let Some(rows) = rows else {
+            return;
+        };
+        spawn(async move {
+            let mut rects = Vec::with_capacity(rows.len());
+            for row in rows {
+                match row.get_client_rect().await {
+                    Ok(rect) => rects.push(rect),
+                    Err(_) => return,
+                }
+            }
+            let mut drag = self.drag.write();
+            if let Some(drag) = drag.as_mut().filter(|drag| drag.from == index) {
+                drag.rects = rects;
+            }
+        });
+    }
+
+    /// Follow the pointer and move the target to the slot the dragged item is over
+    fn move_pointer(mut self, y: f64) {
+        let mut drag = self.drag.write();
+        let Some(drag) = drag.as_mut() else {
+            return;
+        };
+        let Some((start, _)) = drag.pointer else {
+            return;
+        };
+        drag.pointer = Some((start, y));
+
+        if let Some(rect) = drag.rects.get(drag.from) {
+            let center = rect.center().y + y - start;
+            drag.target = drag
+                .rects
+                .iter()
+                .enumerate()
+                .filter(|(index, rect)| *index != drag.from && rect.center().y < center)
+                .count();
+        }
+    }
+
+    /// Move the target one slot up or down with the keyboard
+    fn step(mut self, down: bool) {
+        let count = self.keys.peek().len();
+        let target = {
+            let mut drag = self.drag.write();
+            let Some(drag) = drag.as_mut() else {
+                return;
+            };
+            drag.target = match down {
+                true => (drag.target + 1).min(count.saturating_sub(1)),
+                false => drag.target.saturating_sub(1),
+            };
+            drag.target
+        };
+        self.announce(format!("Moved to position {} of {count}", target + 1));
+    }
+
+    /// Drop the item at the target and update the list
+    fn drop_item(mut self) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+        let (from, to) = (drag.from, drag.target);
+        let count = self.keys.peek().len();
+        let key = self.keys.peek().get(from).cloned();
+
+        if from != to {
+            let mut items = self.items.write();
+            if from < items.len() && to < items.len() {
+                let item = items.remove(from);
+                items.insert(to, item);
+            }
+        }
+        self.announce(format!(
+            "Dropped the item at position {} of {count}",
+            to + 1
+        ));
+
+        // Moving the element can remove the focus from the handle, so give it back
+        if drag.pointer.is_none() {
+            if let Some(handle) = key.and_then(|key| self.handles.peek().get(&key).cloned()) {
+                spawn(async move {
+                    _ = handle.set_focus(true).await;
+                });
+            }
+        }
+
+        if from != to {
+            self.onreorder.call(Reorder { from, to });
+        }
+    }
+
+    /// Put the item back where it was picked up
+    fn cancel(mut self) {
+        if let Some(drag) = self.drag.take() {
+            self.announce(format!(
+                "Cancelled. The item returned to position {}",
+                drag.from + 1
+            ));
+        }
+    }
+
+    fn announce(mut self, message: String) {
+        self.announcement.set(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::euclid::{Point2D, Rect, Size2D};
+    use dioxus_core::VirtualDom;
+    use std::cell::{Cell, RefCell};
+
+    thread_local! {
+        static STATE: Cell<Option<SortableState<&'static str>>> = const { Cell::new(None) };
+        static REORDERS: RefCell<Vec<Reorder>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Three items that are 10 pixels tall with 5 pixels between them
+    fn rects() -> Vec<PixelsRect> {
+        (0..3)
+            .map(|i| Rect::new(Point2D::new(0.0, i as f64 * 15.0), Size2D::new(100.0, 10.0)))
+            .collect()
+    }
+
+    fn offsets(from: usize, target: usize, pointer: Option<(f64, f64)>) -> Vec<f64> {
+        let drag = DragState {
+            from,
+            target,
+            pointer,
+            rects: rects(),
+        };
+        (0..3).map(|index| drag.offset(index)).collect()
+    }
+
+    fn space() -> Key {
+        Key::Character(" ".to_string())
+    }
+
+    /// Run `test` with the state of a list with the items `a`, `b` and `c`
+    fn with_list(test: impl FnOnce(SortableState<&'static str>)) {
+        let mut dom = VirtualDom::new(|| {
+            let items = use_signal(|| vec!["a", "b", "c"]);
+            let state = use_hook(|| SortableState {
+                items,
+                drag: Signal::new(None),
+                announcement: Signal::new(String::new()),
+                rows: CopyValue::new(HashMap::new()),
+                handles: CopyValue::new(HashMap::new()),
+                keys: CopyValue::new(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+                onreorder: EventHandler::new(|reorder: Reorder| {
+                    REORDERS.with(|reorders| reorders.borrow_mut().push(reorder))
+                }),
+            });
+            STATE.with(|cell| cell.set(Some(state)));
+            rsx! {}
+        });
+        dom.rebuild_in_place();
+        let state = STATE.with(Cell::get).unwrap();
+        dom.in_runtime(|| test(state));
+    }
+
+    fn target(state: SortableState<&'static str>) -> Option<usize> {
+        state.drag.peek().as_ref().map(|drag| drag.target)
+    }
+
+    fn reorders() -> Vec<Reorder> {
+        REORDERS.with(|reorders| reorders.borrow().clone())
+    }
+
+    #[test]
+    fn keyboard_moves_shift_the_items_in_between() {
+        assert_eq!(offsets(0, 2, None), [30.0, -15.0, -15.0]);
+        assert_eq!(offsets(2, 0, None), [15.0, 15.0, -30.0]);
+        assert_eq!(offsets(1, 1, None), [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn the_dragged_item_follows_the_pointer() {
+        assert_eq!(offsets(1, 1, Some((10.0, 18.0))), [0.0, 8.0, 0.0]);
+        assert_eq!(offsets(0, 1, Some((5.0, 20.0))), [15.0, -15.0, 0.0]);
+    }
+
+    #[test]
+    fn items_are_not_moved_before_they_are_measured() {
+        let drag = DragState {
+            from: 0,
+            target: 2,
+            pointer: None,
+            rects: Vec::new(),
+        };
+        assert_eq!(drag.offset(0), 0.0);
+        assert_eq!(drag.offset(1), 0.0);
+    }
+
+    #[test]
+    fn keyboard_pick_up_move_and_drop() {
+        with_list(|state| {
+            assert!(state.key_down(0, space()));
+            assert_eq!(target(state), Some(0));
+
+            assert!(state.key_down(0, Key::ArrowDown));
+            assert!(state.key_down(0, Key::ArrowDown));
+            // The target stops at the end of the list
+            assert!(state.key_down(0, Key::ArrowDown));
+            assert_eq!(target(state), Some(2));
+            assert_eq!(*state.announcement.peek(), "Moved to position 3 of 3");
+            // Only the handle of the item that was picked up moves it
+            assert!(!state.key_down(1, Key::ArrowUp));
+
+            assert!(state.key_down(0, space()));
+            assert_eq!(target(state), None);
+            assert_eq!(*state.items.peek(), ["b", "c", "a"]);
+            assert_eq!(reorders(), [Reorder { from: 0, to: 2 }]);
+        });
+    }
+
+    #[test]
+    fn escape_cancels_the_move() {
+        with_list(|state| {
+            assert!(state.key_down(1, space()));
+            assert!(state.key_down(1, Key::ArrowUp));
+            assert_eq!(target(state), Some(0));
+
+            assert!(state.key_down(1, Key::Escape));
+            assert_eq!(target(state), None);
+            assert_eq!(*state.items.peek(), ["a", "b", "c"]);
+            assert_eq!(
+                *state.announcement.peek(),
+                "Cancelled. The item returned to position 2"
+            );
+            assert!(reorders().is_empty());
+
+            // Escape does nothing once nothing is picked up
+            assert!(!state.key_down(1, Key::Escape));
+        });
+    }
+
+    #[test]
+    fn dropping_in_place_does_not_reorder() {
+        with_list(|state| {
+            assert!(state.key_down(2, space()));
+            assert!(state.key_down(2, space()));
+            assert_eq!(*state.items.peek(), ["a", "b", "c"]);
+            assert!(reorders().is_empty());
+        });
+    }
+
+    #[test]
+    fn pointer_drag_targets_the_slot_under_the_item() {
+        with_list(|state| {
+            state.pick_up(0, Some(5.0));
+            let mut drag = state.drag;
+            drag.write().as_mut().unwrap().rects = rects();
+
+            // The center of the item moves from 5 to 25, past the center of the second item
+            state.move_pointer(25.0);
+            assert_eq!(target(state), Some(1));
+            state.move_pointer(45.0);
+            assert_eq!(target(state), Some(2));
+            state.move_pointer(0.0);
+            assert_eq!(target(state), Some(0));
+            state.move_pointer(30.0);
+
+            state.drop_item();
+            assert_eq!(*state.items.peek(), ["b", "a", "c"]);
+            assert_eq!(reorders(), [Reorder { from: 0, to: 1 }]);
+        });
+    }
+
+    #[test]
+    fn pointer_moves_are_ignored_when_moving_with_the_keyboard() {
+        with_list(|state| {
+            state.pick_up(0, None);
+            let mut drag = state.drag;
+            drag.write().as_mut().unwrap().rects = rects();
+            state.move_pointer(45.0);
+            assert_eq!(target(state), Some(0));
+        });
+    }
+}
@@ -0,0 +1,32 @@
+/// The size of a GPU surface in physical pixels. Renderers provide a hook that tracks it for the surface you render
+/// to with wgpu: `use_gpu_canvas` on web and `use_gpu_surface` on desktop.
+///
+/// Configure the surface with `width` and `height` whenever the size changes. `scale_factor` is the number of
+/// physical pixels per logical (css) pixel, which you can use to scale text and lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SurfaceSize {
+    /// The width of the surface in physical pixels
+    pub width: u32,
+    /// The height of the surface in physical pixels
+    pub height: u32,
+    /// The number of physical pixels per logical pixel
+    pub scale_factor: f64,
+}
+
+impl SurfaceSize {
+    /// Check if the surface has no area. Surfaces can't be configured with a size of zero, so skip rendering until
+    /// the surface is visible again.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// The width of the surface in logical pixels
+    pub fn logical_width(&self) -> f64 {
+        self.width as f64 / self.scale_factor
+    }
+
+    /// The height of the surface in logical pixels
+    pub fn logical_height(&self) -> f64 {
+        self.height as f64 / self.scale_factor
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{FocusEvent, FormEvent};
+use dioxus_core::{Attribute, use_hook};
+use dioxus_hooks::use_signal;
+use dioxus_signals::*;
+use serde::de::DeserializeOwned;
+use std::{collections::HashMap, marker::PhantomData, rc::Rc};
+
+type Validator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+#[derive(Clone, Default, PartialEq)]
+struct FieldState {
+    value: String,
+    dirty: bool,
+    touched: bool,
+    error: Option<String>,
+}
+
+/// The fields of a struct that a [`use_form`] deserializes into. Derive it with `#[derive(Form)]`.
+pub trait FormFields: DeserializeOwned {
+    /// The names of the fields, as serde deserializes them
+    const FIELDS: &'static [&'static str];
+}
+
+/// Create a controlled form that deserializes into `T` when it is submitted.
+///
+/// Each input is connected to the form with [`Form::register`], which sets the `name` and `value` of the input and
+/// tracks changes. The form remembers whether each field was changed (dirty) or focused and left (touched), and runs
+/// the validators added with [`Form::validate`] whenever a field changes.
+///
+/// When the form is submitted, every field is marked as touched and validated. If all of the fields are valid, the
+/// values are deserialized into `T` with [`FormData::parsed_values`](crate::FormData::parsed_values) and passed to the
+/// handler given to [`Form::onsubmit`]. Derive [`FormFields`] for `T` with `#[derive(Form)]` to list its fields, and
+/// register each input with the name of its field.
+///
+/// ## Example
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// use dioxus::html::use_form;
+///
+/// #[derive(serde::Deserialize, Form)]
+/// struct Signup {
+///     email: String,
+///     password: String,
+/// }
+///
+/// fn App() -> Element {
+///     let form = use_form::<Signup>();
+///     form.validate("email", |email| match email.contains('@') {
+///         true => Ok(()),
+///         false => Err("Enter a valid email".to_string()),
+///     });
+///     form.validate("password", |password| match password.len() >= 8 {
+///         true => Ok(()),
+///         false => Err("Use at least 8 characters".to_string()),
+///     });
+///
+///     rsx! {
+///         form {
+///             onsubmit: form.onsubmit(|signup: Signup| println!("Signing up {}", signup.email)),
+///             input { r#type: "email", ..form.register("email") }
+///             if let Some(error) = form.error("email") {
+///                 p { "{error}" }
+///             }
+///             input { r#type: "password", ..form.register("password") }
+///             if let Some(error) = form.error("password") {
+///                 p { "{error}" }
+///             }
+///             button { disabled: !form.is_valid(), "Sign up" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_form<T: FormFields + 'static>() -> Form<T> {
+    let fields = use_signal(empty_fields::<T>);
+    let validators = use_hook(|| CopyValue::new(HashMap::new()));
+    let submit_error = use_signal(|| None);
+    Form {
+        fields,
+        validators,
+        submit_error,
+        _marker: PhantomData,
+    }
+}
+
+/// A controlled form created with [`use_form`]
+pub struct Form<T> {
+    fields: Signal<HashMap<String, FieldState>>,
+    validators: CopyValue<HashMap<String, Validator>>,
+    submit_error: Signal<Option<String>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Form<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Form<T> {}
+
+impl<T> PartialEq for Form<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields
+    }
+}
+
+impl<T: FormFields + 'static> Form<T> {
+    /// Get the attributes that connect an input to the field `name`. Spread them into the input with `..form.register("name")`.
+    pub fn register(&self, name: &str) -> Vec<Attribute> {
+        #[cfg(debug_assertions)]
+        if !T::FIELDS.contains(&name) {
+            tracing::warn!(
+                "The form field {name:?} is not one of the fields of {}: {:?}. Its value won't be deserialized when the form is submitted.",
+                std::any::type_name::<T>(),
+                T::FIELDS
+            );
+        }
+
+        let form = *self;
+        let oninput = {
+            let name = name.to_string();
+            move |event: FormEvent| form.set_value(&name, event.value())
+        };
+        let onblur = {
+            let name = name.to_string();
+            move |_: FocusEvent| form.touch(&name)
+        };
+
+        vec![
+            Attribute::new("name", name.to_string(), None, false),
+            Attribute::new("value", self.value(name), None, true),
+            crate::events::oninput(oninput),
+            crate::events::onblur(onblur),
+        ]
+    }
+
+    /// Set the validator for the field `name`. The validator returns an error message if the value is invalid.
+    ///
+    /// Each field has one validator. Calling this again for the same field replaces the old validator, so it is safe
+    /// to call in the body of a component.
+    pub fn validate(&self, name: &str, validator: impl Fn(&str) -> Result<(), String> + 'static) {
+        let mut validators = self.validators;
+        validators
+            .write()
+            .insert(name.to_string(), Rc::new(validator));
+    }
+
+    /// Get the current value of the field `name`
+    pub fn value(&self, name: &str) -> String {
+        self.fields
+            .read()
+            .get(name)
+            .map(|field| field.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Set the value of the field `name`, mark it as dirty, and validate it
+    pub fn set_value(&self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        let error = self.run_validator(name, &value);
+        let mut fields = self.fields;
+        let mut fields = fields.write();
+        let field = fields.entry(name.to_string()).or_default();
+        field.value = value;
+        field.dirty = true;
+        field.error = error;
+    }
+
+    /// Get the validation error for the field `name`. Errors are only returned once the field has been touched, so
+    /// the user doesn't see errors for fields they haven't filled out yet.
+    pub fn error(&self, name: &str) -> Option<String> {
+        self.fields
+            .read()
+            .get(name)
+            .filter(|field| field.touched)
+            .and_then(|field| field.error.clone())
+    }
+
+    /// Check if the field `name` was changed
+    pub fn is_dirty(&self, name: &str) -> bool {
+        self.fields
+            .read()
+            .get(name)
+            .is_some_and(|field| field.dirty)
+    }
+
+    /// Check if the field `name` was focused and then left, or if the form was submitted
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.fields
+            .read()
+            .get(name)
+            .is_some_and(|field| field.touched)
+    }
+
+    /// Check if every field with a validator is currently valid
+    pub fn is_valid(&self) -> bool {
+        let fields = self.fields.read();
+        self.validators.read().iter().all(|(name, validator)| {
+            let value = fields.get(name).map(|field| field.value.as_str());
+            validator(value.unwrap_or_default()).is_ok()
+        })
+    }
+
+    /// The error from the last submit if the values could not be deserialized into `T`
+    pub fn submit_error(&self) -> Option<String> {
+        self.submit_error.cloned()
+    }
+
+    /// Clear every value and the dirty and touched state of every field
+    pub fn reset(&self) {
+        let mut fields = self.fields;
+        fields.set(empty_fields::<T>());
+        let mut submit_error = self.submit_error;
+        submit_error.set(None);
+    }
+
+    /// Create a handler for the `onsubmit` event of the form that calls `handler` with the deserialized values if
+    /// every field is valid.
+    pub fn onsubmit(
+        &self,
+        mut handler: impl FnMut(T) + 'static,
+    ) -> impl FnMut(FormEvent) + 'static {
+        let form = *self;
+        move |event: FormEvent| {
+            event.prevent_default();
+
+            // Touch and validate every field so all of the errors are shown
+            let mut names: Vec<String> = T::FIELDS.iter().map(|name| name.to_string()).collect();
+            names.extend(form.validators.read().keys().cloned());
+            for name in &names {
+                form.touch(name);
+            }
+            if !form.is_valid() {
+                return;
+            }
+
+            let mut submit_error = form.submit_error;
+            match event.parsed_values::<T>() {
+                Ok(values) => {
+                    submit_error.set(None);
+                    handler(values);
+                }
+                Err(err) => submit_error.set(Some(err.to_string())),
+            }
+        }
+    }
+
+    /// Mark the field `name` as touched and validate it
+    fn touch(&self, name: &str) {
+        let value = self
+            .fields
+            .peek()
+            .get(name)
+            .map(|field| field.value.clone());
+        let error = self.run_validator(name, &value.unwrap_or_default());
+        let mut fields = self.fields;
+        let mut fields = fields.write();
+        let field = fields.entry(name.to_string()).or_default();
+        field.touched = true;
+        field.error = error;
+    }
+
+    fn run_validator(&self, name: &str, value: &str) -> Option<String> {
+        let validator = self.validators.peek().get(name).cloned()?;
+        validator(value).err()
+    }
+}
+
+/// The state of every field of `T` before the user changes anything
+fn empty_fields<T: FormFields>() -> HashMap<String, FieldState> {
+    T::FIELDS
+        .iter()
+        .map(|name| (name.to_string(), FieldState::default()))
+        .collect()
+}
@@ -0,0 +1,169 @@
+use crate as dioxus_elements;
+use crate::{ResizeEvent, ScrollEvent};
+use dioxus_core::{Attribute, Callback, Element};
+use dioxus_core_macro::{Props, rsx};
+use dioxus_hooks::use_signal;
+use dioxus_signals::*;
+use std::ops::Range;
+
+/// Props for the [`VirtualList`] component
+#[derive(Clone, Props, PartialEq)]
+pub struct VirtualListProps {
+    /// The number of items in the list
+    pub count: usize,
+    /// The estimated height of an item in pixels. Items that have not been rendered yet use this height.
+    pub item_height: f64,
+    /// Render the item at an index
+    pub render: Callback<usize, Element>,
+    /// How many items to render above and below the visible area
+    #[props(default = 3)]
+    pub overscan: usize,
+    /// Attributes for the scrolling container. The container needs a fixed height for the list to scroll.
+    #[props(extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// Render a long list while only mounting the items that are visible.
+///
+/// The list scrolls inside of a container element. Only the items in view, plus [`VirtualListProps::overscan`] items
+/// on either side, are mounted. A spacer keeps the scrollbar the same size as if every item was rendered.
+///
+/// Items don't need to have the same height. Each mounted item is measured and the measured height replaces
+/// [`VirtualListProps::item_height`] for that index.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// fn App() -> Element {
+///     rsx! {
+///         VirtualList {
+///             height: "400px",
+///             count: 10_000,
+///             item_height: 24.0,
+///             render: move |index| rsx! { p { "Item {index}" } },
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn VirtualList(props: VirtualListProps) -> Element {
+    let mut scroll_top = use_signal(|| 0.0);
+    let mut viewport_height = use_signal(|| 0.0);
+    let mut measured = use_signal(Vec::<Option<f64>>::new);
+
+    let offsets = measured.with(|measured| item_offsets(measured, props.count, props.item_height));
+    let total_height = offsets[props.count];
+    let range = rendered_range(&offsets, scroll_top(), viewport_height(), props.overscan);
+
+    let render = props.render;
+
+    rsx! {
+        div {
+            overflow_y: "auto",
+            onscroll: move |event: ScrollEvent| scroll_top.set(event.data().scroll_top()),
+            onresize: move |event: ResizeEvent| {
+                if let Ok(size) = event.data().get_content_box_size() {
+                    viewport_height.set(size.height);
+                }
+            },
+            ..props.attributes,
+            div { position: "relative", height: "{total_height}px",
+                for index in range {
+                    div {
+                        key: "{index}",
+                        position: "absolute",
+                        top: format!("{}px", offsets[index]),
+                        left: "0",
+                        right: "0",
+                        onresize: move |event: ResizeEvent| {
+                            let Ok(size) = event.data().get_border_box_size() else {
+                                return;
+                            };
+                            // Only write when the height changed to avoid rerendering in a loop
+                            if measured.peek().get(index).copied().flatten() != Some(size.height) {
+                                let mut measured = measured.write();
+                                if measured.len() <= index {
+                                    measured.resize(index + 1, None);
+                                }
+                                measured[index] = Some(size.height);
+                            }
+                        },
+                        {render.call(index)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The offset of the top of every item, plus the total height at the end
+fn item_offsets(measured: &[Option<f64>], count: usize, item_height: f64) -> Vec<f64> {
+    let mut offsets = Vec::with_capacity(count + 1);
+    let mut offset = 0.0;
+    offsets.push(offset);
+    for index in 0..count {
+        offset += measured
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(item_height);
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// The items that are visible between `top` and `top + height`, padded with `overscan` items on either side
+fn rendered_range(offsets: &[f64], top: f64, height: f64, overscan: usize) -> Range<usize> {
+    let count = offsets.len() - 1;
+    let bottom = top + height;
+    let first = offsets
+        .partition_point(|offset| *offset <= top)
+        .saturating_sub(1);
+    let last = offsets
+        .partition_point(|offset| *offset < bottom)
+        .min(count);
+    let start = first.saturating_sub(overscan);
+    let end = (last + overscan).min(count);
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_use_measured_heights() {
+        assert_eq!(item_offsets(&[], 3, 10.0), [0.0, 10.0, 20.0, 30.0]);
+        assert_eq!(
+            item_offsets(&[None, Some(25.0)], 3, 10.0),
+            [0.0, 10.0, 35.0, 45.0]
+        );
+        assert_eq!(item_offsets(&[], 0, 10.0), [0.0]);
+    }
+
+    #[test]
+    fn range_covers_the_visible_items() {
+        let offsets = item_offsets(&[], 100, 10.0);
+        assert_eq!(rendered_range(&offsets, 0.0, 30.0, 0), 0..3);
+        // Partially visible items at either edge are rendered
+        assert_eq!(rendered_range(&offsets, 15.0, 30.0, 0), 1..5);
+        assert_eq!(rendered_range(&offsets, 20.0, 30.0, 0), 2..5);
+        // Nothing is visible before the viewport is measured
+        assert_eq!(rendered_range(&offsets, 0.0, 0.0, 0), 0..0);
+    }
+
+    #[test]
+    fn overscan_pads_the_range_and_stops_at_the_ends() {
+        let offsets = item_offsets(&[], 100, 10.0);
+        assert_eq!(rendered_range(&offsets, 500.0, 30.0, 3), 47..56);
+        assert_eq!(rendered_range(&offsets, 0.0, 30.0, 3), 0..6);
+        assert_eq!(rendered_range(&offsets, 980.0, 30.0, 3), 95..100);
+        // Scrolling past the end doesn't go out of bounds
+        assert_eq!(rendered_range(&offsets, 5000.0, 30.0, 3), 97..100);
+    }
+
+    #[test]
+    fn empty_lists_render_nothing() {
+        let offsets = item_offsets(&[], 0, 10.0);
+        assert_eq!(rendered_range(&offsets, 0.0, 100.0, 3), 0..0);
+    }
+}
@@ -0,0 +1,59 @@
+use dioxus::html::fit_context_menu;
+use dioxus::html::geometry::euclid::{Point2D, Rect, Size2D};
+use dioxus::html::geometry::{ClientPoint, PixelsRect, PixelsSize};
+
+fn window() -> PixelsRect {
+    Rect::new(Point2D::new(0.0, 0.0), Size2D::new(800.0, 600.0))
+}
+
+fn menu() -> PixelsSize {
+    PixelsSize::new(200.0, 100.0)
+}
+
+#[test]
+fn menu_opens_below_and_right_of_the_anchor() {
+    let position = fit_context_menu(ClientPoint::new(100.0, 100.0), menu(), window());
+    assert_eq!(position, ClientPoint::new(100.0, 100.0));
+
+    // A menu that ends exactly at the edge still fits
+    let position = fit_context_menu(ClientPoint::new(600.0, 500.0), menu(), window());
+    assert_eq!(position, ClientPoint::new(600.0, 500.0));
+}
+
+#[test]
+fn menu_flips_at_the_right_and_bottom_edges() {
+    let position = fit_context_menu(ClientPoint::new(700.0, 100.0), menu(), window());
+    assert_eq!(position, ClientPoint::new(500.0, 100.0));
+
+    let position = fit_context_menu(ClientPoint::new(100.0, 550.0), menu(), window());
+    assert_eq!(position, ClientPoint::new(100.0, 450.0));
+
+    let position = fit_context_menu(ClientPoint::new(790.0, 590.0), menu(), window());
+    assert_eq!(position, ClientPoint::new(590.0, 490.0));
+}
+
+#[test]
+fn menu_that_fits_on_neither_side_is_pushed_against_the_top_left() {
+    let tall = PixelsSize::new(200.0, 400.0);
+    let position = fit_context_menu(ClientPoint::new(100.0, 300.0), tall, window());
+    assert_eq!(position, ClientPoint::new(100.0, 0.0));
+
+    let wide = PixelsSize::new(1000.0, 100.0);
+    let position = fit_context_menu(ClientPoint::new(100.0, 100.0), wide, window());
+    assert_eq!(position, ClientPoint::new(0.0, 100.0));
+}
+
+#[test]
+fn menu_stays_inside_of_offset_bounds() {
+    let container = Rect::new(Point2D::new(50.0, 40.0), Size2D::new(300.0, 200.0));
+
+    let position = fit_context_menu(ClientPoint::new(300.0, 200.0), menu(), container);
+    assert_eq!(position, ClientPoint::new(100.0, 100.0));
+
+    let position = fit_context_menu(
+        ClientPoint::new(60.0, 50.0),
+        PixelsSize::new(300.0, 300.0),
+        container,
+    );
+    assert_eq!(position, ClientPoint::new(50.0, 40.0));
+}
@@ -0,0 +1,142 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static WHEN: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    static ITEMS: Cell<Option<Signal<Vec<Todo>>>> = const { Cell::new(None) };
+    static PARENT_RENDERS: Cell<usize> = const { Cell::new(0) };
+    static ITEM_RENDERS: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+}
+
+fn set_when(dom: &mut VirtualDom, value: bool) {
+    let mut when = WHEN.with(Cell::get).unwrap();
+    dom.in_runtime(|| when.set(value));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+}
+
+#[test]
+fn show_switches_between_children_and_fallback() {
+    let mut dom = VirtualDom::new(|| {
+        let when = use_signal(|| false);
+        WHEN.with(|cell| cell.set(Some(when)));
+        PARENT_RENDERS.with(|renders| renders.set(renders.get() + 1));
+
+        rsx! {
+            div {
+                Show { when, fallback: rsx! { "Log in" }, "Welcome back!" }
+            }
+        }
+    });
+    PARENT_RENDERS.with(|renders| renders.set(0));
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "<div>Log in</div>");
+
+    set_when(&mut dom, true);
+    assert_eq!(dioxus_ssr::render(&dom), "<div>Welcome back!</div>");
+
+    set_when(&mut dom, false);
+    assert_eq!(dioxus_ssr::render(&dom), "<div>Log in</div>");
+
+    // The condition is only read inside of Show, so the parent never reruns
+    assert_eq!(PARENT_RENDERS.with(Cell::get), 1);
+}
+
+#[test]
+fn show_renders_nothing_without_a_fallback() {
+    let mut dom = VirtualDom::new(|| {
+        let when = use_signal(|| false);
+        WHEN.with(|cell| cell.set(Some(when)));
+
+        rsx! {
+            div {
+                Show { when, "Welcome back!" }
+            }
+        }
+    });
+    dom.rebuild_in_place();
+    assert_eq!(dioxus_ssr::render(&dom), "<div></div>");
+
+    set_when(&mut dom, true);
+    assert_eq!(dioxus_ssr::render(&dom), "<div>Welcome back!</div>");
+}
+
+#[derive(Clone, PartialEq)]
+struct Todo {
+    id: u32,
+    text: &'static str,
+}
+
+fn todo(id: u32, text: &'static str) -> Todo {
+    Todo { id, text }
+}
+
+fn set_items(dom: &mut VirtualDom, items: Vec<Todo>) {
+    let mut signal = ITEMS.with(Cell::get).unwrap();
+    dom.in_runtime(|| signal.set(items));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+}
+
+fn take_item_renders() -> Vec<u32> {
+    ITEM_RENDERS.with(|renders| std::mem::take(&mut *renders.borrow_mut()))
+}
+
+#[test]
+fn for_only_renders_items_that_changed() {
+    let mut dom = VirtualDom::new(|| {
+        let items = use_signal(|| vec![todo(0, "a"), todo(1, "b"), todo(2, "c")]);
+        ITEMS.with(|cell| cell.set(Some(items)));
+        PARENT_RENDERS.with(|renders| renders.set(renders.get() + 1));
+
+        rsx! {
+            ul {
+                For {
+                    each: items,
+                    key: |todo: Todo| todo.id.to_string(),
+                    render: |todo: Todo| {
+                        ITEM_RENDERS.with(|renders| renders.borrow_mut().push(todo.id));
+                        rsx! { li { "{todo.text}" } }
+                    },
+                }
+            }
+        }
+    });
+    PARENT_RENDERS.with(|renders| renders.set(0));
+    take_item_renders();
+    dom.rebuild_in_place();
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<ul><li>a</li><li>b</li><li>c</li></ul>"
+    );
+    assert_eq!(take_item_renders(), [0, 1, 2]);
+
+    // Reordering keyed items moves them without rerendering
+    set_items(&mut dom, vec![todo(2, "c"), todo(0, "a"), todo(1, "b")]);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<ul><li>c</li><li>a</li><li>b</li></ul>"
+    );
+    assert!(take_item_renders().is_empty());
+
+    // Only the item whose value changed reruns
+    set_items(&mut dom, vec![todo(2, "c"), todo(0, "A"), todo(1, "b")]);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<ul><li>c</li><li>A</li><li>b</li></ul>"
+    );
+    assert_eq!(take_item_renders(), [0]);
+
+    // Adding and removing items only renders the new ones
+    set_items(&mut dom, vec![todo(0, "A"), todo(3, "d"), todo(1, "b")]);
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<ul><li>A</li><li>d</li><li>b</li></ul>"
+    );
+    assert_eq!(take_item_renders(), [3]);
+
+    set_items(&mut dom, vec![]);
+    assert_eq!(dioxus_ssr::render(&dom), "<ul></ul>");
+    assert!(take_item_renders().is_empty());
+
+    assert_eq!(PARENT_RENDERS.with(Cell::get), 1);
+}
@@ -0,0 +1,244 @@
+#![allow(non_snake_case)]
+use dioxus::html::{FormFields, use_form};
+use dioxus::prelude::*;
+
+#[derive(serde::Deserialize, Form)]
+struct Signup {
+    email: String,
+}
+
+fn check_email(email: &str) -> Result<(), String> {
+    match email.contains('@') {
+        true => Ok(()),
+        false => Err("Enter a valid email".to_string()),
+    }
+}
+
+#[test]
+fn form_tracks_values_and_dirty_state() {
+    let mut dom = VirtualDom::new(|| {
+        let form = use_form::<Signup>();
+        use_hook(|| {
+            assert_eq!(form.value("email"), "");
+            assert!(!form.is_dirty("email"));
+
+            form.set_value("email", "dioxus@example.com");
+            assert_eq!(form.value("email"), "dioxus@example.com");
+            assert!(form.is_dirty("email"));
+            // Changing a value doesn't touch the field
+            assert!(!form.is_touched("email"));
+
+            form.reset();
+            assert_eq!(form.value("email"), "");
+            assert!(!form.is_dirty("email"));
+        });
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+}
+
+#[test]
+fn form_validates_fields() {
+    let mut dom = VirtualDom::new(|| {
+        let form = use_form::<Signup>();
+        form.validate("email", check_email);
+        use_hook(|| {
+            // Fields without a value are validated against the empty string
+            assert!(!form.is_valid());
+
+            form.set_value("email", "not an email");
+            assert!(!form.is_valid());
+            // Errors are hidden until the field is touched
+            assert_eq!(form.error("email"), None);
+
+            form.set_value("email", "dioxus@example.com");
+            assert!(form.is_valid());
+            assert_eq!(form.error("email"), None);
+        });
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+}
+
+#[test]
+fn replacing_a_validator_keeps_one_per_field() {
+    let mut dom = VirtualDom::new(|| {
+        let form = use_form::<Signup>();
+        use_hook(|| {
+            form.validate("email", |_| Err("always invalid".to_string()));
+            form.validate("email", check_email);
+            form.set_value("email", "dioxus@example.com");
+            assert!(form.is_valid());
+        });
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+}
+
+#[test]
+fn derive_lists_the_fields_serde_deserializes() {
+    #[derive(serde::Deserialize, Form)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    #[allow(unused)]
+    struct Profile {
+        display_name: String,
+        #[serde(rename = "mail", default)]
+        email: String,
+        #[serde(skip)]
+        visits: u32,
+        #[serde(default)]
+        r#type: String,
+    }
+
+    assert_eq!(Profile::FIELDS, ["displayName", "mail", "type"]);
+    assert_eq!(Signup::FIELDS, ["email"]);
+}
+
+#[test]
+fn fields_are_tracked_before_they_change() {
+    let mut dom = VirtualDom::new(|| {
+        let form = use_form::<Signup>();
+        use_hook(|| {
+            form.set_value("email", "dioxus@example.com");
+            form.reset();
+            // Resetting keeps every field of the struct instead of forgetting them
+            assert_eq!(form.value("email"), "");
+            assert!(!form.is_dirty("email"));
+            assert!(!form.is_touched("email"));
+        });
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+}
+
+#[cfg(feature = "serialize")]
+mod parsed_values {
+    use dioxus::html::{FormData, SerializedFormData, SerializedFormObject};
+
+    fn form(values: &[(&str, &str)]) -> FormData {
+        let values = values
+            .iter()
+            .map(|(key, text)| SerializedFormObject {
+                key: key.to_string(),
+                text: Some(text.to_string()),
+                file: None,
+            })
+            .collect();
+        FormData::new(SerializedFormData::new(String::new(), values))
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Order {
+        name: String,
+        quantity: u32,
+        price: f64,
+        offset: i8,
+        gift: bool,
+        #[serde(default)]
+        express: bool,
+        note: Option<String>,
+        coupon: Option<u32>,
+        #[serde(default)]
+        toppings: Vec<String>,
+    }
+
+    #[test]
+    fn parses_numbers_bools_and_options() {
+        let order: Order = form(&[
+            ("name", "Pizza"),
+            ("quantity", " 2 "),
+            ("price", "9.5"),
+            ("offset", "-3"),
+            ("gift", "on"),
+            ("note", "Extra napkins"),
+            ("coupon", ""),
+        ])
+        .parsed_values()
+        .unwrap();
+
+        assert_eq!(
+            order,
+            Order {
+                name: "Pizza".to_string(),
+                quantity: 2,
+                price: 9.5,
+                offset: -3,
+                gift: true,
+                express: false,
+                note: Some("Extra napkins".to_string()),
+                coupon: None,
+                toppings: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn collects_values_with_the_same_name_into_a_sequence() {
+        #[derive(serde::Deserialize)]
+        struct Toppings {
+            toppings: Vec<String>,
+            sizes: Vec<u32>,
+        }
+
+        let toppings: Toppings = form(&[
+            ("toppings", "cheese"),
+            ("sizes", "12"),
+            ("toppings", "olives"),
+        ])
+        .parsed_values()
+        .unwrap();
+
+        assert_eq!(toppings.toppings, ["cheese", "olives"]);
+        // A single value is still a list if the field is a sequence
+        assert_eq!(toppings.sizes, [12]);
+    }
+
+    #[test]
+    fn missing_fields_are_none_or_an_error() {
+        #[derive(serde::Deserialize)]
+        struct Contact {
+            email: Option<String>,
+        }
+
+        let contact: Contact = form(&[]).parsed_values().unwrap();
+        assert_eq!(contact.email, None);
+
+        let error = form(&[("name", "Pizza")])
+            .parsed_values::<Order>()
+            .unwrap_err();
+        assert!(error.to_string().contains("missing field"));
+    }
+
+    #[test]
+    fn rejects_values_that_do_not_parse() {
+        #[derive(serde::Deserialize, Debug)]
+        struct Quantity {
+            #[allow(unused)]
+            quantity: u32,
+            gift: Option<bool>,
+        }
+
+        assert!(
+            form(&[("quantity", "two")])
+                .parsed_values::<Quantity>()
+                .is_err()
+        );
+        assert!(
+            form(&[("quantity", "2"), ("gift", "maybe")])
+                .parsed_values::<Quantity>()
+                .is_err()
+        );
+        let quantity = form(&[("quantity", "2"), ("gift", "false")])
+            .parsed_values::<Quantity>()
+            .unwrap();
+        assert_eq!(quantity.gift, Some(false));
+    }
+}
@@ -7,6 +7,7 @@ fn main() {
         .with_binding("./src/ts/core.ts", "./src/js/core.js")
         .with_binding("./src/ts/hydrate.ts", "./src/js/hydrate.js")
         .with_binding("./src/ts/patch_console.ts", "./src/js/patch_console.js")
+        .with_binding("./src/ts/focus_trap.ts", "./src/js/focus_trap.js")
         .with_binding(
             "./src/ts/initialize_streaming.ts",
             "./src/js/initialize_streaming.js",
@@ -0,0 +1 @@
+var FOCUSABLE='a[href], area[href], button:not([disabled]), input:not([disabled]):not([type="hidden"]), select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex="-1"])';function focusable(node){return Array.from(node.querySelectorAll(FOCUSABLE)).filter((el)=>el.getClientRects().length>0)}function focusFirst(node){(focusable(node)[0]||node).focus()}function trapFocus(node){if(node.dxFocusTrap)return;let onKeyDown=(event)=>{if(!node.isConnected){trap.release();return}if(event.key!=="Tab")return;let items=focusable(node);if(items.length===0){event.preventDefault(),node.focus();return}let first=items[0],last=items[items.length-1],active=document.activeElement;if(event.shiftKey&&(active===first||!node.contains(active)))event.preventDefault(),last.focus();else if(!event.shiftKey&&(active===last||!node.contains(active)))event.preventDefault(),first.focus()},onFocusIn=(event)=>{if(!node.isConnected){trap.release();return}if(!node.contains(event.target))focusFirst(node)},trap={previous:document.activeElement,release:()=>{document.removeEventListener("keydown",onKeyDown,!0),document.removeEventListener("focusin",onFocusIn,!0),delete node.dxFocusTrap}};if(document.addEventListener("keydown",onKeyDown,!0),document.addEventListener("focusin",onFocusIn,!0),node.dxFocusTrap=trap,!node.contains(document.activeElement))focusFirst(node)}function releaseFocusTrap(node){let trap=node.dxFocusTrap;if(!trap)return;if(trap.release(),trap.previous instanceof HTMLElement&&trap.previous.isConnected)trap.previous.focus()}export{trapFocus,releaseFocusTrap};
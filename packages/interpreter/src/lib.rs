@@ -57,4 +57,13 @@ pub mod minimal_bindings {
     extern "C" {
         pub fn monkeyPatchConsole(ws: JsValue);
     }
+
+    #[wasm_bindgen(module = "/src/js/focus_trap.js")]
+    extern "C" {
+        /// Keep keyboard focus inside of the node until the trap is released or the node is removed
+        pub fn trapFocus(node: &JsValue);
+
+        /// Release the focus trap on the node and restore the focus from before the trap started
+        pub fn releaseFocusTrap(node: &JsValue);
+    }
 }
@@ -19,6 +19,10 @@ export class BaseInterpreter {
 
   root: HTMLElement;
   handler: EventListener;
+
+  // portals are moved out of the element they were rendered in after each batch of edits
+  portals: Map<HTMLElement, Portal>;
+  portalsQueued: boolean;
   resizeObserver: ResizeObserver;
   intersectionObserver: IntersectionObserver;
 
@@ -37,6 +41,8 @@ export class BaseInterpreter {
     this.global = {};
     this.local = {};
     this.root = root;
+    this.portals = new Map();
+    this.portalsQueued = false;
 
     this.nodes = [root];
     this.stack = [root];
@@ -117,6 +123,11 @@ export class BaseInterpreter {
       if (this.global[event_name] === undefined) {
         this.global[event_name] = { active: 1, callback: this.handler };
         this.root.addEventListener(event_name, this.handler);
+        for (const [node, portal] of this.portals) {
+          if (portal.listening) {
+            node.addEventListener(event_name, this.handler);
+          }
+        }
       } else {
         this.global[event_name].active++;
       }
@@ -148,6 +159,14 @@ export class BaseInterpreter {
         event_name,
         this.global[event_name].callback
       );
+      for (const [node, portal] of this.portals) {
+        if (portal.listening) {
+          node.removeEventListener(
+            event_name,
+            this.global[event_name].callback
+          );
+        }
+      }
       delete this.global[event_name];
     }
   }
@@ -208,6 +227,12 @@ export class BaseInterpreter {
 
     this.nodes[id] = hydrateNode;
 
+    // Portals are rendered in place on the server
+    const portal = hydrateNode.getAttribute("dioxus-portal");
+    if (portal !== null) {
+      this.queuePortal(hydrateNode, portal);
+    }
+
     if (split.length > 1) {
       // @ts-ignore
       hydrateNode.listening = split.length - 1;
@@ -320,6 +345,94 @@ export class BaseInterpreter {
     value: string,
     ns: string
   ) {
+    if (field === "dioxus-portal" && !ns) {
+      this.queuePortal(node, value);
+      return;
+    }
     setAttributeInner(node, field, value, ns);
   }
+
+  // Move a node into the element with the target id (or the body if the target is empty) once the current edits
+  // are applied. Until then the node is still attached to the element it was rendered in.
+  queuePortal(node: HTMLElement, target: string) {
+    const portal = this.portals.get(node);
+    if (portal) {
+      portal.target = target;
+    } else {
+      this.portals.set(node, { anchor: null, target, listening: false });
+    }
+
+    if (!this.portalsQueued) {
+      this.portalsQueued = true;
+      queueMicrotask(() => this.mountPortals());
+    }
+  }
+
+  mountPortals() {
+    this.portalsQueued = false;
+    for (const [node, portal] of this.portals) {
+      if (portal.anchor === null) {
+        portal.anchor = node.parentNode;
+        // The portal was removed in the same edits it was created in
+        if (portal.anchor === null) {
+          this.portals.delete(node);
+          continue;
+        }
+      }
+
+      const target = portal.target
+        ? document.getElementById(portal.target)
+        : document.body;
+      if (!target) {
+        console.warn(`Portal target #${portal.target} does not exist`);
+        continue;
+      }
+      if (node.parentNode !== target) {
+        target.appendChild(node);
+      }
+
+      // Bubbling events are only handled at the root, so portals outside of the root need their own listeners
+      const listening = !this.root.contains(node);
+      if (listening !== portal.listening) {
+        portal.listening = listening;
+        for (const event_name in this.global) {
+          if (listening) {
+            node.addEventListener(event_name, this.global[event_name].callback);
+          } else {
+            node.removeEventListener(
+              event_name,
+              this.global[event_name].callback
+            );
+          }
+        }
+      }
+    }
+  }
+
+  // Remove any portals that were rendered in an element that was just removed
+  removeDetachedPortals() {
+    for (const [node, portal] of this.portals) {
+      if (portal.anchor !== null && !portal.anchor.isConnected) {
+        if (portal.listening) {
+          for (const event_name in this.global) {
+            node.removeEventListener(
+              event_name,
+              this.global[event_name].callback
+            );
+          }
+        }
+        node.remove();
+        this.portals.delete(node);
+      }
+    }
+  }
 }
+
+type Portal = {
+  // the element the portal was rendered in
+  anchor: Node | null;
+  // the id of the element the portal is moved into, or an empty string for the body
+  target: string;
+  // if the portal is outside of the root and has its own bubbling listeners
+  listening: boolean;
+};
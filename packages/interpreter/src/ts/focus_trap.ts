@@ -0,0 +1,97 @@
+// Keep keyboard focus inside of an element. Shared between web, desktop and liveview.
+
+const FOCUSABLE =
+  'a[href], area[href], button:not([disabled]), input:not([disabled]):not([type="hidden"]), select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex="-1"])';
+
+type FocusTrap = {
+  previous: Element | null;
+  release: () => void;
+};
+
+type TrappedElement = HTMLElement & { dxFocusTrap?: FocusTrap };
+
+function focusable(node: HTMLElement): HTMLElement[] {
+  return Array.from(node.querySelectorAll<HTMLElement>(FOCUSABLE)).filter(
+    (el) => el.getClientRects().length > 0
+  );
+}
+
+function focusFirst(node: HTMLElement) {
+  const items = focusable(node);
+  (items[0] || node).focus();
+}
+
+// Keep focus inside of `node` until the trap is released or the node is removed from the document
+export function trapFocus(node: TrappedElement) {
+  if (node.dxFocusTrap) {
+    return;
+  }
+
+  const onKeyDown = (event: KeyboardEvent) => {
+    if (!node.isConnected) {
+      trap.release();
+      return;
+    }
+    if (event.key !== "Tab") {
+      return;
+    }
+
+    const items = focusable(node);
+    if (items.length === 0) {
+      event.preventDefault();
+      node.focus();
+      return;
+    }
+
+    const first = items[0];
+    const last = items[items.length - 1];
+    const active = document.activeElement;
+    if (event.shiftKey && (active === first || !node.contains(active))) {
+      event.preventDefault();
+      last.focus();
+    } else if (!event.shiftKey && (active === last || !node.contains(active))) {
+      event.preventDefault();
+      first.focus();
+    }
+  };
+
+  const onFocusIn = (event: FocusEvent) => {
+    if (!node.isConnected) {
+      trap.release();
+      return;
+    }
+    if (!node.contains(event.target as Node)) {
+      focusFirst(node);
+    }
+  };
+
+  const trap: FocusTrap = {
+    previous: document.activeElement,
+    release: () => {
+      document.removeEventListener("keydown", onKeyDown, true);
+      document.removeEventListener("focusin", onFocusIn, true);
+      delete node.dxFocusTrap;
+    },
+  };
+
+  document.addEventListener("keydown", onKeyDown, true);
+  document.addEventListener("focusin", onFocusIn, true);
+  node.dxFocusTrap = trap;
+
+  if (!node.contains(document.activeElement)) {
+    focusFirst(node);
+  }
+}
+
+// Release the focus trap on `node` and restore the focus from before the trap started
+export function releaseFocusTrap(node: TrappedElement) {
+  const trap = node.dxFocusTrap;
+  if (!trap) {
+    return;
+  }
+
+  trap.release();
+  if (trap.previous instanceof HTMLElement && trap.previous.isConnected) {
+    trap.previous.focus();
+  }
+}
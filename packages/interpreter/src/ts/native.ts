@@ -5,6 +5,7 @@
 
 import { BaseInterpreter, NodeId } from "./core";
 import { SerializedEvent, serializeEvent, SerializedFileData, extractSerializedFormValues, SerializedFormObject } from "./serialize";
+import { trapFocus, releaseFocusTrap } from "./focus_trap";
 
 // okay so, we've got this JSChannel thing from sledgehammer, implicitly imported into our scope
 // we want to extend it, and it technically extends base interpreter. To make typescript happy,
@@ -252,6 +253,24 @@ export class NativeInterpreter extends JSChannel_ {
     }
   }
 
+  trapFocus(id: NodeId): boolean {
+    const node = this.nodes[id];
+    if (node instanceof HTMLElement) {
+      trapFocus(node);
+      return true;
+    }
+    return false;
+  }
+
+  releaseFocusTrap(id: NodeId): boolean {
+    const node = this.nodes[id];
+    if (node instanceof HTMLElement) {
+      releaseFocusTrap(node);
+      return true;
+    }
+    return false;
+  }
+
   // Windows drag-n-drop fix code. Called by wry drag-n-drop handler over the event loop.
   handleWindowsDragDrop() {
     // @ts-ignore
@@ -125,6 +125,11 @@ export function serializeEvent(
     extend(serializeScrollEvent(event));
   }
 
+  // Images, scripts, iframes and media elements fire load and error events with no extra data
+  if (event.type === "load" || event.type === "error") {
+    extend({ load_error: event.type === "error" });
+  }
+
   return contents;
 }
 
@@ -43,7 +43,9 @@ impl LiveviewRouter for Router {
         route: &str,
         app: impl Fn() -> dioxus_core::VirtualDom + Send + Sync + 'static,
     ) -> Self {
-        let view = crate::LiveViewPool::new();
+        // The page uses the built-in client, which can resume its session after the connection drops
+        let view =
+            crate::LiveViewPool::new().with_reconnect_timeout(std::time::Duration::from_secs(30));
 
         let ws_path = format!("{}/ws", route.trim_start_matches('/'));
         let title = crate::app_title();
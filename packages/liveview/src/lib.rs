@@ -9,9 +9,11 @@ pub use adapters::*;
 mod element;
 pub mod pool;
 mod query;
+mod session;
 use dioxus_interpreter_js::NATIVE_JS;
 use futures_util::{SinkExt, StreamExt};
 pub use pool::*;
+pub use session::{ConnectionState, QueuedEvents, use_connection_state};
 mod config;
 mod document;
 mod events;
@@ -1,5 +1,11 @@
 const intercept_link_redirects = false;
 
+// How long to wait before reconnecting after the connection drops. The delay doubles after every failed attempt
+const RECONNECT_DELAY = 250;
+const MAX_RECONNECT_DELAY = 10000;
+// How long to wait before telling the server how many frames we received
+const ACK_DELAY = 1000;
+
 function main() {
   let root = window.document.getElementById("main");
   if (root != null) {
@@ -13,23 +19,44 @@ class IPC {
     window.interpreter.initialize(root);
     window.interpreter.liveview = true;
     window.interpreter.ipc = this;
+
+    // The id of the session on the server. We send it when reconnecting to pick up where we left off
+    this.session = null;
+    // The number of frames we received from the session. The server sends the frames after this again when we reconnect
+    this.received = 0;
+    this.ackTimeout = null;
+    // Messages sent while we are disconnected. They are sent once we reconnect to the session
+    this.queue = [];
+    this.connected = false;
+    this.reconnectDelay = RECONNECT_DELAY;
+    this.connect();
+  }
+
+  connect() {
     const ws = new WebSocket(WS_ADDR);
     ws.binaryType = "arraybuffer";
+    let pingInterval = null;
 
     function ping() {
       ws.send("__ping__");
     }
 
     ws.onopen = () => {
+      ws.send("__session__" + (this.session ?? "") + ":" + this.received);
       // we ping every 30 seconds to keep the websocket alive
-      setInterval(ping, 30000);
+      pingInterval = setInterval(ping, 30000);
     };
 
-    ws.onerror = (err) => {
-      // todo: retry the connection
+    ws.onclose = () => {
+      clearInterval(pingInterval);
+      this.setConnected(false);
+      setTimeout(() => this.connect(), this.reconnectDelay);
+      this.reconnectDelay = Math.min(this.reconnectDelay * 2, MAX_RECONNECT_DELAY);
     };
 
     ws.onmessage = (message) => {
+      this.received += 1;
+      this.scheduleAck();
       const u8view = new Uint8Array(message.data);
       const binaryFrame = u8view[0] == 1;
       const messageData = message.data.slice(1);
@@ -50,6 +77,21 @@ class IPC {
             case "query":
               Function("Eval", `"use strict";${event.data};`)();
               break;
+            case "session":
+              // If we already had a session, it ended while we were disconnected and the page is out of date
+              if (this.session != null) {
+                window.location.reload();
+                return;
+              }
+              this.session = event.data;
+              this.setConnected(true);
+              break;
+            case "resumed":
+              this.setConnected(true);
+              break;
+            case "reload":
+              window.location.reload();
+              break;
           }
         }
       }
@@ -58,8 +100,46 @@ class IPC {
     this.ws = ws;
   }
 
+  scheduleAck() {
+    if (this.ackTimeout != null) {
+      return;
+    }
+    this.ackTimeout = setTimeout(() => {
+      this.ackTimeout = null;
+      if (this.ws.readyState == WebSocket.OPEN) {
+        this.ws.send("__ack__" + this.received);
+      }
+    }, ACK_DELAY);
+  }
+
+  setConnected(connected) {
+    this.connected = connected;
+    document.documentElement.setAttribute(
+      "data-liveview-connection",
+      connected ? "connected" : "reconnecting"
+    );
+    if (!connected) {
+      return;
+    }
+
+    this.reconnectDelay = RECONNECT_DELAY;
+    // Send the events that happened while we were disconnected. The server decides if they are replayed
+    for (const msg of this.queue) {
+      const message = JSON.parse(msg);
+      if (message.method == "user_event") {
+        message.method = "queued_event";
+      }
+      this.ws.send(JSON.stringify(message));
+    }
+    this.queue = [];
+  }
+
   postMessage(msg) {
-    this.ws.send(msg);
+    if (this.connected) {
+      this.ws.send(msg);
+    } else {
+      this.queue.push(msg);
+    }
   }
 }
 
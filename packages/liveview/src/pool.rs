@@ -4,19 +4,31 @@ use crate::{
     element::LiveviewElement,
     events::SerializedHtmlEventConverter,
     query::{QueryEngine, QueryResult},
+    session::{Client, Connection, ConnectionState, QueuedEvents, SessionOptions, parse_handshake},
 };
 
 use dioxus_core::{Element, Event, ScopeId, VirtualDom, provide_context};
 use dioxus_html::{EventData, HtmlEvent, PlatformEventData};
 use dioxus_interpreter_js::MutationState;
-use futures_util::{SinkExt, StreamExt, pin_mut};
+use dioxus_signals::{Signal, WritableExt};
+use futures_util::{SinkExt, StreamExt};
 use serde::Serialize;
-use std::{any::Any, rc::Rc};
+use std::{
+    any::Any,
+    collections::HashMap,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, error::SendError, unbounded_channel};
 use tokio_util::task::LocalPoolHandle;
 
 #[derive(Clone)]
 pub struct LiveViewPool {
     pub(crate) pool: LocalPoolHandle,
+    /// The sessions that browsers can reconnect to, keyed by session id
+    sessions: Arc<Mutex<HashMap<String, UnboundedSender<(Connection, u64)>>>>,
+    options: SessionOptions,
 }
 
 impl Default for LiveViewPool {
@@ -36,9 +48,38 @@ impl LiveViewPool {
                     .map(usize::from)
                     .unwrap_or(1),
             ),
+            sessions: Default::default(),
+            options: SessionOptions::default(),
         }
     }
 
+    /// Set how long a session stays alive after the browser disconnects. If the browser reconnects within this time,
+    /// it picks up where it left off. Otherwise the session is dropped and the page is reloaded with a new session
+    /// when the browser reconnects.
+    ///
+    /// Defaults to zero, which drops the session as soon as the browser disconnects. Resuming sessions needs the
+    /// client from [`interpreter_glue`](crate::interpreter_glue), which starts every connection with the session it
+    /// wants to resume and acknowledges the updates it receives. Only set a timeout if your page uses that client.
+    pub fn with_reconnect_timeout(mut self, timeout: Duration) -> Self {
+        self.options.reconnect_timeout = timeout;
+        self
+    }
+
+    /// Set what happens to events the user triggered while the browser was disconnected. Defaults to
+    /// [`QueuedEvents::Discard`].
+    pub fn with_queued_events(mut self, queued_events: QueuedEvents) -> Self {
+        self.options.queued_events = queued_events;
+        self
+    }
+
+    /// Set how many bytes of updates the browser hasn't acknowledged yet are kept. If more than this is unacknowledged
+    /// when the browser disconnects, the page is reloaded when the browser reconnects instead of replaying the
+    /// updates. Defaults to 1MB.
+    pub fn with_max_buffered_bytes(mut self, bytes: usize) -> Self {
+        self.options.max_buffered_bytes = bytes;
+        self
+    }
+
     pub async fn launch(
         &self,
         ws: impl LiveViewSocket,
@@ -57,12 +98,67 @@ impl LiveViewPool {
             .await
     }
 
+    /// Run a VirtualDom for a new socket.
+    ///
+    /// If a [reconnect timeout](Self::with_reconnect_timeout) is set and the browser is reconnecting to a session
+    /// that is still alive, the socket is handed to that session instead and `make_app` is not called.
     pub async fn launch_virtualdom<F: FnOnce() -> VirtualDom + Send + 'static>(
         &self,
         ws: impl LiveViewSocket,
         make_app: F,
     ) -> Result<(), LiveViewError> {
-        match self.pool.spawn_pinned(move || run(make_app(), ws)).await {
+        // Sessions can't be resumed, so don't wait for the client to tell us which session it wants
+        if self.options.reconnect_timeout.is_zero() {
+            return self
+                .pool
+                .spawn_pinned(move || run(make_app(), ws))
+                .await
+                .map_err(|_| LiveViewError::SendingFailed)?;
+        }
+
+        let mut ws = Box::pin(ws);
+        let Some(first_message) = ws.next().await.and_then(Result::ok) else {
+            return Ok(());
+        };
+
+        // The client starts every connection with the id of the session it wants to resume
+        let (resume, first_message) = match parse_handshake(&first_message) {
+            Some(resume) => (Some(resume), None),
+            None => (None, Some(first_message)),
+        };
+        let (mut connection, forward) = Connection::new(ws, first_message);
+
+        if let Some((id, received)) = resume.filter(|(id, _)| !id.is_empty()) {
+            let session = self.sessions.lock().unwrap().get(&id).cloned();
+            if let Some(session) = session {
+                match session.send((connection, received)) {
+                    Ok(()) => {
+                        forward.await;
+                        return Ok(());
+                    }
+                    // The session ended, start a new one instead
+                    Err(SendError((returned, _))) => connection = returned,
+                }
+            }
+        }
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let (reconnect_tx, reconnect_rx) = unbounded_channel();
+        self.sessions
+            .lock()
+            .unwrap()
+            .insert(id.clone(), reconnect_tx);
+
+        let options = self.options.clone();
+        let session = self.pool.spawn_pinned({
+            let id = id.clone();
+            move || run_session(make_app(), connection, id, reconnect_rx, options)
+        });
+        let ((), result) = futures_util::future::join(forward, session).await;
+
+        self.sessions.lock().unwrap().remove(&id);
+
+        match result {
             Ok(Ok(_)) => Ok(()),
             Ok(Err(e)) => Err(e),
             Err(_) => Err(LiveViewError::SendingFailed),
@@ -120,7 +216,31 @@ impl<S> LiveViewSocket for S where
 /// As long as your framework can provide a Sink and Stream of Bytes, you can use this function.
 ///
 /// You might need to transform the error types of the web backend into the LiveView error type.
-pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), LiveViewError> {
+///
+/// The VirtualDom is dropped as soon as the socket closes. Use [`LiveViewPool::launch_virtualdom`] to keep the
+/// session alive so the browser can reconnect to it.
+pub async fn run(vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), LiveViewError> {
+    let (connection, forward) = Connection::new(ws, None);
+    let (_, reconnections) = unbounded_channel();
+    let session = run_session(
+        vdom,
+        connection,
+        uuid::Uuid::new_v4().to_string(),
+        reconnections,
+        SessionOptions::default(),
+    );
+    let ((), result) = futures_util::future::join(forward, session).await;
+    result
+}
+
+/// Run a session until the browser disconnects and doesn't reconnect within the reconnect timeout
+async fn run_session(
+    mut vdom: VirtualDom,
+    connection: Connection,
+    id: String,
+    mut reconnections: UnboundedReceiver<(Connection, u64)>,
+    options: SessionOptions,
+) -> Result<(), LiveViewError> {
     #[cfg(all(feature = "devtools", debug_assertions))]
     let mut hot_reload_rx = {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
@@ -133,20 +253,33 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
     // Create the a proxy for query engine
     let (query_tx, mut query_rx) = tokio::sync::mpsc::unbounded_channel();
     let query_engine = QueryEngine::new(query_tx);
-    vdom.runtime().in_scope(ScopeId::ROOT, || {
+    let mut connection_state = vdom.runtime().in_scope(ScopeId::ROOT, || {
         provide_context(query_engine.clone());
         init_document();
+        provide_context(Signal::new(ConnectionState::Connected))
     });
 
-    // pin the futures so we can use select!
-    pin_mut!(ws);
+    // Frames only need to be kept for the browser if the session can be resumed
+    let max_buffered_bytes = if options.reconnect_timeout.is_zero() {
+        0
+    } else {
+        options.max_buffered_bytes
+    };
+    let mut client = Client::new(connection, max_buffered_bytes);
+    // The time the session ends if the browser doesn't reconnect
+    let mut reconnect_deadline = None;
+
+    // tell the client which session it is connected to so it can resume it if the connection drops
+    client.send(text_frame(
+        &serde_json::to_string(&ClientUpdate::Session(id)).unwrap(),
+    ));
 
     if let Some(edits) = {
         vdom.rebuild(&mut mutations);
         take_edits(&mut mutations)
     } {
         // send the initial render to the client
-        ws.send(edits).await?;
+        client.send(edits);
     }
 
     // desktop uses this wrapper struct thing around the actual event itself
@@ -156,6 +289,9 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
     enum IpcMessage {
         #[serde(rename = "user_event")]
         Event(Box<HtmlEvent>),
+        /// An event the user triggered while the browser was disconnected
+        #[serde(rename = "queued_event")]
+        QueuedEvent(Box<HtmlEvent>),
         #[serde(rename = "query")]
         Query(QueryResult),
     }
@@ -170,34 +306,20 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
             // poll any futures or suspense
             _ = vdom.wait_for_work() => {}
 
-            evt = ws.next() => {
-                match evt.as_ref().map(|o| o.as_deref()) {
+            evt = client.recv() => {
+                match evt.as_deref() {
                     // respond with a pong every ping to keep the websocket alive
-                    Some(Ok(b"__ping__")) => {
-                        ws.send(text_frame("__pong__")).await?;
+                    Some(b"__ping__") => {
+                        client.send(text_frame("__pong__"));
                     }
-                    Some(Ok(evt)) => {
+                    Some(evt) => {
                         if let Ok(message) = serde_json::from_str::<IpcMessage>(&String::from_utf8_lossy(evt)) {
                             match message {
-                                IpcMessage::Event(evt) => {
-                                    // Intercept the mounted event and insert a custom element type
-                                    let event = if let EventData::Mounted = &evt.data {
-                                        let element = LiveviewElement::new(evt.element, query_engine.clone());
-                                        Event::new(
-                                            Rc::new(PlatformEventData::new(Box::new(element))) as Rc<dyn Any>,
-                                            evt.bubbles,
-                                        )
-                                    } else {
-                                        Event::new(
-                                            evt.data.into_any(),
-                                            evt.bubbles,
-                                        )
-                                    };
-                                    vdom.runtime().handle_event(
-                                        &evt.name,
-                                        event,
-                                        evt.element,
-                                    );
+                                IpcMessage::Event(evt) => handle_event(&vdom, &query_engine, *evt),
+                                IpcMessage::QueuedEvent(evt) => {
+                                    if options.queued_events == QueuedEvents::Replay {
+                                        handle_event(&vdom, &query_engine, *evt);
+                                    }
                                 }
                                 IpcMessage::Query(result) => {
                                     query_engine.send(result);
@@ -205,15 +327,35 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
                             }
                         }
                     }
-                    // log this I guess? when would we get an error here?
-                    Some(Err(_e)) => {}
-                    None => return Ok(()),
+                    // The browser disconnected. Keep the session alive for a while in case it reconnects
+                    None => {
+                        if options.reconnect_timeout.is_zero() {
+                            return Ok(());
+                        }
+                        reconnect_deadline = Some(tokio::time::Instant::now() + options.reconnect_timeout);
+                        vdom.runtime().in_scope(ScopeId::ROOT, || connection_state.set(ConnectionState::Reconnecting));
+                    }
                 }
             }
 
+            Some((connection, received)) = reconnections.recv() => {
+                let resumed = text_frame(&serde_json::to_string(&ClientUpdate::Resumed).unwrap());
+                let reload = text_frame(&serde_json::to_string(&ClientUpdate::Reload).unwrap());
+                if !client.reconnect(connection, received, resumed, reload) {
+                    // The browser reloads the page and starts a new session
+                    return Ok(());
+                }
+                reconnect_deadline = None;
+                vdom.runtime().in_scope(ScopeId::ROOT, || connection_state.set(ConnectionState::Connected));
+            }
+
+            _ = tokio::time::sleep_until(reconnect_deadline.unwrap_or_else(tokio::time::Instant::now)), if reconnect_deadline.is_some() => {
+                return Ok(());
+            }
+
             // handle any new queries
             Some(query) = query_rx.recv() => {
-                ws.send(text_frame(&serde_json::to_string(&ClientUpdate::Query(query)).unwrap())).await?;
+                client.send(text_frame(&serde_json::to_string(&ClientUpdate::Query(query)).unwrap()));
             }
 
             Some(msg) = hot_reload_wait => {
@@ -242,11 +384,25 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
         vdom.render_immediate(&mut mutations);
 
         if let Some(edits) = take_edits(&mut mutations) {
-            ws.send(edits).await?;
+            client.send(edits);
         }
     }
 }
 
+fn handle_event(vdom: &VirtualDom, query_engine: &QueryEngine, evt: HtmlEvent) {
+    // Intercept the mounted event and insert a custom element type
+    let event = if let EventData::Mounted = &evt.data {
+        let element = LiveviewElement::new(evt.element, query_engine.clone());
+        Event::new(
+            Rc::new(PlatformEventData::new(Box::new(element))) as Rc<dyn Any>,
+            evt.bubbles,
+        )
+    } else {
+        Event::new(evt.data.into_any(), evt.bubbles)
+    };
+    vdom.runtime().handle_event(&evt.name, event, evt.element);
+}
+
 fn text_frame(text: &str) -> Vec<u8> {
     let mut bytes = vec![0];
     bytes.extend(text.as_bytes());
@@ -265,4 +421,13 @@ fn take_edits(mutations: &mut MutationState) -> Option<Vec<u8>> {
 enum ClientUpdate {
     #[serde(rename = "query")]
     Query(String),
+    /// The id of the session the client is connected to
+    #[serde(rename = "session")]
+    Session(String),
+    /// The client reconnected to its session. This follows the updates it missed while it was disconnected.
+    #[serde(rename = "resumed")]
+    Resumed,
+    /// The client reconnected, but it is too far out of sync with the session and needs to reload the page
+    #[serde(rename = "reload")]
+    Reload,
 }
@@ -0,0 +1,398 @@
+use crate::LiveViewSocket;
+use dioxus_core::{consume_context, use_hook};
+use dioxus_signals::{ReadSignal, Signal};
+use futures_util::{SinkExt, StreamExt, pin_mut};
+use std::{collections::VecDeque, time::Duration};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, error::SendError, unbounded_channel};
+
+/// The first message the client sends on every connection, followed by `<session id>:<frames received>` for the
+/// session it wants to resume
+pub(crate) const SESSION_PREFIX: &[u8] = b"__session__";
+
+/// The prefix of the message the client sends to tell the session how many frames it received so far
+pub(crate) const ACK_PREFIX: &[u8] = b"__ack__";
+
+/// Parse the first message of a connection into the id of the session the client wants to resume and the number of
+/// frames it received from that session
+pub(crate) fn parse_handshake(message: &[u8]) -> Option<(String, u64)> {
+    let message = std::str::from_utf8(message.strip_prefix(SESSION_PREFIX)?).ok()?;
+    let (id, received) = message.rsplit_once(':').unwrap_or((message, "0"));
+    Some((id.to_string(), received.parse().ok()?))
+}
+
+/// The state of the connection between a liveview session and the browser
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The browser is connected and receiving updates
+    Connected,
+    /// The connection dropped and the session is waiting for the browser to reconnect. Updates are buffered and sent
+    /// once the browser reconnects.
+    Reconnecting,
+}
+
+/// Get the state of the connection to the browser for the current liveview session.
+///
+/// The browser can't receive updates while it is disconnected, so anything you render during
+/// [`ConnectionState::Reconnecting`] only shows up after it reconnects. The client sets a
+/// `data-liveview-connection` attribute on the `<html>` element to `"connected"` or `"reconnecting"` that you can use
+/// to show a banner with css while the connection is down:
+///
+/// ```css
+/// html[data-liveview-connection="reconnecting"] #reconnecting-banner {
+///     display: block;
+/// }
+/// ```
+pub fn use_connection_state() -> ReadSignal<ConnectionState> {
+    use_hook(|| consume_context::<Signal<ConnectionState>>().into())
+}
+
+/// What to do with events the user triggered while the browser was disconnected
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum QueuedEvents {
+    /// Drop the events. The user sees the current state of the app when the browser reconnects and can try again
+    #[default]
+    Discard,
+    /// Run the events in the order they were triggered once the browser reconnects
+    Replay,
+}
+
+/// Options for how a session handles the browser disconnecting
+#[derive(Clone, Debug)]
+pub(crate) struct SessionOptions {
+    /// How long to keep the session alive after the browser disconnects. If this is zero, sessions can't be resumed
+    pub(crate) reconnect_timeout: Duration,
+    /// What to do with events the user triggered while the browser was disconnected
+    pub(crate) queued_events: QueuedEvents,
+    /// The number of bytes of updates to buffer while the browser is disconnected before giving up and reloading the
+    /// page when the browser reconnects
+    pub(crate) max_buffered_bytes: usize,
+}
+
+impl Default for SessionOptions {
+    fn default() -> Self {
+        Self {
+            reconnect_timeout: Duration::ZERO,
+            queued_events: QueuedEvents::default(),
+            max_buffered_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// A connection from the browser to a session
+pub(crate) struct Connection {
+    to_client: UnboundedSender<Vec<u8>>,
+    from_client: UnboundedReceiver<Vec<u8>>,
+}
+
+impl Connection {
+    /// Create a connection from a socket. The returned future moves messages between the socket and the connection
+    /// until either side is closed.
+    pub(crate) fn new(
+        ws: impl LiveViewSocket,
+        first_message: Option<Vec<u8>>,
+    ) -> (Self, impl Future<Output = ()>) {
+        let (to_client, mut outgoing) = unbounded_channel();
+        let (incoming, from_client) = unbounded_channel();
+        if let Some(message) = first_message {
+            _ = incoming.send(message);
+        }
+
+        let forward = async move {
+            pin_mut!(ws);
+            loop {
+                tokio::select! {
+                    message = ws.next() => match message {
+                        Some(Ok(message)) => {
+                            if incoming.send(message).is_err() {
+                                break;
+                            }
+                        }
+                        // log this I guess? when would we get an error here?
+                        Some(Err(_e)) => {}
+                        None => break,
+                    },
+                    message = outgoing.recv() => match message {
+                        Some(message) => {
+                            if ws.send(message).await.is_err() {
+                                break;
+                            }
+                        }
+                        None => break,
+                    },
+                }
+            }
+        };
+
+        (
+            Self {
+                to_client,
+                from_client,
+            },
+            forward,
+        )
+    }
+}
+
+/// The browser side of a session.
+///
+/// Every frame sent to the browser is numbered and kept until the browser acknowledges it. If the connection drops,
+/// frames that were sent but not acknowledged may have been lost, so they are sent again when the browser reconnects.
+pub(crate) struct Client {
+    connection: Option<Connection>,
+    /// The number of frames sent to the browser
+    sent: u64,
+    /// The number of frames the browser acknowledged
+    acked: u64,
+    /// The frames the browser hasn't acknowledged yet, starting with frame number `acked + 1`
+    unacked: VecDeque<Vec<u8>>,
+    unacked_bytes: usize,
+    max_buffered_bytes: usize,
+    /// Set if too many frames were unacknowledged to keep them all. If the browser reconnects before acknowledging
+    /// every frame, the page needs to be reloaded to get back in sync with the session.
+    out_of_sync: bool,
+}
+
+impl Client {
+    pub(crate) fn new(connection: Connection, max_buffered_bytes: usize) -> Self {
+        Self {
+            connection: Some(connection),
+            sent: 0,
+            acked: 0,
+            unacked: VecDeque::new(),
+            unacked_bytes: 0,
+            max_buffered_bytes,
+            out_of_sync: false,
+        }
+    }
+
+    /// Send a frame to the browser. The frame is kept until the browser acknowledges it, so it can be sent again if
+    /// the connection drops before it arrives.
+    pub(crate) fn send(&mut self, message: Vec<u8>) {
+        self.sent += 1;
+        if !self.out_of_sync {
+            self.unacked_bytes += message.len();
+            if self.unacked_bytes > self.max_buffered_bytes {
+                self.out_of_sync = true;
+                self.unacked.clear();
+                self.unacked_bytes = 0;
+            } else {
+                self.unacked.push_back(message.clone());
+            }
+        }
+
+        if let Some(connection) = &self.connection {
+            // If the connection is closing, the frame is sent again when the browser reconnects
+            _ = connection.to_client.send(message);
+        }
+    }
+
+    /// Wait for the next message from the browser. Returns `None` when the browser disconnects.
+    pub(crate) async fn recv(&mut self) -> Option<Vec<u8>> {
+        loop {
+            let connection = match &mut self.connection {
+                Some(connection) => connection,
+                None => std::future::pending().await,
+            };
+            let Some(message) = connection.from_client.recv().await else {
+                self.connection = None;
+                return None;
+            };
+
+            if let Some(received) = message.strip_prefix(ACK_PREFIX) {
+                if let Some(received) = std::str::from_utf8(received)
+                    .ok()
+                    .and_then(|received| received.parse().ok())
+                {
+                    self.ack(received);
+                }
+            } else if !message.starts_with(SESSION_PREFIX) {
+                return Some(message);
+            }
+        }
+    }
+
+    /// Forget the frames the browser received
+    fn ack(&mut self, received: u64) {
+        if received <= self.acked || received > self.sent {
+            return;
+        }
+
+        if self.out_of_sync {
+            // Once the browser has every frame, there is nothing left that could be lost
+            if received == self.sent {
+                self.out_of_sync = false;
+            }
+        } else {
+            for _ in self.acked..received {
+                if let Some(message) = self.unacked.pop_front() {
+                    self.unacked_bytes -= message.len();
+                }
+            }
+        }
+        self.acked = received;
+    }
+
+    /// Switch to a new connection from the browser that received `received` frames and send the frames it is
+    /// missing. Returns `false` if the missing frames weren't kept and the browser needs to reload the page instead.
+    pub(crate) fn reconnect(
+        &mut self,
+        connection: Connection,
+        received: u64,
+        resumed: Vec<u8>,
+        reload: Vec<u8>,
+    ) -> bool {
+        self.ack(received);
+        if self.out_of_sync || received != self.acked {
+            _ = connection.to_client.send(reload);
+            self.connection = Some(connection);
+            return false;
+        }
+
+        for message in &self.unacked {
+            _ = connection.to_client.send(message.clone());
+        }
+        self.connection = Some(connection);
+        self.send(resumed);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A connection and the browser side of it
+    struct Browser {
+        received: UnboundedReceiver<Vec<u8>>,
+        send: UnboundedSender<Vec<u8>>,
+    }
+
+    impl Browser {
+        fn received(&mut self) -> Vec<Vec<u8>> {
+            let mut frames = Vec::new();
+            while let Ok(frame) = self.received.try_recv() {
+                frames.push(frame);
+            }
+            frames
+        }
+    }
+
+    fn connect() -> (Connection, Browser) {
+        let (to_client, received) = unbounded_channel();
+        let (send, from_client) = unbounded_channel();
+        (
+            Connection {
+                to_client,
+                from_client,
+            },
+            Browser { received, send },
+        )
+    }
+
+    fn frame(text: &str) -> Vec<u8> {
+        text.as_bytes().to_vec()
+    }
+
+    #[test]
+    fn parses_the_handshake() {
+        assert_eq!(
+            parse_handshake(b"__session__abc:12"),
+            Some(("abc".to_string(), 12))
+        );
+        assert_eq!(parse_handshake(b"__session__"), Some((String::new(), 0)));
+        assert_eq!(parse_handshake(b"__session__abc:x"), None);
+        assert_eq!(parse_handshake(b"{\"method\":\"query\"}"), None);
+    }
+
+    #[tokio::test]
+    async fn frames_lost_with_the_connection_are_sent_again() {
+        let (connection, mut browser) = connect();
+        let mut client = Client::new(connection, 1024);
+        client.send(frame("a"));
+        client.send(frame("b"));
+
+        // Both frames were sent, but the connection dropped before the browser got the second one
+        assert_eq!(browser.received(), [frame("a"), frame("b")]);
+        drop(browser);
+        assert_eq!(client.recv().await, None);
+        client.send(frame("c"));
+
+        let (connection, mut browser) = connect();
+        assert!(client.reconnect(connection, 1, frame("resumed"), frame("reload")));
+        assert_eq!(
+            browser.received(),
+            [frame("b"), frame("c"), frame("resumed")]
+        );
+    }
+
+    #[tokio::test]
+    async fn acknowledged_frames_are_not_sent_again() {
+        let (connection, mut browser) = connect();
+        let mut client = Client::new(connection, 1024);
+        client.send(frame("a"));
+        client.send(frame("b"));
+        browser.received();
+
+        browser.send.send(frame("__ack__2")).unwrap();
+        browser.send.send(frame("event")).unwrap();
+        assert_eq!(client.recv().await, Some(frame("event")));
+        assert!(client.unacked.is_empty());
+
+        drop(browser);
+        assert_eq!(client.recv().await, None);
+        let (connection, mut browser) = connect();
+        assert!(client.reconnect(connection, 2, frame("resumed"), frame("reload")));
+        assert_eq!(browser.received(), [frame("resumed")]);
+    }
+
+    #[tokio::test]
+    async fn reloads_if_too_many_frames_are_unacknowledged() {
+        let (connection, browser) = connect();
+        let mut client = Client::new(connection, 4);
+        client.send(frame("abc"));
+        client.send(frame("def"));
+
+        drop(browser);
+        assert_eq!(client.recv().await, None);
+        let (connection, mut browser) = connect();
+        assert!(!client.reconnect(connection, 1, frame("resumed"), frame("reload")));
+        assert_eq!(browser.received(), [frame("reload")]);
+    }
+
+    #[tokio::test]
+    async fn resumes_once_every_frame_is_acknowledged() {
+        let (connection, mut browser) = connect();
+        let mut client = Client::new(connection, 4);
+        client.send(frame("abc"));
+        client.send(frame("def"));
+        browser.received();
+
+        browser.send.send(frame("__ack__2")).unwrap();
+        drop(browser);
+        assert_eq!(client.recv().await, None);
+
+        let (connection, mut browser) = connect();
+        assert!(client.reconnect(connection, 2, frame("resumed"), frame("reload")));
+        assert_eq!(browser.received(), [frame("resumed")]);
+    }
+
+    #[test]
+    fn reloads_if_the_browser_received_frames_the_session_never_sent() {
+        let (connection, _browser) = connect();
+        let mut client = Client::new(connection, 1024);
+        client.send(frame("a"));
+
+        let (connection, mut browser) = connect();
+        assert!(!client.reconnect(connection, 5, frame("resumed"), frame("reload")));
+        assert_eq!(browser.received(), [frame("reload")]);
+    }
+
+    #[tokio::test]
+    async fn handshakes_are_not_passed_on() {
+        let (connection, browser) = connect();
+        let mut client = Client::new(connection, 1024);
+        browser.send.send(frame("__session__abc:0")).unwrap();
+        browser.send.send(frame("__ping__")).unwrap();
+        assert_eq!(client.recv().await, Some(frame("__ping__")));
+    }
+}
@@ -114,3 +114,52 @@ pub fn init(level: Level) -> Result<(), SetGlobalDefaultError> {
         set_global_default(sub.without_time().with_target(false).finish())
     }
 }
+
+/// Initialize `dioxus-logger` for profiling renders.
+///
+/// This works like [`init`], but also reports when spans close along with how long they were open. With the
+/// `instrument` feature of `dioxus` enabled, every component render, effect, and task poll emits a `debug` span
+/// with the component name and scope id. On desktop and mobile, the span timings are printed to stdout. On web, they
+/// are logged to the console and added to the performance timeline of the browser devtools.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// use dioxus::prelude::*;
+///
+/// fn main() {
+///     dioxus::logger::init_profiling().expect("logger failed to init");
+///     dioxus::launch(App);
+/// }
+/// ```
+pub fn init_profiling() -> Result<(), SetGlobalDefaultError> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use tracing_subscriber::Registry;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let layer_config = tracing_wasm::WASMLayerConfigBuilder::new()
+            .set_max_level(Level::DEBUG)
+            .set_report_logs_in_timings(true)
+            .build();
+        let layer = tracing_wasm::WASMLayer::new(layer_config);
+        let reg = Registry::default().with(layer);
+
+        set_global_default(reg)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let sub = tracing_subscriber::FmtSubscriber::builder()
+            .with_max_level(Level::DEBUG)
+            .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+            .with_env_filter(
+                tracing_subscriber::EnvFilter::builder()
+                    .with_default_directive(Level::DEBUG.into())
+                    .from_env_lossy()
+                    .add_directive("hyper_util=warn".parse().unwrap()),
+            );
+
+        set_global_default(sub.finish())
+    }
+}
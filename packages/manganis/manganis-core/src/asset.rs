@@ -1,4 +1,4 @@
-use crate::AssetOptions;
+use crate::{AssetOptions, AssetVariant, responsive_image_path};
 use const_serialize::{ConstStr, SerializeConst, deserialize_const};
 use std::{fmt::Debug, hash::Hash, path::PathBuf};
 
@@ -183,6 +183,39 @@ impl Asset {
             self.bundled().bundled_path.as_str().trim_start_matches('/'),
         ))
     }
+
+    /// Get a `srcset` for an image asset with responsive widths
+    ///
+    /// Returns `None` if the asset is not an image with [responsive widths](crate::AssetOptionsBuilder::with_responsive_widths)
+    /// or if the app is not bundled. The variants are only created by the CLI when the app is bundled, so the image
+    /// should always have a `src` as a fallback.
+    ///
+    /// ```rust, ignore
+    /// const HERO: Asset = asset!("/assets/hero.png", AssetOptions::image().with_avif().with_responsive_widths(&[400, 800]));
+    ///
+    /// rsx! {
+    ///     img { src: HERO, srcset: HERO.srcset(), sizes: "(max-width: 600px) 400px, 800px" }
+    /// }
+    /// ```
+    pub fn srcset(&self) -> Option<String> {
+        let bundled = self.bundled();
+        let AssetVariant::Image(image) = bundled.options().variant() else {
+            return None;
+        };
+
+        #[cfg(feature = "dioxus")]
+        if !dioxus_core_types::is_bundled_app() {
+            return None;
+        }
+
+        let path = self.resolve();
+        let path = path.to_string_lossy();
+        let srcset = image
+            .responsive_widths()
+            .map(|width| format!("{} {width}w", responsive_image_path(&path, width)))
+            .collect::<Vec<_>>();
+        (!srcset.is_empty()).then(|| srcset.join(", "))
+    }
 }
 
 impl From<Asset> for String {
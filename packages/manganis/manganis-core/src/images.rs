@@ -55,6 +55,9 @@ pub enum ImageSize {
     Automatic,
 }
 
+/// The maximum number of responsive widths an image asset can have
+const MAX_RESPONSIVE_WIDTHS: usize = 8;
+
 /// Options for an image asset
 #[derive(
     Debug,
@@ -73,6 +76,10 @@ pub struct ImageAssetOptions {
     low_quality_preview: bool,
     size: ImageSize,
     preload: bool,
+    /// The widths of the responsive variants of the image. Unused slots are `0`. Assets built with a version of
+    /// manganis from before this field existed decode with no responsive widths.
+    #[const_serialize(default)]
+    responsive_widths: [u32; MAX_RESPONSIVE_WIDTHS],
 }
 
 impl Default for ImageAssetOptions {
@@ -94,6 +101,7 @@ impl ImageAssetOptions {
             low_quality_preview: false,
             size: ImageSize::Automatic,
             preload: false,
+            responsive_widths: [0; MAX_RESPONSIVE_WIDTHS],
         }
     }
 
@@ -112,6 +120,14 @@ impl ImageAssetOptions {
         self.size
     }
 
+    /// Get the widths of the responsive variants of the image
+    pub fn responsive_widths(&self) -> impl Iterator<Item = u32> + '_ {
+        self.responsive_widths
+            .iter()
+            .copied()
+            .take_while(|width| *width != 0)
+    }
+
     pub(crate) const fn extension(&self) -> Option<&'static str> {
         match self.ty {
             ImageFormat::Png => Some("png"),
@@ -225,6 +241,33 @@ impl AssetOptionsBuilder<ImageAssetOptions> {
         self
     }
 
+    /// Emit resized variants of the image at each of the given widths
+    ///
+    /// Each variant keeps the aspect ratio of the image and is written next to the bundled image with the width
+    /// added to the file name. Use [`Asset::srcset`](crate::Asset::srcset) to let the browser pick the variant that
+    /// best fits the size the image is displayed at. Up to 8 widths are supported.
+    ///
+    /// ```rust
+    /// # use manganis::{asset, Asset, AssetOptions};
+    /// const _: Asset = asset!("/assets/image.png", AssetOptions::image().with_avif().with_responsive_widths(&[400, 800, 1600]));
+    /// ```
+    pub const fn with_responsive_widths(mut self, widths: &[u32]) -> Self {
+        if widths.len() > MAX_RESPONSIVE_WIDTHS {
+            panic!("An image asset can have at most 8 responsive widths");
+        }
+        let mut responsive_widths = [0; MAX_RESPONSIVE_WIDTHS];
+        let mut i = 0;
+        while i < widths.len() {
+            if widths[i] == 0 {
+                panic!("Responsive image widths must be greater than zero");
+            }
+            responsive_widths[i] = widths[i];
+            i += 1;
+        }
+        self.variant.responsive_widths = responsive_widths;
+        self
+    }
+
     /// Convert the options into options for a generic asset
     pub const fn into_asset_options(self) -> AssetOptions {
         AssetOptions {
@@ -233,3 +276,16 @@ impl AssetOptionsBuilder<ImageAssetOptions> {
         }
     }
 }
+
+/// Get the path of the responsive variant of an image with the given width. The width is added to the end of the file
+/// name before the extension, so `/assets/hero-123.avif` becomes `/assets/hero-123-800w.avif`.
+pub fn responsive_image_path(path: &str, width: u32) -> String {
+    let file_name_start = path.rfind(['/', '\\']).map_or(0, |i| i + 1);
+    match path[file_name_start..].rfind('.') {
+        Some(dot) => {
+            let (stem, extension) = path.split_at(file_name_start + dot);
+            format!("{stem}-{width}w{extension}")
+        }
+        None => format!("{path}-{width}w"),
+    }
+}
@@ -0,0 +1,62 @@
+use const_serialize::{ConstVec, SerializeConst, deserialize_const, serialize_const};
+use manganis_core::{ImageAssetOptions, ImageFormat, ImageSize, responsive_image_path};
+
+#[test]
+fn responsive_image_paths_add_the_width_before_the_extension() {
+    assert_eq!(
+        responsive_image_path("/assets/hero-123.avif", 800),
+        "/assets/hero-123-800w.avif"
+    );
+    assert_eq!(
+        responsive_image_path("/assets/hero.min.png", 400),
+        "/assets/hero.min-400w.png"
+    );
+    // Dots in folder names are not extensions
+    assert_eq!(
+        responsive_image_path("/assets.v2/hero", 400),
+        "/assets.v2/hero-400w"
+    );
+    assert_eq!(
+        responsive_image_path("C:\\assets\\hero.webp", 1600),
+        "C:\\assets\\hero-1600w.webp"
+    );
+    assert_eq!(responsive_image_path("hero.jpg", 200), "hero-200w.jpg");
+}
+
+#[test]
+fn image_options_from_before_responsive_widths_decode() {
+    // The image options as they were serialized before responsive widths were added
+    #[derive(SerializeConst)]
+    struct ImageAssetOptionsV1 {
+        ty: ImageFormat,
+        low_quality_preview: bool,
+        size: ImageSize,
+        preload: bool,
+    }
+
+    let mut buf = ConstVec::new();
+    buf = serialize_const(
+        &ImageAssetOptionsV1 {
+            ty: ImageFormat::Webp,
+            low_quality_preview: false,
+            size: ImageSize::Manual {
+                width: 512,
+                height: 256,
+            },
+            preload: true,
+        },
+        buf,
+    );
+
+    let (_, options) = deserialize_const!(ImageAssetOptions, buf.as_ref()).unwrap();
+    assert_eq!(options.format(), ImageFormat::Webp);
+    assert_eq!(
+        options.size(),
+        ImageSize::Manual {
+            width: 512,
+            height: 256
+        }
+    );
+    assert!(options.preloaded());
+    assert_eq!(options.responsive_widths().count(), 0);
+}
@@ -51,6 +51,11 @@ use crate::css_module::{CssModuleAttribute, expand_css_module_struct};
 /// # use manganis::{asset, Asset, AssetOptions, ImageSize, ImageFormat};
 /// const _: Asset = asset!("/assets/image.png", AssetOptions::image().with_format(ImageFormat::Avif));
 /// ```
+/// Emit resized variants of the image for responsive layouts and use them with `Asset::srcset`:
+/// ```rust
+/// # use manganis::{asset, Asset, AssetOptions};
+/// const _: Asset = asset!("/assets/image.png", AssetOptions::image().with_avif().with_responsive_widths(&[400, 800]));
+/// ```
 /// You can mark images as preloaded to make them load faster in your app
 /// ```rust
 /// # use manganis::{asset, Asset, AssetOptions};
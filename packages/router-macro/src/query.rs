@@ -1,4 +1,4 @@
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{Ident, Type};
 
 use proc_macro2::TokenStream as TokenStream2;
@@ -20,14 +20,25 @@ impl QuerySegment {
         }
     }
 
-    pub fn parse(&self) -> TokenStream2 {
+    /// Parse the query into the fields of the route. If any query argument fails to parse, the error is pushed into
+    /// `errors` and the generated code breaks out of the `'parse_query` block.
+    pub fn parse(
+        &self,
+        error_enum_name: &Ident,
+        error_enum_variant: &Ident,
+        inner_parse_enum: &Ident,
+    ) -> TokenStream2 {
         match self {
             QuerySegment::Single(segment) => segment.parse(),
             QuerySegment::Segments(segments) => {
                 let mut tokens = TokenStream2::new();
                 tokens.extend(quote! { let split_query: std::collections::HashMap<&str, &str> = query.split('&').filter_map(|s| s.split_once('=')).collect(); });
                 for segment in segments {
-                    tokens.extend(segment.parse());
+                    tokens.extend(segment.parse(
+                        error_enum_name,
+                        error_enum_variant,
+                        inner_parse_enum,
+                    ));
                 }
                 tokens
             }
@@ -142,16 +153,38 @@ pub struct QueryArgument {
 }
 
 impl QueryArgument {
-    pub fn parse(&self) -> TokenStream2 {
+    pub fn error_name(&self) -> Ident {
+        format_ident!("{}ParseError", self.ident)
+    }
+
+    pub fn missing_error_name(&self) -> Ident {
+        format_ident!("{}MissingError", self.ident)
+    }
+
+    pub fn parse(
+        &self,
+        error_enum_name: &Ident,
+        error_enum_variant: &Ident,
+        inner_parse_enum: &Ident,
+    ) -> TokenStream2 {
         let ident = &self.ident;
         let ty = &self.ty;
+        let error_name = self.error_name();
+        let missing_error_name = self.missing_error_name();
         quote! {
-            let #ident = match split_query.get(stringify!(#ident)) {
-                Some(query_argument) => {
-                    use dioxus_router::routable::FromQueryArgument;
-                    <#ty>::from_query_argument(query_argument).unwrap_or_default()
-                },
-                None => <#ty as Default>::default(),
+            let parsed = {
+                use dioxus_router::routable::FromQueryArgument;
+                match split_query.get(stringify!(#ident)) {
+                    Some(query_argument) => <#ty>::from_query_argument(query_argument).map_err(|err| #error_enum_name::#error_enum_variant(#inner_parse_enum::#error_name(err.to_string()))),
+                    None => <#ty>::from_missing_query_argument().ok_or(#error_enum_name::#error_enum_variant(#inner_parse_enum::#missing_error_name)),
+                }
+            };
+            let #ident = match parsed {
+                Ok(#ident) => #ident,
+                Err(err) => {
+                    errors.push(err);
+                    break 'parse_query;
+                }
             };
         }
     }
@@ -32,12 +32,23 @@ impl Redirect {
     pub fn error_type(&self) -> TokenStream {
         let error_name = self.error_ident();
 
-        create_error_type(&self.route.value(), error_name, &self.segments, None)
+        create_error_type(
+            &self.route.value(),
+            error_name,
+            &self.segments,
+            self.query.as_ref(),
+            None,
+        )
     }
 
-    pub fn parse_query(&self) -> TokenStream {
+    pub fn parse_query(
+        &self,
+        error_enum_name: &Ident,
+        error_enum_variant: &Ident,
+        inner_parse_enum: &Ident,
+    ) -> TokenStream {
         match &self.query {
-            Some(query) => query.parse(),
+            Some(query) => query.parse(error_enum_name, error_enum_variant, inner_parse_enum),
             None => quote! {},
         }
     }
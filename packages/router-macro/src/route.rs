@@ -441,12 +441,23 @@ impl Route {
             RouteType::Leaf { .. } => None,
         };
 
-        create_error_type(&self.route, error_name, &self.segments, child_type)
+        create_error_type(
+            &self.route,
+            error_name,
+            &self.segments,
+            self.query.as_ref(),
+            child_type,
+        )
     }
 
-    pub(crate) fn parse_query(&self) -> TokenStream2 {
+    pub(crate) fn parse_query(
+        &self,
+        error_enum_name: &Ident,
+        error_enum_variant: &Ident,
+        inner_parse_enum: &Ident,
+    ) -> TokenStream2 {
         match &self.query {
-            Some(query) => query.parse(),
+            Some(query) => query.parse(error_enum_name, error_enum_variant, inner_parse_enum),
             None => quote! {},
         }
     }
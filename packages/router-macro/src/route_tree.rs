@@ -338,7 +338,8 @@ impl RouteTreeSegmentData<'_> {
                     });
 
                 let construct_variant = route.construct(nests, enum_name);
-                let parse_query = route.parse_query();
+                let parse_query =
+                    route.parse_query(&error_enum_name, enum_variant, &variant_parse_error);
                 let parse_hash = route.parse_hash();
 
                 let insure_not_trailing = match route.ty {
@@ -436,7 +437,8 @@ impl RouteTreeSegmentData<'_> {
                     .enumerate()
                     .skip_while(|(_, seg)| matches!(seg, RouteSegment::Static(_)));
 
-                let parse_query = redirect.parse_query();
+                let parse_query =
+                    redirect.parse_query(&error_enum_name, enum_variant, &variant_parse_error);
                 let parse_hash = redirect.parse_hash();
 
                 let insure_not_trailing = redirect
@@ -523,9 +525,12 @@ fn return_constructed(
             let next_segment = segments_clone.next();
             // This is the last segment, return the parsed route
             if next_segment.is_none() {
-                #parse_query
-                #parse_hash
-                return Ok(#construct_variant);
+                // Query arguments that fail to parse break out of this block after pushing their error
+                'parse_query: {
+                    #parse_query
+                    #parse_hash
+                    return Ok(#construct_variant);
+                }
             } else {
                 let mut trailing = String::new();
                 for seg in remaining_segments {
@@ -538,9 +543,11 @@ fn return_constructed(
         }
     } else {
         quote! {
-            #parse_query
-            #parse_hash
-            return Ok(#construct_variant);
+            'parse_query: {
+                #parse_query
+                #parse_hash
+                return Ok(#construct_variant);
+            }
         }
     }
 }
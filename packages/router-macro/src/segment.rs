@@ -227,6 +227,7 @@ pub(crate) fn create_error_type(
     route: &str,
     error_name: Ident,
     segments: &[RouteSegment],
+    query: Option<&QuerySegment>,
     child_type: Option<&Type>,
 ) -> TokenStream2 {
     let mut error_variants = Vec::new();
@@ -277,6 +278,29 @@ pub(crate) fn create_error_type(
         }
     }
 
+    if let Some(QuerySegment::Segments(arguments)) = query {
+        for argument in arguments {
+            let ident = &argument.ident;
+            let ty = &argument.ty;
+            let error_name = argument.error_name();
+            let missing_error = argument.missing_error_name();
+            let comment = format!(
+                " An error that can occur when trying to parse the query argument '?:{}'.",
+                ident
+            );
+            error_variants.push(quote! {
+                #[doc = #comment]
+                #error_name(String)
+            });
+            display_match.push(quote! { Self::#error_name(err) => write!(f, "Query argument '({}:{})' did not match: {}", stringify!(#ident), stringify!(#ty), err)? });
+            error_variants.push(quote! {
+                #[doc = #comment]
+                #missing_error
+            });
+            display_match.push(quote! { Self::#missing_error => write!(f, "Query argument '({}:{})' was missing", stringify!(#ident), stringify!(#ty))? });
+        }
+    }
+
     let child_type_variant = child_type
         .map(|child_type| {
             let comment = format!(
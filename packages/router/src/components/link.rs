@@ -6,6 +6,7 @@ use dioxus_core::{Attribute, Element, EventHandler, VNode};
 use dioxus_core_macro::{Props, rsx};
 use dioxus_html::{
     self as dioxus_elements, ModifiersInteraction, MountedEvent, MouseEvent, PointerInteraction,
+    VisibleEvent,
 };
 
 use tracing::error;
@@ -13,6 +14,20 @@ use tracing::error;
 use crate::navigation::NavigationTarget;
 use crate::utils::use_router_internal::use_router_internal;
 
+/// When a [`Link`] should start loading the data for its target route.
+///
+/// Prefetching runs the [`RouterConfig::prefetch`](crate::RouterConfig::prefetch) callback for the target route.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PrefetchStrategy {
+    /// Never prefetch the target route.
+    #[default]
+    None,
+    /// Prefetch when the mouse enters the link. The prefetch is cancelled if the mouse leaves without clicking.
+    OnHover,
+    /// Prefetch when the link scrolls into view. The prefetch is cancelled if the link scrolls out of view first.
+    OnVisible,
+}
+
 /// The properties for a [`Link`].
 #[derive(Props, Clone, PartialEq)]
 pub struct LinkProps {
@@ -56,6 +71,10 @@ pub struct LinkProps {
     #[props(into)]
     pub to: NavigationTarget,
 
+    /// When to start loading the data for the `to` route. Only internal targets are prefetched.
+    #[props(default)]
+    pub prefetch: PrefetchStrategy,
+
     #[props(extends = GlobalAttributes)]
     attributes: Vec<Attribute>,
 }
@@ -70,6 +89,7 @@ impl Debug for LinkProps {
             .field("onclick", &self.onclick.as_ref().map(|_| "onclick is set"))
             .field("onclick_only", &self.onclick_only)
             .field("rel", &self.rel)
+            .field("prefetch", &self.prefetch)
             .finish()
     }
 }
@@ -138,13 +158,14 @@ pub fn Link(props: LinkProps) -> Element {
     let LinkProps {
         active_class,
         children,
-        attributes,
+        mut attributes,
         new_tab,
         onclick,
         onclick_only,
         rel,
         to,
         class,
+        prefetch,
         ..
     } = props;
 
@@ -201,6 +222,31 @@ pub fn Link(props: LinkProps) -> Element {
 
     let do_default = onclick.is_none() || !onclick_only;
 
+    // Only internal routes have data the router knows how to load
+    if let NavigationTarget::Internal(route) = &to {
+        let route = route.clone();
+        match prefetch {
+            PrefetchStrategy::None => {}
+            PrefetchStrategy::OnHover => {
+                attributes.push(dioxus_elements::events::onmouseenter({
+                    let route = route.clone();
+                    move |_: MouseEvent| router.prefetch(&route)
+                }));
+                attributes.push(dioxus_elements::events::onmouseleave(
+                    move |_: MouseEvent| router.cancel_prefetch(&route),
+                ));
+            }
+            PrefetchStrategy::OnVisible => {
+                attributes.push(dioxus_elements::events::onvisible(
+                    move |event: VisibleEvent| match event.is_intersecting() {
+                        Ok(true) => router.prefetch(&route),
+                        _ => router.cancel_prefetch(&route),
+                    },
+                ));
+            }
+        }
+    }
+
     let action = move |event: MouseEvent| {
         // Only handle events without modifiers
         if !event.modifiers().is_empty() {
@@ -224,6 +270,9 @@ pub fn Link(props: LinkProps) -> Element {
         event.prevent_default();
 
         if do_default && is_router_nav {
+            if let NavigationTarget::Internal(route) = &to {
+                router.keep_prefetch(route);
+            }
             router.push_any(to.clone());
         }
 
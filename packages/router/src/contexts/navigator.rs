@@ -1,4 +1,6 @@
-use crate::{ExternalNavigationFailure, NavigationTarget, RouterContext};
+use crate::{
+    ExternalNavigationFailure, NavigationTarget, RouterContext, navigation::resolve_relative_route,
+};
 
 /// Acquire the navigator without subscribing to updates.
 ///
@@ -61,4 +63,36 @@ impl Navigator {
     ) -> Option<ExternalNavigationFailure> {
         self.0.replace(target)
     }
+
+    /// Push a path relative to the current route.
+    ///
+    /// The path is resolved with [`resolve_relative_route`], so `"edit"` navigates to a child of the current route,
+    /// `"../2"` navigates to a sibling, and `".."` navigates to the parent.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// #[component]
+    /// fn Post(id: u32) -> Element {
+    ///     rsx! {
+    ///         // From /posts/1, this navigates to /posts/1/edit
+    ///         button { onclick: move |_| { navigator().push_relative("edit"); }, "Edit" }
+    ///         // From /posts/1, this navigates to /posts/2
+    ///         button { onclick: move |_| { navigator().push_relative(&format!("../{}", id + 1)); }, "Next" }
+    ///     }
+    /// }
+    /// ```
+    pub fn push_relative(&self, path: &str) -> Option<ExternalNavigationFailure> {
+        self.push(self.resolve(path))
+    }
+
+    /// Replace the current location with a path relative to the current route.
+    ///
+    /// The path is resolved the same way as [`Navigator::push_relative`].
+    pub fn replace_relative(&self, path: &str) -> Option<ExternalNavigationFailure> {
+        self.replace(self.resolve(path))
+    }
+
+    fn resolve(&self, path: &str) -> NavigationTarget {
+        NavigationTarget::Internal(resolve_relative_route(&self.0.full_route_string(), path))
+    }
 }
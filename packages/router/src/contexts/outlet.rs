@@ -1,4 +1,5 @@
 use dioxus_core::{Element, VNode, provide_context, try_consume_context, use_hook};
+use dioxus_core_macro::rsx;
 
 use crate::{routable::Routable, utils::use_router_internal::use_router_internal};
 
@@ -33,11 +34,54 @@ impl<R> OutletContext<R> {
         }
     }
 
-    /// Returns the current nesting level of this outlet
+    /// Returns the current nesting level of this outlet. The outlet in the root layout is at level 0, and every
+    /// nested outlet is one level deeper.
     pub fn level(&self) -> usize {
         self.current_level
     }
 
+    /// Returns the parents of the current route from the root route down to the direct parent of the current route.
+    ///
+    /// Parents are found with [`Routable::parent`] by removing one segment from the end of the route at a time, so
+    /// this can be used to build breadcrumbs for any route:
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_router::use_outlet_context;
+    /// # #[derive(Routable, Clone, PartialEq, Debug)]
+    /// # enum Route {
+    /// #     #[layout(Breadcrumbs)]
+    /// #     #[route("/")]
+    /// #     Home {},
+    /// # }
+    /// # #[component]
+    /// # fn Home() -> Element { VNode::empty() }
+    /// #[component]
+    /// fn Breadcrumbs() -> Element {
+    ///     let outlet = use_outlet_context::<Route>();
+    ///
+    ///     rsx! {
+    ///         nav {
+    ///             for parent in outlet.parent_routes() {
+    ///                 Link { to: parent.clone(), "{parent}" }
+    ///                 " / "
+    ///             }
+    ///         }
+    ///         Outlet::<Route> {}
+    ///     }
+    /// }
+    /// ```
+    pub fn parent_routes(&self) -> Vec<R>
+    where
+        R: Routable,
+    {
+        let current = crate::router().current::<R>();
+        let mut parents: Vec<R> =
+            std::iter::successors(current.parent(), |route| route.parent()).collect();
+        parents.reverse();
+        parents
+    }
+
     pub(crate) fn render() -> Element
     where
         R: Routable + Clone,
@@ -55,7 +99,19 @@ impl<R> OutletContext<R> {
             };
         }
 
-        router.current::<R>().render(current_level)
+        let route = router.current::<R>().render(current_level);
+
+        // Routers with a pending navigation component render it after the root route while an async guard runs
+        if current_level == 0
+            && let Some(pending) = router.render_navigation_pending()
+        {
+            return rsx! {
+                {route}
+                {pending}
+            };
+        }
+
+        route
     }
 }
 
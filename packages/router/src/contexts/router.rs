@@ -1,11 +1,14 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     error::Error,
     fmt::Display,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
     sync::{Arc, Mutex},
 };
 
-use dioxus_core::{Element, ReactiveContext, ScopeId, provide_context};
+use dioxus_core::{Element, ReactiveContext, ScopeId, Task, provide_context, spawn_forever};
 use dioxus_history::history;
 use dioxus_signals::{CopyValue, ReadableExt, Signal, WritableExt};
 
@@ -14,6 +17,9 @@ use crate::{
     navigation::NavigationTarget, routable::Routable, router_cfg::RouterConfig,
 };
 
+/// The number of times navigation guards can redirect a single navigation before it is cancelled
+const MAX_GUARD_REDIRECTS: usize = 16;
+
 /// An error that is thrown when the router fails to parse a route
 #[derive(Debug, Clone)]
 pub struct ParseRouteError {
@@ -67,6 +73,36 @@ pub(crate) type RoutingCallback<R> =
     Arc<dyn Fn(GenericRouterContext<R>) -> Option<NavigationTarget<R>>>;
 pub(crate) type AnyRoutingCallback = Arc<dyn Fn(RouterContext) -> Option<NavigationTarget>>;
 
+/// A function the router will call to load the data for a route ahead of time.
+pub(crate) type PrefetchCallback<R> = Arc<dyn Fn(R) -> Pin<Box<dyn Future<Output = ()>>>>;
+type AnyPrefetchCallback = Arc<dyn Fn(&str) -> Option<Pin<Box<dyn Future<Output = ()>>>>>;
+
+enum Prefetch {
+    Pending(Task),
+    Done,
+}
+
+/// The type erased result of a navigation guard. Internal targets are full routes from the root router.
+pub(crate) enum AnyGuardResult {
+    Allow,
+    Redirect(NavigationTarget),
+    Cancel,
+}
+
+/// The type erased outcome of running a navigation guard on a route
+pub(crate) enum AnyGuardOutcome {
+    Ready(AnyGuardResult),
+    Pending(Pin<Box<dyn Future<Output = AnyGuardResult>>>),
+}
+
+pub(crate) type AnyNavigationGuard = Rc<dyn Fn(&str) -> AnyGuardOutcome>;
+
+#[derive(Clone, Copy)]
+enum NavigationKind {
+    Push,
+    Replace,
+}
+
 struct RouterContextInner {
     unresolved_error: Option<ExternalNavigationFailure>,
 
@@ -78,6 +114,14 @@ struct RouterContextInner {
     internal_route: fn(&str) -> bool,
 
     site_map: &'static [SiteMapSegment],
+
+    prefetch: Option<AnyPrefetchCallback>,
+    prefetched: HashMap<String, Prefetch>,
+
+    guards: Vec<(usize, AnyNavigationGuard)>,
+    next_guard_id: usize,
+    pending_navigation: Option<Task>,
+    navigation_pending: Option<fn() -> Element>,
 }
 
 impl RouterContextInner {
@@ -145,6 +189,17 @@ impl RouterContext {
             internal_route: |route| R::from_str(route).is_ok(),
 
             site_map: R::SITE_MAP,
+
+            prefetch: cfg.prefetch.map(|prefetch| {
+                Arc::new(move |route: &str| R::from_str(route).ok().map(|route| prefetch(route)))
+                    as AnyPrefetchCallback
+            }),
+            prefetched: HashMap::new(),
+
+            guards: Vec::new(),
+            next_guard_id: 0,
+            pending_navigation: None,
+            navigation_pending: cfg.navigation_pending,
         };
 
         let history = history();
@@ -205,56 +260,212 @@ impl RouterContext {
     }
 
     pub(crate) fn push_any(&self, target: NavigationTarget) -> Option<ExternalNavigationFailure> {
-        {
-            let mut write = self.inner.write_unchecked();
-            match target {
-                NavigationTarget::Internal(p) => history().push(p),
-                NavigationTarget::External(e) => return write.external(e),
-            }
-        }
-
-        self.change_route()
+        self.navigate(target, NavigationKind::Push)
     }
 
     /// Push a new location.
     ///
     /// The previous location will be available to go back to.
+    ///
+    /// If a [navigation guard](crate::use_navigation_guard) is registered, it runs before the location changes.
     pub fn push(&self, target: impl Into<NavigationTarget>) -> Option<ExternalNavigationFailure> {
-        let target = target.into();
-        {
-            let mut write = self.inner.write_unchecked();
-            match target {
-                NavigationTarget::Internal(p) => {
-                    let history = history();
-                    history.push(p)
-                }
-                NavigationTarget::External(e) => return write.external(e),
-            }
-        }
-
-        self.change_route()
+        self.navigate(target.into(), NavigationKind::Push)
     }
 
     /// Replace the current location.
     ///
     /// The previous location will **not** be available to go back to.
+    ///
+    /// If a [navigation guard](crate::use_navigation_guard) is registered, it runs before the location changes.
     pub fn replace(
         &self,
         target: impl Into<NavigationTarget>,
     ) -> Option<ExternalNavigationFailure> {
-        let target = target.into();
-        {
-            let mut state = self.inner.write_unchecked();
-            match target {
-                NavigationTarget::Internal(p) => {
-                    let history = history();
-                    history.replace(p)
+        self.navigate(target.into(), NavigationKind::Replace)
+    }
+
+    fn navigate(
+        &self,
+        target: NavigationTarget,
+        kind: NavigationKind,
+    ) -> Option<ExternalNavigationFailure> {
+        self.navigate_with_redirects(target, kind, 0)
+    }
+
+    /// Navigate to `target`, which guards have already redirected `redirects` times
+    fn navigate_with_redirects(
+        &self,
+        target: NavigationTarget,
+        kind: NavigationKind,
+        redirects: usize,
+    ) -> Option<ExternalNavigationFailure> {
+        let route = match target {
+            NavigationTarget::Internal(route) => route,
+            NavigationTarget::External(e) => return self.inner.write_unchecked().external(e),
+        };
+
+        // A new navigation replaces any navigation that is still waiting for an async guard
+        self.cancel_pending_navigation();
+
+        match self.run_guards(&route) {
+            AnyGuardOutcome::Ready(result) => {
+                self.finish_navigation(route, result, kind, redirects)
+            }
+            AnyGuardOutcome::Pending(future) => {
+                let myself = *self;
+                let task = spawn_forever(async move {
+                    let result = future.await;
+                    myself.inner.write_unchecked().pending_navigation = None;
+                    myself.finish_navigation(route, result, kind, redirects);
+                    myself.inner.read().update_subscribers();
+                });
+                self.inner.write_unchecked().pending_navigation = Some(task);
+                self.inner.read().update_subscribers();
+                None
+            }
+        }
+    }
+
+    fn finish_navigation(
+        &self,
+        route: String,
+        result: AnyGuardResult,
+        kind: NavigationKind,
+        redirects: usize,
+    ) -> Option<ExternalNavigationFailure> {
+        match result {
+            AnyGuardResult::Allow => {
+                let history = history();
+                match kind {
+                    NavigationKind::Push => history.push(route),
+                    NavigationKind::Replace => history.replace(route),
                 }
-                NavigationTarget::External(e) => return state.external(e),
+                self.change_route()
+            }
+            // Guards that redirect to each other would loop forever, so give up after too many redirects
+            AnyGuardResult::Redirect(target) if redirects >= MAX_GUARD_REDIRECTS => {
+                tracing::error!(
+                    "Navigation guards redirected more than {MAX_GUARD_REDIRECTS} times in a row while navigating to {route:?}. This usually means two guards redirect to each other. The navigation was cancelled."
+                );
+                None
             }
+            AnyGuardResult::Redirect(target) => {
+                self.navigate_with_redirects(target, kind, redirects + 1)
+            }
+            AnyGuardResult::Cancel => None,
         }
+    }
+
+    /// Run every guard on the route until one of them doesn't allow the navigation
+    fn run_guards(&self, route: &str) -> AnyGuardOutcome {
+        let guards: Vec<_> = self
+            .inner
+            .peek()
+            .guards
+            .iter()
+            .map(|(_, guard)| guard.clone())
+            .collect();
+        let mut guards = guards.into_iter();
+
+        for guard in guards.by_ref() {
+            match guard(route) {
+                AnyGuardOutcome::Ready(AnyGuardResult::Allow) => {}
+                AnyGuardOutcome::Ready(result) => return AnyGuardOutcome::Ready(result),
+                AnyGuardOutcome::Pending(future) => {
+                    // Once one guard is async, the rest of the guards run after it resolves
+                    let route = route.to_string();
+                    return AnyGuardOutcome::Pending(Box::pin(async move {
+                        match future.await {
+                            AnyGuardResult::Allow => {}
+                            result => return result,
+                        }
+                        for guard in guards {
+                            let result = match guard(&route) {
+                                AnyGuardOutcome::Ready(result) => result,
+                                AnyGuardOutcome::Pending(future) => future.await,
+                            };
+                            if !matches!(result, AnyGuardResult::Allow) {
+                                return result;
+                            }
+                        }
+                        AnyGuardResult::Allow
+                    }));
+                }
+            }
+        }
+
+        AnyGuardOutcome::Ready(AnyGuardResult::Allow)
+    }
+
+    fn cancel_pending_navigation(&self) {
+        let pending = self.inner.write_unchecked().pending_navigation.take();
+        if let Some(task) = pending {
+            task.cancel();
+            self.inner.read().update_subscribers();
+        }
+    }
+
+    /// Register a guard that runs before every navigation. Returns an id to remove the guard with.
+    ///
+    /// The guard also runs on the current route, so a guard that is added while a protected route is active can
+    /// redirect away from it. Guards are usually added while a component renders, so that check runs in a task
+    /// after the render instead of navigating in the middle of it.
+    pub(crate) fn add_guard(&self, guard: AnyNavigationGuard) -> usize {
+        let id = {
+            let mut inner = self.inner.write_unchecked();
+            let id = inner.next_guard_id;
+            inner.next_guard_id += 1;
+            inner.guards.push((id, guard.clone()));
+            id
+        };
+
+        let myself = *self;
+        spawn_forever(async move {
+            // The guard may have been removed before the task ran
+            if !myself
+                .inner
+                .peek()
+                .guards
+                .iter()
+                .any(|(guard_id, _)| *guard_id == id)
+            {
+                return;
+            }
+            let route = history().current_route();
+            let result = match guard(&route) {
+                AnyGuardOutcome::Ready(result) => result,
+                AnyGuardOutcome::Pending(future) => future.await,
+            };
+            // Skip the redirect if the user navigated somewhere else while the guard ran
+            if !matches!(result, AnyGuardResult::Allow) && history().current_route() == route {
+                myself.finish_navigation(route, result, NavigationKind::Replace, 0);
+            }
+        });
 
-        self.change_route()
+        id
+    }
+
+    /// Remove a guard registered with [`Self::add_guard`]
+    pub(crate) fn remove_guard(&self, id: usize) {
+        self.inner
+            .write_unchecked()
+            .guards
+            .retain(|(guard_id, _)| *guard_id != id);
+    }
+
+    /// Check if the router is waiting for an async [navigation guard](crate::use_navigation_guard) to finish before
+    /// navigating.
+    pub fn is_navigating(&self) -> bool {
+        let inner = self.inner.read();
+        inner.subscribe_to_current_context();
+        inner.pending_navigation.is_some()
+    }
+
+    pub(crate) fn render_navigation_pending(&self) -> Option<Option<Element>> {
+        let inner = self.inner.read();
+        let pending = inner.navigation_pending?;
+        inner.subscribe_to_current_context();
+        Some(inner.pending_navigation.is_some().then(pending))
     }
 
     /// The route that is currently active.
@@ -342,6 +553,56 @@ impl RouterContext {
     pub(crate) fn internal_route(&self, route: &str) -> bool {
         (self.inner.read().internal_route)(route)
     }
+
+    /// Start loading the data for `route` with the [`RouterConfig::prefetch`] callback if it hasn't been loaded yet.
+    pub(crate) fn prefetch(&self, route: &str) {
+        let future = {
+            let inner = self.inner.peek();
+            if inner.prefetched.contains_key(route) {
+                return;
+            }
+            match inner.prefetch.as_ref().and_then(|prefetch| prefetch(route)) {
+                Some(future) => future,
+                None => return,
+            }
+        };
+
+        let inner = self.inner;
+        let key = route.to_string();
+        let task = spawn_forever({
+            let key = key.clone();
+            async move {
+                future.await;
+                inner
+                    .write_unchecked()
+                    .prefetched
+                    .insert(key, Prefetch::Done);
+            }
+        });
+        self.inner
+            .write_unchecked()
+            .prefetched
+            .insert(key, Prefetch::Pending(task));
+    }
+
+    /// Cancel the prefetch for `route` if it is still running.
+    pub(crate) fn cancel_prefetch(&self, route: &str) {
+        let mut inner = self.inner.write_unchecked();
+        if let Some(Prefetch::Pending(task)) = inner.prefetched.get(route) {
+            task.cancel();
+            inner.prefetched.remove(route);
+        }
+    }
+
+    /// Let the prefetch for `route` finish even if [`Self::cancel_prefetch`] is called later.
+    pub(crate) fn keep_prefetch(&self, route: &str) {
+        let mut inner = self.inner.write_unchecked();
+        if let Some(prefetch @ Prefetch::Pending(_)) = inner.prefetched.get_mut(route) {
+            // The task keeps running in the background. Marking the route as done stops it from being
+            // cancelled or started again.
+            *prefetch = Prefetch::Done;
+        }
+    }
 }
 
 /// This context is set to the RouterConfig on_update method
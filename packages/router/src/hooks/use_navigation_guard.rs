@@ -0,0 +1,169 @@
+use std::{future::Future, rc::Rc};
+
+use dioxus_core::{use_drop, use_hook};
+
+use crate::{
+    AnyGuardOutcome, AnyGuardResult, AnyNavigationGuard, NavigationTarget, Routable, RouterContext,
+    components::child_router::{ChildRouteMapping, consume_child_route_mapping},
+    utils::use_router_internal::use_router_internal,
+};
+
+/// The result of a navigation guard that decides if a navigation can continue.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GuardResult<R> {
+    /// Continue the navigation to the target.
+    Allow,
+    /// Navigate to a different target instead. Guards run again for the new target.
+    Redirect(NavigationTarget<R>),
+    /// Stay on the current route.
+    Cancel,
+}
+
+/// A hook that runs a guard before the router navigates to a new route.
+///
+/// The guard runs for navigation with [`Link`](crate::Link)s and the [`Navigator`](crate::Navigator) before the
+/// route changes, so the target route is never rendered if the guard redirects or cancels the navigation. When the
+/// guard is added, it also runs on the current route.
+///
+/// Guards are removed when the component that added them is dropped. If multiple guards are active, they run in the
+/// order they were added until one of them doesn't allow the navigation. Going back or forward in the history does
+/// not run guards.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Routable, PartialEq, Debug)]
+/// enum Route {
+///     #[layout(Guarded)]
+///         #[route("/")]
+///         Home {},
+///         #[route("/admin")]
+///         Admin {},
+///         #[route("/login")]
+///         Login {},
+/// }
+///
+/// #[component]
+/// fn Guarded() -> Element {
+///     let logged_in = use_signal(|| false);
+///     use_navigation_guard(move |target: &Route| match target {
+///         Route::Admin {} if !logged_in() => GuardResult::Redirect(Route::Login {}.into()),
+///         _ => GuardResult::Allow,
+///     });
+///
+///     rsx! { Outlet::<Route> {} }
+/// }
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Admin() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Login() -> Element { VNode::empty() }
+/// # let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+/// # vdom.rebuild_in_place();
+/// ```
+pub fn use_navigation_guard<R: Routable>(guard: impl Fn(&R) -> GuardResult<R> + 'static) {
+    use_guard(move |mapping| {
+        Rc::new(move |route: &str| {
+            let result = match parse_route(route, &mapping) {
+                Some(route) => erase_result(guard(&route), &mapping),
+                None => AnyGuardResult::Allow,
+            };
+            AnyGuardOutcome::Ready(result)
+        }) as AnyNavigationGuard
+    })
+}
+
+/// A hook that runs an async guard before the router navigates to a new route.
+///
+/// This works like [`use_navigation_guard`], but the guard can wait for something like checking a token with the
+/// server. The current route stays visible while the guard runs, and the router renders the
+/// [`RouterConfig::navigation_pending`](crate::RouterConfig::navigation_pending) component if it is set. Starting
+/// another navigation while the guard runs cancels the pending navigation.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # async fn token_is_valid() -> bool { true }
+/// #[derive(Clone, Routable, PartialEq, Debug)]
+/// enum Route {
+///     #[layout(Guarded)]
+///         #[route("/")]
+///         Home {},
+///         #[route("/admin")]
+///         Admin {},
+///         #[route("/login")]
+///         Login {},
+/// }
+///
+/// #[component]
+/// fn Guarded() -> Element {
+///     use_async_navigation_guard(move |target: Route| async move {
+///         match target {
+///             Route::Admin {} if !token_is_valid().await => GuardResult::Redirect(Route::Login {}.into()),
+///             _ => GuardResult::Allow,
+///         }
+///     });
+///
+///     rsx! { Outlet::<Route> {} }
+/// }
+/// # #[component]
+/// # fn Home() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Admin() -> Element { VNode::empty() }
+/// # #[component]
+/// # fn Login() -> Element { VNode::empty() }
+/// # let mut vdom = VirtualDom::new(|| rsx! { Router::<Route> {} });
+/// # vdom.rebuild_in_place();
+/// ```
+pub fn use_async_navigation_guard<R: Routable, F: Future<Output = GuardResult<R>> + 'static>(
+    guard: impl Fn(R) -> F + 'static,
+) {
+    use_guard(move |mapping| {
+        Rc::new(move |route: &str| {
+            let Some(route) = parse_route(route, &mapping) else {
+                return AnyGuardOutcome::Ready(AnyGuardResult::Allow);
+            };
+            let future = guard(route);
+            let mapping = mapping.clone();
+            AnyGuardOutcome::Pending(Box::pin(
+                async move { erase_result(future.await, &mapping) },
+            ))
+        }) as AnyNavigationGuard
+    })
+}
+
+/// Register a guard with the router for the lifetime of the component
+fn use_guard<R: Routable>(
+    make_guard: impl FnOnce(Option<ChildRouteMapping<R>>) -> AnyNavigationGuard,
+) {
+    let router: RouterContext =
+        use_router_internal().expect("Must be called in a descendant of a Router component");
+    let id = use_hook(|| router.add_guard(make_guard(consume_child_route_mapping::<R>())));
+    use_drop(move || router.remove_guard(id));
+}
+
+/// Parse a route from the root router into the route of this router
+fn parse_route<R: Routable>(route: &str, mapping: &Option<ChildRouteMapping<R>>) -> Option<R> {
+    match mapping {
+        Some(mapping) => mapping.parse_route_from_root_route(route),
+        None => R::from_str(route).ok(),
+    }
+}
+
+fn erase_result<R: Routable>(
+    result: GuardResult<R>,
+    mapping: &Option<ChildRouteMapping<R>>,
+) -> AnyGuardResult {
+    match result {
+        GuardResult::Allow => AnyGuardResult::Allow,
+        GuardResult::Cancel => AnyGuardResult::Cancel,
+        GuardResult::Redirect(NavigationTarget::Internal(route)) => {
+            AnyGuardResult::Redirect(NavigationTarget::Internal(match mapping {
+                Some(mapping) => mapping.format_route_as_root_route(route),
+                None => route.to_string(),
+            }))
+        }
+        GuardResult::Redirect(NavigationTarget::External(url)) => {
+            AnyGuardResult::Redirect(NavigationTarget::External(url))
+        }
+    }
+}
@@ -61,12 +61,17 @@ pub mod hooks {
 
     mod use_navigator;
     pub use use_navigator::*;
+
+    mod use_navigation_guard;
+    pub use use_navigation_guard::*;
 }
 
 pub use hooks::router;
 
 #[cfg(feature = "html")]
-pub use crate::components::{GoBackButton, GoForwardButton, HistoryButtonProps, Link, LinkProps};
+pub use crate::components::{
+    GoBackButton, GoForwardButton, HistoryButtonProps, Link, LinkProps, PrefetchStrategy,
+};
 pub use crate::components::{Outlet, Router, RouterProps};
 pub use crate::contexts::*;
 pub use crate::hooks::*;
@@ -168,3 +168,52 @@ impl<R: Routable> FromStr for NavigationTarget<R> {
         }
     }
 }
+
+/// Resolve a relative path against the `current` route.
+///
+/// The current route is treated like a directory, so relative paths are resolved against the whole route instead
+/// of replacing its last segment:
+///
+/// - A path that starts with `/` is absolute and is returned as is.
+/// - `.` and empty segments refer to the current route. `edit` from `/posts/1` resolves to `/posts/1/edit`.
+/// - `..` removes one segment. `../2` from `/posts/1` resolves to `/posts/2`. Going above the root stays at `/`.
+/// - A trailing slash on the current route is ignored, and a trailing slash on the relative path is kept.
+/// - The query and hash of the current route are dropped. A query or hash in the relative path is kept, so `?page=2`
+///   from `/posts?page=1` resolves to `/posts?page=2`.
+///
+/// ```rust
+/// # use dioxus_router::navigation::resolve_relative_route;
+/// assert_eq!(resolve_relative_route("/posts/1", "edit"), "/posts/1/edit");
+/// assert_eq!(resolve_relative_route("/posts/1/", "../2"), "/posts/2");
+/// assert_eq!(resolve_relative_route("/posts/1", "/about"), "/about");
+/// assert_eq!(resolve_relative_route("/posts?page=1", "?page=2"), "/posts?page=2");
+/// ```
+pub fn resolve_relative_route(current: &str, relative: &str) -> String {
+    if relative.starts_with('/') {
+        return relative.to_string();
+    }
+
+    let (path, suffix) = relative.split_at(relative.find(['?', '#']).unwrap_or(relative.len()));
+    let current_path = current.split(['?', '#']).next().unwrap_or_default();
+    let mut segments: Vec<&str> = current_path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    let mut resolved = format!("/{}", segments.join("/"));
+    if path.ends_with('/') && !segments.is_empty() {
+        resolved.push('/');
+    }
+    resolved.push_str(suffix);
+    resolved
+}
@@ -105,13 +105,14 @@ impl<T: for<'a> From<&'a str>> FromQuery for T {
 /// }
 ///
 /// // We can derive Default for CustomQuery
-/// // If the router fails to parse the query value, it will use the default value instead
 /// #[derive(Default, Clone, PartialEq, Debug)]
 /// struct CustomQuery {
 ///     count: i32,
 /// }
 ///
 /// // We implement FromStr for CustomQuery so that FromQuerySegment is implemented automatically
+/// // If the query value fails to parse or is missing, the route will not match and the error will be reported in
+/// // the `RouteParseError` instead
 /// impl std::str::FromStr for CustomQuery {
 ///     type Err = <i32 as std::str::FromStr>::Err;
 ///
@@ -134,6 +135,18 @@ impl<T: for<'a> From<&'a str>> FromQuery for T {
 /// #     unimplemented!()
 /// # }
 /// ```
+///
+/// ## Migrating from 0.7
+///
+/// In Dioxus 0.7, a query argument that was missing or failed to parse silently fell back to `Default::default()`
+/// and the route still matched. Query arguments are now required: a missing or malformed argument makes the route
+/// fail to match, and the error is reported as a `{argument}MissingError` or `{argument}ParseError` variant of the
+/// route's parse error. To keep the old behavior for an argument:
+///
+/// - Make the field an `Option<T>` if the argument can be left out of the url, and use
+///   `.unwrap_or_default()` in your component.
+/// - Or implement `FromQueryArgument` manually and return `Some(Self::default())` from
+///   [`FromQueryArgument::from_missing_query_argument`] and `Ok(Self::default())` when parsing fails.
 #[rustversion::attr(
     since(1.78.0),
     diagnostic::on_unimplemented(
@@ -148,6 +161,14 @@ pub trait FromQueryArgument<P = ()>: Default {
 
     /// Create an instance of `Self` from a query string.
     fn from_query_argument(argument: &str) -> Result<Self, Self::Err>;
+
+    /// Create an instance of `Self` when the query argument is missing from the url.
+    ///
+    /// Returning `None` marks the argument as required and the route will not match urls without it. By default every
+    /// argument is required except for `Option<T>` which is `None` when the argument is missing.
+    fn from_missing_query_argument() -> Option<Self> {
+        None
+    }
 }
 
 impl<T: Default + FromStr> FromQueryArgument for T
@@ -185,6 +206,10 @@ where
             }
         }
     }
+
+    fn from_missing_query_argument() -> Option<Self> {
+        Some(None)
+    }
 }
 
 /// Something that can be formatted as a query argument. This trait must be implemented for any type that is used as a query argument like `#[route("/?:query")]`.
@@ -207,7 +232,6 @@ where
 /// }
 ///
 /// // We can derive Default for CustomQuery
-/// // If the router fails to parse the query value, it will use the default value instead
 /// #[derive(Default, Clone, PartialEq, Debug)]
 /// struct CustomQuery {
 ///     count: i32,
@@ -827,13 +851,19 @@ pub trait Routable: FromStr<Err: Display> + Display + Clone + 'static {
     fn parent(&self) -> Option<Self> {
         let as_str = self.to_string();
         let (route_and_query, _) = as_str.split_once('#').unwrap_or((&as_str, ""));
-        let (route, _) = route_and_query
+        let (route, query) = route_and_query
             .split_once('?')
             .unwrap_or((route_and_query, ""));
         let route = route.trim_end_matches('/');
         let segments = route.split_inclusive('/');
         let segment_count = segments.clone().count();
         let new_route: String = segments.take(segment_count.saturating_sub(1)).collect();
+        // Try to keep the query so parents that require the same query arguments still match
+        if !query.is_empty()
+            && let Ok(parent) = Self::from_str(&format!("{new_route}?{query}"))
+        {
+            return Some(parent);
+        }
         Self::from_str(&new_route).ok()
     }
 
@@ -1,6 +1,6 @@
-use crate::{GenericRouterContext, NavigationTarget, Routable, RoutingCallback};
+use crate::{GenericRouterContext, NavigationTarget, PrefetchCallback, Routable, RoutingCallback};
 use dioxus_core::Element;
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 
 /// Global configuration options for the router.
 ///
@@ -29,6 +29,8 @@ use std::sync::Arc;
 pub struct RouterConfig<R> {
     pub(crate) failure_external_navigation: fn() -> Element,
     pub(crate) on_update: Option<RoutingCallback<R>>,
+    pub(crate) prefetch: Option<PrefetchCallback<R>>,
+    pub(crate) navigation_pending: Option<fn() -> Element>,
 }
 
 #[cfg(not(feature = "html"))]
@@ -37,6 +39,8 @@ impl<R> Default for RouterConfig<R> {
         Self {
             failure_external_navigation: || VNode::empty(),
             on_update: None,
+            prefetch: None,
+            navigation_pending: None,
         }
     }
 }
@@ -47,6 +51,8 @@ impl<R> Default for RouterConfig<R> {
         Self {
             failure_external_navigation: crate::components::FailureExternalNavigation,
             on_update: None,
+            prefetch: None,
+            navigation_pending: None,
         }
     }
 }
@@ -78,6 +84,50 @@ where
         }
     }
 
+    /// A function to load the data for a route before the user navigates to it.
+    ///
+    /// [`Link`](crate::Link)s with a [`PrefetchStrategy`](crate::PrefetchStrategy) other than
+    /// [`PrefetchStrategy::None`](crate::PrefetchStrategy::None) call this when they are hovered or scroll into view.
+    /// Store the result somewhere the target route reads from, like a shared cache, so the route can render
+    /// immediately after navigating.
+    ///
+    /// Each path is only prefetched once. If the link stops being hovered or visible before the future finishes and
+    /// the user didn't click it, the future is cancelled and the path can be prefetched again later.
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// ```rust,no_run
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_router::RouterConfig;
+    /// # #[component]
+    /// # fn Index() -> Element { VNode::empty() }
+    /// # #[component]
+    /// # fn Post(id: u32) -> Element { VNode::empty() }
+    /// # async fn load_post(id: u32) {}
+    /// #[derive(Clone, Routable)]
+    /// enum Route {
+    ///     #[route("/")]
+    ///     Index {},
+    ///     #[route("/post/:id")]
+    ///     Post { id: u32 },
+    /// }
+    ///
+    /// let cfg = RouterConfig::<Route>::default().prefetch(|route| async move {
+    ///     if let Route::Post { id } = route {
+    ///         load_post(id).await;
+    ///     }
+    /// });
+    /// ```
+    pub fn prefetch<F: Future<Output = ()> + 'static>(
+        self,
+        prefetch: impl Fn(R) -> F + 'static,
+    ) -> Self {
+        Self {
+            prefetch: Some(Arc::new(move |route| Box::pin(prefetch(route)))),
+            ..self
+        }
+    }
+
     /// A component to render when an external navigation fails.
     ///
     #[cfg_attr(
@@ -90,4 +140,19 @@ where
             ..self
         }
     }
+
+    /// A component to render after the current route while an async [navigation
+    /// guard](crate::use_async_navigation_guard) decides if a navigation can continue. Use it to show a loading
+    /// indicator. The current route stays visible until the guard finishes.
+    ///
+    /// You can also read [`RouterContext::is_navigating`](crate::RouterContext::is_navigating) to show the
+    /// indicator anywhere in your app.
+    ///
+    /// Defaults to [`None`].
+    pub fn navigation_pending(self, component: fn() -> Element) -> Self {
+        Self {
+            navigation_pending: Some(component),
+            ..self
+        }
+    }
 }
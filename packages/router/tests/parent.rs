@@ -58,7 +58,7 @@ fn get_parent() {
         .parent(),
         Some(Route::HashId {
             id: 0,
-            query: "".into()
+            query: "query".into()
         })
     );
     assert_eq!(
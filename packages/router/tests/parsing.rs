@@ -151,15 +151,49 @@ fn optional_query_segments_parse() {
     assert_eq!(route_without_query.to_string(), "/?other=20");
     let parsed_route_without_query = "/?other=20".parse::<Route>().unwrap();
     assert_eq!(parsed_route_without_query, route_without_query);
-    let route_without_query_and_other = Route::Index {
-        query: None,
-        other: 0,
+
+    // `other` is required, so the route doesn't match without it
+    let error = "/".parse::<Route>().unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Query argument '(other:u64)' was missing")
+    );
+}
+
+#[test]
+fn malformed_query_arguments_are_reported() {
+    #[derive(Debug, Clone, PartialEq, Routable)]
+    enum Route {
+        #[route("/search?:query&:page")]
+        Search { query: String, page: Option<u32> },
+    }
+
+    #[component]
+    fn Search(query: String, page: Option<u32>) -> Element {
+        unimplemented!()
+    }
+
+    let route = Route::Search {
+        query: "dioxus".to_string(),
+        page: Some(2),
     };
-    assert_eq!(route_without_query_and_other.to_string(), "/?other=0");
-    let parsed_route_without_query_and_other = "/".parse::<Route>().unwrap();
+    assert_eq!(route.to_string(), "/search?query=dioxus&page=2");
+    assert_eq!(Route::from_str(&route.to_string()).unwrap(), route);
+
+    // Optional arguments may be missing, but not malformed
     assert_eq!(
-        parsed_route_without_query_and_other,
-        route_without_query_and_other
+        Route::from_str("/search?query=dioxus").unwrap(),
+        Route::Search {
+            query: "dioxus".to_string(),
+            page: None,
+        }
+    );
+    let error = Route::from_str("/search?query=dioxus&page=two").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Query argument '(page:Option < u32 >)' did not match")
     );
 }
 
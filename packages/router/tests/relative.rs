@@ -0,0 +1,50 @@
+use dioxus_router::navigation::resolve_relative_route;
+
+#[test]
+fn resolves_children_and_siblings() {
+    assert_eq!(resolve_relative_route("/posts/1", "edit"), "/posts/1/edit");
+    assert_eq!(
+        resolve_relative_route("/posts/1", "./edit"),
+        "/posts/1/edit"
+    );
+    assert_eq!(resolve_relative_route("/posts/1", "../2"), "/posts/2");
+    assert_eq!(resolve_relative_route("/posts/1", ".."), "/posts");
+    assert_eq!(resolve_relative_route("/posts/1", "../.."), "/");
+    assert_eq!(resolve_relative_route("/", "posts"), "/posts");
+}
+
+#[test]
+fn going_above_the_root_stays_at_the_root() {
+    assert_eq!(resolve_relative_route("/posts", "../../.."), "/");
+    assert_eq!(resolve_relative_route("/posts", "../../about"), "/about");
+}
+
+#[test]
+fn absolute_paths_are_not_resolved() {
+    assert_eq!(resolve_relative_route("/posts/1", "/about"), "/about");
+}
+
+#[test]
+fn trailing_slashes() {
+    // A trailing slash on the current route is ignored
+    assert_eq!(resolve_relative_route("/posts/1/", "edit"), "/posts/1/edit");
+    // A trailing slash on the relative path is kept
+    assert_eq!(
+        resolve_relative_route("/posts/1", "edit/"),
+        "/posts/1/edit/"
+    );
+    assert_eq!(resolve_relative_route("/posts", "../"), "/");
+}
+
+#[test]
+fn query_and_hash() {
+    assert_eq!(resolve_relative_route("/posts?page=1#top", "1"), "/posts/1");
+    assert_eq!(
+        resolve_relative_route("/posts?page=1", "?page=2"),
+        "/posts?page=2"
+    );
+    assert_eq!(
+        resolve_relative_route("/posts/1", "../2#comments"),
+        "/posts/2#comments"
+    );
+}
@@ -0,0 +1,326 @@
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::RouterConfig;
+use dioxus_router::components::HistoryProvider;
+use std::rc::Rc;
+
+#[test]
+fn guard_redirects_the_current_route() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: Route::Admin {},
+        },
+    );
+    vdom.rebuild_in_place();
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "Login");
+}
+
+#[test]
+fn guard_runs_before_programmatic_navigation() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: Route::Home {},
+        },
+    );
+    vdom.rebuild_in_place();
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "Login");
+}
+
+#[test]
+fn guard_can_cancel_navigation() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: Route::OpenSettings {},
+        },
+    );
+    vdom.rebuild_in_place();
+    vdom.render_immediate(&mut NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&vdom), "Open settings");
+}
+
+#[test]
+fn guards_that_redirect_to_each_other_stop() {
+    let mut vdom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: Route::StartLoop {},
+        },
+    );
+    vdom.rebuild_in_place();
+    vdom.render_immediate(&mut NoOpMutations);
+    // The navigation is cancelled once the redirect limit is reached instead of overflowing the stack
+    assert_eq!(dioxus_ssr::render(&vdom), "Start loop");
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+#[rustfmt::skip]
+enum Route {
+    #[layout(Guarded)]
+        #[route("/")]
+        Home {},
+
+        #[route("/open-settings")]
+        OpenSettings {},
+
+        #[route("/admin")]
+        Admin {},
+
+        #[route("/settings")]
+        Settings {},
+
+        #[route("/login")]
+        Login {},
+
+        #[route("/start-loop")]
+        StartLoop {},
+
+        #[route("/ping")]
+        Ping {},
+
+        #[route("/pong")]
+        Pong {},
+}
+
+#[component]
+fn App(path: Route) -> Element {
+    rsx! {
+        HistoryProvider {
+            history: move |_| Rc::new(MemoryHistory::with_initial_path(path.clone())) as Rc<dyn History>,
+            Router::<Route> {}
+        }
+    }
+}
+
+#[component]
+fn Guarded() -> Element {
+    use_navigation_guard(|target: &Route| match target {
+        Route::Admin {} => GuardResult::Redirect(Route::Login {}.into()),
+        Route::Settings {} => GuardResult::Cancel,
+        Route::Ping {} => GuardResult::Redirect(Route::Pong {}.into()),
+        Route::Pong {} => GuardResult::Redirect(Route::Ping {}.into()),
+        _ => GuardResult::Allow,
+    });
+
+    rsx! {
+        Outlet::<Route> {}
+    }
+}
+
+#[component]
+fn Home() -> Element {
+    use_effect(|| {
+        router().push(Route::Admin {});
+    });
+
+    rsx! { "Home" }
+}
+
+#[component]
+fn OpenSettings() -> Element {
+    use_effect(|| {
+        router().push(Route::Settings {});
+    });
+
+    rsx! { "Open settings" }
+}
+
+#[component]
+fn Admin() -> Element {
+    rsx! { "Admin" }
+}
+
+#[component]
+fn Settings() -> Element {
+    rsx! { "Settings" }
+}
+
+#[component]
+fn Login() -> Element {
+    rsx! { "Login" }
+}
+
+#[component]
+fn StartLoop() -> Element {
+    use_effect(|| {
+        router().push(Route::Ping {});
+    });
+
+    rsx! { "Start loop" }
+}
+
+#[component]
+fn Ping() -> Element {
+    rsx! { "Ping" }
+}
+
+#[component]
+fn Pong() -> Element {
+    rsx! { "Pong" }
+}
+
+mod async_guard {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+
+    thread_local! {
+        static TARGET: Cell<Option<Signal<Option<Route>>>> = const { Cell::new(None) };
+        static DECISION: RefCell<Option<oneshot::Sender<bool>>> = const { RefCell::new(None) };
+    }
+
+    #[derive(Routable, Clone, Debug, PartialEq)]
+    #[rustfmt::skip]
+    enum Route {
+        #[layout(Guarded)]
+            #[route("/")]
+            Home {},
+
+            #[route("/admin")]
+            Admin {},
+
+            #[route("/settings")]
+            Settings {},
+
+            #[route("/login")]
+            Login {},
+    }
+
+    #[component]
+    fn App() -> Element {
+        rsx! {
+            HistoryProvider {
+                history: move |_| Rc::new(MemoryHistory::with_initial_path(Route::Home {})) as Rc<dyn History>,
+                Router::<Route> {
+                    config: |_| RouterConfig::default().navigation_pending(Pending),
+                }
+            }
+        }
+    }
+
+    #[component]
+    fn Pending() -> Element {
+        rsx! { p { "Checking" } }
+    }
+
+    /// The admin route waits until the test decides if it is allowed
+    #[component]
+    fn Guarded() -> Element {
+        use_async_navigation_guard(|target: Route| async move {
+            if target != (Route::Admin {}) {
+                return GuardResult::Allow;
+            }
+            let (sender, receiver) = oneshot::channel();
+            DECISION.with(|decision| *decision.borrow_mut() = Some(sender));
+            match receiver.await {
+                Ok(true) => GuardResult::Allow,
+                _ => GuardResult::Redirect(Route::Login {}.into()),
+            }
+        });
+
+        // Navigate to the route the test sets
+        let target = use_signal(|| None::<Route>);
+        TARGET.with(|cell| cell.set(Some(target)));
+        use_effect(move || {
+            if let Some(target) = target() {
+                router().push(target);
+            }
+        });
+
+        rsx! {
+            Outlet::<Route> {}
+        }
+    }
+
+    #[component]
+    fn Home() -> Element {
+        rsx! { "Home" }
+    }
+
+    #[component]
+    fn Admin() -> Element {
+        rsx! { "Admin" }
+    }
+
+    #[component]
+    fn Settings() -> Element {
+        rsx! { "Settings" }
+    }
+
+    #[component]
+    fn Login() -> Element {
+        rsx! { "Login" }
+    }
+
+    fn navigate(vdom: &mut VirtualDom, route: Route) {
+        vdom.in_runtime(|| TARGET.with(Cell::get).unwrap().set(Some(route)));
+        vdom.render_immediate(&mut NoOpMutations);
+    }
+
+    fn decide(allow: bool) -> Result<(), bool> {
+        let sender = DECISION.with(|decision| decision.borrow_mut().take());
+        sender.expect("the guard is waiting").send(allow)
+    }
+
+    /// Let the guard task finish and render the result
+    async fn settle(vdom: &mut VirtualDom) {
+        tokio::select! {
+            _ = vdom.wait_for_work() => {}
+            _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+        };
+        vdom.render_immediate(&mut NoOpMutations);
+    }
+
+    fn start() -> VirtualDom {
+        let mut vdom = VirtualDom::new(App);
+        vdom.rebuild_in_place();
+        vdom.render_immediate(&mut NoOpMutations);
+        assert_eq!(dioxus_ssr::render(&vdom), "Home");
+        vdom
+    }
+
+    #[tokio::test]
+    async fn pending_guard_renders_the_pending_component() {
+        let mut vdom = start();
+        navigate(&mut vdom, Route::Admin {});
+        // The current route stays visible while the guard runs
+        assert_eq!(dioxus_ssr::render(&vdom), "Home<p>Checking</p>");
+
+        decide(true).unwrap();
+        settle(&mut vdom).await;
+        assert_eq!(dioxus_ssr::render(&vdom), "Admin");
+    }
+
+    #[tokio::test]
+    async fn async_guard_can_redirect() {
+        let mut vdom = start();
+        navigate(&mut vdom, Route::Admin {});
+        decide(false).unwrap();
+        settle(&mut vdom).await;
+        assert_eq!(dioxus_ssr::render(&vdom), "Login");
+    }
+
+    #[tokio::test]
+    async fn new_navigation_supersedes_a_pending_guard() {
+        let mut vdom = start();
+        navigate(&mut vdom, Route::Admin {});
+        let pending = DECISION
+            .with(|decision| decision.borrow_mut().take())
+            .unwrap();
+
+        navigate(&mut vdom, Route::Settings {});
+        settle(&mut vdom).await;
+        assert_eq!(dioxus_ssr::render(&vdom), "Settings");
+
+        // The pending guard was cancelled, so its decision is never used
+        assert!(pending.send(true).is_err());
+        settle(&mut vdom).await;
+        assert_eq!(dioxus_ssr::render(&vdom), "Settings");
+    }
+}
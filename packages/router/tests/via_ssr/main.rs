@@ -1,6 +1,8 @@
 mod child_outlet;
+mod guard;
 mod link;
 mod navigation;
 mod outlet;
+mod prefetch;
 mod redirect;
 mod without_index;
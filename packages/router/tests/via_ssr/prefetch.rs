@@ -0,0 +1,178 @@
+use dioxus::html::geometry::PixelsRect;
+use dioxus::html::{
+    DOMRect, PlatformEventData, SerializedHtmlEventConverter, SerializedMouseData,
+    SerializedVisibleData, set_event_converter,
+};
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, Event, Mutation, NoOpMutations};
+use dioxus_history::{History, MemoryHistory};
+use dioxus_router::RouterConfig;
+use dioxus_router::components::HistoryProvider;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+thread_local! {
+    static PREFETCHES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Routable, Clone, Debug, PartialEq)]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/hovered")]
+    Hovered {},
+    #[route("/visible")]
+    Visible {},
+}
+
+#[component]
+fn App() -> Element {
+    rsx! {
+        HistoryProvider {
+            history: move |_| Rc::new(MemoryHistory::with_initial_path(Route::Home {})) as Rc<dyn History>,
+            Router::<Route> {
+                config: |_| {
+                    RouterConfig::default()
+                        .prefetch(|route: Route| {
+                            PREFETCHES.with(|log| log.borrow_mut().push(format!("start {route}")));
+                            async move {
+                                tokio::time::sleep(Duration::from_millis(20)).await;
+                                PREFETCHES.with(|log| log.borrow_mut().push(format!("done {route}")));
+                            }
+                        })
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! {
+        Link { to: Route::Hovered {}, prefetch: PrefetchStrategy::OnHover, "Hovered" }
+        Link { to: Route::Visible {}, prefetch: PrefetchStrategy::OnVisible, "Visible" }
+    }
+}
+
+#[component]
+fn Hovered() -> Element {
+    rsx! { "Hovered" }
+}
+
+#[component]
+fn Visible() -> Element {
+    rsx! { "Visible" }
+}
+
+/// Render the app and find the element that listens to each event
+fn start() -> (VirtualDom, HashMap<String, ElementId>) {
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+    PREFETCHES.with(|log| log.borrow_mut().clear());
+
+    let mut dom = VirtualDom::new(App);
+    let mutations = dom.rebuild_to_vec();
+    let listeners = mutations
+        .edits
+        .into_iter()
+        .filter_map(|edit| match edit {
+            Mutation::NewEventListener { name, id } => Some((name, id)),
+            _ => None,
+        })
+        .collect();
+    (dom, listeners)
+}
+
+fn mouse(dom: &mut VirtualDom, listeners: &HashMap<String, ElementId>, name: &str) {
+    let data = PlatformEventData::new(Box::<SerializedMouseData>::default());
+    let event = Event::new(Rc::new(data) as Rc<dyn Any>, false);
+    dom.runtime().handle_event(name, event, listeners[name]);
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+fn visible(dom: &mut VirtualDom, listeners: &HashMap<String, ElementId>, is_intersecting: bool) {
+    let rect = || DOMRect::from(PixelsRect::zero());
+    let data = PlatformEventData::new(Box::new(SerializedVisibleData {
+        bounding_client_rect: rect(),
+        intersection_ratio: if is_intersecting { 1.0 } else { 0.0 },
+        intersection_rect: rect(),
+        is_intersecting,
+        root_bounds: rect(),
+        time_ms: 0,
+    }));
+    let event = Event::new(Rc::new(data) as Rc<dyn Any>, false);
+    dom.runtime()
+        .handle_event("visible", event, listeners["visible"]);
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+async fn run_for(dom: &mut VirtualDom, duration: Duration) {
+    tokio::select! {
+        _ = async {
+            loop {
+                dom.wait_for_work().await;
+                dom.render_immediate(&mut NoOpMutations);
+            }
+        } => {}
+        _ = tokio::time::sleep(duration) => {}
+    };
+}
+
+fn prefetches() -> Vec<String> {
+    PREFETCHES.with(|log| log.borrow().clone())
+}
+
+#[tokio::test]
+async fn hovering_prefetches_once() {
+    let (mut dom, listeners) = start();
+    assert!(prefetches().is_empty());
+
+    mouse(&mut dom, &listeners, "mouseenter");
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(prefetches(), ["start /hovered", "done /hovered"]);
+
+    // Finished prefetches are not started again
+    mouse(&mut dom, &listeners, "mouseleave");
+    mouse(&mut dom, &listeners, "mouseenter");
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(prefetches(), ["start /hovered", "done /hovered"]);
+}
+
+#[tokio::test]
+async fn leaving_cancels_the_prefetch() {
+    let (mut dom, listeners) = start();
+
+    mouse(&mut dom, &listeners, "mouseenter");
+    mouse(&mut dom, &listeners, "mouseleave");
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(prefetches(), ["start /hovered"]);
+
+    // A cancelled prefetch can start again
+    mouse(&mut dom, &listeners, "mouseenter");
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(
+        prefetches(),
+        ["start /hovered", "start /hovered", "done /hovered"]
+    );
+}
+
+#[tokio::test]
+async fn scrolling_into_view_prefetches() {
+    let (mut dom, listeners) = start();
+
+    visible(&mut dom, &listeners, true);
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(prefetches(), ["start /visible", "done /visible"]);
+}
+
+#[tokio::test]
+async fn scrolling_out_of_view_cancels_the_prefetch() {
+    let (mut dom, listeners) = start();
+
+    visible(&mut dom, &listeners, true);
+    visible(&mut dom, &listeners, false);
+    run_for(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(prefetches(), ["start /visible"]);
+}
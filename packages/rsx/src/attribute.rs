@@ -11,6 +11,10 @@
 //!         class: "my-class",
 //!         onclick: move |_| println!("clicked")
 //!     }
+//!
+//!     input {
+//!         bind:value: name,
+//!     }
 //! }
 //! ```
 
@@ -18,6 +22,7 @@ use super::literal::HotLiteral;
 use crate::{innerlude::*, partial_closure::PartialClosure};
 
 use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro2_diagnostics::SpanDiagnosticExt;
 use quote::{ToTokens, TokenStreamExt, quote, quote_spanned};
 use std::fmt::Display;
 use syn::{
@@ -25,7 +30,7 @@ use syn::{
     Stmt, Token,
     ext::IdentExt,
     parse::{Parse, ParseStream},
-    parse_quote,
+    parse_quote, parse_quote_spanned,
     spanned::Spanned,
 };
 
@@ -76,10 +81,17 @@ impl Parse for Attribute {
             });
         }
 
-        // Parse the name as either a known or custom attribute
-        let name = match content.peek(LitStr) {
-            true => AttributeName::Custom(content.parse::<LitStr>()?),
-            false => AttributeName::BuiltIn(parse_raw_ident(content)?),
+        // Parse the name as either a bind, known or custom attribute
+        let name = if peek_bind(content) {
+            AttributeName::Bind {
+                bind: content.parse()?,
+                colon: content.parse()?,
+                name: parse_raw_ident(content)?,
+            }
+        } else if content.peek(LitStr) {
+            AttributeName::Custom(content.parse::<LitStr>()?)
+        } else {
+            AttributeName::BuiltIn(parse_raw_ident(content)?)
         };
 
         // Ensure there's a colon
@@ -105,6 +117,20 @@ impl Parse for Attribute {
     }
 }
 
+/// Check if the stream starts with a `bind:name:` attribute name
+fn peek_bind(content: ParseStream) -> bool {
+    let fork = content.fork();
+    let Ok(bind) = fork.parse::<Ident>() else {
+        return false;
+    };
+
+    bind == "bind"
+        && fork.parse::<Token![:]>().is_ok()
+        && parse_raw_ident(&fork).is_ok()
+        && fork.peek(Token![:])
+        && !fork.peek(Token![::])
+}
+
 impl Attribute {
     /// Create a new attribute from a name and value
     pub fn from_raw(name: AttributeName, value: AttributeValue) -> Self {
@@ -205,6 +231,7 @@ impl Attribute {
             },
             AttributeName::Custom(s) => quote! { #s },
             AttributeName::Spread(_) => unreachable!("Spread attributes are handled elsewhere"),
+            AttributeName::Bind { .. } => unreachable!("Bind attributes are expanded elsewhere"),
         };
 
         let attribute = {
@@ -223,7 +250,20 @@ impl Attribute {
                     let ns = ns(name);
                     let volatile = volatile(name);
                     let attribute = attribute(name);
-                    let value = quote! { #value };
+                    let value = match name {
+                        // Raw HTML built at runtime has to be wrapped in UnsafeHtml
+                        AttributeName::BuiltIn(name) if name == "dangerous_inner_html" => {
+                            quote_spanned! { value.span() =>
+                                {
+                                    fn unsafe_html<T: dioxus_core::InnerHtmlValue>(value: T) -> T {
+                                        value
+                                    }
+                                    unsafe_html(#value)
+                                }
+                            }
+                        }
+                        _ => quote! { #value },
+                    };
 
                     quote! {
                         dioxus_core::Attribute::new(
@@ -281,6 +321,9 @@ impl Attribute {
                         }
                         AttributeName::Custom(_) => unreachable!("Handled elsewhere in the macro"),
                         AttributeName::Spread(_) => unreachable!("Handled elsewhere in the macro"),
+                        AttributeName::Bind { .. } => {
+                            unreachable!("Handled elsewhere in the macro")
+                        }
                     }
                 }
                 _ => {
@@ -302,6 +345,63 @@ impl Attribute {
         .to_token_stream()
     }
 
+    /// Expand a `bind:name: signal` attribute into the attribute and an input listener that writes back to the
+    /// signal. Returns no attributes and adds a diagnostic if the attribute can't be bound.
+    pub(crate) fn expand_bind(&self, diagnostics: &mut Diagnostics) -> Vec<Attribute> {
+        let AttributeName::Bind { name, .. } = &self.name else {
+            return vec![self.clone()];
+        };
+
+        let (read, listen) = match name.to_string().as_str() {
+            "value" => (quote! { value }, quote! { on_value_input }),
+            "checked" => (quote! { checked }, quote! { on_checked_input }),
+            _ => {
+                diagnostics.push(
+                    name.span()
+                        .error("Only `bind:value` and `bind:checked` are supported"),
+                );
+                return vec![];
+            }
+        };
+
+        let signal = match &self.value {
+            AttributeValue::AttrExpr(_) | AttributeValue::Shorthand(_) => &self.value,
+            _ => {
+                diagnostics.push(
+                    self.value
+                        .span()
+                        .error("Bind attributes must be set to a signal")
+                        .help("Try `bind:value: my_signal`"),
+                );
+                return vec![];
+            }
+        };
+
+        let span = signal.span();
+        let value: Expr = parse_quote_spanned! { span => dioxus_elements::bind::#read(&#signal) };
+        let listener: Expr =
+            parse_quote_spanned! { span => dioxus_elements::bind::#listen(#signal) };
+
+        vec![
+            Attribute {
+                name: AttributeName::BuiltIn(name.clone()),
+                colon: self.colon,
+                value: AttributeValue::AttrExpr(PartialExpr::from_expr(&value)),
+                comma: self.comma,
+                dyn_idx: DynIdx::default(),
+                el_name: self.el_name.clone(),
+            },
+            Attribute {
+                name: AttributeName::BuiltIn(Ident::new("oninput", name.span())),
+                colon: self.colon,
+                value: AttributeValue::AttrExpr(PartialExpr::from_expr(&listener)),
+                comma: self.comma,
+                dyn_idx: DynIdx::default(),
+                el_name: self.el_name.clone(),
+            },
+        ]
+    }
+
     pub fn can_be_shorthand(&self) -> bool {
         // If it's a shorthand...
         if matches!(self.value, AttributeValue::Shorthand(_)) {
@@ -383,6 +483,14 @@ pub enum AttributeName {
     ///
     /// we might want to change this one day to be ticked or something and simply a boolean
     Custom(LitStr),
+
+    /// an attribute in the form of `bind:name: signal` that sets the attribute to the value of the signal and
+    /// updates the signal when the input changes
+    Bind {
+        bind: Ident,
+        colon: Token![:],
+        name: Ident,
+    },
 }
 
 impl AttributeName {
@@ -399,6 +507,7 @@ impl AttributeName {
             Self::Custom(lit) => lit.span(),
             Self::BuiltIn(ident) => ident.span(),
             Self::Spread(dots) => dots.span(),
+            Self::Bind { name, .. } => name.span(),
         }
     }
 }
@@ -409,6 +518,7 @@ impl Display for AttributeName {
             Self::Custom(lit) => write!(f, "{}", lit.value()),
             Self::BuiltIn(ident) => write!(f, "{}", ident),
             Self::Spread(_) => write!(f, ".."),
+            Self::Bind { name, .. } => write!(f, "bind:{}", name),
         }
     }
 }
@@ -419,6 +529,11 @@ impl ToTokens for AttributeName {
             Self::Custom(lit) => lit.to_tokens(tokens),
             Self::BuiltIn(ident) => ident.to_tokens(tokens),
             Self::Spread(dots) => dots.to_tokens(tokens),
+            Self::Bind { bind, colon, name } => {
+                bind.to_tokens(tokens);
+                colon.to_tokens(tokens);
+                name.to_tokens(tokens);
+            }
         }
     }
 }
@@ -909,6 +1024,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bind_attributes() {
+        let parsed: Attribute = parse2(quote! { bind:value: name }).unwrap();
+        assert!(matches!(parsed.name, AttributeName::Bind { .. }));
+        assert_eq!(parsed.name.to_string(), "bind:value");
+
+        let parsed: Attribute = parse2(quote! { bind:checked: checked, }).unwrap();
+        assert!(matches!(parsed.name, AttributeName::Bind { .. }));
+
+        // A regular attribute named bind isn't a bind attribute
+        let parsed: Attribute = parse2(quote! { bind: value }).unwrap();
+        assert!(matches!(parsed.name, AttributeName::BuiltIn(_)));
+
+        let mut diagnostics = Diagnostics::new();
+        let mut parsed: Attribute = parse2(quote! { bind:value: name }).unwrap();
+        parsed.el_name = Some(parse_quote!(input));
+        let expanded = parsed.expand_bind(&mut diagnostics);
+        assert!(diagnostics.is_empty());
+        assert_eq!(expanded.len(), 2);
+        assert_eq!(expanded[0].name.to_string(), "value");
+        assert_eq!(expanded[1].name.to_string(), "oninput");
+
+        let parsed: Attribute = parse2(quote! { bind:class: name }).unwrap();
+        assert!(parsed.expand_bind(&mut diagnostics).is_empty());
+        assert!(!diagnostics.is_empty());
+    }
+
     /// Make sure reserved keywords are parsed as attributes
     /// HTML gets annoying sometimes so we just accept them
     #[test]
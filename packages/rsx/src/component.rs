@@ -199,6 +199,12 @@ impl Component {
                         "Spread attributes should be handled in the spread validation step."
                     )
                 }
+                AttributeName::Bind { bind, .. } => {
+                    self.diagnostics.push(
+                        bind.span()
+                            .error("Bind attributes are only supported on elements"),
+                    );
+                }
             }
         }
     }
@@ -321,8 +327,8 @@ impl Component {
                         })
                     }
                 }
-                // spreads are handled elsewhere
-                AttributeName::Spread(_) => {}
+                // spreads are handled elsewhere and binds are rejected while validating the fields
+                AttributeName::Spread(_) | AttributeName::Bind { .. } => {}
             }
         }
 
@@ -91,11 +91,17 @@ impl Parse for Element {
         // And then merge the spreads *after* the attributes are merged. This ensures walking the
         // merged attributes in path order stops before we hit the spreads, but spreads are still
         // counted as dynamic attributes
+        let explicit_names = element.explicit_attribute_names();
         for spread in block.spreads.iter() {
+            // Attributes written on the element win over spread attributes with the same name
+            let expr = &spread.expr;
+            let spread_expr: syn::Expr = syn::parse_quote_spanned! { expr.span() =>
+                dioxus_core::spread_attributes(#expr, &[#(#explicit_names,)*])
+            };
             element.merged_attributes.push(Attribute {
                 name: AttributeName::Spread(spread.dots),
                 colon: None,
-                value: AttributeValue::AttrExpr(PartialExpr::from_expr(&spread.expr)),
+                value: AttributeValue::AttrExpr(PartialExpr::from_expr(&spread_expr)),
                 comma: spread.comma,
                 dyn_idx: spread.dyn_idx.clone(),
                 el_name: Some(name.clone()),
@@ -133,6 +139,9 @@ impl ToTokens for Element {
                     AttributeName::Spread(_) => {
                         unreachable!("spread attributes should not be static")
                     }
+                    AttributeName::Bind { .. } => {
+                        unreachable!("bind attributes should not be static")
+                    }
                 };
 
                 let name = match (el_name, name) {
@@ -241,6 +250,13 @@ impl Element {
                 continue;
             }
 
+            // Bind attributes expand into the attribute and an input listener
+            if let AttributeName::Bind { .. } = attr.name {
+                let expanded = attr.expand_bind(&mut self.diagnostics);
+                self.merged_attributes.extend(expanded);
+                continue;
+            }
+
             // Collect all the attributes with the same name
             let matching_attrs = self
                 .raw_attributes
@@ -300,6 +316,30 @@ impl Element {
         }
     }
 
+    /// The names of the attributes written on the element as `&'static str` expressions. Spread attributes with
+    /// these names are skipped.
+    fn explicit_attribute_names(&self) -> Vec<TokenStream2> {
+        self.merged_attributes
+            .iter()
+            .map(|attr| match (&attr.name, &self.name) {
+                (AttributeName::BuiltIn(name), _) if attr.name.is_likely_event() => {
+                    let name = name.to_string();
+                    quote! { #name }
+                }
+                (AttributeName::BuiltIn(name), ElementName::Ident(el_name)) => {
+                    quote! { dioxus_elements::#el_name::#name.0 }
+                }
+                (AttributeName::BuiltIn(name), ElementName::Custom(_)) => {
+                    let name = name.to_string();
+                    quote! { #name }
+                }
+                (AttributeName::Custom(name), _) => quote! { #name },
+                (AttributeName::Spread(_), _) => unreachable!("Spreads are merged after this"),
+                (AttributeName::Bind { .. }, _) => unreachable!("Binds are expanded before this"),
+            })
+            .collect()
+    }
+
     pub(crate) fn key(&self) -> Option<&AttributeValue> {
         self.raw_attributes
             .iter()
@@ -0,0 +1,102 @@
+use dioxus_core::ReactiveContext;
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+    static BATCH: RefCell<Option<Batch>> = const { RefCell::new(None) };
+}
+
+/// The reactive contexts that were marked dirty while a batch is running
+#[derive(Default)]
+struct Batch {
+    depth: usize,
+    #[allow(clippy::mutable_key_type)]
+    seen: HashSet<ReactiveContext>,
+    pending: Vec<ReactiveContext>,
+}
+
+/// Run a closure and defer all subscriber notifications caused by writes inside of it until the closure returns.
+///
+/// Every reactive context that is marked dirty inside the batch is only notified once after the batch finishes. Reads
+/// inside the batch still see the latest written value, only the notifications are delayed.
+///
+/// Batches can be nested. Notifications are only flushed when the outermost batch returns.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_signals::batch;
+/// fn App() -> Element {
+///     let mut count = use_signal(|| 0);
+///     let mut history = use_signal(Vec::new);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| {
+///                 // Both writes only mark the component as dirty once
+///                 batch(|| {
+///                     count += 1;
+///                     history.push(count());
+///                 });
+///             },
+///             "{count}: {history:?}"
+///         }
+///     }
+/// }
+/// ```
+pub fn batch<O>(f: impl FnOnce() -> O) -> O {
+    BATCH.with(|batch| {
+        batch.borrow_mut().get_or_insert_with(Batch::default).depth += 1;
+    });
+
+    // Flush in a drop guard so a panic inside the batch doesn't leave the batch open forever
+    let _guard = BatchGuard;
+    f()
+}
+
+/// Check if the current thread is inside of a [`batch`]
+pub fn is_batching() -> bool {
+    BATCH.with(|batch| batch.borrow().is_some())
+}
+
+/// Mark a reactive context as dirty, or defer the notification until the current [`batch`] finishes.
+///
+/// This is used by the signal types in this crate to notify their subscribers. Custom reactive primitives can use it
+/// to take part in batching.
+///
+/// Returns false if the reactive context has been dropped and should be removed from the subscriber list.
+pub fn mark_dirty_batched(reactive_context: &ReactiveContext) -> bool {
+    let deferred = BATCH.with(|batch| match &mut *batch.borrow_mut() {
+        Some(batch) => {
+            if batch.seen.insert(*reactive_context) {
+                batch.pending.push(*reactive_context);
+            }
+            true
+        }
+        None => false,
+    });
+
+    match deferred {
+        true => true,
+        false => reactive_context.mark_dirty(),
+    }
+}
+
+struct BatchGuard;
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        let pending = BATCH.with(|batch| {
+            let mut batch = batch.borrow_mut();
+            let current = batch.as_mut()?;
+            current.depth -= 1;
+            if current.depth > 0 {
+                return None;
+            }
+            batch.take().map(|batch| batch.pending)
+        });
+
+        // The batch is closed before flushing so any writes caused by the subscribers notify normally
+        for reactive_context in pending.into_iter().flatten() {
+            reactive_context.mark_dirty();
+        }
+    }
+}
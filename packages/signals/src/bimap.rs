@@ -0,0 +1,223 @@
+use std::ops::Deref;
+
+use crate::{
+    CopyValue, Memo, Readable, ReadableExt, ReadableRef, Signal, Writable, WritableExt,
+    WritableRef, WriteLock, read_impls, write_impls,
+};
+use dioxus_core::{IntoAttributeValue, Subscribers};
+use generational_box::{AnyStorage, BorrowResult, UnsyncStorage};
+
+/// The conversions between the source signal and the projection
+struct BiMapFns<T, V> {
+    to: Box<dyn Fn(&V) -> T>,
+    from: Box<dyn Fn(&T) -> V>,
+}
+
+/// A writable projection of a [`Signal`] created with [`Signal::bimap`].
+///
+/// The source signal is the only copy of the state. Reading the projection converts the current value of the source,
+/// and writing the projection converts the new value back and writes it to the source.
+pub struct BiMappedSignal<T: 'static, V: 'static> {
+    source: Signal<V>,
+    projection: Memo<T>,
+    fns: CopyValue<BiMapFns<T, V>>,
+    // The value that is being written through the projection. It is converted back to the source when the write is dropped
+    pending: CopyValue<Option<T>>,
+}
+
+impl<T: 'static, V: 'static> Clone for BiMappedSignal<T, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static, V: 'static> Copy for BiMappedSignal<T, V> {}
+
+impl<V: PartialEq + 'static> Signal<V> {
+    /// Create a writable projection of this signal. Reading the projection converts the value of this signal with
+    /// `to`, and writing the projection converts the new value back with `from` and writes it to this signal.
+    ///
+    /// This signal stays the single source of truth, so the projection is never out of sync with it: reading the
+    /// projection right after this signal is written already sees the new value, and a render never sees one side
+    /// updated without the other. Writes through the projection only update this signal if the converted value is
+    /// different, so conversions that don't round trip exactly (like floating point unit conversions) don't cause
+    /// extra updates.
+    ///
+    /// The projection is owned by the current scope. If you create it inside a component, use
+    /// [`use_signal_map`](https://docs.rs/dioxus-hooks/latest/dioxus_hooks/fn.use_signal_map.html) so it is only
+    /// created once.
+    ///
+    /// ```rust, no_run
+    /// # use dioxus::prelude::*;
+    /// fn App() -> Element {
+    ///     let celsius = use_signal(|| 20.0);
+    ///     let fahrenheit = use_hook(|| celsius.bimap(|c| c * 9.0 / 5.0 + 32.0, |f| (f - 32.0) * 5.0 / 9.0));
+    ///
+    ///     rsx! {
+    ///         "{celsius}°C is {fahrenheit}°F"
+    ///         // Writing either signal updates the other one
+    ///         TemperatureInput { degrees: celsius }
+    ///         TemperatureInput { degrees: fahrenheit }
+    ///     }
+    /// }
+    ///
+    /// #[component]
+    /// fn TemperatureInput(degrees: WriteSignal<f64>) -> Element {
+    ///     rsx! {
+    ///         input {
+    ///             value: "{degrees}",
+    ///             oninput: move |event| {
+    ///                 if let Ok(value) = event.value().parse() {
+    ///                     degrees.set(value);
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn bimap<T: PartialEq + 'static>(
+        self,
+        to: impl Fn(&V) -> T + 'static,
+        from: impl Fn(&T) -> V + 'static,
+    ) -> BiMappedSignal<T, V> {
+        Self::bimap_with_location(self, to, from, std::panic::Location::caller())
+    }
+
+    /// Create a writable projection of this signal with an explicit location. See [`Signal::bimap`] for more details.
+    pub fn bimap_with_location<T: PartialEq + 'static>(
+        self,
+        to: impl Fn(&V) -> T + 'static,
+        from: impl Fn(&T) -> V + 'static,
+        location: &'static std::panic::Location<'static>,
+    ) -> BiMappedSignal<T, V> {
+        let source = self;
+        let fns = CopyValue::new(BiMapFns {
+            to: Box::new(to),
+            from: Box::new(from),
+        });
+        // The memo is marked dirty as soon as the source changes and recomputes when it is read, so reads are never stale
+        let projection = Memo::new_with_location(move || (fns.read().to)(&source.read()), location);
+
+        BiMappedSignal {
+            source,
+            projection,
+            fns,
+            pending: CopyValue::new(None),
+        }
+    }
+}
+
+impl<T: PartialEq + 'static, V: PartialEq + 'static> Readable for BiMappedSignal<T, V> {
+    type Target = T;
+    type Storage = UnsyncStorage;
+
+    #[track_caller]
+    fn try_read_unchecked(
+        &self,
+    ) -> Result<ReadableRef<'static, Self>, generational_box::BorrowError>
+    where
+        T: 'static,
+    {
+        self.projection.try_read_unchecked()
+    }
+
+    #[track_caller]
+    fn try_peek_unchecked(&self) -> BorrowResult<ReadableRef<'static, Self>>
+    where
+        T: 'static,
+    {
+        self.projection.try_peek_fresh()
+    }
+
+    fn subscribers(&self) -> Subscribers
+    where
+        T: 'static,
+    {
+        self.projection.subscribers()
+    }
+}
+
+impl<T: PartialEq + 'static, V: PartialEq + 'static> Writable for BiMappedSignal<T, V> {
+    type WriteMetadata = WriteBack<T, V>;
+
+    #[track_caller]
+    fn try_write_unchecked(
+        &self,
+    ) -> Result<WritableRef<'static, Self>, generational_box::BorrowMutError> {
+        let source = self.source.try_peek_unchecked()?;
+        let fns = self.fns.try_peek_unchecked()?;
+        let value = (fns.to)(&source);
+        drop((source, fns));
+        let mut pending = self.pending.try_write_unchecked()?.into_inner();
+        *pending = Some(value);
+        let pending = UnsyncStorage::map_mut(pending, |value| {
+            value.as_mut().expect("the pending value was just set")
+        });
+        Ok(WriteLock::new_with_metadata(
+            pending,
+            WriteBack {
+                source: self.source,
+                fns: self.fns,
+                pending: self.pending,
+            },
+        ))
+    }
+}
+
+/// Writes the value of a [`BiMappedSignal`] back to the source signal when the write lock is dropped
+pub struct WriteBack<T: 'static, V: PartialEq + 'static> {
+    source: Signal<V>,
+    fns: CopyValue<BiMapFns<T, V>>,
+    pending: CopyValue<Option<T>>,
+}
+
+impl<T: 'static, V: PartialEq + 'static> Drop for WriteBack<T, V> {
+    fn drop(&mut self) {
+        // The write lock releases the pending value before its metadata is dropped
+        let Some(value) = self
+            .pending
+            .try_write_unchecked()
+            .ok()
+            .and_then(|mut pending| pending.take())
+        else {
+            return;
+        };
+        let Ok(fns) = self.fns.try_peek_unchecked() else {
+            return;
+        };
+        let new = (fns.from)(&value);
+        drop(fns);
+        self.source.set_if_changed(new);
+    }
+}
+
+impl<T, V> IntoAttributeValue for BiMappedSignal<T, V>
+where
+    T: Clone + IntoAttributeValue + PartialEq + 'static,
+    V: PartialEq + 'static,
+{
+    fn into_value(self) -> dioxus_core::AttributeValue {
+        self.with(|f| f.clone().into_value())
+    }
+}
+
+impl<T: 'static, V: 'static> PartialEq for BiMappedSignal<T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && self.projection == other.projection
+    }
+}
+
+/// Allow calling a signal with signal() syntax
+///
+/// Currently only limited to clone types, though could probably specialize for string/arc/rc
+impl<T: Clone + PartialEq + 'static, V: PartialEq + 'static> Deref for BiMappedSignal<T, V> {
+    type Target = dyn Fn() -> T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { ReadableExt::deref_impl(self) }
+    }
+}
+
+read_impls!(BiMappedSignal<T, V> where T: PartialEq, V: PartialEq);
+write_impls!(BiMappedSignal<T, V> where T: PartialEq, V: PartialEq);
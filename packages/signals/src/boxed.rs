@@ -4,8 +4,9 @@ use dioxus_core::{IntoAttributeValue, IntoDynNode, Subscribers};
 use generational_box::{BorrowResult, Storage, SyncStorage, UnsyncStorage};
 
 use crate::{
-    CopyValue, Global, InitializeFromFunction, MappedMutSignal, MappedSignal, Memo, Readable,
-    ReadableExt, ReadableRef, Signal, SignalData, Writable, WritableExt, read_impls, write_impls,
+    BiMappedSignal, CopyValue, Global, InitializeFromFunction, MappedMutSignal, MappedSignal, Memo,
+    Readable, ReadableExt, ReadableRef, Signal, SignalData, Writable, WritableExt, read_impls,
+    write_impls,
 };
 
 /// A signal that can only be read from.
@@ -62,7 +63,7 @@ impl<T: ?Sized + 'static, S: BoxedSignalStorage<T>> ReadSignal<T, S> {
         subscribers.visit(|subscriber| this_subscribers_vec.push(*subscriber));
         for subscriber in this_subscribers_vec {
             subscribers.remove(&subscriber);
-            subscriber.mark_dirty();
+            crate::mark_dirty_batched(&subscriber);
         }
     }
 }
@@ -206,6 +207,11 @@ where
         Self::new_maybe_sync(value)
     }
 }
+impl<T: PartialEq + 'static, V: PartialEq + 'static> From<BiMappedSignal<T, V>> for ReadSignal<T> {
+    fn from(value: BiMappedSignal<T, V>) -> Self {
+        Self::new(value)
+    }
+}
 impl<T: ?Sized + 'static, S> From<WriteSignal<T, S>> for ReadSignal<T, S>
 where
     S: BoxedSignalStorage<T> + CreateBoxedSignalStorage<WriteSignal<T, S>>,
@@ -433,6 +439,11 @@ where
         Self::new_maybe_sync(value)
     }
 }
+impl<T: PartialEq + 'static, V: PartialEq + 'static> From<BiMappedSignal<T, V>> for WriteSignal<T> {
+    fn from(value: BiMappedSignal<T, V>) -> Self {
+        Self::new(value)
+    }
+}
 
 /// A trait for creating boxed readable and writable signals. This is implemented for
 /// [UnsyncStorage] and [SyncStorage].
@@ -0,0 +1,248 @@
+use crate::{CopyValue, ReadableExt, ReadableRef, Writable, read::Readable, read_impls};
+use dioxus_core::{
+    IntoAttributeValue, ReactiveContext, Subscribers, current_scope_id, generation, has_context,
+    provide_context,
+};
+use generational_box::{AnyStorage, BorrowResult, UnsyncStorage};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    ops::Deref,
+    panic::Location,
+    rc::Rc,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// A read only signal that holds a value computed from another signal.
+///
+/// Unlike [`MappedSignal`](crate::MappedSignal) which borrows part of the source value, a derived signal owns the
+/// computed value. The value is computed lazily the first time it is read and cached until the source signal changes.
+///
+/// Derived signals are created with [`ReadableExt::derive`].
+pub struct DerivedSignal<O: 'static, V, F> {
+    value: V,
+    map_fn: F,
+    cache: CopyValue<DerivedCache<O>>,
+    /// Which handle of the cache this is. Handles from earlier renders share the cache, so the value is recomputed
+    /// when a different handle reads it.
+    version: u64,
+}
+
+struct DerivedCache<O> {
+    value: Option<O>,
+    /// The handle the value was computed for
+    computed_for: u64,
+    /// The last handle that was created for the cache
+    latest: Cell<u64>,
+    dirty: Arc<AtomicBool>,
+    reactive_context: ReactiveContext,
+}
+
+impl<O, V, F> DerivedSignal<O, V, F>
+where
+    O: 'static,
+    V: Readable,
+    V::Target: 'static,
+    F: Fn(&V::Target) -> O,
+{
+    /// Create a new derived signal.
+    ///
+    /// The cache is owned by the current component and reused by the derived signals created at the same place in the
+    /// next render, so this doesn't need to be called in a hook.
+    #[track_caller]
+    pub fn new(value: V, map_fn: F) -> Self {
+        let cache = scope_cache::<O>(Location::caller());
+        let version = {
+            let cache = cache.peek();
+            cache.reactive_context.subscribe(value.subscribers());
+            cache.latest.set(cache.latest.get() + 1);
+            cache.latest.get()
+        };
+
+        Self {
+            value,
+            map_fn,
+            cache,
+            version,
+        }
+    }
+
+    /// Recompute the cached value if the source changed since the last read
+    fn update_cache(&self) -> BorrowResult {
+        let cache = self.cache.try_peek_unchecked()?;
+        if !cache.dirty.load(Ordering::Relaxed) && cache.computed_for == self.version {
+            return Ok(());
+        }
+        drop(cache);
+
+        // If the cached value is currently borrowed, keep returning the old value until the borrow ends
+        let Ok(mut cache) = self.cache.try_write_unchecked() else {
+            return Ok(());
+        };
+        let source = self.value.try_peek_unchecked()?;
+        cache.value = Some((self.map_fn)(&source));
+        cache.computed_for = self.version;
+        cache.dirty.store(false, Ordering::Relaxed);
+        // Some sources drop their subscribers when they change, so make sure we are still subscribed
+        cache.reactive_context.subscribe(self.value.subscribers());
+        Ok(())
+    }
+}
+
+/// The caches of the derived signals created in a component, by the place they were created at
+#[derive(Clone, Default)]
+struct ScopeCaches(Rc<RefCell<ScopeCachesInner>>);
+
+#[derive(Default)]
+struct ScopeCachesInner {
+    /// The render `used` counts caches for
+    generation: usize,
+    /// The caches of every call site in the order they were handed out
+    caches: HashMap<&'static Location<'static>, Vec<Box<dyn Any>>>,
+    /// How many caches of every call site were handed out in this render
+    used: HashMap<&'static Location<'static>, usize>,
+}
+
+/// Get the next cache for a derived signal created at `location` in the current component. Derived signals created
+/// in a loop or conditionally each get their own cache, which is reused the next time the component renders.
+fn scope_cache<O: 'static>(location: &'static Location<'static>) -> CopyValue<DerivedCache<O>> {
+    let caches =
+        has_context::<ScopeCaches>().unwrap_or_else(|| provide_context(ScopeCaches::default()));
+    let mut caches = caches.0.borrow_mut();
+    let generation = generation();
+    if caches.generation != generation {
+        caches.generation = generation;
+        caches.used.clear();
+    }
+    let used = caches.used.entry(location).or_default();
+    let index = *used;
+    *used += 1;
+
+    let slots = caches.caches.entry(location).or_default();
+    let existing = slots
+        .get(index)
+        .and_then(|slot| slot.downcast_ref::<CopyValue<DerivedCache<O>>>())
+        .copied()
+        .filter(|cache| cache.try_peek_unchecked().is_ok());
+    if let Some(cache) = existing {
+        return cache;
+    }
+
+    let dirty = Arc::new(AtomicBool::new(true));
+    let reactive_context = ReactiveContext::new_with_callback(
+        {
+            let dirty = dirty.clone();
+            move || dirty.store(true, Ordering::Relaxed)
+        },
+        current_scope_id(),
+        location,
+    );
+    let cache = CopyValue::new(DerivedCache {
+        value: None,
+        computed_for: 0,
+        latest: Cell::new(0),
+        dirty,
+        reactive_context,
+    });
+    match slots.get_mut(index) {
+        Some(slot) => *slot = Box::new(cache),
+        None => slots.push(Box::new(cache)),
+    }
+    cache
+}
+
+impl<O, V, F> Clone for DerivedSignal<O, V, F>
+where
+    V: Readable + Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        DerivedSignal {
+            value: self.value.clone(),
+            map_fn: self.map_fn.clone(),
+            cache: self.cache,
+            version: self.version,
+        }
+    }
+}
+
+impl<O, V, F> Copy for DerivedSignal<O, V, F>
+where
+    V: Readable + Copy,
+    F: Copy,
+{
+}
+
+impl<O, V, F> Readable for DerivedSignal<O, V, F>
+where
+    O: 'static,
+    V: Readable,
+    V::Target: 'static,
+    F: Fn(&V::Target) -> O,
+{
+    type Target = O;
+    type Storage = UnsyncStorage;
+
+    fn try_read_unchecked(
+        &self,
+    ) -> Result<ReadableRef<'static, Self>, generational_box::BorrowError> {
+        // Reading the source subscribes the current reactive context to changes in the source
+        drop(self.value.try_read_unchecked()?);
+        self.try_peek_unchecked()
+    }
+
+    fn try_peek_unchecked(&self) -> BorrowResult<ReadableRef<'static, Self>> {
+        self.update_cache()?;
+        let cache = self.cache.try_peek_unchecked()?;
+        Ok(UnsyncStorage::map(cache, |cache| {
+            cache
+                .value
+                .as_ref()
+                .expect("the value is computed before it is read")
+        }))
+    }
+
+    fn subscribers(&self) -> Subscribers {
+        self.value.subscribers()
+    }
+}
+
+impl<O, V, F> IntoAttributeValue for DerivedSignal<O, V, F>
+where
+    O: Clone + IntoAttributeValue + 'static,
+    V: Readable,
+    V::Target: 'static,
+    F: Fn(&V::Target) -> O,
+{
+    fn into_value(self) -> dioxus_core::AttributeValue {
+        self.with(|f| f.clone().into_value())
+    }
+}
+
+impl<O, V, F> PartialEq for DerivedSignal<O, V, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cache == other.cache && self.version == other.version
+    }
+}
+
+/// Allow calling a signal with signal() syntax
+///
+/// Currently only limited to clone types, though could probably specialize for string/arc/rc
+impl<O, V, F> Deref for DerivedSignal<O, V, F>
+where
+    O: Clone + 'static,
+    V: Readable + 'static,
+    F: Fn(&V::Target) -> O + 'static,
+{
+    type Target = dyn Fn() -> O;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { ReadableExt::deref_impl(self) }
+    }
+}
+
+read_impls!(DerivedSignal<T, V, F> where V: Readable<Target: 'static>, F: Fn(&V::Target) -> T);
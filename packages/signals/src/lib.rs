@@ -16,9 +16,15 @@ pub use map::*;
 mod map_mut;
 pub use map_mut::*;
 
+mod derived;
+pub use derived::*;
+
 mod set_compare;
 pub use set_compare::*;
 
+mod bimap;
+pub use bimap::*;
+
 mod memo;
 pub use memo::*;
 
@@ -45,6 +51,9 @@ pub mod warnings;
 mod boxed;
 pub use boxed::*;
 
+mod batch;
+pub use batch::*;
+
 /// A macro to define extension methods for signal types that call the method with either `with` or `with_mut` depending on the mutability of self.
 macro_rules! ext_methods {
     (
@@ -138,6 +138,23 @@ impl<T> Memo<T> {
             .store(false, std::sync::atomic::Ordering::Relaxed);
     }
 
+    /// Get the current value of the memo without subscribing to it, recomputing it first if any of its
+    /// dependencies changed since it last ran.
+    pub(crate) fn try_peek_fresh(&self) -> BorrowResult<ReadableRef<'static, Self>>
+    where
+        T: PartialEq + 'static,
+    {
+        let needs_update = self
+            .update
+            .try_peek_unchecked()?
+            .dirty
+            .swap(false, std::sync::atomic::Ordering::Relaxed);
+        if needs_update {
+            self.recompute();
+        }
+        self.try_peek_unchecked()
+    }
+
     /// Get the scope that the signal was created in.
     pub fn origin_scope(&self) -> ScopeId
     where
@@ -1,10 +1,11 @@
 use std::collections::{HashMap, HashSet};
 use std::{
+    borrow::Borrow,
     mem::MaybeUninit,
     ops::{Deref, Index},
 };
 
-use crate::{MappedSignal, ReadSignal, ext_methods};
+use crate::{DerivedSignal, MappedSignal, ReadSignal, ext_methods};
 use dioxus_core::Subscribers;
 use generational_box::{AnyStorage, UnsyncStorage};
 
@@ -203,6 +204,43 @@ pub trait ReadableExt: Readable {
         MappedSignal::new(self, f)
     }
 
+    /// Create a read only signal with a value computed from this readable value. Unlike [`ReadableExt::map`], the
+    /// closure returns an owned value instead of a reference into the original value.
+    ///
+    /// The value is computed lazily the first time the derived signal is read and cached until this readable value changes.
+    /// Anything that reads the derived signal subscribes to this readable value.
+    ///
+    /// This is not a hook, so you can call it conditionally or in loops. The cache is owned by the current component
+    /// and reused by the derived signal created at the same place the next time the component renders, so calling it in
+    /// the body of a component doesn't allocate a new cache every render. A derived signal created in the body is
+    /// computed again the first time it is read in each render. Create it inside of `use_hook` to keep the value
+    /// cached across renders. If you want to skip rerunning subscribers when the computed value doesn't change, use a
+    /// [`crate::Memo`] instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// fn App() -> Element {
+    ///     let mut breed = use_signal(|| "hound".to_string());
+    ///     let length = breed.derive(|breed| breed.len());
+    ///
+    ///     rsx! {
+    ///         input { value: "{breed}", oninput: move |event| breed.set(event.value()) }
+    ///         "The breed name is {length} characters long"
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    fn derive<F, O>(self, f: F) -> DerivedSignal<O, Self, F>
+    where
+        Self: Sized,
+        Self::Target: 'static,
+        O: 'static,
+        F: Fn(&Self::Target) -> O,
+    {
+        DerivedSignal::new(self, f)
+    }
+
     /// Clone the inner value and return it. If the value has been dropped, this will panic.
     #[track_caller]
     fn cloned(&self) -> Self::Target
@@ -478,11 +516,13 @@ pub trait ReadableHashMapExt<K: 'static, V: 'static, H: 'static>:
         fn capacity(&self) -> usize = HashMap::capacity;
     }
 
-    /// Get the value for the given key.
+    /// Get the value for the given key. Like [`HashMap::get`], the key may be any borrowed form of the key type, so
+    /// you can look up a `String` key with a `&str`.
     #[track_caller]
-    fn get(&self, key: &K) -> Option<ReadableRef<'_, Self, V>>
+    fn get<Q>(&self, key: &Q) -> Option<ReadableRef<'_, Self, V>>
     where
-        K: std::hash::Hash + Eq,
+        K: Borrow<Q> + std::hash::Hash + Eq,
+        Q: std::hash::Hash + Eq + ?Sized,
         H: std::hash::BuildHasher,
     {
         <Self::Storage as AnyStorage>::try_map(self.read(), |v| v.get(key))
@@ -490,9 +530,10 @@ pub trait ReadableHashMapExt<K: 'static, V: 'static, H: 'static>:
 
     /// Check if the hashmap contains the given key.
     #[track_caller]
-    fn contains_key(&self, key: &K) -> bool
+    fn contains_key<Q>(&self, key: &Q) -> bool
     where
-        K: std::hash::Hash + Eq,
+        K: Borrow<Q> + std::hash::Hash + Eq,
+        Q: std::hash::Hash + Eq + ?Sized,
         H: std::hash::BuildHasher,
     {
         self.with(|v| v.contains_key(key))
@@ -519,9 +560,10 @@ pub trait ReadableHashSetExt<V: 'static, H: 'static>: Readable<Target = HashSet<
 
     /// Check if the hashset contains the given value.
     #[track_caller]
-    fn contains(&self, value: &V) -> bool
+    fn contains<Q>(&self, value: &Q) -> bool
     where
-        V: std::hash::Hash + Eq,
+        V: Borrow<Q> + std::hash::Hash + Eq,
+        Q: std::hash::Hash + Eq + ?Sized,
         H: std::hash::BuildHasher,
     {
         self.with(|v| v.contains(value))
@@ -1,6 +1,6 @@
 use crate::{
     CopyValue, Global, GlobalMemo, GlobalSignal, Memo, ReadableRef, WritableRef, default_impl,
-    fmt_impls, read::*, write::*, write_impls,
+    fmt_impls, mark_dirty_batched, read::*, write::*, write_impls,
 };
 use dioxus_core::{IntoAttributeValue, IntoDynNode, ReactiveContext, ScopeId, Subscribers};
 use generational_box::{BorrowResult, Storage, SyncStorage, UnsyncStorage};
@@ -25,6 +25,16 @@ pub type SyncSignal<T> = Signal<T, SyncStorage>;
 pub struct SignalData<T> {
     pub(crate) subscribers: Arc<Mutex<HashSet<ReactiveContext>>>,
     pub(crate) value: T,
+    /// Boxed so signals that don't deduplicate writes don't pay for a second copy of the value
+    pub(crate) dedupe: Option<Box<Dedupe<T>>>,
+}
+
+/// The state for signals created with [`Signal::new_eq_with_caller`] that skip writes which don't change the value.
+pub(crate) struct Dedupe<T> {
+    snapshot: fn(&T) -> T,
+    eq: fn(&T, &T) -> bool,
+    /// A copy of the value from before the current write
+    previous: Option<T>,
 }
 
 impl<T: 'static> Signal<T> {
@@ -151,6 +161,7 @@ impl<T, S: Storage<SignalData<T>>> Signal<T, S> {
             inner: CopyValue::<SignalData<T>, S>::new_maybe_sync(SignalData {
                 subscribers: Default::default(),
                 value,
+                dedupe: None,
             }),
         }
     }
@@ -177,6 +188,33 @@ impl<T, S: Storage<SignalData<T>>> Signal<T, S> {
                 SignalData {
                     subscribers: Default::default(),
                     value,
+                    dedupe: None,
+                },
+                caller,
+            ),
+        }
+    }
+
+    /// Creates a new Signal that only updates its subscribers when a write changes the value.
+    ///
+    /// Every write clones the value before it is modified and compares it to the value after the write with
+    /// [`PartialEq`]. If they are equal, subscribers are not notified. The clone and comparison run on every write, so
+    /// this can be slower than a normal signal for large values that change on most writes. Prefer
+    /// [`WritableExt::set_if_changed`] if only some writes need to be deduplicated.
+    pub fn new_eq_with_caller(value: T, caller: &'static std::panic::Location<'static>) -> Self
+    where
+        T: Clone + PartialEq + 'static,
+    {
+        Self {
+            inner: CopyValue::new_with_caller(
+                SignalData {
+                    subscribers: Default::default(),
+                    value,
+                    dedupe: Some(Box::new(Dedupe {
+                        snapshot: T::clone,
+                        eq: T::eq,
+                        previous: None,
+                    })),
                 },
                 caller,
             ),
@@ -193,6 +231,7 @@ impl<T, S: Storage<SignalData<T>>> Signal<T, S> {
                 SignalData {
                     subscribers: Default::default(),
                     value,
+                    dedupe: None,
                 },
                 caller,
             ),
@@ -218,6 +257,7 @@ impl<T, S: Storage<SignalData<T>>> Signal<T, S> {
                 SignalData {
                     subscribers: Default::default(),
                     value,
+                    dedupe: None,
                 },
                 owner,
                 caller,
@@ -262,7 +302,7 @@ impl<T, S: Storage<SignalData<T>>> Signal<T, S> {
             // We cannot hold the subscribers lock while calling mark_dirty, because mark_dirty can run user code which may cause a new subscriber to be added. If we hold the lock, we will deadlock.
             #[allow(clippy::mutable_key_type)]
             let mut subscribers = std::mem::take(&mut *inner.subscribers.lock().unwrap());
-            subscribers.retain(|reactive_context| reactive_context.mark_dirty());
+            subscribers.retain(mark_dirty_batched);
             // Extend the subscribers list instead of overwriting it in case a subscriber is added while reactive contexts are marked dirty
             inner.subscribers.lock().unwrap().extend(subscribers);
         }
@@ -447,7 +487,13 @@ impl<T: 'static, S: Storage<SignalData<T>>> Writable for Signal<T, S> {
         #[cfg(debug_assertions)]
         let origin = std::panic::Location::caller();
         self.inner.try_write_unchecked().map(|inner| {
-            let borrow = S::map_mut(inner.into_inner(), |v| &mut v.value);
+            let borrow = S::map_mut(inner.into_inner(), |v| {
+                // Keep a copy of the old value so we can skip notifying subscribers if the write doesn't change it
+                if let Some(dedupe) = &mut v.dedupe {
+                    dedupe.previous = Some((dedupe.snapshot)(&v.value));
+                }
+                &mut v.value
+            });
             WriteLock::new_with_metadata(
                 borrow,
                 SignalSubscriberDrop {
@@ -526,6 +572,24 @@ pub struct SignalSubscriberDrop<T: 'static, S: Storage<SignalData<T>> + 'static>
 #[allow(clippy::no_effect)]
 impl<T: 'static, S: Storage<SignalData<T>> + 'static> Drop for SignalSubscriberDrop<T, S> {
     fn drop(&mut self) {
+        let unchanged = self
+            .signal
+            .inner
+            .try_write_unchecked()
+            .map(|mut data| {
+                let data = &mut *data;
+                data.dedupe.as_mut().is_some_and(|dedupe| {
+                    dedupe
+                        .previous
+                        .take()
+                        .is_some_and(|previous| (dedupe.eq)(&previous, &data.value))
+                })
+            })
+            .unwrap_or(false);
+        if unchanged {
+            return;
+        }
+
         #[cfg(debug_assertions)]
         {
             tracing::trace!(
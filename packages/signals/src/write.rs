@@ -1,4 +1,5 @@
 use std::{
+    borrow::Borrow,
     collections::{HashMap, HashSet},
     ops::{Deref, DerefMut, IndexMut},
 };
@@ -349,6 +350,23 @@ pub trait WritableExt: Writable {
         *self.write() = value;
     }
 
+    /// Set the value of the signal if it is different from the current value. Returns `true` if the value was changed.
+    ///
+    /// Subscribers are only updated if the value changed, which avoids rerunning components and effects when an event
+    /// handler sets the value it already has. This compares the new value to the current value with [`PartialEq`],
+    /// which can be expensive for large values.
+    #[track_caller]
+    fn set_if_changed(&mut self, value: Self::Target) -> bool
+    where
+        Self::Target: Sized + PartialEq + 'static,
+    {
+        if *self.peek() == value {
+            return false;
+        }
+        self.set(value);
+        true
+    }
+
     /// Invert the boolean value of the signal. This will trigger an update on all subscribers.
     #[track_caller]
     fn toggle(&mut self)
@@ -695,9 +713,10 @@ pub trait WritableHashMapExt<K: 'static, V: 'static, H: 'static>:
 
     /// Removes a key from the map, returning the value at the key if the key was previously in the map.
     #[track_caller]
-    fn remove(&mut self, k: &K) -> Option<V>
+    fn remove<Q>(&mut self, k: &Q) -> Option<V>
     where
-        K: std::cmp::Eq + std::hash::Hash,
+        K: Borrow<Q> + std::cmp::Eq + std::hash::Hash,
+        Q: std::cmp::Eq + std::hash::Hash + ?Sized,
         H: std::hash::BuildHasher,
     {
         self.with_mut(|map: &mut HashMap<K, V, H>| map.remove(k))
@@ -705,9 +724,10 @@ pub trait WritableHashMapExt<K: 'static, V: 'static, H: 'static>:
 
     /// Get a mutable reference to the value at the given key.
     #[track_caller]
-    fn get_mut(&mut self, k: &K) -> Option<WritableRef<'_, Self, V>>
+    fn get_mut<Q>(&mut self, k: &Q) -> Option<WritableRef<'_, Self, V>>
     where
-        K: std::cmp::Eq + std::hash::Hash,
+        K: Borrow<Q> + std::cmp::Eq + std::hash::Hash,
+        Q: std::cmp::Eq + std::hash::Hash + ?Sized,
         H: std::hash::BuildHasher,
     {
         WriteLock::filter_map(self.write(), |map: &mut HashMap<K, V, H>| map.get_mut(k))
@@ -751,9 +771,10 @@ pub trait WritableHashSetExt<V: 'static, H: 'static>: Writable<Target = HashSet<
 
     /// Removes a value from the set. Returns true if the value was present.
     #[track_caller]
-    fn remove(&mut self, k: &V) -> bool
+    fn remove<Q>(&mut self, k: &Q) -> bool
     where
-        V: std::cmp::Eq + std::hash::Hash,
+        V: Borrow<Q> + std::cmp::Eq + std::hash::Hash,
+        Q: std::cmp::Eq + std::hash::Hash + ?Sized,
         H: std::hash::BuildHasher,
     {
         self.with_mut(|set| set.remove(k))
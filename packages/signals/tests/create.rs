@@ -3,6 +3,7 @@
 use dioxus::prelude::*;
 use dioxus_core::{ElementId, Mutation, NoOpMutations, generation};
 use dioxus_signals::*;
+use std::collections::{HashMap, HashSet};
 
 #[test]
 fn create_signals_global() {
@@ -115,3 +116,25 @@ fn drop_signals() {
 
     assert_eq!(SIGNAL_DROP_COUNT.load(Ordering::Relaxed), 10);
 }
+
+#[test]
+fn collection_signals_accept_borrowed_keys() {
+    let mut dom = VirtualDom::new(|| {
+        let mut scores = use_signal(|| HashMap::from([("alice".to_string(), 1)]));
+        let mut names = use_signal(|| HashSet::from(["alice".to_string()]));
+
+        assert_eq!(scores.get("alice").as_deref(), Some(&1));
+        assert!(scores.contains_key("alice"));
+        *scores.get_mut("alice").unwrap() += 1;
+        assert_eq!(scores.remove("alice"), Some(2));
+        assert!(scores.is_empty());
+
+        assert!(names.contains("alice"));
+        assert!(names.remove("alice"));
+        assert!(!names.contains("alice"));
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+}
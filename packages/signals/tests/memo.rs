@@ -178,3 +178,124 @@ fn memos_sync_rerun_after_unrelated_write() {
     dom.render_immediate(&mut NoOpMutations);
     assert!(PASSED.load(Ordering::SeqCst));
 }
+
+#[test]
+fn derived_signals_recompute_lazily() {
+    let mut dom = VirtualDom::new(|| {
+        let runs = use_hook(|| Rc::new(RefCell::new(0)));
+        let mut source = use_signal(|| "hound".to_string());
+        let length = use_hook(|| {
+            let runs = runs.clone();
+            source.derive(move |source| {
+                *runs.borrow_mut() += 1;
+                source.len()
+            })
+        });
+
+        // Nothing is computed until the derived signal is read
+        assert_eq!(*runs.borrow(), 0);
+        assert_eq!(length(), 5);
+        assert_eq!(length(), 5);
+        assert_eq!(*runs.borrow(), 1);
+
+        // Writing to the source marks the cache as dirty
+        source.set("husky dog".to_string());
+        assert_eq!(*runs.borrow(), 1);
+        assert_eq!(length(), 9);
+        assert_eq!(*runs.borrow(), 2);
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+}
+
+#[test]
+fn derived_signals_in_the_body_reuse_their_cache() {
+    thread_local! {
+        static RUNS: RefCell<usize> = const { RefCell::new(0) };
+    }
+
+    let mut dom = VirtualDom::new(|| {
+        let mut source = use_signal(|| 1);
+        let previous = use_hook(|| Rc::new(RefCell::new(None)));
+        let doubled = source.derive(|source| {
+            RUNS.with(|runs| *runs.borrow_mut() += 1);
+            source * 2
+        });
+        let runs = || RUNS.with(|runs| *runs.borrow());
+
+        match generation() {
+            0 => {
+                assert_eq!(doubled(), 2);
+                assert_eq!(doubled(), 2);
+                assert_eq!(runs(), 1);
+            }
+            1 => {
+                // The new handle computes the value once for this render
+                assert_eq!(doubled(), 2);
+                assert_eq!(doubled(), 2);
+                assert_eq!(runs(), 2);
+                source.set(2);
+                assert_eq!(doubled(), 4);
+                assert_eq!(runs(), 3);
+
+                // Handles from earlier renders still read their own value
+                let previous: DerivedSignal<i32, Signal<i32>, _> = (*previous.borrow()).unwrap();
+                assert_eq!(previous(), 4);
+            }
+            _ => {}
+        }
+        *previous.borrow_mut() = Some(doubled);
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+#[test]
+fn derived_signals_in_loops_and_branches() {
+    thread_local! {
+        static LIST: std::cell::Cell<Option<Signal<Vec<i32>>>> = const { std::cell::Cell::new(None) };
+        static SEEN: RefCell<Vec<(Vec<i32>, Option<String>)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    let mut dom = VirtualDom::new(|| {
+        let list = use_signal(|| vec![1, 2, 3]);
+        LIST.with(|cell| cell.set(Some(list)));
+
+        // Every iteration creates its derived signal at the same place, but each one keeps its own value
+        let doubled: Vec<i32> = (0..list.read().len())
+            .map(|index| list.map(move |list| &list[index]).derive(|value| value * 2))
+            .map(|doubled| doubled())
+            .collect();
+        let label = match list.read().len() > 2 {
+            true => Some(list.derive(|list| format!("{} items", list.len()))()),
+            false => None,
+        };
+        SEEN.with(|seen| seen.borrow_mut().push((doubled, label)));
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+    let mut list = LIST.with(|cell| cell.get()).unwrap();
+    dom.in_runtime(|| list.set(vec![3, 1]));
+    dom.render_immediate(&mut NoOpMutations);
+    dom.in_runtime(|| list.set(vec![5, 4, 6, 7]));
+    dom.render_immediate(&mut NoOpMutations);
+
+    SEEN.with(|seen| {
+        assert_eq!(
+            *seen.borrow(),
+            [
+                (vec![2, 4, 6], Some("3 items".to_string())),
+                (vec![6, 2], None),
+                (vec![10, 8, 12, 14], Some("4 items".to_string())),
+            ]
+        );
+    });
+}
@@ -1,6 +1,6 @@
 #![allow(unused, non_upper_case_globals, non_snake_case)]
 
-use dioxus_core::{NoOpMutations, current_scope_id, generation};
+use dioxus_core::{NoOpMutations, ReactiveContext, current_scope_id, generation};
 use std::collections::HashMap;
 use std::rc::Rc;
 
@@ -93,3 +93,139 @@ fn reading_subscribes() {
         }
     }
 }
+
+#[test]
+fn batched_writes_notify_once() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut dom = VirtualDom::new(|| {
+        let notified = use_hook(|| Arc::new(AtomicUsize::new(0)));
+        let mut first = use_signal(|| 0);
+        let mut second = use_signal(|| 0);
+
+        let rc = use_hook(|| {
+            let notified = notified.clone();
+            ReactiveContext::new_with_callback(
+                move || {
+                    notified.fetch_add(1, Ordering::SeqCst);
+                },
+                current_scope_id(),
+                std::panic::Location::caller(),
+            )
+        });
+        rc.run_in(|| {
+            first.read();
+            second.read();
+        });
+
+        batch(|| {
+            first += 1;
+            // Nested batches only flush at the outermost boundary
+            batch(|| second += 1);
+            // Reads inside the batch see the latest value
+            assert_eq!(first(), 1);
+            assert_eq!(second(), 1);
+            assert_eq!(notified.load(Ordering::SeqCst), 0);
+        });
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+        // Writes outside of a batch notify immediately
+        first += 1;
+        second += 1;
+        assert_eq!(notified.load(Ordering::SeqCst), 3);
+
+        rsx! {}
+    });
+
+    dom.rebuild_in_place();
+}
+
+#[test]
+fn unchanged_writes_skip_subscribers() {
+    let renders = Rc::new(RefCell::new(0));
+    let mut dom = VirtualDom::new_with_props(
+        |renders: Rc<RefCell<usize>>| {
+            let mut checked = use_signal(|| 0);
+            let mut deduped = use_signal_eq(|| 0);
+            let _ = (checked(), deduped());
+
+            *renders.borrow_mut() += 1;
+
+            // Writing the value the signals already hold should not mark the component dirty again
+            if *renders.borrow() == 2 {
+                assert!(!checked.set_if_changed(0));
+                deduped.set(0);
+            }
+
+            rsx! {}
+        },
+        renders.clone(),
+    );
+
+    dom.rebuild_in_place();
+    assert_eq!(*renders.borrow(), 1);
+
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(*renders.borrow(), 2);
+}
+
+#[test]
+fn deduped_signals_compare_with_the_previous_value() {
+    thread_local! {
+        static COMPARISONS: RefCell<usize> = const { RefCell::new(0) };
+    }
+
+    #[derive(Clone)]
+    struct Counted(i32);
+
+    impl PartialEq for Counted {
+        fn eq(&self, other: &Self) -> bool {
+            COMPARISONS.with(|comparisons| *comparisons.borrow_mut() += 1);
+            self.0 == other.0
+        }
+    }
+
+    let renders = Rc::new(RefCell::new(0));
+    let mut dom = VirtualDom::new_with_props(
+        |renders: Rc<RefCell<usize>>| {
+            let mut deduped = use_signal_eq(|| Counted(0));
+            let _ = deduped();
+
+            *renders.borrow_mut() += 1;
+
+            match *renders.borrow() {
+                2 => {
+                    // Writing an equal value compares it with the value before the write and skips subscribers
+                    deduped.set(Counted(0));
+                    assert_eq!(COMPARISONS.with(|comparisons| *comparisons.borrow()), 1);
+                    // Changing the value in place and changing it back is also unchanged
+                    let mut value = deduped.write();
+                    value.0 = 5;
+                    value.0 = 0;
+                    drop(value);
+                    assert_eq!(COMPARISONS.with(|comparisons| *comparisons.borrow()), 2);
+                }
+                3 => {
+                    deduped.set(Counted(1));
+                    assert_eq!(deduped.peek().0, 1);
+                }
+                _ => {}
+            }
+
+            rsx! {}
+        },
+        renders.clone(),
+    );
+
+    dom.rebuild_in_place();
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(*renders.borrow(), 2);
+
+    dom.mark_dirty(ScopeId::APP);
+    dom.render_immediate(&mut NoOpMutations);
+    assert_eq!(*renders.borrow(), 3);
+    assert_eq!(COMPARISONS.with(|comparisons| *comparisons.borrow()), 3);
+}
@@ -29,7 +29,7 @@
 //! };
 //!```
 
-use crate::renderer::{BOOL_ATTRS, str_truthy};
+use crate::renderer::{is_bool_attr, str_truthy};
 use dioxus_core::{TemplateAttribute, TemplateNode, VNode};
 use std::{fmt::Write, ops::AddAssign};
 
@@ -214,7 +214,7 @@ fn from_template_recursive(
                             inner_html = Some(value);
                         } else if let Some("style") = namespace {
                             styles.push((name, value));
-                        } else if BOOL_ATTRS.contains(name) {
+                        } else if is_bool_attr(name) {
                             if str_truthy(value) {
                                 write!(
                                     chain,
@@ -33,3 +33,37 @@ pub fn pre_render(dom: &VirtualDom) -> String {
     renderer.pre_render = true;
     renderer.render(dom)
 }
+
+/// Render an existing VirtualDom to a string with the hydration markers the web renderer expects.
+///
+/// This is an alias for [`pre_render`] and is equivalent to setting [`Renderer::pre_render`] on your own renderer.
+/// To let the client take over the page, render the output inside the element the web renderer mounts to and launch
+/// the client with hydration enabled. If the app uses server futures, also send down the serialized server state
+/// with `dioxus_fullstack_server::hydration_data_script` so the client doesn't run them again.
+///
+/// # Marker format
+///
+/// Dynamic nodes are given ids in the order they are rendered, starting from zero:
+/// - Root elements and elements with dynamic attributes get a `data-node-hydration="{id}"` attribute. If the element
+///   has event listeners, each listener is appended as `,{event}:{bubbles}` where `bubbles` is `1` or `0`, for
+///   example `data-node-hydration="3,click:1,focus:0"`.
+/// - Dynamic text nodes are wrapped in comments: `<!--node-id{id}-->text<!--#-->`.
+/// - Static text at the root of a template is preceded by `<!--node-id{id}-->`.
+/// - Empty placeholder nodes are rendered as `<!--placeholder{id}-->`.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// fn app() -> Element {
+///     let count = 1;
+///     rsx! { div { onclick: |_| {}, "Count: {count}" } }
+/// }
+///
+/// let mut dom = VirtualDom::new(app);
+/// dom.rebuild_in_place();
+/// let html = dioxus_ssr::render_with_hydration(&dom);
+/// assert_eq!(
+///     html,
+///     r#"<div data-node-hydration="0,click:1"><!--node-id1-->Count: 1<!--#--></div>"#
+/// );
+/// ```
+pub use pre_render as render_with_hydration;
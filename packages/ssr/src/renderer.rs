@@ -150,7 +150,7 @@ impl Renderer {
                             inner_html = Some(attr);
                         } else if attr.namespace == Some("style") {
                             accumulated_dynamic_styles.push(attr);
-                        } else if BOOL_ATTRS.contains(&attr.name) {
+                        } else if is_bool_attr(attr.name) {
                             if truthy(&attr.value) {
                                 write_attribute(buf, attr)?;
                             }
@@ -437,36 +437,12 @@ fn empty_render_works() {
     assert_eq!(out, "");
 }
 
-pub(crate) const BOOL_ATTRS: &[&str] = &[
-    "allowfullscreen",
-    "allowpaymentrequest",
-    "async",
-    "autofocus",
-    "autoplay",
-    "checked",
-    "controls",
-    "default",
-    "defer",
-    "disabled",
-    "formnovalidate",
-    "hidden",
-    "inert",
-    "ismap",
-    "itemscope",
-    "loop",
-    "multiple",
-    "muted",
-    "nomodule",
-    "novalidate",
-    "open",
-    "playsinline",
-    "readonly",
-    "required",
-    "reversed",
-    "selected",
-    "truespeed",
-    "webkitdirectory",
-];
+/// Check if an attribute is only rendered when its value is truthy. These are the boolean attributes from
+/// dioxus-core, plus `checked` and `selected` which are set as properties on the client but still need to be
+/// written as attributes in the html.
+pub(crate) fn is_bool_attr(name: &str) -> bool {
+    dioxus_core::is_boolean_attribute(name) || matches!(name, "checked" | "selected")
+}
 
 pub(crate) fn str_truthy(value: &str) -> bool {
     !value.is_empty() && value != "0" && value.to_lowercase() != "false"
@@ -53,3 +53,38 @@ fn forward_spreads() {
         r#"<div style="width:100%;height:100%;"></div><div style="width:100%;height:100%;"></div>"#
     );
 }
+
+#[test]
+fn explicit_attributes_win_over_spreads() {
+    #[component]
+    fn Button(extra_attributes: Vec<Attribute>) -> Element {
+        rsx! {
+            button {
+                class: "button",
+                ..extra_attributes,
+                "Save"
+            }
+        }
+    }
+
+    let dom = VirtualDom::prebuilt(|| {
+        rsx! {
+            Button {
+                extra_attributes: vec![
+                    Attribute::new("class", "ignored", None, false),
+                    Attribute::new("data-kind", "primary", None, false),
+                    Attribute::new("aria-label", "Save changes", None, false),
+                ]
+            }
+            div {
+                "id": "explicit",
+                ..[("id", "spread"), ("title", "from pairs")],
+            }
+        }
+    });
+    let html = dioxus_ssr::render(&dom);
+    assert_eq!(
+        html,
+        r#"<button class="button" data-kind="primary" aria-label="Save changes">Save</button><div id="explicit" title="from pairs"></div>"#
+    );
+}
@@ -15,8 +15,8 @@ fn static_inner_html() {
 #[test]
 fn dynamic_inner_html() {
     fn app() -> Element {
-        let inner_html = "<div>1234</div>";
-        rsx! { div { dangerous_inner_html: "{inner_html}" } }
+        let inner_html = UnsafeHtml::from("<div>1234</div>");
+        rsx! { div { dangerous_inner_html: inner_html } }
     }
 
     let mut dom = VirtualDom::new(app);
@@ -24,3 +24,16 @@ fn dynamic_inner_html() {
 
     assert_eq!(dioxus_ssr::render(&dom), r#"<div><div>1234</div></div>"#);
 }
+
+#[test]
+fn unsafe_html_inner_html() {
+    fn app() -> Element {
+        let inner_html = UnsafeHtml("<b>bold</b>".to_string());
+        rsx! { div { dangerous_inner_html: inner_html } }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(dioxus_ssr::render(&dom), r#"<div><b>bold</b></div>"#);
+}
@@ -1,5 +1,7 @@
 use dioxus_core::{ReactiveContext, SubscriberList, Subscribers};
-use dioxus_signals::{CopyValue, ReadableExt, SyncStorage, Writable, WritableExt};
+use dioxus_signals::{
+    CopyValue, ReadableExt, SyncStorage, Writable, WritableExt, mark_dirty_batched,
+};
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::hash::BuildHasher;
@@ -304,7 +306,7 @@ impl StoreSubscriptions {
     /// Mark only deep subscribers for a single ancestor node as dirty.
     fn mark_ancestor_deep_subscribers_dirty(&self, key: &[PathKey]) {
         self.retain_subscribers(key, |reactive_context, depth| {
-            !depth.includes_deep() || reactive_context.mark_dirty()
+            !depth.includes_deep() || mark_dirty_batched(reactive_context)
         });
     }
 
@@ -370,7 +372,9 @@ impl StoreSubscriptions {
     }
 
     fn mark_node_subscribers_dirty(&self, key: &[PathKey]) {
-        self.retain_subscribers(key, |reactive_context, _| reactive_context.mark_dirty());
+        self.retain_subscribers(key, |reactive_context, _| {
+            mark_dirty_batched(reactive_context)
+        });
     }
 }
 
@@ -481,3 +481,22 @@ fn deep_parent_reader_sees_nested_vec_push() {
         assert_eq!(current_counter.other, 1);
     }
 }
+
+#[test]
+fn item_stores_are_dropped_when_the_vec_shrinks() {
+    let mut dom = VirtualDom::new(|| rsx! {});
+    dom.rebuild_in_place();
+
+    dom.in_scope(ScopeId::APP, || {
+        let mut items = Store::new(vec![1, 2, 3]);
+        let last = items.get(2).unwrap();
+        assert_eq!(last(), 3);
+
+        items.remove(0);
+        assert!(items.get(2).is_none());
+        assert!(matches!(
+            last.try_read(),
+            Err(dioxus_signals::BorrowError::Dropped(_))
+        ));
+    });
+}
@@ -0,0 +1,356 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+mod tree;
+pub use tree::NodeId;
+
+use dioxus_core::{
+    AttributeValue, ComponentFunction, Element, ElementId, Mutations, Template, VirtualDom,
+    WriteMutations,
+};
+use dioxus_html::{PlatformEventData, SerializedFormData, SerializedMouseData};
+use std::{any::Any, rc::Rc};
+use tree::{NodeKind, Tree};
+
+/// Render a component without a platform and inspect the result.
+///
+/// The test dom applies the mutations from the [`VirtualDom`] to an in memory tree. You can query that tree, send
+/// events to it, and check the mutations from the last render.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_test::TestDom;
+///
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0);
+///     rsx! {
+///         button { id: "increment", onclick: move |_| count += 1, "Count: {count}" }
+///     }
+/// }
+///
+/// let mut dom = TestDom::new(app);
+/// let button = dom.find_by_id("increment").unwrap();
+/// assert_eq!(dom.text(button), "Count: 0");
+///
+/// dom.click(button);
+/// assert_eq!(dom.text(button), "Count: 1");
+/// ```
+pub struct TestDom {
+    dom: VirtualDom,
+    tree: Tree,
+    mutations: Mutations,
+}
+
+impl TestDom {
+    /// Create a test dom for a component without props and render it
+    pub fn new(app: fn() -> Element) -> Self {
+        Self::from_virtual_dom(VirtualDom::new(app))
+    }
+
+    /// Create a test dom for a component with props and render it
+    pub fn new_with_props<P: Clone + 'static, M: 'static>(
+        app: impl ComponentFunction<P, M>,
+        props: P,
+    ) -> Self {
+        Self::from_virtual_dom(VirtualDom::new_with_props(app, props))
+    }
+
+    /// Create a test dom from an existing [`VirtualDom`] and render it. Use this to provide root contexts before the
+    /// first render.
+    pub fn from_virtual_dom(dom: VirtualDom) -> Self {
+        // Events sent from the test dom use the serialized event types
+        dioxus_html::set_event_converter(Box::new(dioxus_html::SerializedHtmlEventConverter));
+
+        let mut test_dom = Self {
+            dom,
+            tree: Tree::new(),
+            mutations: Mutations::default(),
+        };
+        let mut recorder = Recorder {
+            tree: &mut test_dom.tree,
+            mutations: &mut test_dom.mutations,
+        };
+        test_dom.dom.rebuild(&mut recorder);
+        test_dom
+    }
+
+    /// Get the virtual dom that renders into this test dom
+    pub fn virtual_dom(&self) -> &VirtualDom {
+        &self.dom
+    }
+
+    /// Get a mutable reference to the virtual dom that renders into this test dom
+    pub fn virtual_dom_mut(&mut self) -> &mut VirtualDom {
+        &mut self.dom
+    }
+
+    /// The mutations from the last render
+    pub fn mutations(&self) -> &Mutations {
+        &self.mutations
+    }
+
+    /// Render any dirty components and process queued work without waiting. Event helpers like [`TestDom::click`]
+    /// call this for you.
+    pub fn render(&mut self) {
+        self.mutations = Mutations::default();
+        let mut recorder = Recorder {
+            tree: &mut self.tree,
+            mutations: &mut self.mutations,
+        };
+        self.dom.render_immediate(&mut recorder);
+    }
+
+    /// Wait until the virtual dom has work to do, like a future that finished or a signal written from a task,
+    /// and render it.
+    pub async fn wait_for_work(&mut self) {
+        self.dom.wait_for_work().await;
+        self.render();
+    }
+
+    /// Keep rendering until no component is suspended. This resolves once every future passed to
+    /// [`suspend`](https://docs.rs/dioxus/latest/dioxus/prelude/struct.Resource.html#method.suspend) has finished.
+    pub async fn wait_for_suspense(&mut self) {
+        self.render();
+        while self.dom.suspended_tasks_remaining() {
+            self.wait_for_work().await;
+        }
+    }
+
+    /// The node the app is mounted to
+    pub fn root(&self) -> NodeId {
+        NodeId(Tree::ROOT)
+    }
+
+    /// Find the first element with the given `id` attribute
+    pub fn find_by_id(&self, id: &str) -> Option<NodeId> {
+        self.find(|node| self.attribute(node, "id").as_deref() == Some(id))
+    }
+
+    /// Find the first element with a text child that contains the text
+    pub fn find_by_text(&self, text: &str) -> Option<NodeId> {
+        self.find(|node| {
+            self.tree.nodes[node.0].children.iter().any(
+                |child| matches!(&self.tree.nodes[*child].kind, NodeKind::Text(value) if value.contains(text)),
+            )
+        })
+    }
+
+    /// Find every element with the given tag name in document order
+    pub fn find_all_by_tag(&self, tag: &str) -> Vec<NodeId> {
+        self.descendants(self.root())
+            .filter(|node| self.tag(*node) == Some(tag))
+            .collect()
+    }
+
+    /// Find the first element that matches the predicate in document order
+    pub fn find(&self, mut predicate: impl FnMut(NodeId) -> bool) -> Option<NodeId> {
+        self.descendants(self.root())
+            .find(|node| self.tag(*node).is_some() && predicate(*node))
+    }
+
+    /// Get the tag name of a node, or `None` if it is not an element
+    pub fn tag(&self, node: NodeId) -> Option<&str> {
+        match &self.tree.nodes[node.0].kind {
+            NodeKind::Element { tag, .. } => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Get the value of an attribute on an element
+    pub fn attribute(&self, node: NodeId, name: &str) -> Option<String> {
+        match &self.tree.nodes[node.0].kind {
+            NodeKind::Element { attributes, .. } => attributes
+                .iter()
+                .find(|(other, _)| *other == name)
+                .map(|(_, value)| value.clone()),
+            _ => None,
+        }
+    }
+
+    /// Check if an element has a listener for the event. `name` is the event name without the `on` prefix.
+    pub fn has_listener(&self, node: NodeId, name: &str) -> bool {
+        match &self.tree.nodes[node.0].kind {
+            NodeKind::Element { listeners, .. } => listeners.iter().any(|other| other == name),
+            _ => false,
+        }
+    }
+
+    /// Get the children of a node, including text nodes
+    pub fn children(&self, node: NodeId) -> Vec<NodeId> {
+        self.tree.nodes[node.0]
+            .children
+            .iter()
+            .map(|child| NodeId(*child))
+            .collect()
+    }
+
+    /// Get the parent of a node
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.tree.nodes[node.0].parent.map(NodeId)
+    }
+
+    /// Get the text of a node and all of its descendants
+    pub fn text(&self, node: NodeId) -> String {
+        self.descendants(node)
+            .filter_map(|node| match &self.tree.nodes[node.0].kind {
+                NodeKind::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Render a node and its descendants to html. Useful for debugging failing tests.
+    pub fn html(&self, node: NodeId) -> String {
+        let mut html = String::new();
+        self.write_html(node.0, &mut html);
+        html
+    }
+
+    /// Send a click event to a node. The event bubbles from the closest element with a listener.
+    pub fn click(&mut self, node: NodeId) {
+        self.dispatch(node, "click", SerializedMouseData::default(), true);
+    }
+
+    /// Set the value of an input and send it an `input` event
+    pub fn input(&mut self, node: NodeId, value: &str) {
+        self.tree
+            .set_attribute_text(node.0, "value", Some(value.to_string()));
+        let data = SerializedFormData::new(value.to_string(), Vec::new());
+        self.dispatch(node, "input", data, true);
+    }
+
+    /// Send any event to a node and render the result.
+    ///
+    /// `name` is the event name without the `on` prefix. `data` must be the serialized event type the event
+    /// expects, like [`SerializedMouseData`] for `click` or [`SerializedFormData`] for `input`.
+    pub fn dispatch(&mut self, node: NodeId, name: &str, data: impl Any, bubbles: bool) {
+        let Some(id) = self.tree.element_id(node.0) else {
+            panic!("{node:?} is not mounted");
+        };
+        let event = Rc::new(PlatformEventData::new(Box::new(data)));
+        self.dom.handle_event(name, event, id, bubbles);
+        self.render();
+    }
+
+    fn descendants(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut stack = vec![node.0];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(self.tree.nodes[node].children.iter().rev());
+            Some(NodeId(node))
+        })
+    }
+
+    fn write_html(&self, node: usize, html: &mut String) {
+        let node = &self.tree.nodes[node];
+        match &node.kind {
+            NodeKind::Element {
+                tag, attributes, ..
+            } => {
+                html.push('<');
+                html.push_str(tag);
+                for (name, value) in attributes {
+                    html.push_str(&format!(" {name}=\"{value}\""));
+                }
+                html.push('>');
+                for child in &node.children {
+                    self.write_html(*child, html);
+                }
+                html.push_str(&format!("</{tag}>"));
+            }
+            NodeKind::Text(text) => html.push_str(text),
+            NodeKind::Placeholder => html.push_str("<!--placeholder-->"),
+        }
+    }
+}
+
+/// Applies mutations to the tree and records them at the same time
+struct Recorder<'a> {
+    tree: &'a mut Tree,
+    mutations: &'a mut Mutations,
+}
+
+impl WriteMutations for Recorder<'_> {
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        self.tree.append_children(id, m);
+        self.mutations.append_children(id, m);
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        self.tree.assign_node_id(path, id);
+        self.mutations.assign_node_id(path, id);
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        self.tree.create_placeholder(id);
+        self.mutations.create_placeholder(id);
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        self.tree.create_text_node(value, id);
+        self.mutations.create_text_node(value, id);
+    }
+
+    fn load_template(&mut self, template: Template, index: usize, id: ElementId) {
+        self.tree.load_template(template, index, id);
+        self.mutations.load_template(template, index, id);
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        self.tree.replace_node_with(id, m);
+        self.mutations.replace_node_with(id, m);
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        self.tree.replace_placeholder_with_nodes(path, m);
+        self.mutations.replace_placeholder_with_nodes(path, m);
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        self.tree.insert_nodes_after(id, m);
+        self.mutations.insert_nodes_after(id, m);
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        self.tree.insert_nodes_before(id, m);
+        self.mutations.insert_nodes_before(id, m);
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        self.tree.set_attribute(name, ns, value, id);
+        self.mutations.set_attribute(name, ns, value, id);
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        self.tree.set_node_text(value, id);
+        self.mutations.set_node_text(value, id);
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.tree.create_event_listener(name, id);
+        self.mutations.create_event_listener(name, id);
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.tree.remove_event_listener(name, id);
+        self.mutations.remove_event_listener(name, id);
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        self.tree.remove_node(id);
+        self.mutations.remove_node(id);
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        self.tree.push_root(id);
+        self.mutations.push_root(id);
+    }
+}
@@ -0,0 +1,287 @@
+use dioxus_core::{
+    AttributeValue, ElementId, Template, TemplateAttribute, TemplateNode, WriteMutations,
+};
+
+/// A handle to a node in a [`TestDom`](crate::TestDom)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NodeId(pub(crate) usize);
+
+#[derive(Debug)]
+pub(crate) enum NodeKind {
+    Element {
+        tag: &'static str,
+        attributes: Vec<(&'static str, String)>,
+        listeners: Vec<String>,
+    },
+    Text(String),
+    Placeholder,
+}
+
+#[derive(Debug)]
+pub(crate) struct Node {
+    pub(crate) kind: NodeKind,
+    pub(crate) parent: Option<usize>,
+    pub(crate) children: Vec<usize>,
+}
+
+/// An in memory tree that applies the mutations from a virtual dom like a real renderer would.
+///
+/// Nodes are never freed. Removed nodes are detached from their parent, which is fine for the short lived trees
+/// created in tests.
+pub(crate) struct Tree {
+    pub(crate) nodes: Vec<Node>,
+    stack: Vec<usize>,
+    elements: Vec<Option<usize>>,
+}
+
+impl Tree {
+    pub(crate) const ROOT: usize = 0;
+
+    pub(crate) fn new() -> Self {
+        Self {
+            nodes: vec![Node {
+                kind: NodeKind::Element {
+                    tag: "main",
+                    attributes: Vec::new(),
+                    listeners: Vec::new(),
+                },
+                parent: None,
+                children: Vec::new(),
+            }],
+            stack: vec![Self::ROOT],
+            elements: vec![Some(Self::ROOT)],
+        }
+    }
+
+    /// Find the element id of a node or the closest ancestor that has one
+    pub(crate) fn element_id(&self, mut node: usize) -> Option<ElementId> {
+        loop {
+            if let Some(id) = self.elements.iter().position(|n| *n == Some(node)) {
+                return Some(ElementId(id));
+            }
+            node = self.nodes[node].parent?;
+        }
+    }
+
+    pub(crate) fn set_attribute_text(
+        &mut self,
+        node: usize,
+        name: &'static str,
+        value: Option<String>,
+    ) {
+        if let NodeKind::Element { attributes, .. } = &mut self.nodes[node].kind {
+            attributes.retain(|(other, _)| *other != name);
+            if let Some(value) = value {
+                attributes.push((name, value));
+            }
+        }
+    }
+
+    fn create(&mut self, kind: NodeKind) -> usize {
+        self.nodes.push(Node {
+            kind,
+            parent: None,
+            children: Vec::new(),
+        });
+        self.nodes.len() - 1
+    }
+
+    fn create_template_node(&mut self, node: &TemplateNode) -> usize {
+        match node {
+            TemplateNode::Element {
+                tag,
+                attrs,
+                children,
+                ..
+            } => {
+                let attributes = attrs
+                    .iter()
+                    .filter_map(|attr| match attr {
+                        TemplateAttribute::Static { name, value, .. } => {
+                            Some((*name, value.to_string()))
+                        }
+                        TemplateAttribute::Dynamic { .. } => None,
+                    })
+                    .collect();
+                let id = self.create(NodeKind::Element {
+                    tag,
+                    attributes,
+                    listeners: Vec::new(),
+                });
+                for child in children.iter() {
+                    let child = self.create_template_node(child);
+                    self.nodes[child].parent = Some(id);
+                    self.nodes[id].children.push(child);
+                }
+                id
+            }
+            TemplateNode::Text { text } => self.create(NodeKind::Text(text.to_string())),
+            TemplateNode::Dynamic { .. } => self.create(NodeKind::Placeholder),
+        }
+    }
+
+    fn node(&self, id: ElementId) -> usize {
+        self.elements[id.0].expect("element id is not mounted")
+    }
+
+    fn map(&mut self, node: usize, id: ElementId) {
+        if self.elements.len() <= id.0 {
+            self.elements.resize(id.0 + 1, None);
+        }
+        self.elements[id.0] = Some(node);
+    }
+
+    fn node_at_path(&self, path: &[u8]) -> usize {
+        let mut node = *self.stack.last().unwrap();
+        for index in path {
+            node = self.nodes[node].children[*index as usize];
+        }
+        node
+    }
+
+    fn detach(&mut self, node: usize) {
+        if let Some(parent) = self.nodes[node].parent.take() {
+            self.nodes[parent].children.retain(|child| *child != node);
+        }
+    }
+
+    fn pop(&mut self, m: usize) -> Vec<usize> {
+        self.stack.split_off(self.stack.len() - m)
+    }
+
+    /// Insert nodes into the parent of the anchor at the anchor's index plus the offset
+    fn insert_at_anchor(&mut self, anchor: usize, offset: usize, nodes: Vec<usize>) {
+        for node in &nodes {
+            self.detach(*node);
+        }
+        let Some(parent) = self.nodes[anchor].parent else {
+            return;
+        };
+        let index = self.nodes[parent]
+            .children
+            .iter()
+            .position(|child| *child == anchor)
+            .unwrap();
+        for node in &nodes {
+            self.nodes[*node].parent = Some(parent);
+        }
+        let children = &mut self.nodes[parent].children;
+        children.splice(index + offset..index + offset, nodes);
+    }
+
+    fn replace(&mut self, anchor: usize, nodes: Vec<usize>) {
+        self.insert_at_anchor(anchor, 0, nodes);
+        self.detach(anchor);
+    }
+}
+
+impl WriteMutations for Tree {
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        let parent = self.node(id);
+        for node in self.pop(m) {
+            self.detach(node);
+            self.nodes[node].parent = Some(parent);
+            self.nodes[parent].children.push(node);
+        }
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        let node = self.node_at_path(path);
+        self.map(node, id);
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        let node = self.create(NodeKind::Placeholder);
+        self.map(node, id);
+        self.stack.push(node);
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        let node = self.create(NodeKind::Text(value.to_string()));
+        self.map(node, id);
+        self.stack.push(node);
+    }
+
+    fn load_template(&mut self, template: Template, index: usize, id: ElementId) {
+        let node = self.create_template_node(&template.roots()[index]);
+        self.map(node, id);
+        self.stack.push(node);
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        let anchor = self.node(id);
+        let nodes = self.pop(m);
+        self.replace(anchor, nodes);
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        // Pop the new nodes before finding the placeholder. The path is relative to the node under them
+        let nodes = self.pop(m);
+        let anchor = self.node_at_path(path);
+        self.replace(anchor, nodes);
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        let anchor = self.node(id);
+        let nodes = self.pop(m);
+        self.insert_at_anchor(anchor, 1, nodes);
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        let anchor = self.node(id);
+        let nodes = self.pop(m);
+        self.insert_at_anchor(anchor, 0, nodes);
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        _ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        let value = match value {
+            AttributeValue::Text(value) => Some(value.clone()),
+            AttributeValue::Float(value) => Some(value.to_string()),
+            AttributeValue::Int(value) => Some(value.to_string()),
+            AttributeValue::Bool(value) => Some(value.to_string()),
+            AttributeValue::None => None,
+            AttributeValue::Listener(_) | AttributeValue::Any(_) => return,
+        };
+        let node = self.node(id);
+        self.set_attribute_text(node, name, value);
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        let node = self.node(id);
+        if let NodeKind::Text(text) = &mut self.nodes[node].kind {
+            *text = value.to_string();
+        }
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let node = self.node(id);
+        if let NodeKind::Element { listeners, .. } = &mut self.nodes[node].kind {
+            listeners.push(name.to_string());
+        }
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let node = self.node(id);
+        if let NodeKind::Element { listeners, .. } = &mut self.nodes[node].kind
+            && let Some(index) = listeners.iter().position(|listener| listener == name)
+        {
+            listeners.remove(index);
+        }
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        let node = self.node(id);
+        self.detach(node);
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        let node = self.node(id);
+        self.stack.push(node);
+    }
+}
@@ -0,0 +1,158 @@
+use dioxus::prelude::*;
+use dioxus_core::Mutation;
+use dioxus_test::TestDom;
+
+#[test]
+fn click_updates_text() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        rsx! {
+            button { id: "increment", onclick: move |_| count += 1, "Count: {count}" }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    let button = dom.find_by_id("increment").unwrap();
+    assert_eq!(dom.tag(button), Some("button"));
+    assert!(dom.has_listener(button, "click"));
+    assert_eq!(dom.text(button), "Count: 0");
+
+    dom.click(button);
+    assert_eq!(dom.text(button), "Count: 1");
+    assert!(
+        dom.mutations()
+            .edits
+            .iter()
+            .any(|edit| matches!(edit, Mutation::SetText { value, .. } if value == "Count: 1"))
+    );
+}
+
+#[test]
+fn lists_insert_and_remove_nodes() {
+    fn app() -> Element {
+        let mut items = use_signal(|| vec![1, 2]);
+        rsx! {
+            button { onclick: move |_| items.push(items.len() + 1), "Add" }
+            button { onclick: move |_| { items.remove(0); }, "Remove" }
+            ul {
+                for item in items() {
+                    li { key: "{item}", "Item {item}" }
+                }
+            }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    assert_eq!(dom.find_all_by_tag("li").len(), 2);
+
+    let add = dom.find_by_text("Add").unwrap();
+    dom.click(add);
+    let items: Vec<_> = dom
+        .find_all_by_tag("li")
+        .into_iter()
+        .map(|item| dom.text(item))
+        .collect();
+    assert_eq!(items, ["Item 1", "Item 2", "Item 3"]);
+
+    let remove = dom.find_by_text("Remove").unwrap();
+    dom.click(remove);
+    let ul = dom.find_all_by_tag("ul")[0];
+    assert_eq!(dom.html(ul), "<ul><li>Item 2</li><li>Item 3</li></ul>");
+}
+
+#[test]
+fn input_sets_value() {
+    fn app() -> Element {
+        let mut name = use_signal(String::new);
+        rsx! {
+            input { id: "name", value: "{name}", oninput: move |event| name.set(event.value()) }
+            p { "Hello {name}" }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    let input = dom.find_by_id("name").unwrap();
+    dom.input(input, "Dioxus");
+
+    assert_eq!(dom.attribute(input, "value").as_deref(), Some("Dioxus"));
+    assert!(dom.find_by_text("Hello Dioxus").is_some());
+}
+
+#[tokio::test]
+async fn resources_resolve_after_suspense() {
+    fn app() -> Element {
+        let mut breed = use_signal(|| "husky");
+        rsx! {
+            button { id: "corgi", onclick: move |_| breed.set("corgi"), "Corgi" }
+            SuspenseBoundary {
+                fallback: |_| rsx! { "Loading..." },
+                Image { breed }
+            }
+        }
+    }
+
+    #[component]
+    fn Image(breed: ReadSignal<&'static str>) -> Element {
+        let src = use_resource(move || async move {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            format!("https://images.dog.ceo/{}.jpg", breed())
+        })
+        .suspend()?;
+        rsx! { img { src } }
+    }
+
+    let mut dom = TestDom::new(app);
+    assert!(dom.find_by_text("Loading...").is_some());
+
+    dom.wait_for_suspense().await;
+    let img = dom.find_all_by_tag("img")[0];
+    assert_eq!(
+        dom.attribute(img, "src").as_deref(),
+        Some("https://images.dog.ceo/husky.jpg")
+    );
+
+    let corgi = dom.find_by_id("corgi").unwrap();
+    dom.click(corgi);
+    let src = |dom: &TestDom| {
+        let img = dom.find_all_by_tag("img").first().copied()?;
+        dom.attribute(img, "src")
+    };
+    tokio::time::timeout(std::time::Duration::from_secs(1), async {
+        while src(&dom).as_deref() != Some("https://images.dog.ceo/corgi.jpg") {
+            dom.wait_for_work().await;
+        }
+    })
+    .await
+    .unwrap();
+}
+
+#[test]
+fn portal_children_stay_in_the_component_tree() {
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        use_context_provider(|| "Provided by the app");
+        rsx! {
+            div { onclick: move |_| clicks += 1,
+                "Clicks: {clicks}"
+                Portal { Child {} }
+            }
+        }
+    }
+
+    #[component]
+    fn Child() -> Element {
+        let message: &'static str = use_context();
+        rsx! {
+            button { "{message}" }
+        }
+    }
+
+    let mut dom = TestDom::new(app);
+    let button = dom.find_by_text("Provided by the app").unwrap();
+    let portal = dom.parent(button).unwrap();
+    assert_eq!(dom.attribute(portal, "dioxus-portal").as_deref(), Some(""));
+
+    // The event bubbles to the div around the portal
+    dom.click(button);
+    assert!(dom.find_by_text("Clicks: 1").is_some());
+}
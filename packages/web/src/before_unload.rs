@@ -0,0 +1,69 @@
+use dioxus_core::{Runtime, current_scope_id, use_hook};
+pub use dioxus_html::ShouldPrevent;
+use dioxus_signals::CopyValue;
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{BeforeUnloadEvent, Window};
+
+/// Register a handler that runs when the user tries to leave the page, for example by closing the tab, reloading,
+/// or navigating to another site. Return [`ShouldPrevent::Yes`] from the handler to ask the browser to show its
+/// "Leave site?" confirmation dialog.
+///
+/// This listens to the [`beforeunload`](https://developer.mozilla.org/en-US/docs/Web/API/Window/beforeunload_event)
+/// event. Browsers don't let pages customize the confirmation dialog or show their own dialog instead, and they may
+/// skip the dialog if the user never interacted with the page. The listener is removed when the component is
+/// dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::{ShouldPrevent, use_before_unload};
+/// fn App() -> Element {
+///     let mut dirty = use_signal(|| false);
+///     use_before_unload(move || match dirty() {
+///         true => ShouldPrevent::Yes,
+///         false => ShouldPrevent::No,
+///     });
+///
+///     rsx! {
+///         textarea { oninput: move |_| dirty.set(true) }
+///         button { onclick: move |_| dirty.set(false), "Save" }
+///     }
+/// }
+/// ```
+pub fn use_before_unload(mut handler: impl FnMut() -> ShouldPrevent + 'static) {
+    let runtime = Runtime::current();
+    let scope_id = current_scope_id();
+
+    // The listener is owned by the component, so dropping the component removes it
+    use_hook(move || {
+        let window = web_sys::window()?;
+        let callback =
+            Closure::<dyn FnMut(BeforeUnloadEvent)>::new(move |event: BeforeUnloadEvent| {
+                if runtime.in_scope(scope_id, &mut handler) == ShouldPrevent::Yes {
+                    event.prevent_default();
+                    // Older browsers only show the dialog if the return value is set
+                    event.set_return_value("");
+                }
+            });
+        if let Err(err) = window
+            .add_event_listener_with_callback("beforeunload", callback.as_ref().unchecked_ref())
+        {
+            tracing::error!("Failed to listen to beforeunload: {err:?}");
+        }
+
+        Some(CopyValue::new(BeforeUnloadListener { window, callback }))
+    });
+}
+
+struct BeforeUnloadListener {
+    window: Window,
+    callback: Closure<dyn FnMut(BeforeUnloadEvent)>,
+}
+
+impl Drop for BeforeUnloadListener {
+    fn drop(&mut self) {
+        _ = self.window.remove_event_listener_with_callback(
+            "beforeunload",
+            self.callback.as_ref().unchecked_ref(),
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use dioxus_core::use_hook;
+use js_sys::{Array, Object, Reflect, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, BlobPropertyBag, ClipboardItem, DomException};
+
+/// Get a handle to the system clipboard with the [async clipboard api](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API).
+///
+/// The browser only exposes the clipboard in secure contexts (https or localhost). Most browsers only allow writing
+/// while handling a user gesture like a click, and ask the user for permission before reading.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::use_clipboard;
+/// fn App() -> Element {
+///     let clipboard = use_clipboard();
+///     let mut pasted = use_signal(String::new);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| async move {
+///                 if let Err(err) = clipboard.write_text("Hello from dioxus").await {
+///                     pasted.set(err.to_string());
+///                 }
+///             },
+///             "Copy"
+///         }
+///         button {
+///             onclick: move |_| async move {
+///                 match clipboard.read_text().await {
+///                     Ok(text) => pasted.set(text),
+///                     Err(err) => pasted.set(err.to_string()),
+///                 }
+///             },
+///             "Paste"
+///         }
+///         "{pasted}"
+///     }
+/// }
+/// ```
+pub fn use_clipboard() -> Clipboard {
+    use_hook(|| Clipboard { _private: () })
+}
+
+/// A handle to the system clipboard returned by [`use_clipboard`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Clipboard {
+    _private: (),
+}
+
+impl Clipboard {
+    /// Read the text on the clipboard
+    pub async fn read_text(&self) -> Result<String, ClipboardError> {
+        let text = call(clipboard()?.read_text()).await?;
+        text.as_string()
+            .ok_or_else(|| ClipboardError::Other("The clipboard did not return text".to_string()))
+    }
+
+    /// Replace the contents of the clipboard with text
+    pub async fn write_text(&self, text: &str) -> Result<(), ClipboardError> {
+        call(clipboard()?.write_text(text)).await?;
+        Ok(())
+    }
+
+    /// Read the png image on the clipboard. Returns `None` if the clipboard doesn't contain an image.
+    pub async fn read_image(&self) -> Result<Option<Vec<u8>>, ClipboardError> {
+        let items: Array = call(clipboard()?.read()).await?.unchecked_into();
+        for item in items.iter() {
+            let item: ClipboardItem = item.unchecked_into();
+            if !item.types().includes(&JsValue::from_str(PNG), 0) {
+                continue;
+            }
+            let blob: Blob = call(item.get_type(PNG)).await?.unchecked_into();
+            let buffer = call(blob.array_buffer()).await?;
+            return Ok(Some(Uint8Array::new(&buffer).to_vec()));
+        }
+        Ok(None)
+    }
+
+    /// Replace the contents of the clipboard with a png image
+    pub async fn write_image(&self, png: &[u8]) -> Result<(), ClipboardError> {
+        let clipboard = clipboard()?;
+        let options = BlobPropertyBag::new();
+        options.set_type(PNG);
+        let blob = Blob::new_with_u8_array_sequence_and_options(
+            &Array::of1(&Uint8Array::from(png)),
+            &options,
+        )
+        .map_err(ClipboardError::from_js)?;
+        let record = Object::new();
+        Reflect::set(&record, &JsValue::from_str(PNG), &blob).map_err(ClipboardError::from_js)?;
+        let item = ClipboardItem::new_with_record_from_str_to_blob_promise(&record)
+            .map_err(ClipboardError::from_js)?;
+        call(clipboard.write(&Array::of1(&item))).await?;
+        Ok(())
+    }
+}
+
+const PNG: &str = "image/png";
+
+fn clipboard() -> Result<web_sys::Clipboard, ClipboardError> {
+    let navigator = web_sys::window()
+        .ok_or(ClipboardError::Unavailable)?
+        .navigator();
+    // The clipboard is undefined outside of secure contexts
+    let clipboard = Reflect::get(&navigator, &JsValue::from_str("clipboard"))
+        .map_err(ClipboardError::from_js)?;
+    if clipboard.is_undefined() {
+        return Err(ClipboardError::Unavailable);
+    }
+    Ok(clipboard.unchecked_into())
+}
+
+async fn call(promise: js_sys::Promise) -> Result<JsValue, ClipboardError> {
+    JsFuture::from(promise)
+        .await
+        .map_err(ClipboardError::from_js)
+}
+
+/// An error that occurred while using the clipboard
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipboardError {
+    /// The clipboard is not available on this platform or in this context
+    Unavailable,
+    /// The user or the browser denied access to the clipboard
+    PermissionDenied,
+    /// Any other error from the clipboard
+    Other(String),
+}
+
+impl ClipboardError {
+    fn from_js(err: JsValue) -> Self {
+        match err.dyn_ref::<DomException>() {
+            Some(err) if err.name() == "NotAllowedError" => Self::PermissionDenied,
+            Some(err) if err.name() == "NotSupportedError" => Self::Unavailable,
+            Some(err) => Self::Other(err.message()),
+            None => Self::Other(format!("{err:?}")),
+        }
+    }
+}
+
+impl std::fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "The clipboard is not available"),
+            Self::PermissionDenied => write!(f, "Permission to use the clipboard was denied"),
+            Self::Other(err) => write!(f, "Failed to use the clipboard: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
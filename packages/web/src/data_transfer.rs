@@ -21,6 +21,15 @@ impl NativeDataTransfer for WebDataTransfer {
     fn get_data(&self, format: &str) -> Option<String> {
         self.data.get_data(format).ok()
     }
+    fn types(&self) -> Vec<String> {
+        self.data
+            .types()
+            .iter()
+            .filter_map(|format| format.as_string())
+            // "Files" is a marker the browser adds when files are attached, the files are exposed separately
+            .filter(|format| format != "Files")
+            .collect()
+    }
     fn set_data(&self, format: &str, data: &str) -> Result<(), String> {
         self.data.set_data(format, data).map_err(|e| {
             format!(
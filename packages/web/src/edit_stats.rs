@@ -0,0 +1,85 @@
+use std::cell::Cell;
+
+/// Statistics about the edits the web renderer applied to the dom.
+///
+/// Edits are encoded into a binary buffer as the virtual dom is diffed, and the whole buffer is decoded and applied
+/// in a single call into javascript when the edits are flushed. These stats show how many edits end up in each flush
+/// and how long applying them takes. The [`all_events`](https://github.com/DioxusLabs/dioxus/blob/main/examples/09-reference/all_events.rs)
+/// example, which updates a list of 20 items on every event, is a good benchmark.
+///
+/// The stats are only collected when the `instrument` feature is enabled.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EditStats {
+    /// The number of times edits were flushed to the dom. Each flush is a single call into javascript.
+    pub flushes: u64,
+    /// The total number of edits that were applied
+    pub edits: u64,
+    /// The number of edits in the last flush
+    pub last_flush_edits: u64,
+    /// How long the last flush took to decode and apply in milliseconds
+    pub last_flush_ms: f64,
+    /// How long all flushes took to decode and apply in milliseconds
+    pub total_flush_ms: f64,
+}
+
+impl EditStats {
+    /// The average number of edits in each flush
+    pub fn edits_per_flush(&self) -> f64 {
+        match self.flushes {
+            0 => 0.0,
+            flushes => self.edits as f64 / flushes as f64,
+        }
+    }
+}
+
+thread_local! {
+    static STATS: Cell<EditStats> = const {
+        Cell::new(EditStats {
+            flushes: 0,
+            edits: 0,
+            last_flush_edits: 0,
+            last_flush_ms: 0.0,
+            total_flush_ms: 0.0,
+        })
+    };
+    static PENDING_EDITS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Get the edit stats collected since the app started or the stats were last reset
+pub fn edit_stats() -> EditStats {
+    STATS.with(Cell::get)
+}
+
+/// Reset the edit stats to zero. This is useful to measure a single interaction.
+pub fn reset_edit_stats() {
+    STATS.with(|stats| stats.set(EditStats::default()));
+}
+
+/// Count an edit that was written into the buffer for the next flush
+#[inline]
+pub(crate) fn record_edit() {
+    PENDING_EDITS.with(|pending| pending.set(pending.get() + 1));
+}
+
+/// Record a flush of all pending edits that took `ms` milliseconds
+pub(crate) fn record_flush(ms: f64) {
+    let edits = PENDING_EDITS.with(|pending| pending.replace(0));
+    STATS.with(|stats| {
+        let mut current = stats.get();
+        current.flushes += 1;
+        current.edits += edits;
+        current.last_flush_edits = edits;
+        current.last_flush_ms = ms;
+        current.total_flush_ms += ms;
+        stats.set(current);
+    });
+    tracing::debug!(edits, ms, "flushed edits to the dom");
+}
+
+/// The current time in milliseconds from the high resolution timer
+pub(crate) fn now() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now())
+        .unwrap_or_default()
+}
@@ -142,8 +142,100 @@ impl dioxus_html::RenderedElementBacking for Synthetic<web_sys::Element> {
             });
         Box::pin(async { result })
     }
+
+    fn show_modal(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = dioxus_html::MountedResult<()>>>> {
+        let result = self.dialog().and_then(|dialog| {
+            if dialog.open() {
+                return Ok(());
+            }
+            dialog.show_modal().map_err(|err| {
+                dioxus_html::MountedError::OperationFailed(Box::new(DialogError(err)))
+            })
+        });
+        Box::pin(async { result })
+    }
+
+    fn close(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = dioxus_html::MountedResult<()>>>> {
+        let result = self.dialog().map(|dialog| dialog.close());
+        Box::pin(async { result })
+    }
+
+    fn set_pointer_capture(
+        &self,
+        pointer_id: i32,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = dioxus_html::MountedResult<()>>>> {
+        let result = self.event.set_pointer_capture(pointer_id).map_err(|err| {
+            dioxus_html::MountedError::OperationFailed(Box::new(PointerCaptureError(err)))
+        });
+        Box::pin(async { result })
+    }
+
+    fn release_pointer_capture(
+        &self,
+        pointer_id: i32,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = dioxus_html::MountedResult<()>>>> {
+        let result = self
+            .event
+            .release_pointer_capture(pointer_id)
+            .map_err(|err| {
+                dioxus_html::MountedError::OperationFailed(Box::new(PointerCaptureError(err)))
+            });
+        Box::pin(async { result })
+    }
+
+    fn trap_focus(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = dioxus_html::MountedResult<()>>>> {
+        dioxus_interpreter_js::minimal_bindings::trapFocus(&self.event);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn release_focus_trap(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = dioxus_html::MountedResult<()>>>> {
+        dioxus_interpreter_js::minimal_bindings::releaseFocusTrap(&self.event);
+        Box::pin(async { Ok(()) })
+    }
 }
 
+impl Synthetic<web_sys::Element> {
+    fn dialog(&self) -> dioxus_html::MountedResult<&web_sys::HtmlDialogElement> {
+        self.event
+            .dyn_ref::<web_sys::HtmlDialogElement>()
+            .ok_or_else(|| {
+                dioxus_html::MountedError::OperationFailed(Box::new(DialogError(
+                    self.event.clone().into(),
+                )))
+            })
+    }
+}
+
+#[derive(Debug)]
+struct DialogError(wasm_bindgen::JsValue);
+
+impl std::fmt::Display for DialogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to open or close dialog {:?}", self.0)
+    }
+}
+
+impl std::error::Error for DialogError {}
+
+#[derive(Debug)]
+struct PointerCaptureError(wasm_bindgen::JsValue);
+
+impl std::fmt::Display for PointerCaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to set or release pointer capture {:?}", self.0)
+    }
+}
+
+impl std::error::Error for PointerCaptureError {}
+
 impl WebEventExt for MountedData {
     type WebEvent = web_sys::Element;
 
@@ -0,0 +1,291 @@
+use crate::WebEventExt;
+use dioxus_core::{Attribute, use_drop, use_hook};
+use dioxus_html::{MountedData, MountedEvent, SurfaceSize};
+use dioxus_signals::{CopyValue, ReadSignal, ReadableExt, Signal, WritableExt};
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue, closure::Closure};
+use web_sys::{
+    HtmlCanvasElement, ResizeObserver, ResizeObserverBoxOptions, ResizeObserverEntry,
+    ResizeObserverOptions, ResizeObserverSize,
+};
+
+/// Get a handle to a `canvas` element that you can render to with wgpu or WebGL.
+///
+/// Spread [`GpuCanvas::attributes`] onto a `canvas` element, then create a surface from [`GpuCanvas::canvas`] with
+/// `wgpu::SurfaceTarget::Canvas`. [`GpuCanvas::size`] tracks the size of the canvas on screen in physical pixels,
+/// including changes to the `devicePixelRatio` when the page is zoomed or moved to another display. The drawing
+/// buffer of the canvas is resized to match, so you only need to configure the surface with the new size.
+///
+/// ## Lifetime of the surface
+///
+/// The surface renders to the canvas element until Dioxus removes it from the page. Dioxus may replace the canvas
+/// with a new element when it rerenders, in which case [`GpuCanvas::canvas`] returns the new element and you need
+/// to create a new surface for it. Keep the surface in state that reads [`GpuCanvas::canvas`], like a resource, so
+/// it is recreated with the element and dropped with the component.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::use_gpu_canvas;
+/// fn App() -> Element {
+///     let canvas = use_gpu_canvas();
+///
+///     // Create a new renderer every time the canvas element changes
+///     let renderer = use_resource(move || async move {
+///         let element = canvas.canvas()?;
+///         let instance = wgpu::Instance::default();
+///         let surface = instance.create_surface(wgpu::SurfaceTarget::Canvas(element)).ok()?;
+///         Some(Renderer::new(instance, surface).await)
+///     });
+///
+///     // Configure the surface when the canvas is resized
+///     use_effect(move || {
+///         let size = canvas.size()();
+///         if let Some(Some(renderer)) = &*renderer.read() {
+///             renderer.resize(size.width, size.height);
+///         }
+///     });
+///
+///     rsx! {
+///         canvas { style: "width: 100%; height: 100%;", ..canvas.attributes() }
+///         div { class: "overlay", "Dioxus UI on top of the 3D scene" }
+///     }
+/// }
+/// ```
+pub fn use_gpu_canvas() -> GpuCanvas {
+    let canvas = use_hook(|| GpuCanvas {
+        canvas: Signal::new(None),
+        size: Signal::new(SurfaceSize::default()),
+        observer: CopyValue::new(None),
+    });
+
+    use_drop(move || canvas.detach());
+
+    canvas
+}
+
+/// A handle to a `canvas` element for a GPU surface, created with [`use_gpu_canvas`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct GpuCanvas {
+    canvas: Signal<Option<HtmlCanvasElement>>,
+    size: Signal<SurfaceSize>,
+    observer: CopyValue<Option<CanvasObserver>>,
+}
+
+impl GpuCanvas {
+    /// The attributes to spread onto the `canvas` element
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let handle = *self;
+        vec![dioxus_html::events::onmounted(
+            move |event: MountedEvent| handle.attach(event.data()),
+        )]
+    }
+
+    /// Use `element` as the canvas of the handle. Call this from your own `onmounted` handler if you can't spread
+    /// [`GpuCanvas::attributes`] onto the element.
+    pub fn attach(&self, element: Rc<MountedData>) {
+        let Some(canvas) = element
+            .try_as_web_event()
+            .and_then(|element| element.dyn_into::<HtmlCanvasElement>().ok())
+        else {
+            tracing::error!("use_gpu_canvas must be attached to a canvas element");
+            return;
+        };
+        self.detach();
+
+        let mut handle = *self;
+        let size = canvas_size(&canvas);
+        resize_buffer(&canvas, size);
+        handle.size.set(size);
+        let observer = CanvasObserver::new(&canvas, handle.size);
+        handle.observer.set(observer);
+        handle.canvas.set(Some(canvas));
+    }
+
+    /// Get the canvas element, or `None` if it hasn't been mounted yet.
+    ///
+    /// Reading this will subscribe the current scope to changes in the element.
+    pub fn canvas(&self) -> Option<HtmlCanvasElement> {
+        self.canvas.cloned()
+    }
+
+    /// Track the size of the canvas in physical pixels
+    pub fn size(&self) -> ReadSignal<SurfaceSize> {
+        self.size.into()
+    }
+
+    /// Stop observing the current canvas, if there is one
+    fn detach(&self) {
+        // The observer may already be dropped if the component is being dropped
+        let mut observer = self.observer;
+        if let Ok(mut observer) = observer.try_write() {
+            observer.take();
+        }
+    }
+}
+
+/// A resize observer on the canvas that is disconnected when it is dropped
+struct CanvasObserver {
+    observer: ResizeObserver,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl CanvasObserver {
+    fn new(canvas: &HtmlCanvasElement, mut size: Signal<SurfaceSize>) -> Option<Self> {
+        let device_pixels = supports_device_pixel_box();
+        let callback = Closure::<dyn FnMut(js_sys::Array)>::new({
+            let canvas = canvas.clone();
+            move |entries: js_sys::Array| {
+                let Ok(entry) = entries.get(0).dyn_into::<ResizeObserverEntry>() else {
+                    return;
+                };
+                let new = match device_pixels {
+                    true => entry_size(&entry).unwrap_or_else(|| canvas_size(&canvas)),
+                    false => canvas_size(&canvas),
+                };
+                resize_buffer(&canvas, new);
+                if new != *size.peek() {
+                    size.set(new);
+                }
+            }
+        });
+
+        let observer = match ResizeObserver::new(callback.as_ref().unchecked_ref()) {
+            Ok(observer) => observer,
+            Err(err) => {
+                tracing::error!("Failed to observe the size of the canvas: {err:?}");
+                return None;
+            }
+        };
+        // The device pixel box also changes when the devicePixelRatio changes, even if the css size doesn't
+        let options = ResizeObserverOptions::new();
+        if device_pixels {
+            options.set_box(ResizeObserverBoxOptions::DevicePixelContentBox);
+        }
+        observer.observe_with_options(canvas, &options);
+
+        Some(Self {
+            observer,
+            _callback: callback,
+        })
+    }
+}
+
+impl Drop for CanvasObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Check if the browser can report the size of elements in device pixels. Safari doesn't support it, and throws if
+/// you try to observe it.
+fn supports_device_pixel_box() -> bool {
+    js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("ResizeObserverEntry"))
+        .and_then(|entry| js_sys::Reflect::get(&entry, &JsValue::from_str("prototype")))
+        .and_then(|prototype| {
+            js_sys::Reflect::has(&prototype, &JsValue::from_str("devicePixelContentBoxSize"))
+        })
+        .unwrap_or(false)
+}
+
+/// The exact size of the element in device pixels, if the browser reported it
+fn entry_size(entry: &ResizeObserverEntry) -> Option<SurfaceSize> {
+    let size = entry
+        .device_pixel_content_box_size()
+        .get(0)
+        .dyn_into::<ResizeObserverSize>()
+        .ok()?;
+    Some(SurfaceSize {
+        width: size.inline_size() as u32,
+        height: size.block_size() as u32,
+        scale_factor: device_pixel_ratio(),
+    })
+}
+
+/// Estimate the size of the element in device pixels from its css size
+fn canvas_size(canvas: &HtmlCanvasElement) -> SurfaceSize {
+    let rect = canvas.get_bounding_client_rect();
+    scaled_size(rect.width(), rect.height(), device_pixel_ratio())
+}
+
+/// Convert a size in css pixels to device pixels
+fn scaled_size(width: f64, height: f64, scale_factor: f64) -> SurfaceSize {
+    SurfaceSize {
+        width: (width * scale_factor).round() as u32,
+        height: (height * scale_factor).round() as u32,
+        scale_factor,
+    }
+}
+
+/// Match the drawing buffer to the size on screen so the canvas isn't stretched or blurry
+fn resize_buffer(canvas: &HtmlCanvasElement, size: SurfaceSize) {
+    if canvas.width() != size.width {
+        canvas.set_width(size.width);
+    }
+    if canvas.height() != size.height {
+        canvas.set_height(size.height);
+    }
+}
+
+fn device_pixel_ratio() -> f64 {
+    web_sys::window()
+        .map(|window| window.device_pixel_ratio())
+        .unwrap_or(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_pixels_are_scaled_to_device_pixels() {
+        assert_eq!(
+            scaled_size(300.0, 150.0, 2.0),
+            SurfaceSize {
+                width: 600,
+                height: 300,
+                scale_factor: 2.0,
+            }
+        );
+    }
+
+    #[test]
+    fn fractional_sizes_are_rounded() {
+        let size = scaled_size(100.5, 33.3, 1.5);
+        assert_eq!((size.width, size.height), (151, 50));
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    mod browser {
+        use super::*;
+        use wasm_bindgen_test::*;
+
+        wasm_bindgen_test_configure!(run_in_browser);
+
+        fn canvas(style: &str) -> HtmlCanvasElement {
+            let document = web_sys::window().unwrap().document().unwrap();
+            let canvas: HtmlCanvasElement =
+                document.create_element("canvas").unwrap().unchecked_into();
+            canvas.set_attribute("style", style).unwrap();
+            document.body().unwrap().append_child(&canvas).unwrap();
+            canvas
+        }
+
+        #[wasm_bindgen_test]
+        fn canvas_size_uses_the_size_on_screen() {
+            let canvas = canvas("width: 120px; height: 80px;");
+            let ratio = device_pixel_ratio();
+            assert_eq!(canvas_size(&canvas), scaled_size(120.0, 80.0, ratio));
+            canvas.remove();
+        }
+
+        #[wasm_bindgen_test]
+        fn the_drawing_buffer_matches_the_size() {
+            let canvas = canvas("width: 120px; height: 80px;");
+            resize_buffer(&canvas, scaled_size(120.0, 80.0, 2.0));
+            assert_eq!((canvas.width(), canvas.height()), (240, 160));
+            canvas.remove();
+        }
+    }
+}
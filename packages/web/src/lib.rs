@@ -33,6 +33,41 @@ pub use files::*;
 mod data_transfer;
 pub use data_transfer::*;
 
+mod clipboard;
+pub use clipboard::*;
+
+mod media_query;
+pub use media_query::*;
+
+mod before_unload;
+pub use before_unload::*;
+
+mod page_visibility;
+pub use page_visibility::*;
+
+mod window_metrics;
+pub use window_metrics::*;
+
+#[cfg(feature = "mounted")]
+mod viewport;
+#[cfg(feature = "mounted")]
+pub use viewport::*;
+
+#[cfg(feature = "mounted")]
+mod gpu_canvas;
+#[cfg(feature = "mounted")]
+pub use gpu_canvas::*;
+
+#[cfg(feature = "sse")]
+mod server_sent_events;
+#[cfg(feature = "sse")]
+pub use server_sent_events::*;
+
+#[cfg(feature = "instrument")]
+mod edit_stats;
+#[cfg(feature = "instrument")]
+pub use edit_stats::*;
+
 #[cfg(all(feature = "devtools", debug_assertions))]
 mod devtools;
 
@@ -0,0 +1,70 @@
+use dioxus_core::use_hook;
+use dioxus_signals::{CopyValue, ReadSignal, Signal, WritableExt};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{MediaQueryList, MediaQueryListEvent};
+
+/// Track whether a css media query matches with [`window.matchMedia`](https://developer.mozilla.org/en-US/docs/Web/API/Window/matchMedia).
+///
+/// The signal starts with the current match state and updates whenever it changes. The query is read when the
+/// component is created and the listener is removed when the component is dropped. If the query is invalid, the
+/// signal is always `false`.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::use_media_query;
+/// fn App() -> Element {
+///     let dark = use_media_query("(prefers-color-scheme: dark)");
+///     let mobile = use_media_query("(max-width: 600px)");
+///
+///     rsx! {
+///         div {
+///             class: if dark() { "dark" } else { "light" },
+///             if mobile() { "Mobile layout" } else { "Desktop layout" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_media_query(query: &str) -> ReadSignal<bool> {
+    // The listener is owned by the component, so dropping the component removes it
+    let (matches, _) = use_hook(|| {
+        let list = web_sys::window().and_then(|window| match window.match_media(query) {
+            Ok(list) => list,
+            Err(err) => {
+                tracing::error!("Invalid media query {query:?}: {err:?}");
+                None
+            }
+        });
+        let Some(list) = list else {
+            return (Signal::new(false), CopyValue::new(None));
+        };
+
+        let mut matches = Signal::new(list.matches());
+        let callback =
+            Closure::<dyn FnMut(MediaQueryListEvent)>::new(move |event: MediaQueryListEvent| {
+                matches.set(event.matches())
+            });
+        if let Err(err) =
+            list.add_event_listener_with_callback("change", callback.as_ref().unchecked_ref())
+        {
+            tracing::error!("Failed to listen to media query {query:?}: {err:?}");
+        }
+
+        let listener = CopyValue::new(Some(MediaQueryListener { list, callback }));
+        (matches, listener)
+    });
+
+    matches.into()
+}
+
+struct MediaQueryListener {
+    list: MediaQueryList,
+    callback: Closure<dyn FnMut(MediaQueryListEvent)>,
+}
+
+impl Drop for MediaQueryListener {
+    fn drop(&mut self) {
+        _ = self
+            .list
+            .remove_event_listener_with_callback("change", self.callback.as_ref().unchecked_ref());
+    }
+}
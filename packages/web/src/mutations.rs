@@ -51,7 +51,15 @@ impl WebsysDom {
     }
 
     pub fn flush_edits(&mut self) {
+        // Every edit since the last flush was encoded into the interpreter's binary buffer. Flushing sends the whole
+        // buffer to javascript in a single call where it is decoded and applied.
+        #[cfg(feature = "instrument")]
+        let _span = tracing::debug_span!("flush_edits").entered();
+        #[cfg(feature = "instrument")]
+        let start = crate::edit_stats::now();
         self.interpreter.flush();
+        #[cfg(feature = "instrument")]
+        crate::edit_stats::record_flush(crate::edit_stats::now() - start);
 
         // Now that we've flushed the edits and the dom nodes exist, we can send the mounted events.
         #[cfg(feature = "mounted")]
@@ -91,18 +99,29 @@ impl WebsysDom {
             false
         }
     }
+
+    /// Check if an edit should be skipped. Edits that are written to the interpreter are counted in the edit stats.
+    #[inline]
+    fn skip_edit(&self) -> bool {
+        if self.skip_mutations() {
+            return true;
+        }
+        #[cfg(feature = "instrument")]
+        crate::edit_stats::record_edit();
+        false
+    }
 }
 
 impl WriteMutations for WebsysDom {
     fn append_children(&mut self, id: ElementId, m: usize) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.append_children(id.0 as u32, m as u16)
     }
 
     fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter
@@ -110,21 +129,21 @@ impl WriteMutations for WebsysDom {
     }
 
     fn create_placeholder(&mut self, id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.create_placeholder(id.0 as u32)
     }
 
     fn create_text_node(&mut self, value: &str, id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.create_text_node(value, id.0 as u32)
     }
 
     fn load_template(&mut self, template: Template, index: usize, id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         let tmpl_id = self.templates.get(&template).cloned().unwrap_or_else(|| {
@@ -143,14 +162,14 @@ impl WriteMutations for WebsysDom {
     }
 
     fn replace_node_with(&mut self, id: ElementId, m: usize) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.replace_with(id.0 as u32, m as u16)
     }
 
     fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter
@@ -158,14 +177,14 @@ impl WriteMutations for WebsysDom {
     }
 
     fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.insert_after(id.0 as u32, m as u16)
     }
 
     fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.insert_before(id.0 as u32, m as u16)
@@ -178,7 +197,7 @@ impl WriteMutations for WebsysDom {
         value: &AttributeValue,
         id: ElementId,
     ) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         match value {
@@ -213,14 +232,14 @@ impl WriteMutations for WebsysDom {
     }
 
     fn set_node_text(&mut self, value: &str, id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.set_text(id.0 as u32, value)
     }
 
     fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         // mounted events are fired immediately after the element is mounted.
@@ -235,7 +254,7 @@ impl WriteMutations for WebsysDom {
     }
 
     fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         if name == "mounted" {
@@ -247,14 +266,14 @@ impl WriteMutations for WebsysDom {
     }
 
     fn remove_node(&mut self, id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.remove(id.0 as u32)
     }
 
     fn push_root(&mut self, id: ElementId) {
-        if self.skip_mutations() {
+        if self.skip_edit() {
             return;
         }
         self.interpreter.push_root(id.0 as u32)
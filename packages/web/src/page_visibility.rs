@@ -0,0 +1,142 @@
+use dioxus_core::{Callback, use_hook};
+use dioxus_html::PageVisibility;
+use dioxus_signals::{CopyValue, ReadSignal, ReadableExt, Signal, WritableExt};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{Document, Event};
+
+/// Track whether the page is visible with the
+/// [`visibilitychange`](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilitychange_event) event.
+///
+/// The page is hidden when the tab is in the background, the browser is minimized, or the screen is locked. The
+/// signal starts with the current visibility and the listener is removed when the component is dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::use_page_visibility;
+/// fn App() -> Element {
+///     let visibility = use_page_visibility();
+///
+///     rsx! {
+///         // Only run the animation while someone can see it
+///         div { class: if visibility().is_visible() { "spinner" } else { "spinner paused" } }
+///     }
+/// }
+/// ```
+pub fn use_page_visibility() -> ReadSignal<PageVisibility> {
+    // The listener is owned by the component, so dropping the component removes it
+    let (visibility, _) = use_hook(|| {
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            return (Signal::new(PageVisibility::Visible), CopyValue::new(None));
+        };
+
+        let mut visibility = Signal::new(page_visibility(&document));
+        let callback = Closure::<dyn FnMut(Event)>::new({
+            let document = document.clone();
+            move |_: Event| {
+                let new = page_visibility(&document);
+                if new != *visibility.peek() {
+                    visibility.set(new);
+                }
+            }
+        });
+        let listener = DocumentListener::new(document, "visibilitychange", callback);
+        (visibility, CopyValue::new(listener))
+    });
+
+    visibility.into()
+}
+
+fn page_visibility(document: &Document) -> PageVisibility {
+    match document.hidden() {
+        true => PageVisibility::Hidden,
+        false => PageVisibility::Visible,
+    }
+}
+
+/// A change in the [lifecycle](https://developer.chrome.com/docs/web-platform/page-lifecycle-api) of the page
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PageLifecycle {
+    /// The browser is about to freeze the page to save resources. Timers and tasks stop running until the page
+    /// resumes, so save any state you need now.
+    Freeze,
+    /// The page resumed after being frozen
+    Resume,
+}
+
+/// Register a handler that runs when the browser freezes or resumes the page with the
+/// [`freeze`](https://developer.mozilla.org/en-US/docs/Web/API/Document/freeze_event) and
+/// [`resume`](https://developer.mozilla.org/en-US/docs/Web/API/Document/resume_event) events.
+///
+/// Browsers freeze pages that have been hidden for a while. Only Chromium based browsers support these events, so
+/// the handler never runs in other browsers. Use [`use_page_visibility`] to react to the page being hidden in every
+/// browser. The listeners are removed when the component is dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::{PageLifecycle, use_page_lifecycle};
+/// fn App() -> Element {
+///     use_page_lifecycle(|lifecycle| match lifecycle {
+///         PageLifecycle::Freeze => tracing::info!("Saving the draft before the page is frozen"),
+///         PageLifecycle::Resume => tracing::info!("Welcome back"),
+///     });
+///
+///     rsx! { textarea {} }
+/// }
+/// ```
+pub fn use_page_lifecycle(handler: impl FnMut(PageLifecycle) + 'static) {
+    // Replace the handler every render so the listeners call the latest closure
+    let mut handler_callback = use_hook(|| Callback::new(|_: PageLifecycle| {}));
+    handler_callback.replace(Box::new(handler));
+
+    // The listeners are owned by the component, so dropping the component removes them
+    use_hook(move || {
+        let document = web_sys::window().and_then(|window| window.document())?;
+        let listen = |event: &'static str, lifecycle: PageLifecycle| {
+            let callback = Closure::<dyn FnMut(Event)>::new(move |_: Event| {
+                handler_callback.call(lifecycle);
+            });
+            DocumentListener::new(document.clone(), event, callback)
+        };
+
+        Some(CopyValue::new((
+            listen("freeze", PageLifecycle::Freeze),
+            listen("resume", PageLifecycle::Resume),
+        )))
+    });
+}
+
+/// An event listener on the document that is removed when it is dropped
+struct DocumentListener {
+    document: Document,
+    event: &'static str,
+    callback: Closure<dyn FnMut(Event)>,
+}
+
+impl DocumentListener {
+    fn new(
+        document: Document,
+        event: &'static str,
+        callback: Closure<dyn FnMut(Event)>,
+    ) -> Option<Self> {
+        if let Err(err) =
+            document.add_event_listener_with_callback(event, callback.as_ref().unchecked_ref())
+        {
+            tracing::error!("Failed to listen to {event} events: {err:?}");
+            return None;
+        }
+        Some(Self {
+            document,
+            event,
+            callback,
+        })
+    }
+}
+
+impl Drop for DocumentListener {
+    fn drop(&mut self) {
+        _ = self.document.remove_event_listener_with_callback(
+            self.event,
+            self.callback.as_ref().unchecked_ref(),
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use dioxus_core::use_hook;
+pub use dioxus_html::{SseEvent, SseState};
+use dioxus_signals::{CopyValue, ReadSignal, ReadableExt, Signal, WritableExt};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{Event, EventSource, MessageEvent};
+
+/// Subscribe to a [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events) endpoint
+/// with an [`EventSource`](https://developer.mozilla.org/en-US/docs/Web/API/EventSource).
+///
+/// The connection is opened when the component is created and closed when the component is dropped. Every unnamed
+/// event the server sends is stored in [`ServerSentEvents::latest`]. If the connection is lost, the browser reconnects
+/// automatically and sends the id of the last event it received in the `Last-Event-ID` header. If the component is
+/// rendered with a different `url`, the connection is reopened with the new url.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::{SseState, use_server_sent_events};
+/// fn App() -> Element {
+///     let events = use_server_sent_events("/notifications");
+///
+///     rsx! {
+///         if events.state() == SseState::Connecting {
+///             "Connecting..."
+///         }
+///         if let Some(event) = events.latest() {
+///             "Latest notification: {event.data}"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_server_sent_events(url: &str) -> ServerSentEvents {
+    use_named_server_sent_events(url, &["message"])
+}
+
+/// Subscribe to named events from a server-sent events endpoint.
+///
+/// This works like [`use_server_sent_events`], but listens for events sent with one of the `event:` names in
+/// `events` instead of unnamed events. Include `"message"` in the list to receive unnamed events as well. The name
+/// of each event is stored in [`SseEvent::event`].
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::use_named_server_sent_events;
+/// fn App() -> Element {
+///     let events = use_named_server_sent_events("/updates", &["created", "deleted"]);
+///
+///     rsx! {
+///         if let Some(event) = events.latest() {
+///             "{event.event}: {event.data}"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_named_server_sent_events(url: &str, events: &[&str]) -> ServerSentEvents {
+    let mut handle = use_hook(|| {
+        let handle = ServerSentEvents {
+            latest: Signal::new(None),
+            state: Signal::new(SseState::Connecting),
+            url: CopyValue::new(url.to_string()),
+            events: CopyValue::new(events.iter().map(|event| event.to_string()).collect()),
+            connection: CopyValue::new(None),
+        };
+        handle.open();
+        handle
+    });
+
+    // Reopen the connection if the component passes in a new url
+    if *handle.url.peek() != url {
+        handle.url.set(url.to_string());
+        handle.reconnect();
+    }
+
+    handle
+}
+
+/// A handle to a server-sent events connection created with [`use_server_sent_events`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ServerSentEvents {
+    latest: Signal<Option<SseEvent>>,
+    state: Signal<SseState>,
+    url: CopyValue<String>,
+    events: CopyValue<Vec<String>>,
+    connection: CopyValue<Option<Connection>>,
+}
+
+impl ServerSentEvents {
+    /// Get the latest event the server sent, if any.
+    ///
+    /// Reading this will subscribe the current scope to new events.
+    pub fn latest(&self) -> Option<SseEvent> {
+        self.latest.cloned()
+    }
+
+    /// Get the state of the connection.
+    ///
+    /// Reading this will subscribe the current scope to changes in the state.
+    pub fn state(&self) -> SseState {
+        self.state.cloned()
+    }
+
+    /// Close the connection. The connection will not reconnect until [`ServerSentEvents::reconnect`] is called.
+    pub fn close(&mut self) {
+        self.connection.set(None);
+        self.state.set(SseState::Closed);
+    }
+
+    /// Close the current connection and open a new one
+    pub fn reconnect(&mut self) {
+        self.connection.set(None);
+        self.state.set(SseState::Connecting);
+        self.open();
+    }
+
+    fn open(&self) {
+        let mut latest = self.latest;
+        let mut state = self.state;
+        let mut connection = self.connection;
+        let url = self.url.peek();
+
+        let source = match EventSource::new(&url) {
+            Ok(source) => source,
+            Err(err) => {
+                tracing::error!("Failed to open server-sent events connection to {url:?}: {err:?}");
+                state.set(SseState::Closed);
+                return;
+            }
+        };
+
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let id = event.last_event_id();
+            latest.set(Some(SseEvent {
+                event: event.type_(),
+                data: event.data().as_string().unwrap_or_default(),
+                id: (!id.is_empty()).then_some(id),
+            }));
+        });
+        for event in self.events.peek().iter() {
+            if let Err(err) =
+                source.add_event_listener_with_callback(event, on_message.as_ref().unchecked_ref())
+            {
+                tracing::error!("Failed to listen to server-sent event {event:?}: {err:?}");
+            }
+        }
+
+        let on_open = Closure::<dyn FnMut(Event)>::new(move |_| state.set(SseState::Open));
+        source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+
+        // The browser reconnects automatically unless the server responded with an error
+        let on_error = Closure::<dyn FnMut(Event)>::new({
+            let source = source.clone();
+            move |_| match source.ready_state() {
+                EventSource::CLOSED => state.set(SseState::Closed),
+                _ => state.set(SseState::Connecting),
+            }
+        });
+        source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        connection.set(Some(Connection {
+            source,
+            _on_message: on_message,
+            _on_open: on_open,
+            _on_error: on_error,
+        }));
+    }
+}
+
+impl From<ServerSentEvents> for ReadSignal<Option<SseEvent>> {
+    fn from(events: ServerSentEvents) -> Self {
+        events.latest.into()
+    }
+}
+
+struct Connection {
+    source: EventSource,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_error: Closure<dyn FnMut(Event)>,
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.source.close();
+    }
+}
@@ -0,0 +1,127 @@
+use crate::WebEventExt;
+use dioxus_core::{Callback, use_hook};
+use dioxus_html::MountedEvent;
+use dioxus_signals::{CopyValue, ReadSignal, Signal, WritableExt};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+/// Options for [`use_in_viewport`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InViewportOptions {
+    /// How much of the element needs to be visible before it is considered in the viewport, from `0.0` to `1.0`.
+    /// A threshold of `0.0` means a single visible pixel is enough.
+    pub threshold: f64,
+
+    /// A css margin around the viewport that grows or shrinks the area the element is checked against, for
+    /// example `"200px"` to start loading content before it scrolls into view.
+    pub root_margin: String,
+}
+
+impl Default for InViewportOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 0.0,
+            root_margin: "0px".to_string(),
+        }
+    }
+}
+
+/// Track whether an element is inside the viewport with an [`IntersectionObserver`].
+///
+/// Returns an `onmounted` handler and a signal that is `true` while the element is in the viewport. Attach the
+/// handler to the element you want to observe. If the options change, the element is observed again with the new
+/// options. The observer is disconnected when the component is dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::{use_in_viewport, InViewportOptions};
+/// fn LazyImage(src: String) -> Element {
+///     let (onmounted, visible) = use_in_viewport(InViewportOptions {
+///         root_margin: "200px".to_string(),
+///         ..Default::default()
+///     });
+///
+///     rsx! {
+///         div { onmounted,
+///             if visible() {
+///                 img { src }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_in_viewport(options: InViewportOptions) -> (Callback<MountedEvent>, ReadSignal<bool>) {
+    let visible = use_hook(|| Signal::new(false));
+    let mut observer = use_hook(|| CopyValue::new(None::<ViewportObserver>));
+    let mut current = use_hook(|| CopyValue::new(options.clone()));
+
+    // Observe the element again with the new options if they change after it is mounted
+    if *current.peek() != options {
+        current.set(options.clone());
+        let element = observer.peek().as_ref().map(|old| old.element.clone());
+        if let Some(element) = element {
+            observer.set(observe(element, &options, visible));
+        }
+    }
+
+    let onmounted = use_hook(|| {
+        Callback::new(move |event: MountedEvent| {
+            let Some(element) = event.try_as_web_event() else {
+                tracing::warn!("use_in_viewport can only observe elements rendered by dioxus-web");
+                return;
+            };
+
+            // Replacing the old observer disconnects it if the handler is attached to a new element
+            let options = current.peek().clone();
+            observer.set(observe(element, &options, visible));
+        })
+    });
+
+    (onmounted, visible.into())
+}
+
+/// Start observing `element` with a new [`IntersectionObserver`] that updates `visible`
+fn observe(
+    element: web_sys::Element,
+    options: &InViewportOptions,
+    mut visible: Signal<bool>,
+) -> Option<ViewportObserver> {
+    let callback = Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+        // Only the latest entry matters if the element crossed the threshold several times
+        if let Some(entry) = entries.iter().last() {
+            let entry: IntersectionObserverEntry = entry.unchecked_into();
+            visible.set(entry.is_intersecting());
+        }
+    });
+
+    let init = IntersectionObserverInit::new();
+    init.set_threshold(&options.threshold.into());
+    init.set_root_margin(&options.root_margin);
+
+    match IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init) {
+        Ok(observer) => {
+            observer.observe(&element);
+            Some(ViewportObserver {
+                observer,
+                element,
+                _callback: callback,
+            })
+        }
+        Err(err) => {
+            tracing::error!("Failed to create an IntersectionObserver: {err:?}");
+            None
+        }
+    }
+}
+
+struct ViewportObserver {
+    observer: IntersectionObserver,
+    element: web_sys::Element,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl Drop for ViewportObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
@@ -0,0 +1,138 @@
+use dioxus_core::use_hook;
+use dioxus_signals::{CopyValue, ReadSignal, ReadableExt, Signal, WritableExt};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::{Event, Window};
+
+/// Track the inner size of the browser window in css pixels as `(width, height)`.
+///
+/// The signal updates at most once per animation frame while the window is being resized, and the listener is
+/// removed when the component is dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::use_window_size;
+/// fn App() -> Element {
+///     let size = use_window_size();
+///     let (width, height) = size();
+///
+///     rsx! { "The window is {width}x{height}" }
+/// }
+/// ```
+pub fn use_window_size() -> ReadSignal<(f64, f64)> {
+    use_window_metric("resize", |window| {
+        let dimension = |value: Result<wasm_bindgen::JsValue, _>| {
+            value
+                .ok()
+                .and_then(|value| value.as_f64())
+                .unwrap_or_default()
+        };
+        (
+            dimension(window.inner_width()),
+            dimension(window.inner_height()),
+        )
+    })
+}
+
+/// Track the scroll position of the page in css pixels as `(x, y)`.
+///
+/// The signal updates at most once per animation frame while the page is scrolling, and the listener is removed
+/// when the component is dropped.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_web::use_scroll_position;
+/// fn App() -> Element {
+///     let scroll = use_scroll_position();
+///
+///     rsx! {
+///         if scroll().1 > 200.0 {
+///             a { href: "#top", "Back to top" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_scroll_position() -> ReadSignal<(f64, f64)> {
+    use_window_metric("scroll", |window| {
+        (
+            window.scroll_x().unwrap_or_default(),
+            window.scroll_y().unwrap_or_default(),
+        )
+    })
+}
+
+/// Read a value from the window when it is created and again on the next animation frame after each event
+fn use_window_metric(
+    event: &'static str,
+    read: fn(&Window) -> (f64, f64),
+) -> ReadSignal<(f64, f64)> {
+    // The listener is owned by the component, so dropping the component removes it
+    let (value, _) = use_hook(|| {
+        let Some(window) = web_sys::window() else {
+            return (Signal::new((0.0, 0.0)), CopyValue::new(None));
+        };
+
+        let mut value = Signal::new(read(&window));
+        let frame = Rc::new(Cell::new(None));
+        let update = Closure::<dyn FnMut()>::new({
+            let window = window.clone();
+            let frame = frame.clone();
+            move || {
+                frame.set(None);
+                let new = read(&window);
+                if new != *value.peek() {
+                    value.set(new);
+                }
+            }
+        });
+
+        // Batch all events until the next frame into a single update
+        let callback = Closure::<dyn FnMut(Event)>::new({
+            let window = window.clone();
+            let frame = frame.clone();
+            let update = update.as_ref().unchecked_ref::<js_sys::Function>().clone();
+            move |_: Event| {
+                if frame.get().is_none() {
+                    frame.set(window.request_animation_frame(&update).ok());
+                }
+            }
+        });
+        if let Err(err) =
+            window.add_event_listener_with_callback(event, callback.as_ref().unchecked_ref())
+        {
+            tracing::error!("Failed to listen to {event} events: {err:?}");
+        }
+
+        let listener = CopyValue::new(Some(WindowListener {
+            window,
+            event,
+            frame,
+            callback,
+            _update: update,
+        }));
+        (value, listener)
+    });
+
+    value.into()
+}
+
+struct WindowListener {
+    window: Window,
+    event: &'static str,
+    frame: Rc<Cell<Option<i32>>>,
+    callback: Closure<dyn FnMut(Event)>,
+    _update: Closure<dyn FnMut()>,
+}
+
+impl Drop for WindowListener {
+    fn drop(&mut self) {
+        // The update closure is freed with the listener, so it can't run in a pending frame
+        if let Some(frame) = self.frame.take() {
+            _ = self.window.cancel_animation_frame(frame);
+        }
+        _ = self.window.remove_event_listener_with_callback(
+            self.event,
+            self.callback.as_ref().unchecked_ref(),
+        );
+    }
+}